@@ -587,7 +587,7 @@ def temporal_SEIR(graph: GraphView, seeds: int | float | list[NodeInput], infect
 
     """
 
-def louvain(graph: GraphView, resolution: float = 1.0, weight_prop: str | None = None, tol: None | float = None) -> NodeStateUsize:
+def louvain(graph: GraphView, resolution: float = 1.0, weight_prop: str | None = None, tol: None | float = None, seed: Optional[bytes] = None) -> NodeStateUsize:
     """
     Louvain algorithm for community detection
 
@@ -596,6 +596,7 @@ def louvain(graph: GraphView, resolution: float = 1.0, weight_prop: str | None =
         resolution (float): the resolution parameter for modularity. Defaults to 1.0.
         weight_prop (str | None): the edge property to use for weights (has to be float)
         tol (None | float): the floating point tolerance for deciding if improvements are significant (default: 1e-8)
+        seed (bytes, optional): Array of 32 bytes of u8 which is set as the rng seed
 
     Returns:
         NodeStateUsize: Mapping of nodes to their community assignment
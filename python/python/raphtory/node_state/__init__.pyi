@@ -18,6 +18,7 @@ from raphtory.graphql import *
 from raphtory.typing import *
 from datetime import datetime
 from pandas import DataFrame
+import numpy as np
 from os import PathLike
 import networkx as nx  # type: ignore
 import pyvis  # type: ignore
@@ -369,6 +370,17 @@ class DegreeView(object):
              Optional[Tuple[Node, int]]: The Node and minimum value or `None` if empty
         """
 
+    def node_ids(self) -> np.ndarray:
+        """
+        The ids of the nodes as a numpy array
+
+        Integer ids are returned as an array of unsigned integers and string ids as an
+        array of objects.
+
+        Returns:
+            np.ndarray: one-dimensional array of the node ids
+        """
+
     def nodes(self) -> Nodes:
         """
         Iterate over nodes
@@ -504,6 +516,17 @@ class DegreeView(object):
             DataFrame: the pandas DataFrame
         """
 
+    def to_numpy(self) -> np.ndarray:
+        """
+        Convert the values to a numpy array
+
+        The values are in the same order as the ids returned by `node_ids()`, i.e., the
+        value at position `i` belongs to the `i`-th node of the node state.
+
+        Returns:
+            np.ndarray: one-dimensional array of the values
+        """
+
     def top_k(self, k: int) -> NodeStateUsize:
         """
         Compute the k largest values
@@ -683,6 +706,17 @@ class NodeStateUsize(object):
              Optional[Tuple[Node, int]]: The Node and minimum value or `None` if empty
         """
 
+    def node_ids(self) -> np.ndarray:
+        """
+        The ids of the nodes as a numpy array
+
+        Integer ids are returned as an array of unsigned integers and string ids as an
+        array of objects.
+
+        Returns:
+            np.ndarray: one-dimensional array of the node ids
+        """
+
     def nodes(self) -> Nodes:
         """
         Iterate over nodes
@@ -729,6 +763,17 @@ class NodeStateUsize(object):
             DataFrame: the pandas DataFrame
         """
 
+    def to_numpy(self) -> np.ndarray:
+        """
+        Convert the values to a numpy array
+
+        The values are in the same order as the ids returned by `node_ids()`, i.e., the
+        value at position `i` belongs to the `i`-th node of the node state.
+
+        Returns:
+            np.ndarray: one-dimensional array of the values
+        """
+
     def top_k(self, k: int) -> NodeStateUsize:
         """
         Compute the k largest values
@@ -867,6 +912,17 @@ class NodeStateU64(object):
              Optional[Tuple[Node, int]]: The Node and minimum value or `None` if empty
         """
 
+    def node_ids(self) -> np.ndarray:
+        """
+        The ids of the nodes as a numpy array
+
+        Integer ids are returned as an array of unsigned integers and string ids as an
+        array of objects.
+
+        Returns:
+            np.ndarray: one-dimensional array of the node ids
+        """
+
     def nodes(self) -> Nodes:
         """
         Iterate over nodes
@@ -913,6 +969,17 @@ class NodeStateU64(object):
             DataFrame: the pandas DataFrame
         """
 
+    def to_numpy(self) -> np.ndarray:
+        """
+        Convert the values to a numpy array
+
+        The values are in the same order as the ids returned by `node_ids()`, i.e., the
+        value at position `i` belongs to the `i`-th node of the node state.
+
+        Returns:
+            np.ndarray: one-dimensional array of the values
+        """
+
     def top_k(self, k: int) -> NodeStateU64:
         """
         Compute the k largest values
@@ -4657,6 +4724,17 @@ class EdgeHistoryCountView(object):
              Optional[Tuple[Node, int]]: The Node and minimum value or `None` if empty
         """
 
+    def node_ids(self) -> np.ndarray:
+        """
+        The ids of the nodes as a numpy array
+
+        Integer ids are returned as an array of unsigned integers and string ids as an
+        array of objects.
+
+        Returns:
+            np.ndarray: one-dimensional array of the node ids
+        """
+
     def nodes(self) -> Nodes:
         """
         Iterate over nodes
@@ -4792,6 +4870,17 @@ class EdgeHistoryCountView(object):
             DataFrame: the pandas DataFrame
         """
 
+    def to_numpy(self) -> np.ndarray:
+        """
+        Convert the values to a numpy array
+
+        The values are in the same order as the ids returned by `node_ids()`, i.e., the
+        value at position `i` belongs to the `i`-th node of the node state.
+
+        Returns:
+            np.ndarray: one-dimensional array of the values
+        """
+
     def top_k(self, k: int) -> EdgeHistoryCountView:
         """
         Compute the k largest values
@@ -6388,6 +6477,17 @@ class NodeStateF64(object):
              Optional[Tuple[Node, float]]: The Node and minimum value or `None` if empty
         """
 
+    def node_ids(self) -> np.ndarray:
+        """
+        The ids of the nodes as a numpy array
+
+        Integer ids are returned as an array of unsigned integers and string ids as an
+        array of objects.
+
+        Returns:
+            np.ndarray: one-dimensional array of the node ids
+        """
+
     def nodes(self) -> Nodes:
         """
         Iterate over nodes
@@ -6434,6 +6534,17 @@ class NodeStateF64(object):
             DataFrame: the pandas DataFrame
         """
 
+    def to_numpy(self) -> np.ndarray:
+        """
+        Convert the values to a numpy array
+
+        The values are in the same order as the ids returned by `node_ids()`, i.e., the
+        value at position `i` belongs to the `i`-th node of the node state.
+
+        Returns:
+            np.ndarray: one-dimensional array of the values
+        """
+
     def top_k(self, k: int) -> NodeStateF64:
         """
         Compute the k largest values
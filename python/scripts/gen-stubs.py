@@ -13,6 +13,7 @@ imports = [
     "from raphtory.typing import *",
     "from datetime import datetime",
     "from pandas import DataFrame",
+    "import numpy as np",
     "from os import PathLike",
     "import networkx as nx  # type: ignore",
     "import pyvis  # type: ignore",
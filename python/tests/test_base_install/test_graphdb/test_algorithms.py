@@ -1,6 +1,7 @@
 import pytest
 import pandas as pd
 import pandas.core.frame
+import numpy as np
 
 from raphtory import Graph
 from raphtory import algorithms
@@ -312,6 +313,36 @@ def test_page_rank():
     assert actual == expected
 
 
+def test_node_state_to_numpy():
+    g = gen_graph()
+    ranks = algorithms.pagerank(g)
+    values = ranks.to_numpy()
+    ids = ranks.node_ids()
+    assert values.shape == (8,)
+    assert values.dtype == np.float64
+    assert ids.shape == (8,)
+    assert ids.dtype == np.uint64
+    assert dict(zip(ids.tolist(), values.tolist())) == {
+        node.id: value for node, value in ranks.items()
+    }
+
+    degrees = g.window(0, 12).nodes.degree()
+    assert degrees.to_numpy().dtype == np.uint64
+    assert degrees.to_numpy().tolist() == list(degrees)
+    assert degrees.node_ids().tolist() == g.window(0, 12).nodes.id.collect()
+
+    sorted_ranks = ranks.sorted()
+    assert np.all(np.diff(sorted_ranks.to_numpy()) >= 0)
+    assert sorted_ranks.node_ids()[-1] in (4, 5)
+
+    g = Graph()
+    g.add_edge(0, "a", "b")
+    degrees = g.nodes.degree()
+    assert degrees.node_ids().dtype == object
+    assert degrees.node_ids().tolist() == ["a", "b"]
+    assert Graph().nodes.degree().to_numpy().shape == (0,)
+
+
 def test_temporal_reachability():
     g = gen_graph()
 
@@ -130,6 +130,17 @@ impl EdgeRef {
         e_ref
     }
 
+    /// The same edge with source and destination swapped, `local()` and `remote()` are unchanged
+    #[inline]
+    pub fn reversed(mut self) -> Self {
+        std::mem::swap(&mut self.src_pid, &mut self.dst_pid);
+        self.e_type = match self.e_type {
+            Dir::Into => Dir::Out,
+            Dir::Out => Dir::Into,
+        };
+        self
+    }
+
     #[inline]
     pub fn unexplode(mut self) -> Self {
         self.layer_id = None;
@@ -18,6 +18,17 @@ pub enum Direction {
     BOTH,
 }
 
+impl Direction {
+    /// The opposite direction, `BOTH` is unchanged
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::OUT => Direction::IN,
+            Direction::IN => Direction::OUT,
+            Direction::BOTH => Direction::BOTH,
+        }
+    }
+}
+
 #[derive(
     Iterator,
     DoubleEndedIterator,
@@ -12,7 +12,7 @@ use raphtory::{
             local_triangle_count::local_triangle_count,
         },
     },
-    graphgen::random_attachment::random_attachment,
+    graphgen::{random_attachment::random_attachment, TimestampStrategy},
     prelude::*,
 };
 use raphtory_benchmark::common::bench;
@@ -54,7 +54,7 @@ pub fn graphgen_large_clustering_coeff(c: &mut Criterion) {
     // generate graph
     let graph = Graph::new();
     let seed: [u8; 32] = [1; 32];
-    random_attachment(&graph, 500000, 4, Some(seed));
+    random_attachment(&graph, 500000, 4, TimestampStrategy::Sequential, Some(seed)).unwrap();
 
     group.sampling_mode(SamplingMode::Flat);
     group.measurement_time(std::time::Duration::from_secs(60));
@@ -77,7 +77,7 @@ pub fn graphgen_large_pagerank(c: &mut Criterion) {
     // generate graph
     let graph = Graph::new();
     let seed: [u8; 32] = [1; 32];
-    random_attachment(&graph, 500000, 4, Some(seed));
+    random_attachment(&graph, 500000, 4, TimestampStrategy::Sequential, Some(seed)).unwrap();
 
     group.sampling_mode(SamplingMode::Flat);
     group.measurement_time(std::time::Duration::from_secs(20));
@@ -100,7 +100,7 @@ pub fn graphgen_large_concomp(c: &mut Criterion) {
     // generate graph
     let graph = Graph::new();
     let seed: [u8; 32] = [1; 32];
-    random_attachment(&graph, 500000, 4, Some(seed));
+    random_attachment(&graph, 500000, 4, TimestampStrategy::Sequential, Some(seed)).unwrap();
 
     group.sampling_mode(SamplingMode::Flat);
     group.measurement_time(std::time::Duration::from_secs(60));
@@ -13,7 +13,7 @@ pub mod arrow_bench {
             },
             motifs::local_triangle_count::local_triangle_count,
         },
-        graphgen::random_attachment::random_attachment,
+        graphgen::{random_attachment::random_attachment, TimestampStrategy},
         prelude::*,
     };
     use raphtory_benchmark::common::bench;
@@ -92,7 +92,7 @@ pub mod arrow_bench {
         // generate graph
         let graph = Graph::new();
         let seed: [u8; 32] = [1; 32];
-        random_attachment(&graph, 500000, 4, Some(seed));
+        random_attachment(&graph, 500000, 4, TimestampStrategy::Sequential, Some(seed)).unwrap();
 
         let test_dir = TempDir::new().unwrap();
         let graph = graph.persist_as_disk_graph(test_dir.path()).unwrap();
@@ -118,7 +118,7 @@ pub mod arrow_bench {
         // generate graph
         let graph = Graph::new();
         let seed: [u8; 32] = [1; 32];
-        random_attachment(&graph, 500000, 4, Some(seed));
+        random_attachment(&graph, 500000, 4, TimestampStrategy::Sequential, Some(seed)).unwrap();
 
         let test_dir = TempDir::new().unwrap();
         let graph = graph.persist_as_disk_graph(test_dir.path()).unwrap();
@@ -143,7 +143,7 @@ pub mod arrow_bench {
         // generate graph
         let graph = Graph::new();
         let seed: [u8; 32] = [1; 32];
-        random_attachment(&graph, 500000, 4, Some(seed));
+        random_attachment(&graph, 500000, 4, TimestampStrategy::Sequential, Some(seed)).unwrap();
         let test_dir = TempDir::new().unwrap();
         let graph = graph.persist_as_disk_graph(test_dir.path()).unwrap();
 
@@ -18,6 +18,7 @@ use crate::{
 };
 use dashmap::DashSet;
 use either::Either;
+use itertools::Itertools;
 use raphtory_api::core::{
     entities::{
         properties::{meta::Meta, prop::Prop},
@@ -54,9 +55,9 @@ pub struct TemporalGraph {
 }
 
 #[derive(Error, Debug)]
-#[error("Invalid layer: {invalid_layer}. Valid layers: {valid_layers}")]
+#[error("Invalid layer: {}. Valid layers: {valid_layers}", .invalid_layers.iter().join(", "))]
 pub struct InvalidLayer {
-    invalid_layer: ArcStr,
+    invalid_layers: Vec<ArcStr>,
     valid_layers: String,
 }
 
@@ -66,12 +67,22 @@ pub struct TooManyLayers;
 
 impl InvalidLayer {
     pub fn new(invalid_layer: ArcStr, valid: Vec<String>) -> Self {
+        Self::new_multiple(vec![invalid_layer], valid)
+    }
+
+    /// Error for a request where all the layers in `invalid_layers` do not exist
+    pub fn new_multiple(invalid_layers: Vec<ArcStr>, valid: Vec<String>) -> Self {
         let valid_layers = valid.join(", ");
         Self {
-            invalid_layer,
+            invalid_layers,
             valid_layers,
         }
     }
+
+    /// The requested layers that do not exist
+    pub fn invalid_layers(&self) -> &[ArcStr] {
+        &self.invalid_layers
+    }
 }
 
 impl std::fmt::Display for TemporalGraph {
@@ -163,14 +174,16 @@ impl TemporalGraph {
                 )),
             },
             Layer::Multiple(ids) => {
-                let mut new_layers = ids
+                let (mut new_layers, invalid): (Vec<_>, Vec<_>) = ids
                     .iter()
-                    .map(|id| {
-                        self.edge_meta.get_layer_id(id).ok_or_else(|| {
-                            InvalidLayer::new(id.clone(), Self::get_valid_layers(&self.edge_meta))
-                        })
-                    })
-                    .collect::<Result<Vec<_>, InvalidLayer>>()?;
+                    .map(|id| self.edge_meta.get_layer_id(id).ok_or_else(|| id.clone()))
+                    .partition_result();
+                if !invalid.is_empty() {
+                    return Err(InvalidLayer::new_multiple(
+                        invalid,
+                        Self::get_valid_layers(&self.edge_meta),
+                    ));
+                }
                 let num_layers = self.num_layers();
                 let num_new_layers = new_layers.len();
                 if num_new_layers == 0 {
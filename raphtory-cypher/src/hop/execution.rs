@@ -571,7 +571,10 @@ mod test {
     use proptest::{prelude::*, proptest};
     use tempfile::tempdir;
 
-    use raphtory::{graphgen::random_attachment::random_attachment, prelude::*};
+    use raphtory::{
+        graphgen::{random_attachment::random_attachment, TimestampStrategy},
+        prelude::*,
+    };
 
     use crate::run_cypher;
 
@@ -637,7 +640,7 @@ mod test {
 
     fn graph_gen_edges(num_nodes: usize) -> Vec<(u64, u64, i64, f64)> {
         let graph = Graph::new();
-        random_attachment(&graph, num_nodes, 10, None);
+        random_attachment(&graph, num_nodes, 10, TimestampStrategy::Sequential, None).unwrap();
         let mut edges = vec![];
         for edge in graph.edges().into_iter() {
             for e in edge.explode() {
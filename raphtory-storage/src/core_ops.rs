@@ -101,6 +101,12 @@ pub trait CoreGraphOps: Send + Sync {
 
     fn core_graph(&self) -> &GraphStorage;
 
+    /// If true, the source and destination of all edges in the storage are swapped in the view
+    #[inline]
+    fn edges_reversed(&self) -> bool {
+        false
+    }
+
     #[inline]
     fn core_edges(&self) -> EdgesStorage {
         self.core_graph().owned_edges()
@@ -273,6 +279,11 @@ where
     fn core_graph(&self) -> &GraphStorage {
         self.base().core_graph()
     }
+
+    #[inline]
+    fn edges_reversed(&self) -> bool {
+        self.base().edges_reversed()
+    }
 }
 
 impl<T: ?Sized> InheritCoreGraphOps for Arc<T> {}
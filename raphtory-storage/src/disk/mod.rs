@@ -1,6 +1,7 @@
 use crate::{
     core_ops::CoreGraphOps, disk::graph_impl::prop_conversion::make_node_properties_from_graph,
 };
+use itertools::Itertools;
 use polars_arrow::{
     array::{Array, PrimitiveArray, StructArray},
     datatypes::{ArrowDataType as DataType, Field},
@@ -144,14 +145,16 @@ impl DiskGraphStorage {
             }
             Layer::None => Ok(LayerIds::None),
             Layer::Multiple(names) => {
-                let mut new_layers = names
+                let (mut new_layers, invalid): (Vec<_>, Vec<_>) = names
                     .iter()
-                    .map(|name| {
-                        self.inner.find_layer_id(name).ok_or_else(|| {
-                            InvalidLayer::new(name.clone(), self.inner.get_valid_layers())
-                        })
-                    })
-                    .collect::<Result<Vec<_>, _>>()?;
+                    .map(|name| self.inner.find_layer_id(name).ok_or_else(|| name.clone()))
+                    .partition_result();
+                if !invalid.is_empty() {
+                    return Err(InvalidLayer::new_multiple(
+                        invalid,
+                        self.inner.get_valid_layers(),
+                    ));
+                }
 
                 let num_layers = self.inner.num_layers();
                 let num_new_layers = new_layers.len();
@@ -1,8 +1,10 @@
 use crate::{
     core::entities::VID,
     db::{api::state::NodeState, graph::node::NodeView},
-    prelude::{GraphViewOps, NodeViewOps},
+    prelude::{EdgeViewOps, GraphViewOps, NodeViewOps},
 };
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+use rayon::prelude::*;
 use std::collections::{HashMap, VecDeque};
 
 /// Computes the betweenness centrality for nodes in a given graph.
@@ -100,10 +102,243 @@ pub fn betweenness_centrality<'graph, G: GraphViewOps<'graph>>(
     NodeState::new_from_eval(g.clone(), betweenness)
 }
 
+/// Approximates the betweenness centrality for nodes in a given graph.
+///
+/// Runs Brandes' accumulation from `n_samples` source nodes, sampled uniformly without
+/// replacement, and scales the result by `n / n_samples` to estimate the betweenness centrality
+/// over all sources. The searches from the sampled sources run in parallel. If `n_samples` is at
+/// least the number of nodes, the exact [betweenness_centrality] is computed instead.
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph.
+/// - `n_samples`: The number of source nodes to sample.
+/// - `seed`: Option, the seed for sampling the source nodes. If set, the result is deterministic.
+/// - `normalized`: If `true` normalize the centrality values.
+///
+/// # Returns
+///
+/// A NodeState containing the estimated betweenness centrality of each node.
+pub fn betweenness_centrality_approx<'graph, G: GraphViewOps<'graph>>(
+    g: &G,
+    n_samples: usize,
+    seed: Option<u64>,
+    normalized: bool,
+) -> NodeState<'graph, f64, G> {
+    let nodes: Vec<VID> = g.nodes().iter_refs().collect();
+    let n = nodes.len();
+    if n_samples >= n {
+        return betweenness_centrality(g, None, normalized);
+    }
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let sources: Vec<VID> = sample(&mut rng, n, n_samples)
+        .into_iter()
+        .map(|i| nodes[i])
+        .collect();
+
+    let num_nodes = g.unfiltered_num_nodes();
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; num_nodes];
+    for node in g.nodes().iter() {
+        adjacency[node.node.index()] = node
+            .out_neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .collect();
+    }
+
+    let mut betweenness = sources
+        .par_iter()
+        .map(|source| dependencies(&adjacency, source.index()))
+        .reduce(
+            || vec![0.0; num_nodes],
+            |mut acc, dependencies| {
+                for (total, dependency) in acc.iter_mut().zip(dependencies) {
+                    *total += dependency;
+                }
+                acc
+            },
+        );
+
+    let mut factor = n as f64 / n_samples as f64;
+    if normalized {
+        factor /= (n as f64 - 1.0) * (n as f64 - 2.0);
+    }
+    for value in betweenness.iter_mut() {
+        *value *= factor;
+    }
+
+    NodeState::new_from_eval(g.clone(), betweenness)
+}
+
+/// Brandes' single-source step, returns the dependency of the source on every node.
+fn dependencies(adjacency: &[Vec<usize>], source: usize) -> Vec<f64> {
+    let mut dist: Vec<Option<usize>> = vec![None; adjacency.len()];
+    let mut sigma = vec![0.0; adjacency.len()];
+    let mut predecessors: Vec<Vec<usize>> = vec![vec![]; adjacency.len()];
+    let mut stack = Vec::new();
+    let mut queue = VecDeque::from([source]);
+    dist[source] = Some(0);
+    sigma[source] = 1.0;
+
+    // BFS to count the shortest paths.
+    while let Some(current) = queue.pop_front() {
+        stack.push(current);
+        let d = dist[current].unwrap() + 1;
+        for &neighbour in &adjacency[current] {
+            if *dist[neighbour].get_or_insert_with(|| {
+                queue.push_back(neighbour);
+                d
+            }) == d
+            {
+                sigma[neighbour] += sigma[current];
+                predecessors[neighbour].push(current);
+            }
+        }
+    }
+
+    // Accumulation
+    let mut delta = vec![0.0; adjacency.len()];
+    while let Some(w) = stack.pop() {
+        for &v in &predecessors[w] {
+            delta[v] += (sigma[v] / sigma[w]) * (1.0 + delta[w]);
+        }
+    }
+    delta[source] = 0.0;
+    delta
+}
+
+/// Computes the temporal betweenness centrality for nodes in a given graph.
+///
+/// This is an adaptation of Brandes' algorithm that only counts temporally consistent paths,
+/// i.e., paths where the timestamp of every hop is greater than or equal to the timestamp of the
+/// previous hop. Among those, only the paths with the fewest hops between two nodes are
+/// considered shortest. The searches from the different source nodes run in parallel.
+///
+/// If all edges occur at the same time, the result is the same as for [betweenness_centrality].
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph.
+///
+/// # Returns
+///
+/// A NodeState containing the temporal betweenness centrality of each node, normalised by `(n-1)(n-2)`.
+pub fn temporal_betweenness_centrality<'graph, G: GraphViewOps<'graph>>(
+    g: &G,
+) -> NodeState<'graph, f64, G> {
+    let num_nodes = g.unfiltered_num_nodes();
+
+    // Time-sorted out-edges of every node, parallel updates at the same time are counted once.
+    let mut adjacency: Vec<Vec<(i64, usize)>> = vec![vec![]; num_nodes];
+    for edge in g.edges().explode().iter() {
+        if let Ok(t) = edge.time() {
+            adjacency[edge.src().node.index()].push((t, edge.dst().node.index()));
+        }
+    }
+    for out_edges in adjacency.iter_mut() {
+        out_edges.sort_unstable();
+        out_edges.dedup();
+    }
+
+    let mut betweenness = g
+        .nodes()
+        .par_iter_refs()
+        .map(|source| temporal_dependencies(&adjacency, source.index()))
+        .reduce(
+            || vec![0.0; num_nodes],
+            |mut acc, dependencies| {
+                for (total, dependency) in acc.iter_mut().zip(dependencies) {
+                    *total += dependency;
+                }
+                acc
+            },
+        );
+
+    // Normalization
+    let n = g.count_nodes() as f64;
+    if n > 2.0 {
+        let factor = 1.0 / ((n - 1.0) * (n - 2.0));
+        for value in betweenness.iter_mut() {
+            *value *= factor;
+        }
+    }
+
+    NodeState::new_from_eval(g.clone(), betweenness)
+}
+
+/// Brandes' single-source step over temporal states `(node, arrival time)`.
+///
+/// Shortest temporal paths cannot visit a node twice, so the dependencies of all states of a node
+/// can be summed up.
+fn temporal_dependencies(adjacency: &[Vec<(i64, usize)>], source: usize) -> Vec<f64> {
+    let mut state_ids: HashMap<(usize, i64), usize> = HashMap::new();
+    let mut states = vec![(source, i64::MIN)];
+    let mut dist = vec![0usize];
+    let mut sigma = vec![1.0];
+    let mut predecessors: Vec<Vec<usize>> = vec![vec![]];
+    let mut node_dist: Vec<Option<usize>> = vec![None; adjacency.len()];
+    let mut node_sigma = vec![0.0; adjacency.len()];
+    let mut stack = Vec::new();
+    let mut queue = VecDeque::from([0]);
+
+    // BFS over states to count the shortest temporal paths.
+    while let Some(current) = queue.pop_front() {
+        stack.push(current);
+        let (node, time) = states[current];
+        let d = dist[current];
+        if *node_dist[node].get_or_insert(d) == d {
+            node_sigma[node] += sigma[current];
+        }
+        let out_edges = &adjacency[node];
+        let first = out_edges.partition_point(|(t, _)| *t < time);
+        for &(t, neighbour) in &out_edges[first..] {
+            let next = *state_ids.entry((neighbour, t)).or_insert_with(|| {
+                states.push((neighbour, t));
+                dist.push(d + 1);
+                sigma.push(0.0);
+                predecessors.push(vec![]);
+                queue.push_back(states.len() - 1);
+                states.len() - 1
+            });
+            if dist[next] == d + 1 {
+                sigma[next] += sigma[current];
+                predecessors[next].push(current);
+            }
+        }
+    }
+
+    // Accumulation, `delta` only counts paths continuing past a state, `ends_here` is the
+    // fraction of shortest paths to the node of a state that end in that state.
+    let mut delta = vec![0.0; states.len()];
+    let mut dependencies = vec![0.0; adjacency.len()];
+    while let Some(w) = stack.pop() {
+        let node = states[w].0;
+        let ends_here = if node != source && node_dist[node] == Some(dist[w]) {
+            sigma[w] / node_sigma[node]
+        } else {
+            0.0
+        };
+        for &v in &predecessors[w] {
+            delta[v] += (sigma[v] / sigma[w]) * (ends_here + delta[w]);
+        }
+        if node != source {
+            dependencies[node] += delta[w];
+        }
+    }
+    dependencies
+}
+
 #[cfg(test)]
 mod betweenness_centrality_test {
     use super::*;
-    use crate::{prelude::*, test_storage};
+    use crate::{
+        graphgen::{erdos_renyl::erdos_renyl, TimestampStrategy},
+        prelude::*,
+        test_storage,
+    };
 
     #[test]
     fn test_betweenness_centrality() {
@@ -149,4 +384,141 @@ mod betweenness_centrality_test {
             assert_eq!(res, expected);
         });
     }
+
+    fn load_random_graph() -> Graph {
+        let graph = Graph::new();
+        erdos_renyl(
+            &graph,
+            60,
+            0.08,
+            TimestampStrategy::Sequential,
+            None,
+            Some([7; 32]),
+        )
+        .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_betweenness_approx_all_samples_is_exact() {
+        let graph = load_random_graph();
+
+        test_storage!(&graph, |graph| {
+            let n = graph.count_nodes();
+            for normalized in [false, true] {
+                let exact = betweenness_centrality(graph, None, normalized);
+                let approx = betweenness_centrality_approx(graph, n, Some(1), normalized);
+                for node in graph.nodes().iter() {
+                    let diff = approx.get_by_node(node.node).unwrap()
+                        - exact.get_by_node(node.node).unwrap();
+                    assert!(diff.abs() < 1e-9);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_betweenness_approx_seeded_is_reproducible() {
+        let graph = load_random_graph();
+
+        test_storage!(&graph, |graph| {
+            let first = betweenness_centrality_approx(graph, 20, Some(42), true);
+            let second = betweenness_centrality_approx(graph, 20, Some(42), true);
+            // the sources are the same, only the order of the parallel sums can differ
+            for (a, b) in first.iter_values().zip(second.iter_values()) {
+                assert!((a - b).abs() < 1e-12);
+            }
+            assert!(first.iter_values().any(|v| *v > 0.0));
+        });
+    }
+
+    #[test]
+    fn test_betweenness_approx_finds_bridge() {
+        let graph = Graph::new();
+        // two cliques 1-2-3 and 5-6-7 connected through 4
+        for (a, b) in [
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (5, 6),
+            (5, 7),
+            (6, 7),
+            (3, 4),
+            (4, 5),
+        ] {
+            graph.add_edge(0, a, b, NO_PROPS, None).unwrap();
+            graph.add_edge(0, b, a, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let approx = betweenness_centrality_approx(graph, 4, Some(3), false);
+            let bridge = *approx.get_by_node(4).unwrap();
+            for node in [1, 2, 6, 7] {
+                assert!(*approx.get_by_node(node).unwrap() < bridge);
+            }
+        });
+    }
+
+    #[test]
+    fn test_temporal_betweenness_matches_static_for_single_time() {
+        let graph = Graph::new();
+        let vs = vec![
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (2, 5),
+            (3, 4),
+            (3, 5),
+            (3, 6),
+            (4, 3),
+            (4, 2),
+            (4, 4),
+        ];
+        for (src, dst) in &vs {
+            graph.add_edge(0, *src, *dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let temporal = temporal_betweenness_centrality(graph);
+            let expected = betweenness_centrality(graph, None, true);
+            for node in graph.nodes().iter() {
+                let diff = temporal.get_by_node(node.node).unwrap()
+                    - expected.get_by_node(node.node).unwrap();
+                assert!(diff.abs() < 1e-9);
+            }
+        });
+    }
+
+    #[test]
+    fn test_temporal_betweenness_brief_bridge() {
+        let graph = Graph::new();
+        // two communities 1-2-3 and 5-6-7 can only reach each other through 4
+        for (a, b) in [(1, 2), (1, 3), (2, 3)] {
+            graph.add_edge(1, a, b, NO_PROPS, None).unwrap();
+            graph.add_edge(1, b, a, NO_PROPS, None).unwrap();
+        }
+        for (a, b) in [(5, 6), (5, 7), (6, 7)] {
+            graph.add_edge(20, a, b, NO_PROPS, None).unwrap();
+            graph.add_edge(20, b, a, NO_PROPS, None).unwrap();
+        }
+        // the bridge is only open briefly, so only paths from the first community to the second
+        // are temporally consistent
+        graph.add_edge(10, 3, 4, NO_PROPS, None).unwrap();
+        graph.add_edge(11, 4, 5, NO_PROPS, None).unwrap();
+        graph.add_edge(12, 5, 4, NO_PROPS, None).unwrap();
+        graph.add_edge(13, 4, 3, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let temporal = *temporal_betweenness_centrality(graph)
+                .get_by_node("4")
+                .unwrap();
+            let static_score = *betweenness_centrality(graph, None, true)
+                .get_by_node("4")
+                .unwrap();
+            assert!(temporal > 0.0);
+            assert!(temporal < static_score);
+        });
+    }
 }
@@ -0,0 +1,155 @@
+use crate::{
+    db::api::{state::NodeState, view::StaticGraphViewOps},
+    prelude::*,
+};
+use rayon::prelude::*;
+
+/// Computes the temporal closeness centrality of all nodes in the graph.
+///
+/// A node can only reach another node along a temporal path, i.e., a path following edge updates
+/// (see `explode`) with non-decreasing timestamps. The distance between two nodes is the smallest
+/// number of hops of any temporal path between them. The closeness of a node is the reciprocal of
+/// the average distance to the nodes it can reach, multiplied by the fraction of the other nodes
+/// it can reach (Wasserman–Faust normalisation), nodes that cannot reach any other node have
+/// closeness 0.
+///
+/// The distances are found by relaxing the earliest arrival time at every node one hop at a time,
+/// the searches from the different nodes run in parallel.
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph.
+///
+/// # Returns
+///
+/// A [NodeState] containing the temporal closeness centrality of each node.
+pub fn closeness<G: StaticGraphViewOps>(g: &G) -> NodeState<'static, f64, G> {
+    let mut adjacency: Vec<Vec<(usize, i64)>> = vec![vec![]; g.unfiltered_num_nodes()];
+    let out_edges: Vec<(usize, Vec<(usize, i64)>)> = g
+        .par_nodes()
+        .map(|node| {
+            let out_edges = node
+                .out_edges()
+                .explode()
+                .into_iter()
+                .filter_map(|edge| Some((edge.nbr().node.index(), edge.time().ok()?)))
+                .collect();
+            (node.node.index(), out_edges)
+        })
+        .collect();
+    for (node, out_edges) in out_edges {
+        adjacency[node] = out_edges;
+    }
+    let adjacency = &adjacency;
+    let num_others = g.count_nodes().saturating_sub(1);
+
+    let values: Vec<f64> = g
+        .nodes()
+        .par_iter()
+        .map(|node| {
+            let source = node.node.index();
+            // earliest arrival at each node using at most the current number of hops
+            let mut arrival: Vec<Option<i64>> = vec![None; adjacency.len()];
+            arrival[source] = Some(i64::MIN);
+            let mut reached = vec![false; adjacency.len()];
+            reached[source] = true;
+            let mut frontier = vec![source];
+            let mut hops = 0;
+            let mut num_reached = 0usize;
+            let mut total_distance = 0usize;
+            while !frontier.is_empty() {
+                hops += 1;
+                let mut next_arrival = arrival.clone();
+                let mut next_frontier = vec![];
+                for &current in &frontier {
+                    let time = arrival[current].unwrap();
+                    for &(neighbour, edge_time) in &adjacency[current] {
+                        if edge_time >= time
+                            && next_arrival[neighbour].is_none_or(|earliest| edge_time < earliest)
+                        {
+                            if next_arrival[neighbour] == arrival[neighbour] {
+                                next_frontier.push(neighbour);
+                            }
+                            next_arrival[neighbour] = Some(edge_time);
+                        }
+                    }
+                }
+                for &neighbour in &next_frontier {
+                    if !reached[neighbour] {
+                        reached[neighbour] = true;
+                        num_reached += 1;
+                        total_distance += hops;
+                    }
+                }
+                arrival = next_arrival;
+                frontier = next_frontier;
+            }
+            if num_reached == 0 {
+                0f64
+            } else {
+                (num_reached as f64 / total_distance as f64)
+                    * (num_reached as f64 / num_others as f64)
+            }
+        })
+        .collect();
+
+    NodeState::new_from_values(g.clone(), values)
+}
+
+#[cfg(test)]
+mod closeness_test {
+    use super::*;
+    use crate::{algorithms::centrality::pagerank::page_rank_tests::assert_eq_f64, test_storage};
+
+    fn load_graph(edges: &[(i64, u64, u64)]) -> Graph {
+        let graph = Graph::new();
+        for &(t, src, dst) in edges {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_time_respecting_path() {
+        // 1 -> 2 -> 3 is a temporal path, 2 -> 3 -> 4 is not
+        let graph = load_graph(&[(1, 1, 2), (2, 2, 3), (1, 3, 4)]);
+
+        test_storage!(&graph, |graph| {
+            let results = closeness(graph);
+            assert_eq_f64(results.get_by_node(1u64), Some(&(2.0 / 3.0 * 2.0 / 3.0)), 9);
+            assert_eq_f64(results.get_by_node(2u64), Some(&(1.0 / 3.0)), 9);
+            assert_eq_f64(results.get_by_node(3u64), Some(&(1.0 / 3.0)), 9);
+            assert_eq_f64(results.get_by_node(4u64), Some(&0.0), 9);
+        });
+    }
+
+    #[test]
+    fn test_fewest_hops() {
+        // the earliest arrival at 4 takes three hops but the direct edge later on takes one
+        let graph = load_graph(&[(1, 1, 2), (2, 2, 3), (3, 3, 4), (10, 1, 4)]);
+
+        test_storage!(&graph, |graph| {
+            let results = closeness(graph);
+            assert_eq_f64(results.get_by_node(1u64), Some(&(3.0 / 4.0)), 9);
+        });
+    }
+
+    #[test]
+    fn test_isolated_after_time_10() {
+        let edges = [(1, 2, 3), (2, 3, 4), (5, 1, 2)];
+        let isolated = load_graph(&edges);
+        let connected = load_graph(&edges);
+        connected.add_edge(11, 1, 3, NO_PROPS, None).unwrap();
+        connected.add_edge(12, 1, 4, NO_PROPS, None).unwrap();
+
+        let isolated_closeness = *closeness(&isolated).get_by_node(1u64).unwrap();
+        let connected_closeness = *closeness(&connected).get_by_node(1u64).unwrap();
+        assert_eq_f64(Some(isolated_closeness), Some(1.0 / 3.0), 9);
+        assert_eq_f64(Some(connected_closeness), Some(1.0), 9);
+        assert!(isolated_closeness < connected_closeness);
+
+        // in the window before time 10 both graphs are the same
+        let windowed = closeness(&connected.window(0, 10));
+        assert_eq_f64(windowed.get_by_node(1u64), Some(&isolated_closeness), 9);
+    }
+}
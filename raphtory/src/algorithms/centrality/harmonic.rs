@@ -0,0 +1,141 @@
+use crate::{
+    db::{
+        api::{state::NodeState, view::StaticGraphViewOps},
+        task::{custom_pool, POOL},
+    },
+    prelude::*,
+};
+use raphtory_api::core::Direction;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+
+/// Computes the harmonic centrality of all nodes in the graph.
+///
+/// The harmonic centrality of a node is the sum of the reciprocal (unweighted) distances from
+/// the node to all other nodes, where unreachable nodes contribute 0. Unlike closeness
+/// centrality this is well-defined on disconnected graphs. The distances are found with a
+/// breadth-first search from every node, the searches run in parallel.
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph.
+/// - `direction`: The direction in which edges are followed, `OUT` sums over the distances to
+///   the nodes reachable from a node, `IN` over the distances from the nodes that can reach it
+///   and `BOTH` ignores edge directions.
+/// - `threads`: Number of threads to use for parallel execution
+///
+/// # Returns
+///
+/// A [NodeState] containing the harmonic centrality of each node.
+pub fn harmonic_centrality<G: StaticGraphViewOps>(
+    g: &G,
+    direction: Direction,
+    threads: Option<usize>,
+) -> NodeState<'static, f64, G> {
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; g.unfiltered_num_nodes()];
+    let neighbours: Vec<(usize, Vec<usize>)> = g
+        .par_nodes()
+        .map(|node| {
+            let neighbours = match direction {
+                Direction::OUT => node.out_neighbours(),
+                Direction::IN => node.in_neighbours(),
+                Direction::BOTH => node.neighbours(),
+            };
+            let neighbours = neighbours.iter().map(|n| n.node.index()).collect();
+            (node.node.index(), neighbours)
+        })
+        .collect();
+    for (node, neighbours) in neighbours {
+        adjacency[node] = neighbours;
+    }
+    let adjacency = &adjacency;
+
+    let pool = threads.map(custom_pool).unwrap_or_else(|| POOL.clone());
+    let values: Vec<f64> = pool.install(|| {
+        g.nodes()
+            .par_iter()
+            .map(|node| {
+                let source = node.node.index();
+                let mut dist: Vec<Option<usize>> = vec![None; adjacency.len()];
+                dist[source] = Some(0);
+                let mut queue = VecDeque::from([source]);
+                let mut centrality = 0f64;
+                while let Some(current) = queue.pop_front() {
+                    let next_dist = dist[current].unwrap() + 1;
+                    for &neighbour in &adjacency[current] {
+                        if dist[neighbour].is_none() {
+                            dist[neighbour] = Some(next_dist);
+                            centrality += 1f64 / next_dist as f64;
+                            queue.push_back(neighbour);
+                        }
+                    }
+                }
+                centrality
+            })
+            .collect()
+    });
+
+    NodeState::new_from_values(g.clone(), values)
+}
+
+#[cfg(test)]
+mod harmonic_centrality_test {
+    use super::*;
+    use crate::{algorithms::centrality::pagerank::page_rank_tests::assert_eq_f64, test_storage};
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        // a path 1 -> 2 -> 3 and a separate pair 4 -> 5
+        for (src, dst) in [(1, 2), (2, 3), (4, 5)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_disconnected_components() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let out = harmonic_centrality(graph, Direction::OUT, None);
+            assert!(out.iter_values().all(|v| v.is_finite()));
+            assert_eq_f64(out.get_by_node(1), Some(&1.5), 9);
+            assert_eq_f64(out.get_by_node(2), Some(&1.0), 9);
+            assert_eq_f64(out.get_by_node(3), Some(&0.0), 9);
+            assert_eq_f64(out.get_by_node(4), Some(&1.0), 9);
+
+            let incoming = harmonic_centrality(graph, Direction::IN, Some(2));
+            assert_eq_f64(incoming.get_by_node(1), Some(&0.0), 9);
+            assert_eq_f64(incoming.get_by_node(3), Some(&1.5), 9);
+
+            let both = harmonic_centrality(graph, Direction::BOTH, Some(1));
+            assert_eq_f64(both.get_by_node(2), Some(&2.0), 9);
+            assert_eq_f64(both.get_by_node(1), Some(&1.5), 9);
+            assert_eq_f64(both.get_by_node(5), Some(&1.0), 9);
+        });
+    }
+
+    #[test]
+    fn test_respects_window() {
+        let graph = load_graph();
+        graph.add_edge(10, 3, 4, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let full = harmonic_centrality(graph, Direction::OUT, None);
+            assert_eq_f64(
+                full.get_by_node(1),
+                Some(&(1.0 + 1.0 / 2.0 + 1.0 / 3.0 + 1.0 / 4.0)),
+                9,
+            );
+
+            for windowed in graph.rolling(10, None).unwrap() {
+                let results = harmonic_centrality(&windowed, Direction::OUT, None);
+                if windowed.has_node(1) {
+                    assert_eq_f64(results.get_by_node(1), Some(&1.5), 9);
+                } else {
+                    assert_eq_f64(results.get_by_node(3), Some(&1.0), 9);
+                }
+            }
+        });
+    }
+}
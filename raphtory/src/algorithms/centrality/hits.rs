@@ -4,10 +4,7 @@ use crate::{
         compute_state::ComputeStateVec,
     },
     db::{
-        api::{
-            state::NodeState,
-            view::{NodeViewOps, StaticGraphViewOps},
-        },
+        api::{state::NodeState, view::StaticGraphViewOps},
         task::{
             context::Context,
             node::eval_node::EvalNodeView,
@@ -15,8 +12,11 @@ use crate::{
             task_runner::TaskRunner,
         },
     },
+    errors::GraphError,
+    prelude::*,
 };
 use num_traits::abs;
+use rayon::prelude::*;
 
 #[derive(Debug, Clone)]
 struct Hits {
@@ -151,13 +151,154 @@ pub fn hits<G: StaticGraphViewOps>(
     )
 }
 
+/// Result of [hits_with_convergence]
+#[derive(Debug, Clone)]
+pub struct HitsResult<G: StaticGraphViewOps> {
+    /// The hub score of each node
+    pub hubs: NodeState<'static, f64, G>,
+    /// The authority score of each node
+    pub authorities: NodeState<'static, f64, G>,
+    /// The number of iterations that were run
+    pub iterations: usize,
+    /// Whether the scores changed by less than the tolerance in the last iteration
+    pub converged: bool,
+}
+
+/// HITS (Hubs and Authority) Algorithm with L2 normalisation:
+/// AuthScore of a node (A) = Sum of HubScore of all nodes pointing at node (A) from previous iteration
+///
+/// HubScore of a node (A) = Sum of AuthScore of all nodes pointing away from node (A) from the current iteration
+///
+/// Both scores are normalised to unit L2 norm after every iteration. The iteration stops once no
+/// score changes by more than `tol` or after `max_iter` iterations.
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph.
+/// - `max_iter` - The maximum number of iterations to run
+/// - `tol` - The largest change of a score for which the scores are considered converged
+///
+/// # Returns
+///
+/// A tuple of [NodeState]s containing the hub scores and the authority scores of the nodes
+pub fn hits_l2<G: StaticGraphViewOps>(
+    g: &G,
+    max_iter: usize,
+    tol: f64,
+) -> (NodeState<'static, f64, G>, NodeState<'static, f64, G>) {
+    let result = hits_iterate(g, max_iter, tol);
+    (result.hubs, result.authorities)
+}
+
+/// HITS (Hubs and Authority) Algorithm with L2 normalisation and convergence reporting
+///
+/// Computes the same scores as [hits_l2] but also reports how many iterations were needed. All
+/// scores are 0 if the graph (or view) has no edges. The algorithm respects the view it is
+/// called on, so it can be run on each window of `graph.rolling(...)` to track how the scores
+/// change over time.
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph.
+/// - `max_iter` - The maximum number of iterations to run
+/// - `tol` - The largest change of a score for which the scores are considered converged
+///
+/// # Returns
+///
+/// A [HitsResult] containing the hub scores, the authority scores and the number of iterations
+///
+/// # Errors
+///
+/// Returns [GraphError::InvalidAlgorithmParameters] if `tol` is negative or not a number
+///
+pub fn hits_with_convergence<G: StaticGraphViewOps>(
+    g: &G,
+    max_iter: usize,
+    tol: f64,
+) -> Result<HitsResult<G>, GraphError> {
+    if tol.is_nan() || tol < 0.0 {
+        return Err(GraphError::InvalidAlgorithmParameters(format!(
+            "tol must be non-negative, got {tol}"
+        )));
+    }
+    Ok(hits_iterate(g, max_iter, tol))
+}
+
+fn hits_iterate<G: StaticGraphViewOps>(g: &G, max_iter: usize, tol: f64) -> HitsResult<G> {
+    let n = g.unfiltered_num_nodes();
+    let mut out_adj: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut in_adj: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut num_nodes = 0;
+    for node in g.nodes() {
+        out_adj[node.node.index()] = node
+            .out_neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .collect();
+        in_adj[node.node.index()] = node
+            .in_neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .collect();
+        num_nodes += 1;
+    }
+
+    // without edges all scores are 0 rather than the initial uniform scores
+    let initial = if in_adj.iter().any(|nbrs| !nbrs.is_empty()) {
+        1f64 / (num_nodes as f64).sqrt()
+    } else {
+        0f64
+    };
+    let mut hubs: Vec<f64> = vec![0f64; n];
+    for node in g.nodes() {
+        hubs[node.node.index()] = initial;
+    }
+    let mut auths = hubs.clone();
+
+    let update = |adj: &Vec<Vec<usize>>, scores: &Vec<f64>| -> Vec<f64> {
+        let mut new_scores: Vec<f64> = adj
+            .par_iter()
+            .map(|nbrs| nbrs.iter().map(|nbr| scores[*nbr]).sum())
+            .collect();
+        let norm = new_scores.iter().map(|s| s * s).sum::<f64>().sqrt();
+        if norm > 0f64 {
+            new_scores.par_iter_mut().for_each(|s| *s /= norm);
+        }
+        new_scores
+    };
+    let max_diff = |a: &Vec<f64>, b: &Vec<f64>| {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| abs(x - y))
+            .fold(0f64, f64::max)
+    };
+
+    let mut iterations = 0;
+    let mut converged = false;
+    while iterations < max_iter {
+        let new_auths = update(&in_adj, &hubs);
+        let new_hubs = update(&out_adj, &new_auths);
+        let diff = max_diff(&new_hubs, &hubs).max(max_diff(&new_auths, &auths));
+        hubs = new_hubs;
+        auths = new_auths;
+        iterations += 1;
+        if diff < tol {
+            converged = true;
+            break;
+        }
+    }
+
+    HitsResult {
+        hubs: NodeState::new_from_eval(g.clone(), hubs),
+        authorities: NodeState::new_from_eval(g.clone(), auths),
+        iterations,
+        converged,
+    }
+}
+
 #[cfg(test)]
 mod hits_tests {
-    use crate::{
-        db::{api::mutation::AdditionOps, graph::graph::Graph},
-        prelude::NO_PROPS,
-        test_storage,
-    };
+    use crate::{db::api::state::NodeStateOps, prelude::*, test_storage};
     use std::collections::HashMap;
 
     use super::*;
@@ -208,4 +349,119 @@ mod hits_tests {
             );
         });
     }
+
+    #[test]
+    fn test_hits_l2_bipartite() {
+        // every edge points from a source in 1..=3 to a sink in 4..=6
+        let graph = load_graph(vec![(1, 4), (1, 5), (1, 6), (2, 4), (2, 5), (3, 4)]);
+        test_storage!(&graph, |graph| {
+            let (hubs, auths) = hits_l2(graph, 100, 1e-10);
+
+            for source in 1..=3u64 {
+                for sink in 4..=6u64 {
+                    let source_hub = hubs.get_by_node(source).unwrap();
+                    let sink_hub = hubs.get_by_node(sink).unwrap();
+                    let source_auth = auths.get_by_node(source).unwrap();
+                    let sink_auth = auths.get_by_node(sink).unwrap();
+                    assert!(*source_hub > 0.1 && *sink_hub == 0.0);
+                    assert!(*sink_auth > 0.1 && *source_auth == 0.0);
+                }
+            }
+            // the source and sink with the most edges have the highest scores
+            assert_eq!(
+                hubs.max_item_by(f64::total_cmp).unwrap().0.id(),
+                GID::U64(1)
+            );
+            assert_eq!(
+                auths.max_item_by(f64::total_cmp).unwrap().0.id(),
+                GID::U64(4)
+            );
+
+            let norm = |scores: &NodeState<'static, f64, _>| {
+                scores.iter_values().map(|s| s * s).sum::<f64>().sqrt()
+            };
+            assert!((norm(&hubs) - 1.0).abs() < 1e-9);
+            assert!((norm(&auths) - 1.0).abs() < 1e-9);
+        });
+    }
+
+    #[test]
+    fn test_hits_l2_empty() {
+        let graph = Graph::new();
+        let (hubs, auths) = hits_l2(&graph, 10, 1e-6);
+        assert_eq!(hubs.len(), 0);
+        assert_eq!(auths.len(), 0);
+    }
+
+    #[test]
+    fn test_hits_fans_and_celebrity() {
+        let graph = Graph::new();
+        for (t, fan) in (1..=4u64).enumerate() {
+            graph.add_edge(t as i64, fan, 100, NO_PROPS, None).unwrap();
+        }
+        test_storage!(&graph, |graph| {
+            let result = hits_with_convergence(graph, 100, 1e-9).unwrap();
+            assert!(result.converged);
+            assert!(result.iterations <= 100);
+
+            assert!((result.authorities.get_by_node(100u64).unwrap() - 1.0).abs() < 1e-9);
+            assert_eq!(result.hubs.get_by_node(100u64), Some(&0.0));
+            for fan in 1..=4u64 {
+                assert_eq!(result.authorities.get_by_node(fan), Some(&0.0));
+                assert!((result.hubs.get_by_node(fan).unwrap() - 0.5).abs() < 1e-9);
+            }
+
+            // the first window only contains the first fan, which becomes the only hub
+            let window = graph.window(0, 1);
+            let result = hits_with_convergence(&window, 100, 1e-9).unwrap();
+            assert_eq!(result.hubs.len(), 2);
+            assert!((result.hubs.get_by_node(1u64).unwrap() - 1.0).abs() < 1e-9);
+        });
+    }
+
+    #[test]
+    fn test_hits_rolling_windows() {
+        let graph = Graph::new();
+        // the first window has two celebrities with a fan each, in the second window both fans
+        // point at the same celebrity
+        for (t, fan, celebrity) in [(0i64, 1u64, 100u64), (1, 2, 200), (2, 3, 100), (3, 4, 100)] {
+            graph.add_edge(t, fan, celebrity, NO_PROPS, None).unwrap();
+        }
+        let authority: Vec<f64> = graph
+            .rolling(2, None)
+            .unwrap()
+            .map(|window| {
+                let result = hits_with_convergence(&window, 100, 1e-9).unwrap();
+                *result.authorities.get_by_node(100u64).unwrap()
+            })
+            .collect();
+        assert_eq!(authority.len(), 2);
+        assert!((authority[0] - 1.0 / 2f64.sqrt()).abs() < 1e-9);
+        assert!((authority[1] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hits_no_edges() {
+        let graph = Graph::new();
+        for node in 1..=3u64 {
+            graph.add_node(0, node, NO_PROPS, None).unwrap();
+        }
+        let result = hits_with_convergence(&graph, 10, 1e-6).unwrap();
+        assert!(result.converged);
+        assert!(result.hubs.iter_values().all(|s| *s == 0.0));
+        assert!(result.authorities.iter_values().all(|s| *s == 0.0));
+    }
+
+    #[test]
+    fn test_hits_invalid_tolerance() {
+        let graph = load_graph(vec![(1, 2)]);
+        assert!(matches!(
+            hits_with_convergence(&graph, 10, -1.0),
+            Err(GraphError::InvalidAlgorithmParameters(_))
+        ));
+        assert!(matches!(
+            hits_with_convergence(&graph, 10, f64::NAN),
+            Err(GraphError::InvalidAlgorithmParameters(_))
+        ));
+    }
 }
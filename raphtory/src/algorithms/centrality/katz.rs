@@ -0,0 +1,259 @@
+use crate::{
+    db::api::{state::NodeState, view::StaticGraphViewOps},
+    errors::GraphError,
+    prelude::*,
+};
+use raphtory_api::core::entities::VID;
+use rayon::prelude::*;
+use tracing::warn;
+
+/// Katz Centrality Algorithm:
+/// The Katz centrality of a node is the weighted number of walks ending at the node, where a
+/// walk of length k is weighted by `alpha^k`, plus a constant `beta` for every node.
+///
+/// The scores are computed by iterating `x = alpha * A^T x + beta` (where `A` is the adjacency
+/// matrix of the graph view, so windows and layers are respected) starting from `x = 0`, until
+/// the L1 norm of the change in scores between two iterations falls below `tol`. The series only
+/// converges if `alpha` is smaller than `1 / λ_max`, the inverse of the largest eigenvalue of `A`.
+///
+/// # Arguments
+///
+/// - `g`: A GraphView object
+/// - `alpha`: The attenuation factor for longer walks, needs to be positive
+/// - `beta`: The score every node gets independent of its in-edges
+/// - `max_iter`: Maximum number of iterations to run the algorithm for
+/// - `tol`: The tolerance value for the L1 norm of the score change
+///
+/// # Returns
+///
+/// A [NodeState] containing the Katz centrality of each node
+///
+/// # Errors
+///
+/// Returns [GraphError::InvalidAlgorithmParameters] if `alpha` is not positive or if the scores
+/// diverge because `alpha >= 1 / λ_max`, and [GraphError::AlgorithmNotConverged] if the scores
+/// do not converge within `max_iter` iterations.
+///
+pub fn katz_centrality<G: StaticGraphViewOps>(
+    g: &G,
+    alpha: f64,
+    beta: f64,
+    max_iter: usize,
+    tol: f64,
+) -> Result<NodeState<'static, f64, G>, GraphError> {
+    if alpha.is_nan() || alpha <= 0f64 {
+        return Err(GraphError::InvalidAlgorithmParameters(format!(
+            "alpha needs to be positive, got {alpha}"
+        )));
+    }
+    let in_neighbours: Vec<(VID, Vec<VID>)> = g
+        .nodes()
+        .par_iter()
+        .map(|node| {
+            let neighbours = node.in_neighbours().iter().map(|n| n.node).collect();
+            (node.node, neighbours)
+        })
+        .collect();
+
+    let mut scores = vec![0f64; g.unfiltered_num_nodes()];
+    let mut first_diff = None;
+    let mut diff = 0f64;
+    for _ in 0..max_iter {
+        let updates: Vec<(VID, f64)> = in_neighbours
+            .par_iter()
+            .map(|(node, neighbours)| {
+                let walks: f64 = neighbours.iter().map(|n| scores[n.index()]).sum();
+                (*node, alpha * walks + beta)
+            })
+            .collect();
+        diff = 0f64;
+        for (node, score) in updates {
+            diff += (score - scores[node.index()]).abs();
+            scores[node.index()] = score;
+        }
+        if !diff.is_finite() {
+            return Err(diverged(alpha));
+        }
+        if diff < tol {
+            return Ok(NodeState::new_from_eval(g.clone(), scores));
+        }
+        first_diff.get_or_insert(diff);
+    }
+    // below 1 / λ_max the change shrinks geometrically, otherwise it never gets smaller
+    match first_diff {
+        Some(first_diff) if diff >= first_diff && max_iter > 1 => Err(diverged(alpha)),
+        _ => Err(GraphError::AlgorithmNotConverged(max_iter)),
+    }
+}
+
+/// Normalised Katz Centrality:
+/// Computes the Katz centrality like [katz_centrality] and scales the scores to unit L2 norm.
+///
+/// See [katz_with_normalization] for the details.
+pub fn katz<G: StaticGraphViewOps>(
+    g: &G,
+    alpha: f64,
+    beta: f64,
+    max_iter: usize,
+    tol: f64,
+) -> Result<NodeState<'static, f64, G>, GraphError> {
+    katz_with_normalization(g, alpha, beta, max_iter, tol, true)
+}
+
+/// Katz Centrality with optional normalisation:
+/// Computes the Katz centrality like [katz_centrality]. If `normalized` is set, the scores are
+/// scaled to unit L2 norm afterwards.
+///
+/// Computing `λ_max` is as expensive as the centrality itself, so before iterating `alpha` is
+/// only checked against the cheap bound `λ_max <= min(max in-degree, max out-degree)`. If
+/// convergence cannot be guaranteed by the bound a warning is logged, divergence is still
+/// detected while iterating.
+///
+/// # Arguments
+///
+/// - `g`: A GraphView object
+/// - `alpha`: The attenuation factor for longer walks, needs to be positive
+/// - `beta`: The score every node gets independent of its in-edges
+/// - `max_iter`: Maximum number of iterations to run the algorithm for
+/// - `tol`: The tolerance value for the L1 norm of the score change
+/// - `normalized`: Scale the scores to unit L2 norm
+///
+/// # Returns
+///
+/// A [NodeState] containing the Katz centrality of each node
+///
+/// # Errors
+///
+/// Same as [katz_centrality]
+///
+pub fn katz_with_normalization<G: StaticGraphViewOps>(
+    g: &G,
+    alpha: f64,
+    beta: f64,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+) -> Result<NodeState<'static, f64, G>, GraphError> {
+    let (max_in, max_out) = g
+        .nodes()
+        .par_iter()
+        .map(|node| (node.in_degree(), node.out_degree()))
+        .reduce(|| (0, 0), |(a, b), (c, d)| (a.max(c), b.max(d)));
+    let bound = max_in.min(max_out) as f64;
+    if alpha * bound >= 1f64 {
+        warn!(
+            "Cannot verify that alpha = {} is smaller than 1 / λ_max, the largest degree bound is {}, Katz centrality may not converge",
+            alpha, bound
+        );
+    }
+    let scores = katz_centrality(g, alpha, beta, max_iter, tol)?;
+    if !normalized {
+        return Ok(scores);
+    }
+    let norm = scores.par_iter_values().map(|s| s * s).sum::<f64>().sqrt();
+    let mut values = vec![0f64; g.unfiltered_num_nodes()];
+    for (node, score) in scores.iter() {
+        values[node.node.index()] = if norm > 0f64 { score / norm } else { *score };
+    }
+    Ok(NodeState::new_from_eval(g.clone(), values))
+}
+
+fn diverged(alpha: f64) -> GraphError {
+    GraphError::InvalidAlgorithmParameters(format!(
+        "the scores diverge, alpha = {alpha} needs to be smaller than 1 / λ_max"
+    ))
+}
+
+#[cfg(test)]
+mod katz_tests {
+    use super::*;
+    use crate::{algorithms::centrality::pagerank::page_rank_tests::assert_eq_f64, test_storage};
+
+    #[test]
+    fn test_star_centre_dominates() {
+        let graph = Graph::new();
+        for leaf in 1..=5 {
+            graph.add_edge(0, leaf, 0, NO_PROPS, None).unwrap();
+        }
+        graph.add_edge(0, 0, 1, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let results = katz_centrality(graph, 0.1, 1.0, 100, 1e-10).unwrap();
+            let centre = *results.get_by_node(0).unwrap();
+            for leaf in 2..=5 {
+                assert_eq_f64(results.get_by_node(leaf), Some(&1.0), 9);
+            }
+            // centre = 1 + 0.1 * (4 + leaf_1) with leaf_1 = 1 + 0.1 * centre
+            assert_eq_f64(Some(centre), Some(1.5 / 0.99), 9);
+            assert!(results.get_by_node(1).unwrap() < &centre);
+        });
+    }
+
+    #[test]
+    fn test_respects_window() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 0, NO_PROPS, None).unwrap();
+        graph.add_edge(5, 2, 0, NO_PROPS, None).unwrap();
+        graph.add_edge(5, 2, 1, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let full = katz_centrality(graph, 0.5, 1.0, 100, 1e-10).unwrap();
+            assert_eq_f64(full.get_by_node(0), Some(&2.25), 9);
+
+            let window = graph.window(0, 5);
+            let windowed = katz_centrality(&window, 0.5, 1.0, 100, 1e-10).unwrap();
+            assert_eq_f64(windowed.get_by_node(0), Some(&1.5), 9);
+            assert_eq!(windowed.get_by_node(2), None);
+        });
+    }
+
+    #[test]
+    fn test_divergence() {
+        // a directed cycle has λ_max = 1
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 1)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            assert!(katz_centrality(graph, 0.5, 1.0, 1000, 1e-10).is_ok());
+            assert!(matches!(
+                katz_centrality(graph, 1.5, 1.0, 1000, 1e-10),
+                Err(GraphError::InvalidAlgorithmParameters(_))
+            ));
+            assert!(matches!(
+                katz_centrality(graph, 1.0, 1.0, 1000, 1e-10),
+                Err(GraphError::InvalidAlgorithmParameters(_))
+            ));
+            assert!(matches!(
+                katz_centrality(graph, 0.5, 1.0, 3, 1e-10),
+                Err(GraphError::AlgorithmNotConverged(3))
+            ));
+        });
+    }
+
+    #[test]
+    fn test_normalised_star() {
+        let graph = Graph::new();
+        for leaf in 1..=5 {
+            graph.add_edge(0, leaf, 0, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let results = katz(graph, 0.1, 1.0, 100, 1e-10).unwrap();
+            let (top, _) = results.max_item_by(f64::total_cmp).unwrap();
+            assert_eq!(top.id(), GID::U64(0));
+            let norm: f64 = results.iter_values().map(|s| s * s).sum::<f64>().sqrt();
+            assert_eq_f64(Some(norm), Some(1.0), 9);
+
+            // the centre has score 1.5 and the leaves 1.0 before normalisation
+            let expected_norm = (1.5f64 * 1.5 + 5.0).sqrt();
+            assert_eq_f64(results.get_by_node(0), Some(&(1.5 / expected_norm)), 9);
+            assert_eq_f64(results.get_by_node(3), Some(&(1.0 / expected_norm)), 9);
+
+            let raw = katz_with_normalization(graph, 0.1, 1.0, 100, 1e-10, false).unwrap();
+            let expected = katz_centrality(graph, 0.1, 1.0, 100, 1e-10).unwrap();
+            assert_eq!(raw, expected);
+        });
+    }
+}
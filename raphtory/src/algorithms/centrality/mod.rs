@@ -1,4 +1,9 @@
 pub mod betweenness;
+pub mod closeness;
 pub mod degree_centrality;
+pub mod eigenvector;
+pub mod harmonic;
 pub mod hits;
+pub mod katz;
 pub mod pagerank;
+pub mod temporal_pagerank;
@@ -1,31 +1,35 @@
 use crate::{
-    core::state::{accumulator_id::accumulators, compute_state::ComputeStateVec},
+    core::{
+        entities::nodes::node_ref::{AsNodeRef, NodeRef},
+        state::{accumulator_id::accumulators, compute_state::ComputeStateVec},
+    },
     db::{
-        api::{
-            state::NodeState,
-            view::{NodeViewOps, StaticGraphViewOps},
-        },
+        api::{state::NodeState, view::StaticGraphViewOps},
         task::{
             context::Context,
             task::{ATask, Job, Step},
             task_runner::TaskRunner,
         },
     },
-    prelude::GraphViewOps,
+    errors::GraphError,
+    prelude::*,
 };
 use num_traits::abs;
+use raphtory_api::core::{entities::VID, storage::arc_str::ArcStr};
+use std::sync::Arc;
 
 #[derive(Clone, Debug, Default)]
 struct PageRankState {
     score: f64,
-    out_degree: usize,
+    /// the out-degree, or the total weight of the out-edges for weighted PageRank
+    out_weight: f64,
 }
 
 impl PageRankState {
     fn new(num_nodes: usize) -> Self {
         Self {
             score: 1f64 / num_nodes as f64,
-            out_degree: 0,
+            out_weight: 0f64,
         }
     }
 
@@ -57,6 +61,83 @@ pub fn unweighted_page_rank<G: StaticGraphViewOps>(
     tol: Option<f64>,
     use_l2_norm: bool,
     damping_factor: Option<f64>,
+) -> NodeState<'static, f64, G> {
+    page_rank(
+        g,
+        iter_count,
+        threads,
+        tol,
+        use_l2_norm,
+        damping_factor,
+        None,
+    )
+}
+
+/// Weighted PageRank Algorithm:
+/// PageRank where the score of a node is distributed over its out-edges proportionally to
+/// their weight instead of evenly.
+///
+/// The weight of an edge is the latest value of the `weight_prop` temporal property within the
+/// current view (so windows and layers are respected) and defaults to 1.0 for edges without a
+/// value. Weights are expected to be non-negative, a node whose out-edges have a total weight of
+/// 0 is treated as dangling.
+///
+/// # Arguments
+///
+/// - `g`: A GraphView object
+/// - `iter_count`: Number of iterations to run the algorithm for
+/// - `threads`: Number of threads to use for parallel execution
+/// - `tol`: The tolerance value for convergence
+/// - `use_l2_norm`: Whether to use L2 norm for convergence
+/// - `damping_factor`: Probability of likelihood the spread will continue
+/// - `weight_prop`: The name of the edge property holding the weight
+///
+/// # Returns
+///
+/// A [NodeState] containing the PageRank score of each node
+///
+/// # Errors
+///
+/// Returns [GraphError::InvalidProperty] if `weight_prop` is an edge property with a
+/// non-numeric type.
+///
+pub fn weighted_page_rank<G: StaticGraphViewOps>(
+    g: &G,
+    iter_count: Option<usize>,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    use_l2_norm: bool,
+    damping_factor: Option<f64>,
+    weight_prop: &str,
+) -> Result<NodeState<'static, f64, G>, GraphError> {
+    if let Some((_, dtype)) = g.edge_meta().get_prop_id_and_type(weight_prop, false) {
+        if !dtype.is_numeric() {
+            return Err(GraphError::InvalidProperty {
+                reason: format!(
+                    "Edge property {weight_prop} has type {dtype:?} and cannot be used as a weight"
+                ),
+            });
+        }
+    }
+    Ok(page_rank(
+        g,
+        iter_count,
+        threads,
+        tol,
+        use_l2_norm,
+        damping_factor,
+        Some(weight_prop.into()),
+    ))
+}
+
+fn page_rank<G: StaticGraphViewOps>(
+    g: &G,
+    iter_count: Option<usize>,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    use_l2_norm: bool,
+    damping_factor: Option<f64>,
+    weight_prop: Option<ArcStr>,
 ) -> NodeState<'static, f64, G> {
     let n = g.count_nodes();
 
@@ -76,10 +157,23 @@ pub fn unweighted_page_rank<G: StaticGraphViewOps>(
 
     ctx.global_agg_reset(total_sink_contribution);
 
+    let weight = weight_prop.clone();
     let step1 = ATask::new(move |s| {
-        let out_degree = s.out_degree();
+        let out_weight = match &weight {
+            None => s.out_degree() as f64,
+            Some(weight) => s
+                .out_edges()
+                .iter()
+                .map(|e| {
+                    e.properties()
+                        .get(weight)
+                        .and_then(|w| w.as_f64())
+                        .unwrap_or(1f64)
+                })
+                .sum(),
+        };
         let state: &mut PageRankState = s.get_mut();
-        state.out_degree = out_degree;
+        state.out_weight = out_weight;
         Step::Continue
     });
 
@@ -90,10 +184,30 @@ pub fn unweighted_page_rank<G: StaticGraphViewOps>(
             state.reset();
         }
 
-        for t in s.in_neighbours() {
-            let prev = t.prev();
+        match &weight_prop {
+            None => {
+                for t in s.in_neighbours() {
+                    let prev = t.prev();
 
-            s.get_mut().score += prev.score / prev.out_degree as f64;
+                    s.get_mut().score += prev.score / prev.out_weight;
+                }
+            }
+            Some(weight) => {
+                let mut score = 0f64;
+                for e in s.in_edges() {
+                    let w = e
+                        .properties()
+                        .get(weight)
+                        .and_then(|w| w.as_f64())
+                        .unwrap_or(1f64);
+                    let src = e.src();
+                    let prev = src.prev();
+                    if prev.out_weight > 0f64 {
+                        score += prev.score * w / prev.out_weight;
+                    }
+                }
+                s.get_mut().score += score;
+            }
         }
 
         s.get_mut().score *= damp;
@@ -105,7 +219,7 @@ pub fn unweighted_page_rank<G: StaticGraphViewOps>(
     let step3 = ATask::new(move |s| {
         let state: &mut PageRankState = s.get_mut();
 
-        if state.out_degree == 0 {
+        if state.out_weight == 0f64 {
             let curr = s.prev().score;
 
             let ts_contrib = factor * curr;
@@ -169,6 +283,246 @@ pub fn unweighted_page_rank<G: StaticGraphViewOps>(
     )
 }
 
+/// PageRank Algorithm with explicit convergence parameters
+///
+/// Runs [unweighted_page_rank] on the graph view (use `g.snapshot_at(t)` or `g.before(t)` to score a
+/// different point in time) until the L1 norm of the change in scores between two iterations
+/// falls below `tol` or `max_iter` iterations have run.
+///
+/// # Arguments
+///
+/// - `g`: A GraphView object
+/// - `damping`: Probability of likelihood the spread will continue
+/// - `max_iter`: Maximum number of iterations to run the algorithm for
+/// - `tol`: The tolerance value for the L1 norm of the score change
+///
+/// # Returns
+///
+/// A [NodeState] containing the PageRank score of each node, the scores sum to 1
+///
+pub fn pagerank<G: StaticGraphViewOps>(
+    g: &G,
+    damping: f64,
+    max_iter: usize,
+    tol: f64,
+) -> NodeState<'static, f64, G> {
+    // `unweighted_page_rank` compares the L1 norm against `tol * n`
+    let n = g.count_nodes().max(1) as f64;
+    unweighted_page_rank(g, Some(max_iter), None, Some(tol / n), false, Some(damping))
+}
+
+/// Personalized PageRank Algorithm:
+/// PageRank where the random walk restarts at a set of seed nodes instead of at a uniformly
+/// random node, so the scores measure how relevant every node is to the seeds.
+///
+/// The restart distribution is uniform over the distinct `seeds`, see
+/// [personalized_page_rank_with_weights] for a weighted restart distribution.
+///
+/// # Arguments
+///
+/// - `g`: A GraphView object
+/// - `seeds`: The nodes the random walk restarts at
+/// - `iter_count`: Maximum number of iterations to run the algorithm for
+/// - `threads`: Number of threads to use for parallel execution
+/// - `tol`: The tolerance value for the L1 norm of the score change
+/// - `damping_factor`: Probability of likelihood the spread will continue
+///
+/// # Returns
+///
+/// A [NodeState] containing the personalized PageRank score of each node, the scores sum to 1
+///
+/// # Errors
+///
+/// Returns [GraphError::NodesMissingError] with the ids of all seeds that are not in the graph
+/// and [GraphError::InvalidAlgorithmParameters] if `seeds` is empty.
+///
+pub fn personalized_page_rank<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    seeds: &[T],
+    iter_count: Option<usize>,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    damping_factor: Option<f64>,
+) -> Result<NodeState<'static, f64, G>, GraphError> {
+    let mut restart = vec![0f64; g.unfiltered_num_nodes()];
+    for node in resolve_seeds(g, seeds.iter().map(|seed| (seed, ())))? {
+        restart[node.0.index()] = 1f64;
+    }
+    personalized_page_rank_impl(g, restart, iter_count, threads, tol, damping_factor)
+}
+
+/// Personalized PageRank Algorithm with a weighted restart distribution:
+/// Like [personalized_page_rank], but the random walk restarts at each seed with probability
+/// proportional to its weight. Weights of repeated seeds are added up.
+///
+/// # Arguments
+///
+/// - `g`: A GraphView object
+/// - `seeds`: The nodes the random walk restarts at with their non-negative weights, e.g., a
+///   `HashMap<NodeRef, f64>`
+/// - `iter_count`: Maximum number of iterations to run the algorithm for
+/// - `threads`: Number of threads to use for parallel execution
+/// - `tol`: The tolerance value for the L1 norm of the score change
+/// - `damping_factor`: Probability of likelihood the spread will continue
+///
+/// # Returns
+///
+/// A [NodeState] containing the personalized PageRank score of each node, the scores sum to 1
+///
+/// # Errors
+///
+/// Returns [GraphError::NodesMissingError] with the ids of all seeds that are not in the graph
+/// and [GraphError::InvalidAlgorithmParameters] if a weight is negative or the weights do not
+/// have a positive sum.
+///
+pub fn personalized_page_rank_with_weights<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    seeds: impl IntoIterator<Item = (T, f64)>,
+    iter_count: Option<usize>,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    damping_factor: Option<f64>,
+) -> Result<NodeState<'static, f64, G>, GraphError> {
+    let mut restart = vec![0f64; g.unfiltered_num_nodes()];
+    for (node, weight) in resolve_seeds(g, seeds)? {
+        if weight.is_nan() || weight < 0f64 {
+            return Err(GraphError::InvalidAlgorithmParameters(format!(
+                "seed weights need to be non-negative, got {weight}"
+            )));
+        }
+        restart[node.index()] += weight;
+    }
+    personalized_page_rank_impl(g, restart, iter_count, threads, tol, damping_factor)
+}
+
+/// Look up all seeds, collecting the ids of the missing ones
+fn resolve_seeds<G: StaticGraphViewOps, T: AsNodeRef, V>(
+    g: &G,
+    seeds: impl IntoIterator<Item = (T, V)>,
+) -> Result<Vec<(VID, V)>, GraphError> {
+    let mut found = vec![];
+    let mut missing = vec![];
+    for (seed, value) in seeds {
+        match g.node(seed.as_node_ref()) {
+            Some(node) => found.push((node.node, value)),
+            None => missing.push(match seed.as_node_ref() {
+                NodeRef::Internal(vid) => g.node_id(vid),
+                NodeRef::External(gid) => gid.to_owned(),
+            }),
+        }
+    }
+    if !missing.is_empty() {
+        return Err(GraphError::NodesMissingError(missing));
+    }
+    if found.is_empty() {
+        return Err(GraphError::InvalidAlgorithmParameters(
+            "personalized PageRank needs at least one seed".to_owned(),
+        ));
+    }
+    Ok(found)
+}
+
+fn personalized_page_rank_impl<G: StaticGraphViewOps>(
+    g: &G,
+    mut restart: Vec<f64>,
+    iter_count: Option<usize>,
+    threads: Option<usize>,
+    tol: Option<f64>,
+    damping_factor: Option<f64>,
+) -> Result<NodeState<'static, f64, G>, GraphError> {
+    let total: f64 = restart.iter().sum();
+    if total <= 0f64 {
+        return Err(GraphError::InvalidAlgorithmParameters(
+            "seed weights need to have a positive sum".to_owned(),
+        ));
+    }
+    restart.iter_mut().for_each(|p| *p /= total);
+    // the walk starts at the restart distribution, so unreachable nodes stay at 0
+    let init: Vec<_> = restart
+        .iter()
+        .map(|&score| PageRankState {
+            score,
+            out_weight: 0f64,
+        })
+        .collect();
+    let restart = Arc::new(restart);
+
+    let mut ctx: Context<G, ComputeStateVec> = g.into();
+
+    let tol: f64 = tol.unwrap_or(0.000001f64);
+    let damp = damping_factor.unwrap_or(0.85);
+    let iter_count = iter_count.unwrap_or(20);
+
+    let max_diff = accumulators::sum::<f64>(2);
+    let total_sink_contribution = accumulators::sum::<f64>(4);
+
+    ctx.global_agg_reset(max_diff);
+    ctx.global_agg_reset(total_sink_contribution);
+
+    let step1 = ATask::new(move |s| {
+        let out_degree = s.out_degree();
+        let state: &mut PageRankState = s.get_mut();
+        state.out_weight = out_degree as f64;
+        Step::Continue
+    });
+
+    let teleport = restart.clone();
+    let step2: ATask<G, ComputeStateVec, PageRankState, _> = ATask::new(move |s| {
+        let mut score = 0f64;
+        for t in s.in_neighbours() {
+            let prev: &PageRankState = t.prev();
+            score += prev.score / prev.out_weight;
+        }
+        s.get_mut().score = damp * score + (1f64 - damp) * teleport[s.node.index()];
+        Step::Continue
+    });
+
+    // the mass of dangling nodes restarts at the seeds
+    let step3 = ATask::new(move |s| {
+        let state: &PageRankState = s.get();
+        if state.out_weight == 0f64 {
+            let curr = s.prev().score;
+            s.global_update(&total_sink_contribution, damp * curr);
+        }
+        Step::Continue
+    });
+
+    let step4 = ATask::new(move |s| {
+        let total_sink_contribution = s
+            .read_global_state(&total_sink_contribution)
+            .unwrap_or_default();
+        let restart_prob = restart[s.node.index()];
+        let state: &mut PageRankState = s.get_mut();
+        state.score += total_sink_contribution * restart_prob;
+
+        let curr = state.score;
+        let prev = s.prev().score;
+        s.global_update(&max_diff, abs(prev - curr));
+        Step::Continue
+    });
+
+    let step5 = Job::Check(Box::new(move |state| {
+        if state.read(&max_diff) > tol {
+            Step::Continue
+        } else {
+            Step::Done
+        }
+    }));
+
+    let mut runner: TaskRunner<G, _> = TaskRunner::new(ctx);
+
+    Ok(runner.run(
+        vec![Job::new(step1)],
+        vec![Job::new(step2), Job::new(step3), Job::new(step4), step5],
+        Some(init),
+        |_, _, _, local| NodeState::new_from_eval_mapped(g.clone(), local, |v| v.score),
+        threads,
+        iter_count,
+        None,
+        None,
+    ))
+}
+
 #[cfg(test)]
 pub mod page_rank_tests {
     use super::*;
@@ -179,7 +533,7 @@ pub mod page_rank_tests {
     };
     use itertools::Itertools;
     use pretty_assertions::assert_eq;
-    use std::borrow::Borrow;
+    use std::{borrow::Borrow, collections::HashMap};
 
     fn load_graph() -> Graph {
         let graph = Graph::new();
@@ -206,6 +560,21 @@ pub mod page_rank_tests {
         });
     }
 
+    #[test]
+    fn test_pagerank_convergence() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let results = pagerank(graph, 0.85, 1000, 1e-9);
+
+            assert_eq_f64(results.get_by_node("1"), Some(&0.38694), 5);
+            assert_eq_f64(results.get_by_node("2"), Some(&0.20195), 5);
+            assert_eq_f64(results.get_by_node("4"), Some(&0.20195), 5);
+            assert_eq_f64(results.get_by_node("3"), Some(&0.20916), 5);
+            assert_eq_f64(Some(results.sum::<f64>()), Some(1.0), 6);
+        });
+    }
+
     #[test]
     fn motif_page_rank() {
         let edges = vec![
@@ -339,6 +708,170 @@ pub mod page_rank_tests {
         });
     }
 
+    fn load_weighted_graph() -> Graph {
+        let graph = Graph::new();
+        let edges = vec![
+            ("A", "B", 1.0),
+            ("A", "C", 9.0),
+            ("D", "B", 1.0),
+            ("D", "E", 9.0),
+        ];
+        for (src, dst, weight) in edges {
+            graph
+                .add_edge(0, src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn weighted_page_rank_heavy_edges_flip_ordering() {
+        let graph = load_weighted_graph();
+
+        test_storage!(&graph, |graph| {
+            let unweighted = unweighted_page_rank(graph, Some(1000), Some(1), None, true, None);
+            assert!(unweighted.get_by_node("B") > unweighted.get_by_node("C"));
+
+            let weighted =
+                weighted_page_rank(graph, Some(1000), Some(1), None, true, None, "weight").unwrap();
+            assert!(weighted.get_by_node("B") < weighted.get_by_node("C"));
+            assert_eq_f64(weighted.get_by_node("C"), weighted.get_by_node("E"), 5);
+            assert_eq_f64(Some(weighted.sum::<f64>()), Some(1.0), 5);
+        });
+    }
+
+    #[test]
+    fn weighted_page_rank_uses_latest_weight_in_view() {
+        let graph = load_weighted_graph();
+        graph
+            .add_edge(10, "A", "C", [("weight", 0.1)], None)
+            .unwrap();
+        graph
+            .add_edge(10, "D", "E", [("weight", 0.1)], None)
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            let before = weighted_page_rank(
+                &graph.before(10),
+                Some(1000),
+                Some(1),
+                None,
+                true,
+                None,
+                "weight",
+            )
+            .unwrap();
+            assert!(before.get_by_node("B") < before.get_by_node("C"));
+
+            let latest =
+                weighted_page_rank(graph, Some(1000), Some(1), None, true, None, "weight").unwrap();
+            assert!(latest.get_by_node("B") > latest.get_by_node("C"));
+        });
+    }
+
+    #[test]
+    fn weighted_page_rank_missing_property_is_unweighted() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let unweighted = unweighted_page_rank(graph, Some(1000), Some(1), None, true, None);
+            let weighted =
+                weighted_page_rank(graph, Some(1000), Some(1), None, true, None, "weight").unwrap();
+            for node in ["1", "2", "3", "4"] {
+                assert_eq_f64(weighted.get_by_node(node), unweighted.get_by_node(node), 8);
+            }
+        });
+    }
+
+    #[test]
+    fn weighted_page_rank_non_numeric_weight() {
+        let graph = Graph::new();
+        graph
+            .add_edge(0, 1, 2, [("weight", "heavy")], None)
+            .unwrap();
+
+        assert!(matches!(
+            weighted_page_rank(&graph, None, None, None, true, None, "weight"),
+            Err(GraphError::InvalidProperty { .. })
+        ));
+    }
+
+    fn load_chain_graph() -> Graph {
+        let graph = Graph::new();
+        // 1 -> 2 -> 3 -> 4 with 4 dangling, 5 and 6 are not reachable from 1
+        let edges = vec![(1, 2), (2, 3), (3, 4), (5, 1), (6, 5)];
+        for (t, (src, dst)) in edges.into_iter().enumerate() {
+            graph.add_edge(t as i64, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn personalized_page_rank_favours_seed_neighbourhood() {
+        let graph = load_chain_graph();
+
+        test_storage!(&graph, |graph| {
+            let results =
+                personalized_page_rank(graph, &[1u64], Some(1000), Some(1), Some(1e-10), None)
+                    .unwrap();
+
+            assert!(results.get_by_node("2") > results.get_by_node("4"));
+            assert!(results.get_by_node("1") > results.get_by_node("2"));
+            assert_eq!(results.get_by_node("5"), Some(&0.0));
+            assert_eq!(results.get_by_node("6"), Some(&0.0));
+            assert_eq_f64(Some(results.sum::<f64>()), Some(1.0), 6);
+        });
+    }
+
+    #[test]
+    fn personalized_page_rank_weighted_seeds() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 3), (2, 4)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let uniform =
+                personalized_page_rank(graph, &[1u64, 2], Some(1000), Some(1), Some(1e-10), None)
+                    .unwrap();
+            assert_eq_f64(uniform.get_by_node("3"), uniform.get_by_node("4"), 8);
+
+            let weights = HashMap::from([(1u64, 3.0), (2, 1.0)]);
+            let weighted = personalized_page_rank_with_weights(
+                graph,
+                weights,
+                Some(1000),
+                Some(1),
+                Some(1e-10),
+                None,
+            )
+            .unwrap();
+            assert!(weighted.get_by_node("3") > weighted.get_by_node("4"));
+            assert_eq_f64(
+                Some(weighted.get_by_node("1").unwrap() / weighted.get_by_node("2").unwrap()),
+                Some(3.0),
+                6,
+            );
+        });
+    }
+
+    #[test]
+    fn personalized_page_rank_missing_seeds() {
+        let graph = load_chain_graph();
+
+        match personalized_page_rank(&graph, &[1u64, 42, 43], None, None, None, None) {
+            Err(GraphError::NodesMissingError(missing)) => {
+                assert_eq!(missing, vec![GID::U64(42), GID::U64(43)])
+            }
+            _ => panic!("expected missing seeds to be reported"),
+        }
+        let no_seeds: [u64; 0] = [];
+        assert!(matches!(
+            personalized_page_rank(&graph, &no_seeds, None, None, None, None),
+            Err(GraphError::InvalidAlgorithmParameters(_))
+        ));
+    }
+
     pub fn assert_eq_f64<T: Borrow<f64> + PartialEq + std::fmt::Debug>(
         a: Option<T>,
         b: Option<T>,
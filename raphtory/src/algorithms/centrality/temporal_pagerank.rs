@@ -0,0 +1,106 @@
+use crate::{
+    algorithms::centrality::pagerank::pagerank,
+    db::{
+        api::{
+            state::NodeState,
+            view::{StaticGraphViewOps, TimeOps},
+        },
+        graph::views::window_graph::WindowedGraph,
+    },
+    errors::GraphError,
+};
+use std::collections::HashMap;
+
+/// Temporal PageRank Algorithm:
+/// Computes PageRank over a rolling window that slides across the timeline of the graph.
+///
+/// Every window is a view over the original graph (see [TimeOps::rolling]), so no graph data is
+/// copied. PageRank is run from scratch for each window, only nodes that are active inside a
+/// window are scored in it.
+///
+/// # Arguments
+///
+/// - `g`: A GraphView object
+/// - `window`: The size of each window
+/// - `step`: How far the window moves forward between consecutive windows
+/// - `damping`: Probability of likelihood the spread will continue
+///
+/// # Returns
+///
+/// A map from the start of each window to the PageRank scores of the nodes in that window
+///
+/// # Errors
+///
+/// Returns [GraphError::ParseTime] if `step` is 0
+///
+pub fn temporal_pagerank<G: StaticGraphViewOps>(
+    g: &G,
+    window: i64,
+    step: i64,
+    damping: f64,
+) -> Result<HashMap<i64, NodeState<'static, f64, WindowedGraph<G>>>, GraphError> {
+    let windows = g.rolling(window, Some(step))?;
+    Ok(windows
+        .filter_map(|view| {
+            let start = view.start()?;
+            Some((start, pagerank(&view, damping, 100, 1e-9)))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod temporal_pagerank_tests {
+    use super::*;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::{NodeStateOps, NO_PROPS},
+        test_storage,
+    };
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        // every node points at the hub 0 in the first window only
+        for (t, src) in [(0, 1), (1, 2), (2, 3), (3, 4)] {
+            graph.add_edge(t, src, 0, NO_PROPS, None).unwrap();
+        }
+        // afterwards the hub only has outgoing edges while the rest form a cycle
+        for offset in [10, 20] {
+            let edges = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 1)];
+            for (t, (src, dst)) in edges.into_iter().enumerate() {
+                graph
+                    .add_edge(offset + t as i64, src, dst, NO_PROPS, None)
+                    .unwrap();
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_temporal_pagerank() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let results = temporal_pagerank(graph, 10, 10, 0.85).unwrap();
+
+            let mut starts: Vec<_> = results.keys().copied().collect();
+            starts.sort();
+            assert_eq!(starts, vec![0, 10, 20]);
+
+            let hub_score = |start: i64| results[&start].get_by_node("0").copied().unwrap();
+            assert!(hub_score(0) > hub_score(10));
+            assert!(hub_score(0) > hub_score(20));
+            for scores in results.values() {
+                assert!((scores.sum::<f64>() - 1.0).abs() < 1e-6);
+            }
+        });
+    }
+
+    #[test]
+    fn test_temporal_pagerank_zero_step() {
+        let graph = load_graph();
+        assert!(matches!(
+            temporal_pagerank(&graph, 10, 0, 0.85),
+            Err(GraphError::ParseTime { .. })
+        ));
+    }
+}
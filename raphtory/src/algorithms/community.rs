@@ -0,0 +1,3 @@
+//! Short paths to the community detection algorithms in [community_detection](super::community_detection)
+
+pub use super::community_detection::label_propagation::label_propagation_sync as label_propagation;
@@ -0,0 +1,235 @@
+use crate::{
+    db::api::{state::NodeState, view::StaticGraphViewOps},
+    prelude::*,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::HashMap;
+
+/// Number of random initial assignments optimised by [core_periphery]
+const RESTARTS: usize = 10;
+
+/// Improvements of the fit smaller than this are ignored to guarantee termination
+const TOLERANCE: f64 = 1e-12;
+
+/// Undirected simple graph with the number of core neighbours of every node
+struct CoreAssignment<'a> {
+    neighbours: &'a [Vec<usize>],
+    num_pairs: f64,
+    num_edges: f64,
+    core: Vec<bool>,
+    core_neighbours: Vec<usize>,
+    core_size: usize,
+    core_edges: usize,
+}
+
+impl<'a> CoreAssignment<'a> {
+    fn new(neighbours: &'a [Vec<usize>], num_edges: usize, core: Vec<bool>) -> Self {
+        let n = neighbours.len();
+        let core_neighbours: Vec<usize> = neighbours
+            .iter()
+            .map(|nbrs| nbrs.iter().filter(|&&w| core[w]).count())
+            .collect();
+        let core_size = core.iter().filter(|&&c| c).count();
+        let core_edges = (0..n)
+            .filter(|&v| core[v])
+            .map(|v| core_neighbours[v])
+            .sum::<usize>()
+            / 2;
+        Self {
+            neighbours,
+            num_pairs: (n * n.saturating_sub(1) / 2) as f64,
+            num_edges: num_edges as f64,
+            core,
+            core_neighbours,
+            core_size,
+            core_edges,
+        }
+    }
+
+    /// Pearson correlation between the adjacency matrix and the ideal core-periphery pattern with
+    /// a given core size and number of edges inside the core, `None` if it is undefined
+    fn correlation(&self, core_size: usize, core_edges: usize) -> Option<f64> {
+        let ideal_pairs = (core_size * core_size.saturating_sub(1) / 2) as f64;
+        let denominator = self.num_edges
+            * (self.num_pairs - self.num_edges)
+            * ideal_pairs
+            * (self.num_pairs - ideal_pairs);
+        (denominator > 0.0).then(|| {
+            (self.num_pairs * core_edges as f64 - self.num_edges * ideal_pairs) / denominator.sqrt()
+        })
+    }
+
+    fn fit(&self) -> Option<f64> {
+        self.correlation(self.core_size, self.core_edges)
+    }
+
+    /// The fit after moving `v` to the other side
+    fn fit_after_flip(&self, v: usize) -> Option<f64> {
+        if self.core[v] {
+            self.correlation(
+                self.core_size - 1,
+                self.core_edges - self.core_neighbours[v],
+            )
+        } else {
+            self.correlation(
+                self.core_size + 1,
+                self.core_edges + self.core_neighbours[v],
+            )
+        }
+    }
+
+    fn flip(&mut self, v: usize) {
+        if self.core[v] {
+            self.core_size -= 1;
+            self.core_edges -= self.core_neighbours[v];
+            for &w in &self.neighbours[v] {
+                self.core_neighbours[w] -= 1;
+            }
+        } else {
+            self.core_size += 1;
+            self.core_edges += self.core_neighbours[v];
+            for &w in &self.neighbours[v] {
+                self.core_neighbours[w] += 1;
+            }
+        }
+        self.core[v] = !self.core[v];
+    }
+}
+
+/// Fits a discrete core-periphery model (Borgatti and Everett, 2000) to the graph
+///
+/// Edge directions are ignored. The fit of an assignment of nodes to the core or the periphery is
+/// the Pearson correlation between the adjacency matrix and the ideal pattern where exactly the
+/// pairs of core nodes are connected. Starting from several random assignments, nodes are moved
+/// between the core and the periphery as long as this improves the fit, and the best assignment
+/// found is returned. The result is deterministic for a given seed but is not guaranteed to be
+/// the global optimum.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph, this can be a windowed view
+/// - `max_iter` - The maximum number of passes over all nodes for each random start
+/// - `seed` - Seed for the random starts and the order in which nodes are visited
+///
+/// # Returns
+///
+/// A [NodeState] mapping each node to `true` if it is in the core, and the fit of the assignment.
+/// The fit is `f64::NAN` if the graph has no edges or is complete.
+///
+pub fn core_periphery<G: StaticGraphViewOps>(
+    g: &G,
+    max_iter: usize,
+    seed: Option<u64>,
+) -> (NodeState<'static, bool, G>, f64) {
+    let index: HashMap<usize, usize> = g
+        .nodes()
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.node.index(), i))
+        .collect();
+    let mut neighbours: Vec<Vec<usize>> = vec![vec![]; index.len()];
+    for node in g.nodes() {
+        let v = index[&node.node.index()];
+        neighbours[v] = node
+            .neighbours()
+            .iter()
+            .map(|n| index[&n.node.index()])
+            .filter(|&n| n != v)
+            .collect();
+    }
+    let num_edges = neighbours.iter().map(|nbrs| nbrs.len()).sum::<usize>() / 2;
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut order: Vec<usize> = (0..neighbours.len()).collect();
+    let mut best: Option<(Vec<bool>, f64)> = None;
+    for _ in 0..RESTARTS {
+        let core = (0..neighbours.len()).map(|_| rng.gen_bool(0.5)).collect();
+        let mut assignment = CoreAssignment::new(&neighbours, num_edges, core);
+        for _ in 0..max_iter {
+            order.shuffle(&mut rng);
+            let mut improved = false;
+            for &v in &order {
+                let current = assignment.fit().unwrap_or(f64::NEG_INFINITY);
+                if let Some(fit) = assignment.fit_after_flip(v) {
+                    if fit > current + TOLERANCE {
+                        assignment.flip(v);
+                        improved = true;
+                    }
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+        let fit = assignment.fit().unwrap_or(f64::NAN);
+        if best
+            .as_ref()
+            .map_or(true, |(_, best_fit)| best_fit.is_nan() || fit > *best_fit)
+        {
+            best = Some((assignment.core, fit));
+        }
+    }
+
+    let (core, fit) = best.unwrap_or((vec![false; neighbours.len()], f64::NAN));
+    (NodeState::new_from_values(g.clone(), core), fit)
+}
+
+#[cfg(test)]
+mod core_periphery_test {
+    use super::*;
+    use crate::test_storage;
+
+    /// A clique of 8 nodes with a pendant node attached to three of them
+    fn clique_with_pendants() -> Graph {
+        let graph = Graph::new();
+        for src in 1..=8u64 {
+            for dst in src + 1..=8 {
+                graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+            }
+        }
+        for (clique_node, pendant) in [(1, 11), (2, 12), (3, 13)] {
+            graph
+                .add_edge(1, clique_node, pendant, NO_PROPS, None)
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn clique_is_core() {
+        let graph = clique_with_pendants();
+
+        test_storage!(&graph, |graph| {
+            let (core, fit) = core_periphery(graph, 100, Some(7));
+            for node in 1..=8u64 {
+                assert_eq!(core.get_by_node(node), Some(&true));
+            }
+            for node in 11..=13u64 {
+                assert_eq!(core.get_by_node(node), Some(&false));
+            }
+            // 28 clique edges, 3 pendant edges and 24 unconnected pairs out of 55
+            let expected = 28.0 * 24.0 / (31.0f64 * 24.0 * 28.0 * 27.0).sqrt();
+            assert!((fit - expected).abs() < 1e-9);
+            assert!(fit > 0.85);
+
+            let (same_core, same_fit) = core_periphery(graph, 100, Some(7));
+            assert_eq!(core, same_core);
+            assert_eq!(fit, same_fit);
+        });
+    }
+
+    #[test]
+    fn respects_windows() {
+        let graph = clique_with_pendants();
+
+        test_storage!(&graph, |graph| {
+            // without the pendants the clique is complete and the fit is undefined
+            let (core, fit) = core_periphery(&graph.window(0, 1), 100, Some(7));
+            assert_eq!(core.len(), 8);
+            assert!(fit.is_nan());
+        });
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    db::api::{state::NodeState, view::StaticGraphViewOps},
+    prelude::*,
+};
+use std::collections::{HashMap, VecDeque};
+
+/// Computes the edge betweenness of every edge of an undirected graph using Brandes' algorithm
+fn edge_betweenness(adjacency: &[Vec<usize>]) -> HashMap<(usize, usize), f64> {
+    let n = adjacency.len();
+    let mut betweenness: HashMap<(usize, usize), f64> = HashMap::new();
+    for source in 0..n {
+        let mut stack = Vec::with_capacity(n);
+        let mut predecessors: Vec<Vec<usize>> = vec![vec![]; n];
+        let mut sigma = vec![0f64; n];
+        let mut dist: Vec<Option<usize>> = vec![None; n];
+        sigma[source] = 1.0;
+        dist[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            stack.push(v);
+            let next_dist = dist[v].unwrap() + 1;
+            for &w in &adjacency[v] {
+                if dist[w].is_none() {
+                    dist[w] = Some(next_dist);
+                    queue.push_back(w);
+                }
+                if dist[w] == Some(next_dist) {
+                    sigma[w] += sigma[v];
+                    predecessors[w].push(v);
+                }
+            }
+        }
+
+        let mut delta = vec![0f64; n];
+        while let Some(w) = stack.pop() {
+            for &v in &predecessors[w] {
+                let contribution = sigma[v] / sigma[w] * (1.0 + delta[w]);
+                *betweenness.entry((v.min(w), v.max(w))).or_insert(0.0) += contribution;
+                delta[v] += contribution;
+            }
+        }
+    }
+    betweenness
+}
+
+/// Labels the connected components of an undirected graph, numbered in order of their first node
+fn components(adjacency: &[Vec<usize>]) -> (Vec<u64>, usize) {
+    let mut labels = vec![u64::MAX; adjacency.len()];
+    let mut num_components = 0;
+    for start in 0..adjacency.len() {
+        if labels[start] != u64::MAX {
+            continue;
+        }
+        labels[start] = num_components as u64;
+        let mut stack = vec![start];
+        while let Some(v) = stack.pop() {
+            for &w in &adjacency[v] {
+                if labels[w] == u64::MAX {
+                    labels[w] = num_components as u64;
+                    stack.push(w);
+                }
+            }
+        }
+        num_components += 1;
+    }
+    (labels, num_components)
+}
+
+/// Computes communities using the Girvan–Newman algorithm
+///
+/// Edge directions are ignored. In every step the edge with the highest edge betweenness is
+/// removed (ties are broken in favour of the edge between the earliest nodes) and the connected
+/// components of the remaining graph are recorded, until there are at least `n_communities`
+/// components or no edges are left. The edge betweenness is recomputed after every removal, so
+/// the algorithm is only suitable for small graphs.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `n_communities` - The number of communities at which to stop
+///
+/// # Returns
+///
+/// One [NodeState] per removed edge with the community id of each node after the removal.
+/// Communities are numbered in the order of the nodes in the graph. The result is empty if the
+/// graph already has at least `n_communities` connected components.
+///
+pub fn girvan_newman<G>(g: &G, n_communities: usize) -> Vec<NodeState<'static, u64, G>>
+where
+    G: StaticGraphViewOps,
+{
+    let index: HashMap<usize, usize> = g
+        .nodes()
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (node.node.index(), i))
+        .collect();
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; index.len()];
+    for node in g.nodes() {
+        let v = index[&node.node.index()];
+        adjacency[v] = node
+            .neighbours()
+            .iter()
+            .map(|n| index[&n.node.index()])
+            .filter(|&n| n != v)
+            .collect();
+    }
+
+    let mut snapshots = Vec::new();
+    let (_, mut num_components) = components(&adjacency);
+    while num_components < n_communities {
+        let betweenness = edge_betweenness(&adjacency);
+        let Some((&(a, b), _)) = betweenness
+            .iter()
+            .max_by(|(e1, b1), (e2, b2)| b1.partial_cmp(b2).unwrap().then_with(|| e2.cmp(e1)))
+        else {
+            break;
+        };
+        adjacency[a].retain(|&n| n != b);
+        adjacency[b].retain(|&n| n != a);
+
+        let (labels, count) = components(&adjacency);
+        num_components = count;
+        snapshots.push(NodeState::new_from_values(g.clone(), labels));
+    }
+    snapshots
+}
+
+#[cfg(test)]
+mod girvan_newman_test {
+    use super::*;
+    use crate::test_storage;
+
+    fn dumbbell() -> Graph {
+        let graph = Graph::new();
+        for clique in [1..5u64, 5..9] {
+            for src in clique.clone() {
+                for dst in src + 1..clique.end {
+                    graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+                }
+            }
+        }
+        graph.add_edge(0, 4, 5, NO_PROPS, None).unwrap();
+        graph
+    }
+
+    #[test]
+    fn dumbbell_splits_at_bridge() {
+        let graph = dumbbell();
+
+        test_storage!(&graph, |graph| {
+            let snapshots = girvan_newman(graph, 2);
+            assert_eq!(snapshots.len(), 1);
+            let communities = &snapshots[0];
+            for node in 1..=4u64 {
+                assert_eq!(communities.get_by_node(node), Some(&0));
+            }
+            for node in 5..=8u64 {
+                assert_eq!(communities.get_by_node(node), Some(&1));
+            }
+
+            assert!(girvan_newman(graph, 1).is_empty());
+        });
+    }
+
+    #[test]
+    fn path_splits_until_no_edges() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (3, 2)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let snapshots = girvan_newman(graph, 5);
+            assert_eq!(snapshots.len(), 2);
+            // both edges have the same betweenness, the edge between 1 and 2 is removed first
+            assert_eq!(snapshots[0], vec![0, 1, 1]);
+            assert_eq!(snapshots[1], vec![0, 1, 2]);
+        });
+    }
+}
@@ -1,9 +1,17 @@
-use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, SeedableRng};
-use raphtory_api::core::entities::GID;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, Rng, SeedableRng};
+use raphtory_api::core::entities::{GID, VID};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    hash::Hash,
+};
 
 use crate::{
-    db::{api::view::StaticGraphViewOps, graph::node::NodeView},
+    core::entities::nodes::node_ref::{AsNodeRef, NodeRef},
+    db::{
+        api::{state::NodeState, view::StaticGraphViewOps},
+        graph::node::NodeView,
+    },
+    errors::GraphError,
     prelude::*,
 };
 
@@ -75,6 +83,179 @@ fn find_max_label(label_count: &BTreeMap<GID, f64>) -> Option<GID> {
         .map(|(label, _)| label.clone())
 }
 
+/// Infers the labels of nodes from a few labelled seed nodes using asynchronous label propagation
+///
+/// Seed nodes keep their label. In every iteration, all other nodes are visited in a random order
+/// and take the most common label among their labelled neighbours, ties are broken randomly
+/// unless the current label of the node is one of the most common labels. Iterations stop once
+/// no label changes or after `max_iter` iterations.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `seeds` - The known labels of the seed nodes
+/// - `max_iter` - The maximum number of iterations
+/// - `rng_seed` - (Optional) Seed for the visiting order and tie breaks, the result is deterministic if a seed is provided
+///
+/// # Returns
+///
+/// A [NodeState] with the label of each node, nodes that are not connected to any seed node
+/// remain unlabelled (`None`)
+///
+/// # Errors
+///
+/// Returns [GraphError::NodeMissingError] if a seed node is not in the graph
+///
+pub fn label_propagation_seeded<G, V>(
+    g: &G,
+    seeds: HashMap<V, u64>,
+    max_iter: usize,
+    rng_seed: Option<u64>,
+) -> Result<NodeState<'static, Option<u64>, G>, GraphError>
+where
+    G: StaticGraphViewOps,
+    V: AsNodeRef + Hash + Eq,
+{
+    let mut labels: Vec<Option<u64>> = vec![None; g.unfiltered_num_nodes()];
+    let mut fixed = vec![false; g.unfiltered_num_nodes()];
+    for (node, label) in seeds {
+        let node_ref = node.as_node_ref();
+        let node = g.node(node_ref).ok_or_else(|| {
+            GraphError::NodeMissingError(match node_ref {
+                NodeRef::Internal(vid) => g.node_id(vid),
+                NodeRef::External(gid) => gid.to_owned(),
+            })
+        })?;
+        labels[node.node.index()] = Some(label);
+        fixed[node.node.index()] = true;
+    }
+
+    let mut rng = StdRng::seed_from_u64(rng_seed.unwrap_or_else(|| thread_rng().gen()));
+    let mut order: Vec<VID> = g
+        .nodes()
+        .iter()
+        .map(|node| node.node)
+        .filter(|node| !fixed[node.index()])
+        .collect();
+    for _ in 0..max_iter {
+        order.shuffle(&mut rng);
+        let mut changed = false;
+        for &node in &order {
+            let mut label_count: BTreeMap<u64, usize> = BTreeMap::new();
+            for neighbour in g.node(node).unwrap().neighbours() {
+                if let Some(label) = labels[neighbour.node.index()] {
+                    *label_count.entry(label).or_insert(0) += 1;
+                }
+            }
+            let Some(&max_count) = label_count.values().max() else {
+                continue;
+            };
+            let max_labels: Vec<u64> = label_count
+                .into_iter()
+                .filter(|(_, count)| *count == max_count)
+                .map(|(label, _)| label)
+                .collect();
+            let current = labels[node.index()];
+            if current.is_some_and(|label| max_labels.contains(&label)) {
+                continue;
+            }
+            labels[node.index()] = max_labels.choose(&mut rng).copied();
+            changed = true;
+        }
+        if !changed {
+            break;
+        }
+    }
+    Ok(NodeState::new_from_eval(g.clone(), labels))
+}
+
+/// Computes communities using the synchronous label propagation algorithm
+///
+/// Every node starts with its own label. In every round, all nodes simultaneously take the most
+/// common label among their neighbours in the previous round, ties are broken randomly unless the
+/// current label of the node is one of the most common labels. Rounds stop once no label changes
+/// or after `max_iter` rounds.
+///
+/// Synchronous updates can oscillate, e.g., the two sides of a bipartite graph keep swapping
+/// labels. If a round reproduces the labels from two rounds before, the next round is
+/// asynchronous, i.e., the nodes are updated one after the other in random order and see the
+/// labels their neighbours took earlier in the same round.
+///
+/// Also available as [algorithms::community::label_propagation](crate::algorithms::community::label_propagation).
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `seed` - (Optional) Array of 32 bytes of u8 which is set as the rng seed
+/// - `max_iter` - The maximum number of rounds
+///
+/// # Returns
+///
+/// A [NodeState] with the community id of each node
+///
+pub fn label_propagation_sync<G>(
+    g: &G,
+    seed: Option<[u8; 32]>,
+    max_iter: usize,
+) -> NodeState<'static, u64, G>
+where
+    G: StaticGraphViewOps,
+{
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; g.unfiltered_num_nodes()];
+    let mut nodes = Vec::with_capacity(g.count_nodes());
+    for node in g.nodes() {
+        let v = node.node.index();
+        adjacency[v] = node
+            .neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .filter(|&n| n != v)
+            .collect();
+        nodes.push(v);
+    }
+
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut labels: Vec<u64> = (0..adjacency.len() as u64).collect();
+    let mut previous_labels: Option<Vec<u64>> = None;
+    let mut asynchronous = false;
+    for _ in 0..max_iter {
+        if asynchronous {
+            nodes.shuffle(&mut rng);
+        }
+        let mut next_labels = labels.clone();
+        let mut changed = false;
+        for &node in &nodes {
+            let neighbour_labels = if asynchronous { &next_labels } else { &labels };
+            let mut label_count: BTreeMap<u64, usize> = BTreeMap::new();
+            for &neighbour in &adjacency[node] {
+                *label_count.entry(neighbour_labels[neighbour]).or_insert(0) += 1;
+            }
+            let Some(&max_count) = label_count.values().max() else {
+                continue;
+            };
+            let max_labels: Vec<u64> = label_count
+                .into_iter()
+                .filter(|(_, count)| *count == max_count)
+                .map(|(label, _)| label)
+                .collect();
+            if max_labels.contains(&labels[node]) {
+                continue;
+            }
+            next_labels[node] = *max_labels.choose(&mut rng).unwrap();
+            changed = true;
+        }
+        if !changed {
+            break;
+        }
+        asynchronous = previous_labels.as_ref() == Some(&next_labels);
+        previous_labels = Some(std::mem::replace(&mut labels, next_labels));
+    }
+    NodeState::new_from_eval(g.clone(), labels)
+}
+
 #[cfg(test)]
 mod lpa_tests {
     use super::*;
@@ -123,4 +304,162 @@ mod lpa_tests {
             }
         });
     }
+
+    fn planted_communities() -> Graph {
+        let graph = Graph::new();
+        for community in [0..6u64, 6..12] {
+            for src in community.clone() {
+                for dst in src + 1..community.end {
+                    graph.add_edge(1, src, dst, NO_PROPS, None).unwrap();
+                }
+            }
+        }
+        // the communities are only connected through their seed nodes
+        graph.add_edge(1, 0, 6, NO_PROPS, None).unwrap();
+        graph.add_node(1, 12, NO_PROPS, None).unwrap();
+        graph
+    }
+
+    #[test]
+    fn seeded_recovers_planted_communities() {
+        let graph = planted_communities();
+
+        test_storage!(&graph, |graph| {
+            let seeds = HashMap::from([(0u64, 1u64), (6u64, 2u64)]);
+            let result = label_propagation_seeded(graph, seeds.clone(), 10, Some(3)).unwrap();
+            for node in 0..6u64 {
+                assert_eq!(result.get_by_node(node), Some(&Some(1)));
+            }
+            for node in 6..12u64 {
+                assert_eq!(result.get_by_node(node), Some(&Some(2)));
+            }
+            // not connected to any seed
+            assert_eq!(result.get_by_node(12u64), Some(&None));
+
+            let again = label_propagation_seeded(graph, seeds, 10, Some(3)).unwrap();
+            assert_eq!(result, again);
+        });
+    }
+
+    #[test]
+    fn seeded_labels_are_fixed() {
+        let graph = Graph::new();
+        for (src, dst) in [("a", "b"), ("a", "c"), ("a", "d"), ("b", "c")] {
+            graph.add_edge(1, src, dst, NO_PROPS, None).unwrap();
+        }
+        // "a" would take the label of its neighbours if it was not a seed
+        let seeds = HashMap::from([("a", 1), ("b", 2), ("c", 2)]);
+        let result = label_propagation_seeded(&graph, seeds, 10, Some(1)).unwrap();
+        assert_eq!(result.get_by_node("a"), Some(&Some(1)));
+        assert_eq!(result.get_by_node("d"), Some(&Some(1)));
+
+        let result = label_propagation_seeded(&graph, HashMap::from([("z", 1)]), 10, None);
+        assert!(matches!(result, Err(GraphError::NodeMissingError(_))));
+    }
+
+    fn two_cliques(bridge: bool) -> Graph {
+        let graph = Graph::new();
+        for clique in [0..5u64, 5..10] {
+            for src in clique.clone() {
+                for dst in src + 1..clique.end {
+                    graph.add_edge(1, src, dst, NO_PROPS, None).unwrap();
+                }
+            }
+        }
+        if bridge {
+            graph.add_edge(1, 0, 5, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    fn clique_labels(
+        result: &NodeState<'static, u64, impl StaticGraphViewOps>,
+    ) -> [HashSet<u64>; 2] {
+        [0..5u64, 5..10].map(|clique| {
+            clique
+                .map(|node| *result.get_by_node(node).unwrap())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn sync_cliques_are_uniform() {
+        let graph = two_cliques(true);
+
+        test_storage!(&graph, |graph| {
+            for seed in 0..5 {
+                let result = label_propagation_sync(graph, Some([seed; 32]), 100);
+                for labels in clique_labels(&result) {
+                    assert_eq!(labels.len(), 1);
+                }
+                let again = label_propagation_sync(graph, Some([seed; 32]), 100);
+                assert_eq!(result, again);
+            }
+        });
+    }
+
+    #[test]
+    fn sync_bipartite_does_not_oscillate() {
+        let graph = Graph::new();
+        // a single edge and a star, both swap labels forever with purely synchronous updates
+        graph.add_edge(1, 0, 1, NO_PROPS, None).unwrap();
+        for leaf in 11..14 {
+            graph.add_edge(1, 10, leaf, NO_PROPS, None).unwrap();
+        }
+        // complete bipartite graph
+        for left in 20..23 {
+            for right in 23..26 {
+                graph.add_edge(1, left, right, NO_PROPS, None).unwrap();
+            }
+        }
+
+        test_storage!(&graph, |graph| {
+            for seed in 0..5 {
+                // an even number of rounds returns to the initial labels when oscillating
+                let result = label_propagation_sync(graph, Some([seed; 32]), 100);
+                let label = |node: u64| *result.get_by_node(node).unwrap();
+                assert_eq!(label(0), label(1));
+                for leaf in 11..14 {
+                    assert_eq!(label(10), label(leaf));
+                }
+                // the labels are stable, every node has one of the most common labels of its
+                // neighbours
+                for node in graph.nodes() {
+                    let mut counts: HashMap<u64, usize> = HashMap::new();
+                    for neighbour in node.neighbours() {
+                        *counts
+                            .entry(label(neighbour.id().as_u64().unwrap()))
+                            .or_default() += 1;
+                    }
+                    let max_count = counts.values().max().copied().unwrap();
+                    let own = label(node.id().as_u64().unwrap());
+                    assert_eq!(counts.get(&own), Some(&max_count));
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn community_path() {
+        let graph = two_cliques(true);
+        let result = crate::algorithms::community::label_propagation(&graph, Some([1; 32]), 100);
+        assert_eq!(result, label_propagation_sync(&graph, Some([1; 32]), 100));
+    }
+
+    #[test]
+    fn sync_disconnected_cliques() {
+        let graph = two_cliques(false);
+        graph.add_node(1, 10, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let result = label_propagation_sync(graph, None, 100);
+            let [first, second] = clique_labels(&result);
+            assert_eq!(first.len(), 1);
+            assert_eq!(second.len(), 1);
+            assert_ne!(first, second);
+            // an isolated node keeps its own label
+            let isolated = *result.get_by_node(10u64).unwrap();
+            assert!(!first.contains(&isolated) && !second.contains(&isolated));
+        });
+    }
 }
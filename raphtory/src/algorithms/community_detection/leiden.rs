@@ -0,0 +1,405 @@
+use crate::{
+    algorithms::community_detection::modularity::{
+        ComID, ModularityFunction, ModularityUnDir, Partition,
+    },
+    core::entities::VID,
+    db::api::state::NodeState,
+    prelude::*,
+};
+use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use std::collections::{HashMap, VecDeque};
+
+const TOL: f64 = 1e-8;
+
+/// Leiden algorithm for community detection
+///
+/// Traag, V. A., Waltman, L., & van Eck, N. J. (2019). From Louvain to Leiden: guaranteeing
+/// well-connected communities. Scientific Reports, 9(1), 5233.
+///
+/// Like [Louvain](crate::algorithms::community_detection::louvain::louvain), the algorithm
+/// optimises modularity (see [ModularityUnDir]) by moving nodes between communities and
+/// aggregating the communities into nodes. Before aggregating, every community is refined by
+/// merging its nodes into well-connected subcommunities and the aggregated nodes are the refined
+/// communities. As a result, every returned community induces a connected subgraph. The graph
+/// is treated as undirected.
+///
+/// # Arguments
+///
+/// - `g` (GraphView): the graph view
+/// - `resolution` (float): the resolution parameter for modularity
+/// - `weight_prop` (str | None): the edge property to use for weights, edges without a numeric value count with weight 1.0
+/// - `max_iterations` (int): the maximum number of iterations of moving, refining and aggregating
+/// - `seed` (None | int): seed for the order in which nodes are visited, the result is deterministic if a seed is provided
+///
+/// # Returns
+///
+///  A [NodeState] containing a mapping of vertices to cluster ID.
+pub fn leiden<'graph, G: GraphViewOps<'graph>>(
+    g: &G,
+    resolution: f64,
+    weight_prop: Option<&str>,
+    max_iterations: usize,
+    seed: Option<u64>,
+) -> NodeState<'graph, usize, G> {
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::seed_from_u64(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut modularity_state = ModularityUnDir::new(
+        g,
+        weight_prop,
+        resolution,
+        Partition::new_singletons(g.count_nodes()),
+        TOL,
+    );
+    // the node on the current level for each node of the graph
+    let mut global_partition: Vec<_> = (0..g.count_nodes()).collect();
+
+    for _ in 0..max_iterations {
+        move_nodes_fast(&mut modularity_state, &mut rng);
+        let mut partition = communities(&modularity_state);
+        let num_nodes = partition.len();
+        if compact(&mut partition) == num_nodes {
+            // every community is a single node of this level
+            break;
+        }
+
+        let mut refined = refine(&modularity_state, &partition, &mut rng);
+        if compact(&mut refined) == num_nodes {
+            // aggregating would not change the graph
+            break;
+        }
+        // aggregate the refined communities, every aggregated node starts out in the community
+        // of the nodes it contains
+        for (v, r) in refined.iter().enumerate() {
+            modularity_state.move_node(&VID(v), ComID(*r));
+        }
+        let aggregated = modularity_state.aggregate();
+        let mut aggregated_partition = vec![0; aggregated.num_coms()];
+        for (v, c) in partition.iter().enumerate() {
+            aggregated_partition[aggregated.com(&VID(v)).index()] = *c;
+        }
+        for (v, c) in aggregated_partition.into_iter().enumerate() {
+            modularity_state.move_node(&VID(v), ComID(c));
+        }
+        for c in global_partition.iter_mut() {
+            *c = aggregated.com(&VID(*c)).index();
+        }
+    }
+    // the nodes of every level induce connected subgraphs, but if the iterations stopped before
+    // converging, the communities of the last level may not be connected
+    let partition = split_disconnected(&modularity_state, &communities(&modularity_state));
+    for c in global_partition.iter_mut() {
+        *c = partition[*c];
+    }
+    NodeState::new_from_values(g.clone(), global_partition)
+}
+
+/// The community of every node of the current level
+fn communities(modularity_state: &ModularityUnDir) -> Vec<usize> {
+    modularity_state
+        .nodes()
+        .map(|v| modularity_state.partition().com(&v).index())
+        .collect()
+}
+
+/// Modularity gain (up to a constant factor) of adding an isolated node with total weight `k_v`
+/// and edge weight `w` to a community with total weight `k_com`
+fn gain(w: f64, k_v: f64, k_com: f64, resolution: f64, m2: f64) -> f64 {
+    w - resolution * k_v * k_com / m2
+}
+
+/// Local moving phase, nodes are revisited whenever the community of a neighbour changes
+fn move_nodes_fast(modularity_state: &mut ModularityUnDir, rng: &mut StdRng) {
+    let mut order: Vec<_> = modularity_state.nodes().collect();
+    order.shuffle(rng);
+    let mut in_queue = vec![true; order.len()];
+    let mut queue = VecDeque::from(order);
+
+    while let Some(v) = queue.pop_front() {
+        in_queue[v.index()] = false;
+        // ties are broken by the smallest community id to not depend on the iteration order
+        let best = modularity_state
+            .candidate_moves(&v)
+            .map(|c| (c, modularity_state.move_delta(&v, c)))
+            .max_by(|(c1, delta1), (c2, delta2)| delta1.total_cmp(delta2).then(c2.cmp(c1)));
+        if let Some((best_c, delta)) = best {
+            if best_c != modularity_state.partition().com(&v) && delta > TOL {
+                modularity_state.move_node(&v, best_c);
+                for (u, _) in modularity_state.neighbours(&v) {
+                    if !in_queue[u.index()] && modularity_state.partition().com(&u) != best_c {
+                        in_queue[u.index()] = true;
+                        queue.push_back(u);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Sum the weights from `v` to each of the communities of its neighbours, sorted by community
+fn com_weights(
+    modularity_state: &ModularityUnDir,
+    v: usize,
+    partition: &[usize],
+) -> Vec<(usize, f64)> {
+    let mut weights: HashMap<usize, f64> = HashMap::new();
+    for (u, w) in modularity_state.neighbours(&VID(v)) {
+        *weights.entry(partition[u.index()]).or_insert(0.0) += w;
+    }
+    let mut weights: Vec<_> = weights.into_iter().collect();
+    weights.sort_by_key(|(c, _)| *c);
+    weights
+}
+
+/// Refinement phase, starting from singletons nodes are merged with well-connected subsets of
+/// their community
+fn refine(modularity_state: &ModularityUnDir, partition: &[usize], rng: &mut StdRng) -> Vec<usize> {
+    let n = partition.len();
+    let m2 = modularity_state.total_weight();
+    let resolution = modularity_state.resolution();
+    let mut refined: Vec<usize> = (0..n).collect();
+    if m2 == 0.0 {
+        return refined;
+    }
+    let k: Vec<f64> = (0..n).map(|v| modularity_state.degree(&VID(v))).collect();
+    let mut k_com = vec![0.0; n];
+    for v in 0..n {
+        k_com[partition[v]] += k[v];
+    }
+    let mut k_refined = k.clone();
+    let mut size_refined = vec![1usize; n];
+    // weight of the edges from each refined community to the rest of its community
+    let mut external: Vec<f64> = (0..n)
+        .map(|v| {
+            modularity_state
+                .neighbours(&VID(v))
+                .filter(|(u, _)| partition[u.index()] == partition[v])
+                .map(|(_, w)| w)
+                .sum()
+        })
+        .collect();
+    let well_connected =
+        |external: f64, k: f64, k_com: f64| external >= resolution * k * (k_com - k) / m2 - TOL;
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.shuffle(rng);
+    for v in order {
+        let c = partition[v];
+        if size_refined[refined[v]] > 1 || !well_connected(external[v], k[v], k_com[c]) {
+            continue;
+        }
+        let k_v = k[v];
+        let mut best: Option<(usize, f64)> = None;
+        for (r, w) in com_weights(modularity_state, v, &refined) {
+            // node `r` stays in refined community `r` as long as it is not empty
+            if r == refined[v] || partition[r] != c {
+                continue;
+            }
+            if !well_connected(external[r], k_refined[r], k_com[c]) {
+                continue;
+            }
+            let delta = gain(w, k_v, k_refined[r], resolution, m2);
+            if delta >= 0.0 && best.is_none_or(|(_, best_delta)| delta > best_delta + TOL) {
+                best = Some((r, delta));
+            }
+        }
+        if let Some((r, _)) = best {
+            let w_v_r: f64 = modularity_state
+                .neighbours(&VID(v))
+                .filter(|(u, _)| refined[u.index()] == r)
+                .map(|(_, w)| w)
+                .sum();
+            external[r] += external[v] - 2.0 * w_v_r;
+            k_refined[r] += k_v;
+            size_refined[r] += 1;
+            size_refined[refined[v]] -= 1;
+            k_refined[refined[v]] -= k_v;
+            refined[v] = r;
+        }
+    }
+    refined
+}
+
+/// Split every community into its connected components, labelled in order of first appearance
+fn split_disconnected(modularity_state: &ModularityUnDir, partition: &[usize]) -> Vec<usize> {
+    let mut components = vec![usize::MAX; partition.len()];
+    let mut num_components = 0;
+    for start in 0..partition.len() {
+        if components[start] != usize::MAX {
+            continue;
+        }
+        components[start] = num_components;
+        let mut stack = vec![start];
+        while let Some(v) = stack.pop() {
+            for (u, _) in modularity_state.neighbours(&VID(v)) {
+                let u = u.index();
+                if components[u] == usize::MAX && partition[u] == partition[v] {
+                    components[u] = num_components;
+                    stack.push(u);
+                }
+            }
+        }
+        num_components += 1;
+    }
+    components
+}
+
+/// Relabel communities as `0..num_coms` in order of first appearance and return `num_coms`
+fn compact(partition: &mut [usize]) -> usize {
+    let mut labels: HashMap<usize, usize> = HashMap::new();
+    for c in partition.iter_mut() {
+        let next = labels.len();
+        *c = *labels.entry(*c).or_insert(next);
+    }
+    labels.len()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        algorithms::{
+            community_detection::leiden::leiden, components::weakly_connected_components,
+        },
+        db::api::{state::NodeState, view::StaticGraphViewOps},
+        graphgen::stochastic_block_model::stochastic_block_model,
+        prelude::*,
+        test_storage,
+    };
+    use std::collections::{HashMap, HashSet};
+
+    #[cfg(feature = "io")]
+    use crate::{
+        algorithms::community_detection::modularity::{
+            ModularityFunction, ModularityUnDir, Partition,
+        },
+        graph_loader::karate_club::karate_club_graph,
+    };
+
+    fn assert_communities_connected<G: StaticGraphViewOps>(
+        graph: &G,
+        result: &NodeState<'static, usize, G>,
+    ) {
+        let mut communities: HashMap<usize, Vec<_>> = HashMap::new();
+        for (node, c) in result.iter() {
+            communities.entry(*c).or_default().push(node.node);
+        }
+        for (c, nodes) in communities {
+            let components = weakly_connected_components(&graph.subgraph(nodes));
+            let num_components = components.iter_values().collect::<HashSet<_>>().len();
+            assert_eq!(num_components, 1, "community {c} is not connected");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "io")]
+    fn test_leiden_karate_club() {
+        let graph = karate_club_graph();
+
+        test_storage!(&graph, |graph| {
+            let result = leiden(graph, 1.0, None, 10, Some(42));
+            let again = leiden(graph, 1.0, None, 10, Some(42));
+            assert_eq!(result, again);
+            assert_communities_connected(graph, &result);
+
+            let partition: Partition = result.iter_values().collect();
+            let modularity = ModularityUnDir::new(graph, None, 1.0, partition, 1e-8).value();
+            assert!(modularity > 0.4, "modularity {modularity} is too low");
+            assert_ne!(result.get_by_node("0"), result.get_by_node("33"));
+        });
+    }
+
+    #[test]
+    fn test_leiden_weighted() {
+        let graph = Graph::new();
+        // two triangles joined by a heavy edge that should pull 3 and 4 together
+        for (src, dst, weight) in [
+            (1, 2, 1.0),
+            (2, 3, 1.0),
+            (1, 3, 1.0),
+            (4, 5, 1.0),
+            (5, 6, 1.0),
+            (4, 6, 1.0),
+            (3, 4, 20.0),
+        ] {
+            graph
+                .add_edge(0, src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+
+        let unweighted = leiden(&graph, 1.0, None, 10, Some(1));
+        assert_eq!(unweighted.get_by_node(1u64), unweighted.get_by_node(3u64));
+        assert_ne!(unweighted.get_by_node(3u64), unweighted.get_by_node(4u64));
+
+        let weighted = leiden(&graph, 1.0, Some("weight"), 10, Some(1));
+        assert_eq!(weighted.get_by_node(3u64), weighted.get_by_node(4u64));
+        assert_ne!(weighted.get_by_node(1u64), weighted.get_by_node(3u64));
+    }
+
+    #[test]
+    fn test_leiden_missing_weights() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (1, 3), (4, 5), (5, 6), (4, 6)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        // a property has one type across the graph, so the weight is either missing or a string
+        graph
+            .add_edge(0, 3, 4, [("weight", Prop::str("heavy"))], None)
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            // edges without a numeric weight count with weight 1.0 instead of panicking
+            let result = leiden(graph, 1.0, Some("weight"), 10, Some(1));
+            assert_eq!(result.get_by_node(1u64), result.get_by_node(3u64));
+            assert_eq!(result.get_by_node(4u64), result.get_by_node(6u64));
+            assert_ne!(result.get_by_node(3u64), result.get_by_node(4u64));
+        });
+    }
+
+    #[test]
+    fn test_leiden_communities_are_connected() {
+        // a hub that bridges two dense groups which are only connected through it, once the hub
+        // leaves, the community it leaves behind falls apart
+        let graph = Graph::new();
+        let mut edges = vec![];
+        for group in [[1, 2, 3], [4, 5, 6]] {
+            for (i, a) in group.iter().enumerate() {
+                for b in &group[i + 1..] {
+                    edges.push((*a, *b));
+                }
+                edges.push((0, *a));
+            }
+        }
+        for a in [0, 1, 4] {
+            for b in 10..14 {
+                edges.push((a, b));
+            }
+        }
+        for (src, dst) in edges {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        for seed in 0..20 {
+            let result = leiden(&graph, 1.0, None, 10, Some(seed));
+            assert_communities_connected(&graph, &result);
+        }
+
+        for seed in 0..5u8 {
+            let graph = Graph::new();
+            stochastic_block_model(
+                &graph,
+                &[20, 20, 20],
+                &[[0.3, 0.02, 0.02], [0.02, 0.3, 0.02], [0.02, 0.02, 0.3]],
+                Some([seed; 32]),
+            )
+            .unwrap();
+            test_storage!(&graph, |graph| {
+                let result = leiden(graph, 1.0, None, 10, Some(seed as u64));
+                assert!(graph
+                    .nodes()
+                    .iter()
+                    .all(|n| result.get_by_node(n).is_some()));
+                assert_communities_connected(graph, &result);
+            });
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     db::api::state::NodeState,
     prelude::GraphViewOps,
 };
-use rand::prelude::SliceRandom;
+use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
 
 /// Louvain algorithm for community detection
 ///
@@ -12,20 +12,25 @@ use rand::prelude::SliceRandom;
 ///
 /// - `g` (GraphView): the graph view
 /// - `resolution` (float): the resolution parameter for modularity
-/// - `weight_prop` (str | None): the edge property to use for weights (has to be float)
+/// - `weight_prop` (str | None): the edge property to use for weights, edges without a numeric value count with weight 1.0
 /// - `tol` (None | float): the floating point tolerance for deciding if improvements are significant (default: 1e-8)
+/// - `seed` (None | [u8; 32]): seed for the order in which nodes are visited, the result is deterministic if a seed is provided
 ///
 /// # Returns
 ///
-///  An [AlgorithmResult] containing a mapping of vertices to cluster ID.
+///  A [NodeState] containing a mapping of vertices to cluster ID.
 pub fn louvain<'graph, M: ModularityFunction, G: GraphViewOps<'graph>>(
     g: &G,
     resolution: f64,
     weight_prop: Option<&str>,
     tol: Option<f64>,
+    seed: Option<[u8; 32]>,
 ) -> NodeState<'graph, usize, G> {
     let tol = tol.unwrap_or(1e-8);
-    let mut rng = rand::thread_rng();
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
     let mut modularity_state = M::new(
         g,
         weight_prop,
@@ -76,8 +81,15 @@ mod test {
     };
     use proptest::prelude::*;
 
+    #[cfg(feature = "io")]
+    use crate::{
+        algorithms::community_detection::modularity::{ModularityFunction, Partition},
+        graph_loader::karate_club::karate_club_graph,
+    };
     #[cfg(feature = "io")]
     use raphtory_api::core::utils::logging::global_info_logger;
+    #[cfg(feature = "io")]
+    use std::collections::HashMap;
 
     #[test]
     fn test_louvain() {
@@ -108,7 +120,7 @@ mod test {
         }
 
         test_storage!(&graph, |graph| {
-            let result = louvain::<ModularityUnDir, _>(graph, 1.0, Some("weight"), None);
+            let result = louvain::<ModularityUnDir, _>(graph, 1.0, Some("weight"), None, None);
             assert!(graph
                 .nodes()
                 .iter()
@@ -124,7 +136,7 @@ mod test {
         }
 
         test_storage!(&graph, |graph| {
-            let result = louvain::<ModularityUnDir, _>(graph, 1.0, None, None);
+            let result = louvain::<ModularityUnDir, _>(graph, 1.0, None, None, None);
             assert!(graph
                 .nodes()
                 .iter()
@@ -132,6 +144,42 @@ mod test {
         });
     }
 
+    #[test]
+    #[cfg(feature = "io")]
+    fn test_louvain_karate_club() {
+        let graph = karate_club_graph();
+
+        test_storage!(&graph, |graph| {
+            let result = louvain::<ModularityUnDir, _>(graph, 1.0, None, None, Some([42; 32]));
+            let again = louvain::<ModularityUnDir, _>(graph, 1.0, None, None, Some([42; 32]));
+            assert_eq!(result, again);
+
+            let partition: Partition = result.iter_values().collect();
+            let modularity = ModularityUnDir::new(graph, None, 1.0, partition, 1e-8).value();
+            assert!(modularity > 0.38, "modularity {modularity} is too low");
+
+            // every community should mostly contain members of one club
+            let mut clubs: HashMap<usize, HashMap<String, usize>> = HashMap::new();
+            for node in graph.nodes().iter() {
+                let club = node.metadata().get("club").unwrap().to_string();
+                *clubs
+                    .entry(*result.get_by_node(node.node).unwrap())
+                    .or_default()
+                    .entry(club)
+                    .or_default() += 1;
+            }
+            let misassigned: usize = clubs
+                .values()
+                .map(|counts| counts.values().sum::<usize>() - counts.values().max().unwrap())
+                .sum();
+            assert!(
+                misassigned <= 3,
+                "{misassigned} nodes are not with their club"
+            );
+            assert_ne!(result.get_by_node("0"), result.get_by_node("33"));
+        });
+    }
+
     proptest! {
         #[test]
         fn test_all_nodes_in_communities(edges in any::<Vec<(u64, u64, f64)>>().prop_map(|mut v| {v.iter_mut().for_each(|(_, _, w)| *w = w.abs()); v})) {
@@ -169,7 +217,7 @@ mod test {
             .unwrap();
 
         test_storage!(&graph, |graph| {
-            let _ = louvain::<ModularityUnDir, _>(graph, 1.0, None, None);
+            let _ = louvain::<ModularityUnDir, _>(graph, 1.0, None, None, None);
             // TODO: Add assertions
         });
     }
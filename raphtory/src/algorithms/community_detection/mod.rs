@@ -1,3 +1,6 @@
+pub mod core_periphery;
+pub mod girvan_newman;
 pub mod label_propagation;
+pub mod leiden;
 pub mod louvain;
 pub mod modularity;
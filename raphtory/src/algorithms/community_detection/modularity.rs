@@ -160,6 +160,8 @@ pub trait ModularityFunction {
 }
 
 /// Undirected modularity function (assumes edges are all present in both directions in the graph)
+///
+/// Edges without a numeric value for the weight property have weight 1.0.
 pub struct ModularityUnDir {
     resolution: f64,
     partition: Partition,
@@ -172,6 +174,28 @@ pub struct ModularityUnDir {
     tol: f64,
 }
 
+impl ModularityUnDir {
+    /// The neighbours of `node` on the current level with the weights of the edges, without
+    /// self-loops
+    pub fn neighbours(&self, node: &VID) -> impl Iterator<Item = (VID, f64)> + '_ {
+        self.adj[node.index()].iter().copied()
+    }
+
+    /// The total weight of the edges of `node` on the current level
+    pub fn degree(&self, node: &VID) -> f64 {
+        self.k[node.index()]
+    }
+
+    /// Twice the total weight of the edges of the graph
+    pub fn total_weight(&self) -> f64 {
+        self.m2
+    }
+
+    pub fn resolution(&self) -> f64 {
+        self.resolution
+    }
+}
+
 impl ModularityFunction for ModularityUnDir {
     fn new<'graph, G: GraphViewOps<'graph>>(
         graph: G,
@@ -192,7 +216,8 @@ impl ModularityFunction for ModularityUnDir {
                     .filter(|e| e.dst() != e.src())
                     .map(|e| {
                         let w = weight_prop
-                            .map(|w| e.properties().get(w).unwrap_f64())
+                            .and_then(|w| e.properties().get(w))
+                            .and_then(|w| w.as_f64())
                             .unwrap_or(1.0);
                         let dst_id = local_id_map[&e.nbr().cloned()];
                         (dst_id, w)
@@ -209,7 +234,8 @@ impl ModularityFunction for ModularityUnDir {
                     .edge(node.node, node.node)
                     .map(|e| {
                         weight_prop
-                            .map(|w| e.properties().get(w).unwrap_f64())
+                            .and_then(|w| e.properties().get(w))
+                            .and_then(|w| w.as_f64())
                             .unwrap_or(1.0)
                     })
                     .filter(|w| w >= &tol)
@@ -199,7 +199,8 @@ impl<'graph, G: GraphView + 'graph> ComponentState<'graph, G> {
 ///
 /// # Returns
 ///
-/// An [NodeState] containing the mapping from each node to its component ID
+/// An [NodeState] containing the mapping from each node to its component ID. The IDs are only
+/// labels, use `groups()` on the result to get the nodes of each component.
 ///
 pub fn weakly_connected_components<G>(g: &G) -> NodeState<'static, usize, G>
 where
@@ -325,6 +326,21 @@ mod cc_test {
         }
     }
 
+    #[test]
+    fn isolated_node_cycle_and_dag() {
+        let graph = Graph::new();
+        let edges = [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (4, 6)];
+        for (src, dst) in edges {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_node(0, 7, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let results = weakly_connected_components(graph);
+            assert_same_partition(results, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+        });
+    }
+
     // connected community_detection on a graph with 1 node and a self loop
     #[test]
     fn simple_connected_components_3() {
@@ -4,13 +4,15 @@ use crate::{
         api::view::{GraphViewOps, StaticGraphViewOps},
         graph::views::node_subgraph::NodeSubgraph,
     },
-    prelude::{Graph, NodeStateGroupBy},
+    prelude::{Graph, NodeStateGroupBy, NodeStateOps, NodeViewOps},
 };
 use raphtory_api::core::entities::VID;
+use std::cmp::Reverse;
 
 /// Gives the large connected component of a graph.
 /// The large connected component is the largest (i.e., with the highest number of nodes)
-/// connected sub-graph of the network.
+/// connected sub-graph of the network. If several components have the largest number of nodes,
+/// the one containing the smallest node id is returned.
 ///
 /// # Example Usage:
 ///
@@ -35,8 +37,14 @@ impl LargestConnectedComponent for Graph {
 
         let lcc = connected_components
             .into_iter_subgraphs()
-            .map(|(_, subgraph)| subgraph)
-            .max_by(|l, r| l.count_nodes().cmp(&r.count_nodes()));
+            .map(|(_, subgraph)| {
+                let min_id = subgraph.nodes().id().iter_values().min();
+                (subgraph.count_nodes(), Reverse(min_id), subgraph)
+            })
+            .max_by(|(l_count, l_id, _), (r_count, r_id, _)| {
+                l_count.cmp(r_count).then_with(|| l_id.cmp(r_id))
+            })
+            .map(|(_, _, subgraph)| subgraph);
 
         lcc.unwrap_or(self.subgraph(Vec::<VID>::new()))
     }
@@ -47,7 +55,7 @@ mod largest_connected_component_test {
     use super::*;
     use crate::{
         db::api::view::GraphViewOps,
-        prelude::{AdditionOps, Graph, NodeViewOps, NO_PROPS},
+        prelude::{AdditionOps, Graph, NodeViewOps, GID, NO_PROPS},
     };
 
     #[test]
@@ -124,6 +132,25 @@ mod largest_connected_component_test {
         for (ts, src, dst) in edges {
             graph.add_edge(ts, src, dst, NO_PROPS, None).unwrap();
         }
-        let _subgraph = graph.largest_connected_component();
+        for _ in 0..100 {
+            let subgraph = graph.largest_connected_component();
+            let mut nodes = subgraph.nodes().id().collect_vec();
+            nodes.sort();
+            assert_eq!(nodes, vec![GID::U64(1), GID::U64(2), GID::U64(3)]);
+        }
+    }
+
+    #[test]
+    fn test_large_and_small_component() {
+        let graph = Graph::new();
+        for node in 0..9u64 {
+            graph.add_edge(0, node, node + 1, NO_PROPS, None).unwrap();
+        }
+        for (src, dst) in [(100, 101), (101, 102)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        let subgraph = graph.largest_connected_component();
+        assert_eq!(subgraph.count_nodes(), 10);
+        assert!(!subgraph.has_node(100));
     }
 }
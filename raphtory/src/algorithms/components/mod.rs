@@ -2,10 +2,12 @@ mod connected_components;
 mod in_components;
 mod lcc;
 mod out_components;
+mod rolling_components;
 mod scc;
 
 pub use connected_components::weakly_connected_components;
 pub use in_components::{in_component, in_components};
 pub use lcc::LargestConnectedComponent;
 pub use out_components::{out_component, out_components};
+pub use rolling_components::connected_components_rolling;
 pub use scc::strongly_connected_components;
@@ -0,0 +1,219 @@
+use crate::{
+    db::{
+        api::{
+            state::NodeState,
+            view::{StaticGraphViewOps, TimeOps},
+        },
+        graph::views::window_graph::WindowedGraph,
+    },
+    errors::GraphError,
+    prelude::*,
+};
+
+/// Union-find over node indices where the root of a set is always its smallest member
+struct UnionFind {
+    parent: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(num_nodes: usize) -> Self {
+        Self {
+            parent: (0..num_nodes).collect(),
+        }
+    }
+
+    fn find(&mut self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            self.parent[node] = self.parent[self.parent[node]];
+            node = self.parent[node];
+        }
+        node
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let a = self.find(a);
+        let b = self.find(b);
+        if a < b {
+            self.parent[b] = a;
+        } else if b < a {
+            self.parent[a] = b;
+        }
+    }
+}
+
+/// Computes the weakly connected components of every window of a rolling or expanding window
+/// set (see [TimeOps::rolling] and [TimeOps::expanding])
+///
+/// Instead of running [weakly_connected_components](super::weakly_connected_components) on every
+/// window, the components are maintained in a union-find structure while edge updates enter the
+/// window. As long as no edge update leaves the window between two consecutive windows, as is
+/// always the case for expanding windows, only the new edge updates are processed. Otherwise the
+/// union-find structure is rebuilt from the edge updates inside the new window.
+///
+/// An edge connects its endpoints in a window if it has an update inside the window.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `window` - The size of each window, `None` for expanding windows
+/// - `step` - How far the end of the window moves forward between consecutive windows
+///
+/// # Returns
+///
+/// The start and end of each window together with a [NodeState] containing the mapping from each
+/// node in the window to its component ID. The ID of a component is the internal id of its
+/// first node, use `groups()` on the result to get the nodes of each component.
+///
+/// # Errors
+///
+/// Returns [GraphError::ParseTime] if `step` is 0
+///
+#[allow(clippy::type_complexity)]
+pub fn connected_components_rolling<G>(
+    g: &G,
+    window: Option<i64>,
+    step: i64,
+) -> Result<
+    Vec<(
+        (Option<i64>, Option<i64>),
+        NodeState<'static, usize, WindowedGraph<G>>,
+    )>,
+    GraphError,
+>
+where
+    G: StaticGraphViewOps,
+{
+    let windows = match window {
+        Some(window) => g.rolling(window, Some(step))?,
+        None => g.expanding(step)?,
+    };
+
+    let mut events: Vec<(i64, usize, usize)> = g
+        .edges()
+        .explode()
+        .into_iter()
+        .filter_map(|edge| {
+            Some((
+                edge.time().ok()?,
+                edge.src().node.index(),
+                edge.dst().node.index(),
+            ))
+        })
+        .collect();
+    events.sort_unstable();
+
+    let num_nodes = g.unfiltered_num_nodes();
+    let mut components = UnionFind::new(num_nodes);
+    // the edge updates `events[start..end]` are in the union-find structure
+    let (mut start, mut end) = (0, 0);
+    let mut results = Vec::with_capacity(windows.len());
+    for view in windows {
+        let window_start = view
+            .start()
+            .map_or(0, |t| events.partition_point(|(time, _, _)| *time < t));
+        let window_end = view.end().map_or(events.len(), |t| {
+            events.partition_point(|(time, _, _)| *time < t)
+        });
+        if window_start != start || window_end < end {
+            components = UnionFind::new(num_nodes);
+            start = window_start;
+            end = window_start;
+        }
+        for &(_, src, dst) in &events[end..window_end.max(end)] {
+            components.union(src, dst);
+        }
+        end = window_end.max(end);
+
+        let values: Vec<usize> = (0..num_nodes).map(|node| components.find(node)).collect();
+        results.push((
+            (view.start(), view.end()),
+            NodeState::new_from_eval(view, values),
+        ));
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod rolling_components_test {
+    use super::*;
+    use crate::{algorithms::components::weakly_connected_components, test_storage};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::{BTreeSet, HashSet};
+
+    fn partition<G: StaticGraphViewOps>(
+        state: &NodeState<'static, usize, G>,
+    ) -> HashSet<BTreeSet<GID>> {
+        state
+            .groups()
+            .into_iter_groups()
+            .map(|(_, nodes)| nodes.id().collect())
+            .collect()
+    }
+
+    fn random_temporal_graph(seed: u64) -> Graph {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let graph = Graph::new();
+        for _ in 0..150 {
+            let t = rng.gen_range(0..100i64);
+            let src = rng.gen_range(0..60u64);
+            let dst = rng.gen_range(0..60u64);
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        for node in 60..65u64 {
+            graph
+                .add_node(rng.gen_range(0..100i64), node, NO_PROPS, None)
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn matches_per_window_computation() {
+        for seed in 0..3 {
+            let graph = random_temporal_graph(seed);
+
+            test_storage!(&graph, |graph| {
+                for (window, step) in [(Some(10), 5), (Some(10), 20), (Some(30), 7), (None, 9)] {
+                    let results = connected_components_rolling(graph, window, step).unwrap();
+                    let windows: Vec<_> = match window {
+                        Some(window) => graph.rolling(window, Some(step)).unwrap().collect(),
+                        None => graph.expanding(step).unwrap().collect(),
+                    };
+                    assert_eq!(results.len(), windows.len());
+                    for ((bounds, result), view) in results.iter().zip(windows) {
+                        assert_eq!(*bounds, (view.start(), view.end()));
+                        let expected = weakly_connected_components(&view);
+                        assert_eq!(result.len(), expected.len());
+                        assert_eq!(partition(result), partition(&expected));
+                    }
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn expanding_window_merges_components() {
+        let graph = Graph::new();
+        for (t, src, dst) in [(0, 1, 2), (5, 3, 4), (10, 2, 3)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let results = connected_components_rolling(graph, None, 5).unwrap();
+            let num_components: Vec<_> = results
+                .iter()
+                .map(|(_, result)| partition(result).len())
+                .collect();
+            assert_eq!(num_components, [1, 2, 1]);
+        });
+    }
+
+    #[test]
+    fn zero_step() {
+        let graph = random_temporal_graph(0);
+        assert!(matches!(
+            connected_components_rolling(&graph, Some(10), 0),
+            Err(GraphError::ParseTime { .. })
+        ));
+    }
+}
@@ -1,75 +1,76 @@
 use crate::{
     core::entities::VID,
-    db::{
-        api::{state::NodeState, view::StaticGraphViewOps},
-        graph::node::NodeView,
-    },
+    db::api::{state::NodeState, view::StaticGraphViewOps},
     prelude::*,
 };
-use std::collections::{HashMap, HashSet};
-
-fn tarjan<'graph, G>(
-    node: NodeView<&'graph G>,
-    index: &'graph mut u64,
-    stack: &'graph mut Vec<VID>,
-    indices: &'graph mut HashMap<VID, u64>,
-    lowlink: &'graph mut HashMap<VID, u64>,
-    on_stack: &'graph mut HashSet<VID>,
-    result: &'graph mut Vec<Vec<VID>>,
-) where
-    G: StaticGraphViewOps,
-{
-    *index += 1;
-    indices.insert(node.node, *index);
-    lowlink.insert(node.node, *index);
-    stack.push(node.node);
-    on_stack.insert(node.node);
-
-    for neighbor in node.out_neighbours() {
-        if !indices.contains_key(&neighbor.node) {
-            tarjan(neighbor, index, stack, indices, lowlink, on_stack, result);
-            lowlink.insert(node.node, lowlink[&node.node].min(lowlink[&neighbor.node]));
-        } else if on_stack.contains(&neighbor.node) {
-            lowlink.insert(node.node, lowlink[&node.node].min(indices[&neighbor.node]));
-        }
-    }
-
-    if indices[&node.node] == lowlink[&node.node] {
-        let mut component = Vec::new();
-        let mut top = stack.pop().unwrap();
-        on_stack.remove(&top);
-        component.push(top);
-        while top != node.node {
-            top = stack.pop().unwrap();
-            on_stack.remove(&top);
-            component.push(top);
-        }
-        result.push(component);
-    }
-}
+use raphtory_api::core::entities::GID;
 
 fn tarjan_scc<G>(graph: &G) -> Vec<Vec<VID>>
 where
     G: StaticGraphViewOps,
 {
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; graph.unfiltered_num_nodes()];
+    for node in graph.nodes() {
+        adjacency[node.node.index()] = node
+            .out_neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .collect();
+    }
+
     let mut index = 0;
     let mut stack = Vec::new();
-    let mut indices: HashMap<VID, u64> = HashMap::new();
-    let mut lowlink: HashMap<VID, u64> = HashMap::new();
-    let mut on_stack: HashSet<VID> = HashSet::new();
+    let mut indices = vec![usize::MAX; adjacency.len()];
+    let mut lowlink = vec![0; adjacency.len()];
+    let mut on_stack = vec![false; adjacency.len()];
     let mut result: Vec<Vec<VID>> = Vec::new();
+    // explicit call stack of (node, position of the next neighbour to visit) to avoid recursion
+    let mut call_stack: Vec<(usize, usize)> = Vec::new();
 
-    for node in (&graph).nodes() {
-        if !indices.contains_key(&node.node) {
-            tarjan(
-                node,
-                &mut index,
-                &mut stack,
-                &mut indices,
-                &mut lowlink,
-                &mut on_stack,
-                &mut result,
-            );
+    for node in graph.nodes() {
+        let root = node.node.index();
+        if indices[root] != usize::MAX {
+            continue;
+        }
+        indices[root] = index;
+        lowlink[root] = index;
+        index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        call_stack.push((root, 0));
+
+        while let Some(frame) = call_stack.last_mut() {
+            let v = frame.0;
+            if let Some(&w) = adjacency[v].get(frame.1) {
+                frame.1 += 1;
+                if indices[w] == usize::MAX {
+                    indices[w] = index;
+                    lowlink[w] = index;
+                    index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    call_stack.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(indices[w]);
+                }
+            } else {
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                }
+                if lowlink[v] == indices[v] {
+                    let mut component = Vec::new();
+                    loop {
+                        let top = stack.pop().unwrap();
+                        on_stack[top] = false;
+                        component.push(VID(top));
+                        if top == v {
+                            break;
+                        }
+                    }
+                    result.push(component);
+                }
+            }
         }
     }
     result
@@ -80,13 +81,17 @@ where
 /// Original Paper:
 /// https://web.archive.org/web/20170829214726id_/http://www.cs.ucsb.edu/~gilbert/cs240a/old/cs240aSpr2011/slides/TarjanDFS.pdf
 ///
+/// The depth-first search is iterative, so long paths do not overflow the stack.
+///
 /// # Arguments
 ///
 /// - `graph` - A reference to the graph
 ///
 /// # Returns
 ///
-/// An [AlgorithmResult] containing the mapping from each node to its component ID
+/// A [NodeState] containing the mapping from each node to its component ID. The components are
+/// numbered in order of their smallest node id, so the IDs are stable across runs. Use
+/// `groups()` on the result to get the nodes of each component.
 ///
 pub fn strongly_connected_components<G>(graph: &G) -> NodeState<'static, usize, G>
 where
@@ -147,11 +152,18 @@ where
             .map(|(vid, _)| VID(vid)),
     );
      */
-    let groups = tarjan_scc(graph);
+    let mut groups: Vec<(GID, Vec<VID>)> = tarjan_scc(graph)
+        .into_iter()
+        .map(|group| {
+            let min_id = group.iter().map(|&node| graph.node_id(node)).min().unwrap();
+            (min_id, group)
+        })
+        .collect();
+    groups.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
 
     let mut values = vec![usize::MAX; graph.unfiltered_num_nodes()];
 
-    for (id, group) in groups.into_iter().enumerate() {
+    for (id, (_, group)) in groups.into_iter().enumerate() {
         for VID(node) in group {
             values[node] = id;
         }
@@ -164,7 +176,10 @@ where
 mod strongly_connected_components_tests {
     use crate::{
         algorithms::components::scc::strongly_connected_components,
-        prelude::{AdditionOps, Graph, NodeStateGroupBy, NodeStateOps, NodeViewOps, NO_PROPS},
+        prelude::{
+            AdditionOps, Graph, LayerOps, NodeStateGroupBy, NodeStateOps, NodeViewOps, TimeOps,
+            NO_PROPS,
+        },
         test_storage,
     };
     use itertools::Itertools;
@@ -309,4 +324,98 @@ mod strongly_connected_components_tests {
             assert_eq!(scc_nodes, expected);
         });
     }
+
+    #[test]
+    fn scc_test_isolated_node_cycle_and_dag() {
+        let graph = Graph::new();
+        let edges = [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (4, 6)];
+        for (src, dst) in edges {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_node(0, 7, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let scc_nodes: HashSet<Vec<_>> = strongly_connected_components(graph)
+                .groups()
+                .into_iter_groups()
+                .map(|(_, v)| v.name().into_iter_values().sorted().collect())
+                .collect();
+
+            let expected: HashSet<Vec<String>> = [
+                vec!["1", "2", "3"],
+                vec!["4"],
+                vec!["5"],
+                vec!["6"],
+                vec!["7"],
+            ]
+            .into_iter()
+            .map(|v| v.into_iter().map(|s| s.to_owned()).collect())
+            .collect();
+            assert_eq!(scc_nodes, expected);
+        });
+    }
+
+    #[test]
+    fn scc_test_cycles_joined_by_bridge() {
+        let graph = Graph::new();
+        let edges = [
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 4),
+            (7, 1),
+        ];
+        for (src, dst) in edges {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_node(0, 8, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let result = strongly_connected_components(graph);
+            let ids: Vec<usize> = (1..=8u64)
+                .map(|node| *result.get_by_node(node).unwrap())
+                .collect();
+            // components are numbered by their smallest node id
+            assert_eq!(ids, [0, 0, 0, 1, 1, 1, 2, 3]);
+        });
+    }
+
+    #[test]
+    fn scc_test_respects_views() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 1), (3, 4), (4, 5)] {
+            graph.add_edge(0, src, dst, NO_PROPS, Some("a")).unwrap();
+        }
+        graph.add_edge(5, 5, 4, NO_PROPS, Some("b")).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let result = strongly_connected_components(graph);
+            assert_eq!(result.get_by_node(4u64), result.get_by_node(5u64));
+
+            let window = graph.window(0, 5);
+            let result = strongly_connected_components(&window);
+            assert_ne!(result.get_by_node(4u64), result.get_by_node(5u64));
+
+            let layer = graph.layers("a").unwrap();
+            let result = strongly_connected_components(&layer);
+            assert_eq!(result.get_by_node(1u64), result.get_by_node(3u64));
+            assert_ne!(result.get_by_node(4u64), result.get_by_node(5u64));
+        });
+    }
+
+    #[test]
+    fn scc_test_long_cycle() {
+        let graph = Graph::new();
+        let n = 200_000u64;
+        for node in 0..n {
+            graph
+                .add_edge(0, node, (node + 1) % n, NO_PROPS, None)
+                .unwrap();
+        }
+        let result = strongly_connected_components(&graph);
+        assert!(result.iter_values().all(|id| *id == 0));
+    }
 }
@@ -0,0 +1,249 @@
+use crate::{
+    db::{
+        api::{state::NodeState, view::StaticGraphViewOps},
+        graph::views::node_subgraph::NodeSubgraph,
+    },
+    prelude::*,
+};
+
+/// Computes the core number (coreness) of every node
+///
+/// The core number of a node is the largest `k` such that the node is part of the k-core, i.e.,
+/// the maximal subgraph in which every node has at least `k` neighbours. Edge directions are
+/// ignored, parallel edges are counted once and self-loops are ignored, so isolated nodes have a
+/// core number of 0. Uses the peeling algorithm by Batagelj and Zaversnik which runs in linear
+/// time in the number of edges.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+///
+/// # Returns
+///
+/// A [NodeState] containing the core number of each node
+///
+pub fn core_number<G>(g: &G) -> NodeState<'static, usize, G>
+where
+    G: StaticGraphViewOps,
+{
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; g.unfiltered_num_nodes()];
+    let mut nodes = Vec::with_capacity(g.count_nodes());
+    for node in g.nodes() {
+        let v = node.node.index();
+        adjacency[v] = node
+            .neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .filter(|&n| n != v)
+            .collect();
+        nodes.push(v);
+    }
+
+    // sort the nodes by degree using bin sort, `bin[d]` is the start of the nodes with degree d
+    let mut degree: Vec<usize> = adjacency.iter().map(|nbrs| nbrs.len()).collect();
+    let max_degree = nodes.iter().map(|&v| degree[v]).max().unwrap_or(0);
+    let mut bin = vec![0; max_degree + 1];
+    for &v in &nodes {
+        bin[degree[v]] += 1;
+    }
+    let mut start = 0;
+    for count in bin.iter_mut() {
+        let num = *count;
+        *count = start;
+        start += num;
+    }
+    let mut pos = vec![0; adjacency.len()];
+    let mut vert = vec![0; nodes.len()];
+    for &v in &nodes {
+        pos[v] = bin[degree[v]];
+        vert[pos[v]] = v;
+        bin[degree[v]] += 1;
+    }
+    for d in (1..=max_degree).rev() {
+        bin[d] = bin[d - 1];
+    }
+    bin[0] = 0;
+
+    // peel the nodes in order of their current degree, moving each neighbour with a larger
+    // degree to the front of its bin before decrementing it
+    for i in 0..vert.len() {
+        let v = vert[i];
+        for &u in &adjacency[v] {
+            if degree[u] > degree[v] {
+                let du = degree[u];
+                let pu = pos[u];
+                let pw = bin[du];
+                let w = vert[pw];
+                if u != w {
+                    pos[u] = pw;
+                    vert[pu] = w;
+                    pos[w] = pu;
+                    vert[pw] = u;
+                }
+                bin[du] += 1;
+                degree[u] -= 1;
+            }
+        }
+    }
+
+    NodeState::new_from_eval(g.clone(), degree)
+}
+
+/// Returns the k-core of the graph, i.e., the subgraph of all nodes with a core number of at
+/// least `k` (see [core_number])
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `k` - The minimum core number of the nodes in the subgraph
+///
+/// # Returns
+///
+/// The k-core as a node subgraph of `g`
+///
+pub fn k_core_subgraph<G>(g: &G, k: usize) -> NodeSubgraph<G>
+where
+    G: StaticGraphViewOps,
+{
+    let core_numbers = core_number(g);
+    g.subgraph(
+        core_numbers
+            .iter()
+            .filter(|(_, core)| **core >= k)
+            .map(|(node, _)| node.node),
+    )
+}
+
+#[cfg(test)]
+mod core_number_test {
+    use super::*;
+    use crate::{algorithms::cores::k_core::k_core_set, test_storage};
+    use std::collections::HashSet;
+
+    fn clique_with_path() -> Graph {
+        let graph = Graph::new();
+        // 3-clique 1, 2, 3 attached to the path 3 - 4 - 5
+        for (t, src, dst) in [
+            (1, 1, 2),
+            (2, 2, 3),
+            (3, 3, 1),
+            (4, 3, 4),
+            (5, 4, 5),
+            (6, 1, 2),
+        ] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_node(0, 6, NO_PROPS, None).unwrap();
+        graph
+    }
+
+    #[test]
+    fn clique_attached_to_path() {
+        let graph = clique_with_path();
+
+        test_storage!(&graph, |graph| {
+            let result = core_number(graph);
+            assert_eq!(result, vec![2, 2, 2, 1, 1, 0]);
+
+            let core = k_core_subgraph(graph, 2);
+            let mut names: Vec<_> = core.nodes().name().collect();
+            names.sort();
+            assert_eq!(names, ["1", "2", "3"]);
+            assert_eq!(core.count_edges(), 3);
+            assert_eq!(k_core_subgraph(graph, 3).count_nodes(), 0);
+        });
+    }
+
+    #[test]
+    fn clique_in_sparse_graph() {
+        let graph = Graph::new();
+        // 4-clique 1, 2, 3, 4 with a triangle 4, 5, 6 and the tree 5 - 7 - 8, 7 - 9 hanging off it
+        let mut edges = vec![(4, 5), (5, 6), (6, 4), (5, 7), (7, 8), (7, 9)];
+        for src in 1..=4u64 {
+            for dst in src + 1..=4 {
+                edges.push((src, dst));
+            }
+        }
+        for (src, dst) in edges {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let result = core_number(graph);
+            for (node, expected) in [
+                (1u64, 3),
+                (2, 3),
+                (3, 3),
+                (4, 3),
+                (5, 2),
+                (6, 2),
+                (7, 1),
+                (8, 1),
+                (9, 1),
+            ] {
+                assert_eq!(result.get_by_node(node), Some(&expected), "node {node}");
+            }
+            assert_eq!(k_core_subgraph(graph, 2).count_nodes(), 6);
+            let core = k_core_subgraph(graph, 3);
+            assert_eq!(core.count_nodes(), 4);
+            assert_eq!(core.count_edges(), 6);
+        });
+    }
+
+    #[test]
+    fn windowed_view() {
+        let graph = clique_with_path();
+
+        test_storage!(&graph, |graph| {
+            // the edge 3 -> 1 closing the clique is not in the window
+            let result = core_number(&graph.window(4, 10));
+            assert_eq!(result.get_by_node(1u64), Some(&1));
+            assert_eq!(result.get_by_node(3u64), Some(&1));
+            assert_eq!(result.get_by_node(5u64), Some(&1));
+            assert_eq!(result.get_by_node(6u64), None);
+
+            let result = core_number(&graph.window(0, 4));
+            assert_eq!(result, vec![2, 2, 2, 0]);
+        });
+    }
+
+    #[test]
+    fn consistent_with_k_core_set() {
+        let graph = Graph::new();
+        let edges = [
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (3, 4),
+            (3, 5),
+            (4, 5),
+            (5, 6),
+            (5, 8),
+            (7, 5),
+            (1, 9),
+            (6, 3),
+            (4, 8),
+            (8, 3),
+            (5, 10),
+            (10, 8),
+            (1, 11),
+            (9, 11),
+        ];
+        for (t, (src, dst)) in edges.into_iter().enumerate() {
+            graph.add_edge(t as i64, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let result = core_number(graph);
+            for k in 1..=4 {
+                let expected = k_core_set(graph, k, usize::MAX, None);
+                let actual: HashSet<_> = result
+                    .iter()
+                    .filter(|(_, core)| **core >= k)
+                    .map(|(node, _)| node.node)
+                    .collect();
+                assert_eq!(actual, expected, "k = {k}");
+            }
+        });
+    }
+}
@@ -1 +1,2 @@
+pub mod core_number;
 pub mod k_core;
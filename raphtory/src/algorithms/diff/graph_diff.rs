@@ -0,0 +1,248 @@
+//! Structural and property differences between two graph views
+//!
+//! [diff] compares two views, typically two windows of the same graph or two snapshots of the
+//! same data, and lists the nodes and edges that only exist in one of them as well as the
+//! properties whose latest value differs.
+use crate::{db::api::view::StaticGraphViewOps, prelude::*};
+use raphtory_api::core::{entities::GID, storage::arc_str::ArcStr};
+use std::{cmp::Ordering, collections::BTreeMap};
+
+/// An edge identified by its source, destination and layer
+pub type EdgeKey = (GID, GID, ArcStr);
+
+/// A property whose latest value differs between the two views, `None` if it is not set
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyChange {
+    pub name: ArcStr,
+    pub old: Option<Prop>,
+    pub new: Option<Prop>,
+}
+
+/// The differences between two graph views computed by [diff]
+///
+/// All lists are sorted by node id or edge key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GraphDiff {
+    pub added_nodes: Vec<GID>,
+    pub removed_nodes: Vec<GID>,
+    pub added_edges: Vec<EdgeKey>,
+    pub removed_edges: Vec<EdgeKey>,
+    /// Property changes of the nodes that exist in both views
+    pub changed_node_properties: Vec<(GID, PropertyChange)>,
+    /// Property changes of the edges that exist in both views
+    pub changed_edge_properties: Vec<(EdgeKey, PropertyChange)>,
+}
+
+impl GraphDiff {
+    /// `true` if the views have the same nodes, edges and latest property values
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_node_properties.is_empty()
+            && self.changed_edge_properties.is_empty()
+    }
+}
+
+/// Merges two lists sorted by key, calling `removed` for the keys only in `left`, `added` for the
+/// keys only in `right` and `both` for the keys in both lists
+fn merge_sorted<K: Ord, L, R>(
+    left: Vec<(K, L)>,
+    right: Vec<(K, R)>,
+    mut removed: impl FnMut(K),
+    mut added: impl FnMut(K),
+    mut both: impl FnMut(K, L, R),
+) {
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    loop {
+        let order = match (left.peek(), right.peek()) {
+            (None, None) => break,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some((l, _)), Some((r, _))) => l.cmp(r),
+        };
+        match order {
+            Ordering::Less => removed(left.next().unwrap().0),
+            Ordering::Greater => added(right.next().unwrap().0),
+            Ordering::Equal => {
+                let (key, l) = left.next().unwrap();
+                let (_, r) = right.next().unwrap();
+                both(key, l, r);
+            }
+        }
+    }
+}
+
+/// The changes between two sets of latest property values
+fn property_changes(
+    old: impl IntoIterator<Item = (ArcStr, Option<Prop>)>,
+    new: impl IntoIterator<Item = (ArcStr, Option<Prop>)>,
+) -> Vec<PropertyChange> {
+    let mut values: BTreeMap<ArcStr, (Option<Prop>, Option<Prop>)> = BTreeMap::new();
+    for (name, value) in old {
+        values.entry(name).or_default().0 = value;
+    }
+    for (name, value) in new {
+        values.entry(name).or_default().1 = value;
+    }
+    values
+        .into_iter()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(name, (old, new))| PropertyChange { name, old, new })
+        .collect()
+}
+
+/// The nodes of a view sorted by id
+fn sorted_nodes<G: StaticGraphViewOps>(g: &G) -> Vec<(GID, Vec<(ArcStr, Option<Prop>)>)> {
+    let mut nodes: Vec<_> = g
+        .nodes()
+        .iter()
+        .map(|node| (node.id(), node.properties().iter().collect()))
+        .collect();
+    nodes.sort_by(|(l, _), (r, _)| l.cmp(r));
+    nodes
+}
+
+/// The edges of a view split by layer and sorted by key
+fn sorted_edges<G: StaticGraphViewOps>(g: &G) -> Vec<(EdgeKey, Vec<(ArcStr, Option<Prop>)>)> {
+    let mut edges: Vec<_> = g
+        .edges()
+        .explode_layers()
+        .into_iter()
+        .filter_map(|edge| {
+            let layer = edge.layer_name().ok()?;
+            let key = (edge.src().id(), edge.dst().id(), layer);
+            Some((key, edge.properties().iter().collect()))
+        })
+        .collect();
+    edges.sort_by(|(l, _), (r, _)| l.cmp(r));
+    edges
+}
+
+/// Computes the differences between two graph views
+///
+/// Nodes are matched by id and edges by source, destination and layer. A property has changed if
+/// its latest value in `right` differs from its latest value in `left`, where a property that is
+/// not set has value `None`. Both views are sorted by id and merged, so the views are only
+/// traversed once.
+///
+/// # Arguments
+///
+/// - `left` - The old view, e.g., `g.before(t)`
+/// - `right` - The new view, e.g., `g`
+///
+/// # Returns
+///
+/// The [GraphDiff] where additions are relative to `left`, i.e., an added node is only in `right`
+///
+pub fn diff<L: StaticGraphViewOps, R: StaticGraphViewOps>(left: &L, right: &R) -> GraphDiff {
+    let mut result = GraphDiff::default();
+
+    let mut changed_nodes = vec![];
+    merge_sorted(
+        sorted_nodes(left),
+        sorted_nodes(right),
+        |id| result.removed_nodes.push(id),
+        |id| result.added_nodes.push(id),
+        |id, old, new| {
+            for change in property_changes(old, new) {
+                changed_nodes.push((id.clone(), change));
+            }
+        },
+    );
+    result.changed_node_properties = changed_nodes;
+
+    let mut changed_edges = vec![];
+    merge_sorted(
+        sorted_edges(left),
+        sorted_edges(right),
+        |key| result.removed_edges.push(key),
+        |key| result.added_edges.push(key),
+        |key, old, new| {
+            for change in property_changes(old, new) {
+                changed_edges.push((key.clone(), change));
+            }
+        },
+    );
+    result.changed_edge_properties = changed_edges;
+
+    result
+}
+
+#[cfg(test)]
+mod graph_diff_test {
+    use super::*;
+    use crate::test_storage;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, 1, 2), (2, 2, 3), (3, 3, 1)] {
+            graph
+                .add_edge(t, src, dst, [("weight", 1.0)], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn added_edge() {
+        let graph = load_graph();
+        graph
+            .add_edge(10, 1, 3, [("weight", 2.0)], Some("new"))
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            let result = diff(&graph.before(10), graph);
+            assert_eq!(
+                result,
+                GraphDiff {
+                    added_edges: vec![(GID::U64(1), GID::U64(3), "new".into())],
+                    ..Default::default()
+                }
+            );
+            assert!(diff(graph, graph).is_empty());
+        });
+    }
+
+    #[test]
+    fn removed_nodes_and_changed_properties() {
+        let graph = load_graph();
+        graph.add_edge(10, 1, 2, [("weight", 5.0)], None).unwrap();
+        graph.add_edge(11, 3, 4, NO_PROPS, None).unwrap();
+        graph.add_node(12, 2, [("status", "active")], None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let result = diff(graph, &graph.before(10));
+            assert!(result.added_nodes.is_empty());
+            assert_eq!(result.removed_nodes, vec![GID::U64(4)]);
+            assert_eq!(
+                result.removed_edges,
+                vec![(GID::U64(3), GID::U64(4), "_default".into())]
+            );
+            assert_eq!(
+                result.changed_node_properties,
+                vec![(
+                    GID::U64(2),
+                    PropertyChange {
+                        name: "status".into(),
+                        old: Some(Prop::str("active")),
+                        new: None,
+                    }
+                )]
+            );
+            assert_eq!(
+                result.changed_edge_properties,
+                vec![(
+                    (GID::U64(1), GID::U64(2), "_default".into()),
+                    PropertyChange {
+                        name: "weight".into(),
+                        old: Some(Prop::F64(5.0)),
+                        new: Some(Prop::F64(1.0)),
+                    }
+                )]
+            );
+        });
+    }
+}
@@ -0,0 +1,3 @@
+mod graph_diff;
+
+pub use graph_diff::{diff, EdgeKey, GraphDiff, PropertyChange};
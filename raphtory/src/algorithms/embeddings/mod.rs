@@ -1 +1,2 @@
 pub mod fast_rp;
+pub mod walks;
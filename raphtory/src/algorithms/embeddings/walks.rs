@@ -0,0 +1,391 @@
+use crate::{db::api::view::StaticGraphViewOps, errors::GraphError, prelude::*};
+use rand::{distributions::WeightedIndex, prelude::*, rngs::StdRng};
+use raphtory_api::core::entities::{GID, VID};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// Controls how the next edge event of a temporal random walk is sampled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WalkBias {
+    /// All valid edge events are equally likely
+    Uniform,
+    /// The probability of an edge event decays exponentially with the time elapsed since the
+    /// earliest valid edge event, using the given non-negative rate
+    ExponentialDecay(f64),
+    /// The valid edge events are ranked by time, the earliest event gets the largest weight and
+    /// the weights decrease linearly down to 1 for the latest event
+    Linear,
+}
+
+/// Samples time-respecting random walks, e.g., as input for training temporal node embeddings
+///
+/// `walks_per_node` walks are started from every node of the graph. Every step of a walk samples
+/// an outgoing edge event (see `explode`) of the current node with a timestamp greater than or
+/// equal to the timestamp of the previous step, the first step can use any edge event. A walk
+/// ends early if the current node has no such edge events left.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `walks_per_node` - The number of walks to start from every node
+/// - `walk_length` - The maximum number of nodes in a walk, including the start node
+/// - `seed` - The seed for the random number generator, walks are deterministic for a given seed
+/// - `bias` - How the edge event for the next step is sampled, see [WalkBias]
+///
+/// # Returns
+///
+/// A lazy iterator over the walks as lists of node ids. The walks are generated in rounds, each
+/// round contains one walk for every node in the order of `g.nodes()`.
+///
+/// # Errors
+///
+/// Returns [GraphError::InvalidAlgorithmParameters] if `walk_length` is 0 or if the rate of
+/// [WalkBias::ExponentialDecay] is negative or not finite.
+///
+pub fn temporal_random_walks<G: StaticGraphViewOps>(
+    g: &G,
+    walks_per_node: usize,
+    walk_length: usize,
+    seed: Option<u64>,
+    bias: WalkBias,
+) -> Result<impl Iterator<Item = Vec<GID>>, GraphError> {
+    if walk_length == 0 {
+        return Err(GraphError::InvalidAlgorithmParameters(
+            "walk_length must be at least 1".to_string(),
+        ));
+    }
+    if let WalkBias::ExponentialDecay(rate) = bias {
+        if !rate.is_finite() || rate < 0.0 {
+            return Err(GraphError::InvalidAlgorithmParameters(format!(
+                "decay rate must be non-negative and finite, got {rate}"
+            )));
+        }
+    }
+
+    let g = g.clone();
+    let nodes: Vec<VID> = g.nodes().iter().map(|node| node.node).collect();
+    let num_nodes = nodes.len();
+    let mut rng = StdRng::seed_from_u64(seed.unwrap_or_else(|| thread_rng().gen()));
+    Ok((0..walks_per_node * num_nodes)
+        .map(move |i| temporal_walk(&g, nodes[i % num_nodes], walk_length, bias, &mut rng)))
+}
+
+fn temporal_walk<G: StaticGraphViewOps>(
+    g: &G,
+    start: VID,
+    walk_length: usize,
+    bias: WalkBias,
+    rng: &mut StdRng,
+) -> Vec<GID> {
+    let mut walk = vec![g.node_id(start)];
+    let mut current = start;
+    let mut time = i64::MIN;
+    while walk.len() < walk_length {
+        let mut candidates: Vec<(i64, VID)> = g
+            .node(current)
+            .unwrap()
+            .out_edges()
+            .explode()
+            .iter()
+            .filter_map(|edge| Some((edge.time().ok()?, edge.nbr().node)))
+            .filter(|(t, _)| *t >= time)
+            .collect();
+        if candidates.is_empty() {
+            break;
+        }
+        let (next_time, next) = match bias {
+            WalkBias::Uniform => *candidates.choose(rng).unwrap(),
+            WalkBias::ExponentialDecay(rate) => {
+                let earliest = candidates.iter().map(|(t, _)| *t).min().unwrap();
+                let weights = candidates
+                    .iter()
+                    .map(|(t, _)| (-rate * (*t - earliest) as f64).exp());
+                // the earliest events have weight 1 so the weights never sum to 0
+                let dist = WeightedIndex::new(weights).unwrap();
+                candidates[dist.sample(rng)]
+            }
+            WalkBias::Linear => {
+                candidates.sort();
+                let n = candidates.len();
+                let dist = WeightedIndex::new((0..n).map(|rank| n - rank)).unwrap();
+                candidates[dist.sample(rng)]
+            }
+        };
+        walk.push(g.node_id(next));
+        current = next;
+        time = next_time;
+    }
+    walk
+}
+
+/// Samples biased second-order random walks as described by node2vec
+///
+/// Grover, Aditya, and Jure Leskovec. "node2vec: Scalable feature learning for networks."
+/// Proceedings of the 22nd ACM SIGKDD (2016).
+///
+/// `walks_per_node` walks are started from every node of the graph. Edges are treated as
+/// undirected, the first step moves to a uniformly random neighbour. Afterwards, a neighbour of
+/// the current node is sampled with weight `1 / p` if it is the previous node, weight 1 if it is
+/// also a neighbour of the previous node and weight `1 / q` otherwise. Walks end early at nodes
+/// without neighbours. Walks are sampled in parallel, each with its own random number generator
+/// derived from the seed.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `walks_per_node` - The number of walks to start from every node
+/// - `walk_length` - The maximum number of nodes in a walk, including the start node
+/// - `p` - The return parameter, larger values make returning to the previous node less likely
+/// - `q` - The in-out parameter, larger values keep walks close to the previous node while
+///   smaller values favour moving outwards
+/// - `seed` - The seed for the random number generators, walks are deterministic for a given seed
+///
+/// # Returns
+///
+/// The walks as lists of node ids. The walks are ordered in rounds, each round contains one walk
+/// for every node in the order of `g.nodes()`.
+///
+/// # Errors
+///
+/// Returns [GraphError::InvalidAlgorithmParameters] if `walk_length` is 0 or if `p` or `q` is not
+/// positive and finite.
+///
+pub fn node2vec_walks<G: StaticGraphViewOps>(
+    g: &G,
+    walks_per_node: usize,
+    walk_length: usize,
+    p: f64,
+    q: f64,
+    seed: Option<u64>,
+) -> Result<Vec<Vec<GID>>, GraphError> {
+    if walk_length == 0 {
+        return Err(GraphError::InvalidAlgorithmParameters(
+            "walk_length must be at least 1".to_string(),
+        ));
+    }
+    for (name, value) in [("p", p), ("q", q)] {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(GraphError::InvalidAlgorithmParameters(format!(
+                "{name} must be positive and finite, got {value}"
+            )));
+        }
+    }
+
+    let nodes: Vec<VID> = g.nodes().iter().map(|node| node.node).collect();
+    let num_nodes = nodes.len();
+    let seed = seed.unwrap_or_else(|| thread_rng().gen());
+    Ok((0..walks_per_node * num_nodes)
+        .into_par_iter()
+        .map(|i| {
+            let mut rng = StdRng::seed_from_u64(seed.wrapping_add(i as u64));
+            node2vec_walk(g, nodes[i % num_nodes], walk_length, p, q, &mut rng)
+        })
+        .collect())
+}
+
+fn node2vec_walk<G: StaticGraphViewOps>(
+    g: &G,
+    start: VID,
+    walk_length: usize,
+    p: f64,
+    q: f64,
+    rng: &mut StdRng,
+) -> Vec<GID> {
+    let neighbours = |node: VID| g.node(node).unwrap().neighbours().iter().map(|n| n.node);
+    let mut walk = vec![start];
+    while walk.len() < walk_length {
+        let current = walk[walk.len() - 1];
+        let candidates: Vec<VID> = neighbours(current).collect();
+        if candidates.is_empty() {
+            break;
+        }
+        let next = if walk.len() == 1 {
+            *candidates.choose(rng).unwrap()
+        } else {
+            let previous = walk[walk.len() - 2];
+            let previous_neighbours: HashSet<VID> = neighbours(previous).collect();
+            let weights = candidates.iter().map(|candidate| {
+                if *candidate == previous {
+                    1.0 / p
+                } else if previous_neighbours.contains(candidate) {
+                    1.0
+                } else {
+                    1.0 / q
+                }
+            });
+            candidates[WeightedIndex::new(weights).unwrap().sample(rng)]
+        };
+        walk.push(next);
+    }
+    walk.into_iter().map(|node| g.node_id(node)).collect()
+}
+
+#[cfg(test)]
+mod walks_tests {
+    use super::*;
+    use crate::{db::api::mutation::AdditionOps, test_storage};
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [
+            (1, 1, 2),
+            (2, 2, 3),
+            (3, 3, 1),
+            (4, 1, 3),
+            (5, 3, 4),
+            (6, 4, 1),
+            (2, 4, 2),
+            (7, 2, 4),
+            (8, 1, 2),
+            (0, 5, 1),
+        ] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn walks_respect_time() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            for bias in [
+                WalkBias::Uniform,
+                WalkBias::ExponentialDecay(0.5),
+                WalkBias::Linear,
+            ] {
+                let walks: Vec<_> = temporal_random_walks(graph, 10, 6, Some(42), bias)
+                    .unwrap()
+                    .collect();
+                assert_eq!(walks.len(), 10 * graph.count_nodes());
+                for walk in walks {
+                    assert!(!walk.is_empty() && walk.len() <= 6);
+                    // there has to be a non-decreasing sequence of edge events along the walk
+                    let mut time = i64::MIN;
+                    for pair in walk.windows(2) {
+                        let edge = graph.edge(&pair[0], &pair[1]).unwrap();
+                        time = edge
+                            .explode()
+                            .iter()
+                            .filter_map(|e| e.time().ok())
+                            .filter(|t| *t >= time)
+                            .min()
+                            .unwrap();
+                    }
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn walks_end_without_future_edges() {
+        let graph = Graph::new();
+        graph.add_edge(2, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+
+        let walks: Vec<_> = temporal_random_walks(&graph, 1, 5, Some(1), WalkBias::Uniform)
+            .unwrap()
+            .collect();
+        assert_eq!(
+            walks,
+            vec![
+                vec![GID::U64(1), GID::U64(2)],
+                vec![GID::U64(2), GID::U64(3)],
+                vec![GID::U64(3)],
+            ]
+        );
+    }
+
+    #[test]
+    fn walks_are_deterministic() {
+        let graph = load_graph();
+        let walks = |seed| {
+            temporal_random_walks(&graph, 5, 8, Some(seed), WalkBias::Linear)
+                .unwrap()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(walks(7), walks(7));
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let graph = load_graph();
+        assert!(matches!(
+            temporal_random_walks(&graph, 1, 0, None, WalkBias::Uniform),
+            Err(GraphError::InvalidAlgorithmParameters(_))
+        ));
+        assert!(matches!(
+            temporal_random_walks(&graph, 1, 3, None, WalkBias::ExponentialDecay(-1.0)),
+            Err(GraphError::InvalidAlgorithmParameters(_))
+        ));
+    }
+
+    fn barbell_graph(clique_size: u64) -> Graph {
+        let graph = Graph::new();
+        for offset in [0, clique_size] {
+            for i in 0..clique_size {
+                for j in i + 1..clique_size {
+                    graph
+                        .add_edge(0, offset + i, offset + j, NO_PROPS, None)
+                        .unwrap();
+                }
+            }
+        }
+        graph
+            .add_edge(0, clique_size - 1, clique_size, NO_PROPS, None)
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn node2vec_walks_follow_edges() {
+        let graph = barbell_graph(5);
+
+        test_storage!(&graph, |graph| {
+            let walks = node2vec_walks(graph, 3, 10, 1.0, 0.5, Some(3)).unwrap();
+            assert_eq!(walks.len(), 3 * 10);
+            for walk in walks {
+                assert_eq!(walk.len(), 10);
+                for pair in walk.windows(2) {
+                    assert!(
+                        graph.has_edge(&pair[0], &pair[1]) || graph.has_edge(&pair[1], &pair[0])
+                    );
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn node2vec_in_out_parameter() {
+        let graph = barbell_graph(5);
+        let bridge_crossings = |q| {
+            node2vec_walks(&graph, 50, 20, 1.0, q, Some(11))
+                .unwrap()
+                .iter()
+                .flat_map(|walk| walk.windows(2))
+                .filter(|pair| {
+                    matches!(
+                        (&pair[0], &pair[1]),
+                        (GID::U64(4), GID::U64(5)) | (GID::U64(5), GID::U64(4))
+                    )
+                })
+                .count()
+        };
+        let local = bridge_crossings(10.0);
+        let outward = bridge_crossings(0.1);
+        assert!(outward > 3 * local, "{outward} vs {local}");
+    }
+
+    #[test]
+    fn node2vec_walks_are_deterministic() {
+        let graph = barbell_graph(4);
+        assert_eq!(
+            node2vec_walks(&graph, 5, 8, 0.5, 2.0, Some(5)).unwrap(),
+            node2vec_walks(&graph, 5, 8, 0.5, 2.0, Some(5)).unwrap()
+        );
+        assert!(matches!(
+            node2vec_walks(&graph, 1, 3, 0.0, 1.0, None),
+            Err(GraphError::InvalidAlgorithmParameters(_))
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+//! Maximum flow with default capacities, built on the Dinic implementation in
+//! [max_flow](super::pathing::max_flow)
+
+use crate::{
+    algorithms::pathing::max_flow::dinic, core::entities::nodes::node_ref::AsNodeRef,
+    db::api::view::StaticGraphViewOps, prelude::*,
+};
+use raphtory_api::core::entities::GID;
+use std::{collections::HashMap, convert::Infallible};
+
+/// Computes the value of the maximum flow from `source` to `sink` using Dinic's algorithm
+///
+/// See [max_flow_with_assignment] for how the capacities are read.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `source` - The node the flow starts at
+/// - `sink` - The node the flow ends at
+/// - `capacity_prop` - The name of the numeric edge property holding the capacities
+///
+/// # Returns
+///
+/// The value of the maximum flow, 0.0 if the source or sink is not in the graph or if they are
+/// the same node
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::algorithms::flow::max_flow;
+///
+/// let graph = Graph::new();
+/// graph.add_edge(0, 1, 2, [("capacity", 3.0)], None).unwrap();
+/// graph.add_edge(0, 2, 3, NO_PROPS, None).unwrap();
+/// assert_eq!(max_flow(&graph, 1, 3, "capacity"), 1.0);
+/// ```
+pub fn max_flow<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    source: V,
+    sink: V,
+    capacity_prop: &str,
+) -> f64 {
+    max_flow_with_assignment(g, source, sink, capacity_prop).0
+}
+
+/// Computes the maximum flow from `source` to `sink` using Dinic's algorithm, together with the
+/// flow along each edge
+///
+/// The capacity of each edge is the latest value of the `capacity_prop` property, edges where it
+/// is missing or not numeric have capacity 1.0 and edges with a capacity that is not positive
+/// carry no flow. Edges that exist in several layers are treated as parallel edges and their
+/// capacities are summed. Self-loops are ignored.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `source` - The node the flow starts at
+/// - `sink` - The node the flow ends at
+/// - `capacity_prop` - The name of the numeric edge property holding the capacities
+///
+/// # Returns
+///
+/// The value of the maximum flow and the flow along each edge with a positive flow, keyed by the
+/// ids of its endpoints. The flow is 0.0 and the map is empty if the source or sink is not in
+/// the graph or if they are the same node.
+pub fn max_flow_with_assignment<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    source: V,
+    sink: V,
+    capacity_prop: &str,
+) -> (f64, HashMap<(GID, GID), f64>) {
+    let (Some(source), Some(sink)) = (g.node(source), g.node(sink)) else {
+        return (0.0, HashMap::new());
+    };
+    if source.node == sink.node {
+        return (0.0, HashMap::new());
+    }
+    let Ok(result) = dinic(
+        g,
+        source.node.index(),
+        sink.node.index(),
+        capacity_prop,
+        |_, _, value| Ok::<_, Infallible>(value.map_or(1.0, |value| value.max(0.0))),
+    );
+    (result.flow_value, result.edge_flows)
+}
+
+#[cfg(test)]
+mod flow_test {
+    use super::*;
+    use crate::test_storage;
+
+    fn five_node_network() -> Graph {
+        let graph = Graph::new();
+        let edges = [
+            ("s", "a", 10.0),
+            ("s", "b", 10.0),
+            ("a", "b", 2.0),
+            ("a", "c", 4.0),
+            ("a", "t", 5.0),
+            ("b", "c", 9.0),
+            ("c", "t", 10.0),
+        ];
+        for (src, dst, capacity) in edges {
+            graph
+                .add_edge(0, src, dst, [("capacity", capacity)], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn five_node_max_flow() {
+        let graph = five_node_network();
+
+        test_storage!(&graph, |graph| {
+            // the edges into the sink form a minimum cut
+            assert_eq!(max_flow(graph, "s", "t", "capacity"), 15.0);
+            assert_eq!(max_flow(graph, "t", "s", "capacity"), 0.0);
+        });
+    }
+
+    #[test]
+    fn flow_is_conserved() {
+        let graph = five_node_network();
+
+        test_storage!(&graph, |graph| {
+            let (flow, assignment) = max_flow_with_assignment(graph, "s", "t", "capacity");
+            let net_flow = |name: &str| -> f64 {
+                let id = GID::Str(name.to_string());
+                assignment
+                    .iter()
+                    .map(|((src, dst), flow)| {
+                        if *src == id {
+                            *flow
+                        } else if *dst == id {
+                            -flow
+                        } else {
+                            0.0
+                        }
+                    })
+                    .sum()
+            };
+            assert_eq!(net_flow("s"), flow);
+            assert_eq!(net_flow("t"), -flow);
+            for name in ["a", "b", "c"] {
+                assert_eq!(net_flow(name), 0.0);
+            }
+        });
+    }
+
+    #[test]
+    fn missing_capacities_default_to_one() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (1, 3), (2, 4), (3, 4)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_edge(0, 1, 4, [("capacity", 2.5)], None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            assert_eq!(max_flow(graph, 1, 4, "capacity"), 4.5);
+            assert_eq!(max_flow(graph, 1, 4, "weight"), 3.0);
+            assert_eq!(max_flow(graph, 1, 5, "capacity"), 0.0);
+            assert_eq!(max_flow(graph, 1, 1, "capacity"), 0.0);
+        });
+    }
+}
@@ -0,0 +1,474 @@
+//! Neighbourhood based link prediction scores
+//!
+//! All scores are computed on the undirected neighbour sets of the current view, i.e., edge
+//! directions are ignored and a node is never counted as its own neighbour. The functions score
+//! a list of node pairs and return the scores in the same order as the input, [all_pairs] scores
+//! every pair of nodes that are not connected but have a common neighbour and
+//! [score_all_non_edges] scores every pair of nodes that are not connected.
+use crate::{
+    core::entities::nodes::node_ref::{AsNodeRef, NodeRef},
+    db::{api::view::StaticGraphViewOps, graph::node::NodeView},
+    errors::GraphError,
+    prelude::*,
+};
+use raphtory_api::core::entities::{GID, VID};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// The link prediction scores supported by [all_pairs]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkPredictionScore {
+    /// See [jaccard_coefficient]
+    Jaccard,
+    /// See [adamic_adar]
+    AdamicAdar,
+    /// See [preferential_attachment_score]
+    PreferentialAttachment,
+}
+
+/// Sorted undirected neighbours of every node in the view, indexed by `VID`
+struct Neighbourhoods {
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl Neighbourhoods {
+    fn new<G: StaticGraphViewOps>(g: &G) -> Self {
+        let mut neighbours: Vec<Vec<usize>> = vec![vec![]; g.unfiltered_num_nodes()];
+        for node in g.nodes() {
+            let v = node.node.index();
+            let mut nbrs: Vec<usize> = node
+                .neighbours()
+                .iter()
+                .map(|n| n.node.index())
+                .filter(|&n| n != v)
+                .collect();
+            nbrs.sort_unstable();
+            neighbours[v] = nbrs;
+        }
+        Self { neighbours }
+    }
+
+    fn common(&self, u: usize, v: usize) -> impl Iterator<Item = usize> + '_ {
+        let other = &self.neighbours[v];
+        self.neighbours[u]
+            .iter()
+            .copied()
+            .filter(move |n| other.binary_search(n).is_ok())
+    }
+
+    fn score(&self, score: LinkPredictionScore, u: usize, v: usize) -> f64 {
+        let degree_u = self.neighbours[u].len();
+        let degree_v = self.neighbours[v].len();
+        match score {
+            LinkPredictionScore::Jaccard => {
+                let common = self.common(u, v).count();
+                let union = degree_u + degree_v - common;
+                if union == 0 {
+                    0f64
+                } else {
+                    common as f64 / union as f64
+                }
+            }
+            LinkPredictionScore::AdamicAdar => self
+                .common(u, v)
+                .map(|w| 1f64 / (self.neighbours[w].len() as f64).ln())
+                .sum(),
+            LinkPredictionScore::PreferentialAttachment => (degree_u * degree_v) as f64,
+        }
+    }
+}
+
+fn node_gid<G: StaticGraphViewOps>(g: &G, node: &NodeRef) -> GID {
+    match node {
+        NodeRef::Internal(vid) => g.node_id(*vid),
+        NodeRef::External(gid) => GID::from(*gid),
+    }
+}
+
+fn find_pair<G: StaticGraphViewOps>(
+    g: &G,
+    src: NodeRef,
+    dst: NodeRef,
+) -> Result<(NodeView<'static, G>, NodeView<'static, G>), GraphError> {
+    match (g.node(src), g.node(dst)) {
+        (Some(src), Some(dst)) => Ok((src, dst)),
+        (src_node, _) => Err(GraphError::NodePairMissingError {
+            src: node_gid(g, &src),
+            dst: node_gid(g, &dst),
+            missing: if src_node.is_none() {
+                node_gid(g, &src)
+            } else {
+                node_gid(g, &dst)
+            },
+        }),
+    }
+}
+
+fn score_pairs<G: StaticGraphViewOps>(
+    g: &G,
+    pairs: &[(NodeRef, NodeRef)],
+    score: LinkPredictionScore,
+) -> Result<Vec<((GID, GID), f64)>, GraphError> {
+    let nodes = pairs
+        .iter()
+        .map(|(src, dst)| find_pair(g, *src, *dst))
+        .collect::<Result<Vec<_>, _>>()?;
+    let neighbourhoods = Neighbourhoods::new(g);
+    Ok(nodes
+        .into_iter()
+        .map(|(src, dst)| {
+            let value = neighbourhoods.score(score, src.node.index(), dst.node.index());
+            ((src.id(), dst.id()), value)
+        })
+        .collect())
+}
+
+/// Computes the Jaccard coefficient `|N(u) ∩ N(v)| / |N(u) ∪ N(v)|` of each pair of nodes
+///
+/// The coefficient is 0 if both nodes have no neighbours.
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph
+/// - `pairs`: The node pairs to score
+///
+/// # Returns
+///
+/// The ids of each pair together with its score, in the order of `pairs`
+///
+/// # Errors
+///
+/// Returns [GraphError::NodePairMissingError] if a node of a pair is not in the graph.
+///
+pub fn jaccard_coefficient<G: StaticGraphViewOps>(
+    g: &G,
+    pairs: &[(NodeRef, NodeRef)],
+) -> Result<Vec<((GID, GID), f64)>, GraphError> {
+    score_pairs(g, pairs, LinkPredictionScore::Jaccard)
+}
+
+/// Computes the Adamic-Adar index `Σ 1 / ln |N(w)|` over the common neighbours `w` of each pair
+/// of nodes
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph
+/// - `pairs`: The node pairs to score
+///
+/// # Returns
+///
+/// The ids of each pair together with its score, in the order of `pairs`
+///
+/// # Errors
+///
+/// Returns [GraphError::NodePairMissingError] if a node of a pair is not in the graph.
+///
+pub fn adamic_adar<G: StaticGraphViewOps>(
+    g: &G,
+    pairs: &[(NodeRef, NodeRef)],
+) -> Result<Vec<((GID, GID), f64)>, GraphError> {
+    score_pairs(g, pairs, LinkPredictionScore::AdamicAdar)
+}
+
+/// Computes the preferential attachment score `|N(u)| * |N(v)|` of each pair of nodes
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph
+/// - `pairs`: The node pairs to score
+///
+/// # Returns
+///
+/// The ids of each pair together with its score, in the order of `pairs`
+///
+/// # Errors
+///
+/// Returns [GraphError::NodePairMissingError] if a node of a pair is not in the graph.
+///
+pub fn preferential_attachment_score<G: StaticGraphViewOps>(
+    g: &G,
+    pairs: &[(NodeRef, NodeRef)],
+) -> Result<Vec<((GID, GID), f64)>, GraphError> {
+    score_pairs(g, pairs, LinkPredictionScore::PreferentialAttachment)
+}
+
+fn score_pair<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    src: V,
+    dst: V,
+    score: LinkPredictionScore,
+) -> Result<f64, GraphError> {
+    let (src, dst) = find_pair(g, src.as_node_ref(), dst.as_node_ref())?;
+    Ok(Neighbourhoods::new(g).score(score, src.node.index(), dst.node.index()))
+}
+
+/// Computes the Adamic-Adar index of a single pair of nodes, see [adamic_adar]
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph
+/// - `src`: The first node of the pair
+/// - `dst`: The second node of the pair
+///
+/// # Errors
+///
+/// Returns [GraphError::NodePairMissingError] if a node of the pair is not in the graph.
+///
+pub fn adamic_adar_index<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    src: V,
+    dst: V,
+) -> Result<f64, GraphError> {
+    score_pair(g, src, dst, LinkPredictionScore::AdamicAdar)
+}
+
+/// Computes the preferential attachment score of a single pair of nodes, see
+/// [preferential_attachment_score]
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph
+/// - `src`: The first node of the pair
+/// - `dst`: The second node of the pair
+///
+/// # Errors
+///
+/// Returns [GraphError::NodePairMissingError] if a node of the pair is not in the graph.
+///
+pub fn preferential_attachment<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    src: V,
+    dst: V,
+) -> Result<f64, GraphError> {
+    score_pair(g, src, dst, LinkPredictionScore::PreferentialAttachment)
+}
+
+/// Scores every pair of distinct nodes that are not adjacent
+///
+/// Unlike [all_pairs], pairs without a common neighbour are included as well, so this scores
+/// O(n²) pairs and is only suitable for small graphs. Every unordered pair is scored once, the
+/// nodes are processed in parallel.
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph
+/// - `score`: The score to compute
+///
+/// # Returns
+///
+/// The ids of each pair together with its score, sorted by descending score. Pairs with the same
+/// score are ordered by their ids.
+///
+pub fn score_all_non_edges<G: StaticGraphViewOps>(
+    g: &G,
+    score: LinkPredictionScore,
+) -> Vec<((GID, GID), f64)> {
+    let neighbourhoods = Neighbourhoods::new(g);
+    let neighbourhoods = &neighbourhoods;
+    let nodes: Vec<_> = g.nodes().iter().map(|node| node.node.index()).collect();
+    let nodes = &nodes;
+    let mut results: Vec<((GID, GID), f64)> = (0..nodes.len())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let u = nodes[i];
+            nodes[i + 1..]
+                .iter()
+                .copied()
+                .filter(move |&v| v != u && neighbourhoods.neighbours[u].binary_search(&v).is_err())
+                .map(move |v| {
+                    let value = neighbourhoods.score(score, u, v);
+                    let (a, b) = (g.node_id(VID(u)), g.node_id(VID(v)));
+                    let ids = if a <= b { (a, b) } else { (b, a) };
+                    (ids, value)
+                })
+        })
+        .collect();
+    results.sort_by(|(ids1, v1), (ids2, v2)| v2.total_cmp(v1).then_with(|| ids1.cmp(ids2)));
+    results
+}
+
+/// Scores every pair of nodes that are not adjacent but at most 2 hops apart
+///
+/// Only pairs with a common neighbour are considered, as scoring all pairs of nodes does not
+/// scale. Every unordered pair is scored once, the nodes are processed in parallel.
+///
+/// # Arguments
+///
+/// - `g`: A reference to the graph
+/// - `score`: The score to compute
+///
+/// # Returns
+///
+/// The ids of each candidate pair together with its score, ordered by the first and then the
+/// second node of the pair
+///
+pub fn all_pairs<G: StaticGraphViewOps>(
+    g: &G,
+    score: LinkPredictionScore,
+) -> Vec<((GID, GID), f64)> {
+    let neighbourhoods = Neighbourhoods::new(g);
+    let neighbourhoods = &neighbourhoods;
+    g.nodes()
+        .par_iter()
+        .flat_map_iter(|node| {
+            let u = node.node.index();
+            let nbrs = &neighbourhoods.neighbours[u];
+            let mut candidates: Vec<usize> = nbrs
+                .iter()
+                .flat_map(|&w| neighbourhoods.neighbours[w].iter().copied())
+                .filter(|&v| v > u && nbrs.binary_search(&v).is_err())
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect();
+            candidates.sort_unstable();
+            candidates.into_iter().map(move |v| {
+                let value = neighbourhoods.score(score, u, v);
+                ((node.id(), g.node_id(VID(v))), value)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod link_prediction_test {
+    use super::*;
+    use crate::{algorithms::centrality::pagerank::page_rank_tests::assert_eq_f64, test_storage};
+    use raphtory_api::core::entities::GidRef;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        // 1 and 4 share the neighbours 2 and 3, 5 hangs off 4
+        for (src, dst) in [(1, 2), (3, 1), (2, 3), (2, 4), (4, 3)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_edge(5, 4, 5, NO_PROPS, None).unwrap();
+        graph
+    }
+
+    fn node_ref(id: u64) -> NodeRef<'static> {
+        NodeRef::External(GidRef::U64(id))
+    }
+
+    fn pairs() -> Vec<(NodeRef<'static>, NodeRef<'static>)> {
+        [(1, 4), (2, 5), (1, 5)]
+            .into_iter()
+            .map(|(src, dst)| (node_ref(src), node_ref(dst)))
+            .collect()
+    }
+
+    fn check(results: Vec<((GID, GID), f64)>, expected: [f64; 3]) {
+        let ids: Vec<_> = results.iter().map(|(ids, _)| ids.clone()).collect();
+        assert_eq!(
+            ids,
+            [(1, 4), (2, 5), (1, 5)].map(|(src, dst)| (GID::U64(src), GID::U64(dst)))
+        );
+        for ((_, value), expected) in results.into_iter().zip(expected) {
+            assert_eq_f64(Some(value), Some(expected), 9);
+        }
+    }
+
+    #[test]
+    fn test_pair_scores() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            check(
+                jaccard_coefficient(graph, &pairs()).unwrap(),
+                [2.0 / 3.0, 1.0 / 3.0, 0.0],
+            );
+            check(
+                adamic_adar(graph, &pairs()).unwrap(),
+                [2.0 / 3f64.ln(), 1.0 / 3f64.ln(), 0.0],
+            );
+            check(
+                preferential_attachment_score(graph, &pairs()).unwrap(),
+                [6.0, 3.0, 2.0],
+            );
+        });
+    }
+
+    #[test]
+    fn test_all_pairs() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let results = all_pairs(graph, LinkPredictionScore::PreferentialAttachment);
+            assert_eq!(
+                results,
+                [((1, 4), 6.0), ((2, 5), 3.0), ((3, 5), 3.0)]
+                    .map(|((src, dst), value)| ((GID::U64(src), GID::U64(dst)), value))
+            );
+
+            // without the edge 4 -> 5 only the pair (1, 4) is left
+            let results = all_pairs(&graph.window(0, 5), LinkPredictionScore::Jaccard);
+            assert_eq!(results, [((GID::U64(1), GID::U64(4)), 1.0)]);
+        });
+    }
+
+    #[test]
+    fn test_single_pair_scores() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            assert_eq_f64(
+                Some(adamic_adar_index(graph, 1, 4).unwrap()),
+                Some(2.0 / 3f64.ln()),
+                9,
+            );
+            assert_eq!(preferential_attachment(graph, 2, 5).unwrap(), 3.0);
+            assert!(matches!(
+                preferential_attachment(graph, 99, 1),
+                Err(GraphError::NodePairMissingError { .. })
+            ));
+        });
+    }
+
+    #[test]
+    fn test_score_all_non_edges() {
+        // 1 and 2 share all other clique members as neighbours, 6 only hangs off 5
+        let graph = Graph::new();
+        for src in 1..=5u64 {
+            for dst in src + 1..=5 {
+                if (src, dst) != (1, 2) {
+                    graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+                }
+            }
+        }
+        graph.add_edge(0, 6, 5, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let results = score_all_non_edges(graph, LinkPredictionScore::AdamicAdar);
+            let ids: Vec<_> = results.iter().map(|(ids, _)| ids.clone()).collect();
+            assert_eq!(
+                ids,
+                [(1, 2), (1, 6), (2, 6), (3, 6), (4, 6)]
+                    .map(|(src, dst)| (GID::U64(src), GID::U64(dst)))
+            );
+            assert_eq_f64(
+                Some(results[0].1),
+                Some(2.0 / 4f64.ln() + 1.0 / 5f64.ln()),
+                9,
+            );
+            for (_, value) in &results[1..] {
+                assert_eq_f64(Some(*value), Some(1.0 / 5f64.ln()), 9);
+            }
+        });
+    }
+
+    #[test]
+    fn test_missing_node() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let pairs = [(node_ref(1), node_ref(99))];
+            match jaccard_coefficient(graph, &pairs) {
+                Err(GraphError::NodePairMissingError { src, dst, missing }) => {
+                    assert_eq!(src, GID::U64(1));
+                    assert_eq!(dst, GID::U64(99));
+                    assert_eq!(missing, GID::U64(99));
+                }
+                other => panic!("expected a missing node error, got {other:?}"),
+            }
+        });
+    }
+}
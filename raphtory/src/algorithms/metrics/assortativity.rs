@@ -0,0 +1,206 @@
+//! Assortativity coefficients
+//!
+//! The assortativity of a graph is the Pearson correlation between a value of the source and the
+//! same value of the destination across all edges. Positive values mean that nodes tend to
+//! connect to similar nodes, negative values that nodes tend to connect to dissimilar nodes.
+//!
+//! If the values at either end of the edges have zero variance, e.g., in a regular graph or a
+//! graph without edges, the correlation is undefined and `f64::NAN` is returned.
+use crate::{
+    db::{api::view::StaticGraphViewOps, graph::node::NodeView},
+    errors::GraphError,
+    prelude::*,
+};
+use raphtory_api::core::Direction;
+
+/// Pearson correlation of the pairs, `NAN` if either side has zero variance
+fn pearson(pairs: &[(f64, f64)]) -> f64 {
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in pairs {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        cov += dx * dy;
+        var_x += dx * dx;
+        var_y += dy * dy;
+    }
+    if var_x == 0.0 || var_y == 0.0 {
+        f64::NAN
+    } else {
+        cov / (var_x * var_y).sqrt()
+    }
+}
+
+/// Computes the degree assortativity coefficient of the graph
+///
+/// For [Direction::OUT] and [Direction::IN], every edge contributes the out-degrees (or
+/// in-degrees) of its source and destination. For [Direction::BOTH], edge directions are ignored,
+/// every pair of connected nodes contributes their undirected degrees in both orders.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph, this can be a windowed view
+/// - `direction` - Which degree to correlate
+///
+/// # Returns
+///
+/// The assortativity coefficient in `[-1, 1]`, or `f64::NAN` if the degrees at either end of the
+/// edges all have the same value
+///
+pub fn degree_assortativity<G: StaticGraphViewOps>(g: &G, direction: Direction) -> f64 {
+    let degree = |node: &NodeView<'static, G>| match direction {
+        Direction::OUT => node.out_degree(),
+        Direction::IN => node.in_degree(),
+        Direction::BOTH => node.degree(),
+    } as f64;
+    let mut pairs = vec![];
+    for edge in g.edges() {
+        let (src, dst) = (edge.src(), edge.dst());
+        let pair = (degree(&src), degree(&dst));
+        match direction {
+            Direction::BOTH => {
+                // count reciprocal edges only once
+                if src.id() > dst.id() && g.has_edge(dst.node, src.node) {
+                    continue;
+                }
+                pairs.push(pair);
+                pairs.push((pair.1, pair.0));
+            }
+            _ => pairs.push(pair),
+        }
+    }
+    pearson(&pairs)
+}
+
+/// Computes the assortativity coefficient of a numeric node metadata property
+///
+/// Edge directions are ignored, every pair of connected nodes contributes their values in both
+/// orders.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph, this can be a windowed view
+/// - `node_prop` - The name of the numeric node metadata property
+///
+/// # Returns
+///
+/// The assortativity coefficient in `[-1, 1]`, or `f64::NAN` if the property has the same value
+/// for all nodes with edges
+///
+/// # Errors
+///
+/// Returns [GraphError::InvalidProperty] if a node with edges does not have a numeric value for
+/// the property.
+///
+pub fn numeric_assortativity<G: StaticGraphViewOps>(
+    g: &G,
+    node_prop: &str,
+) -> Result<f64, GraphError> {
+    let value = |node: &NodeView<'static, G>| {
+        node.metadata()
+            .get(node_prop)
+            .and_then(|prop| prop.as_f64())
+            .ok_or_else(|| GraphError::InvalidProperty {
+                reason: format!("Node {} has no numeric property {node_prop}", node.id()),
+            })
+    };
+    let mut pairs = vec![];
+    for edge in g.edges() {
+        let (src, dst) = (edge.src(), edge.dst());
+        if src.id() > dst.id() && g.has_edge(dst.node, src.node) {
+            continue;
+        }
+        let (x, y) = (value(&src)?, value(&dst)?);
+        pairs.push((x, y));
+        pairs.push((y, x));
+    }
+    Ok(pearson(&pairs))
+}
+
+#[cfg(test)]
+mod assortativity_test {
+    use super::*;
+    use crate::{algorithms::centrality::pagerank::page_rank_tests::assert_eq_f64, test_storage};
+
+    #[test]
+    fn star_is_disassortative() {
+        let graph = Graph::new();
+        for leaf in 1..=5 {
+            graph.add_edge(0, 0, leaf, NO_PROPS, None).unwrap();
+        }
+        // reciprocal edges do not change the undirected degree correlation
+        graph.add_edge(0, 3, 0, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            assert_eq_f64(
+                Some(degree_assortativity(graph, Direction::BOTH)),
+                Some(-1.0),
+                9,
+            );
+        });
+    }
+
+    #[test]
+    fn ring_is_degenerate() {
+        let graph = Graph::new();
+        for node in 0..6 {
+            graph
+                .add_edge(0, node, (node + 1) % 6, NO_PROPS, None)
+                .unwrap();
+        }
+        graph.add_edge(10, 0, 3, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let window = graph.window(0, 10);
+            for direction in [Direction::OUT, Direction::IN, Direction::BOTH] {
+                assert!(degree_assortativity(&window, direction).is_nan());
+            }
+            // the chord at time 10 breaks the regularity
+            assert!(!degree_assortativity(graph, Direction::BOTH).is_nan());
+        });
+    }
+
+    #[test]
+    fn directed_out_degrees() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (1, 3), (2, 3)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            assert_eq_f64(
+                Some(degree_assortativity(graph, Direction::OUT)),
+                Some(0.5),
+                9,
+            );
+        });
+    }
+
+    #[test]
+    fn numeric_property() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (3, 4), (2, 3)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        for (node, value) in [(1, 1.0), (2, 1.0), (3, 5.0), (4, 5.0)] {
+            graph
+                .node(node)
+                .unwrap()
+                .add_metadata([("value", value)])
+                .unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            assert_eq_f64(
+                Some(numeric_assortativity(graph, "value").unwrap()),
+                Some(1.0 / 3.0),
+                9,
+            );
+            assert!(matches!(
+                numeric_assortativity(graph, "missing"),
+                Err(GraphError::InvalidProperty { .. })
+            ));
+        });
+    }
+}
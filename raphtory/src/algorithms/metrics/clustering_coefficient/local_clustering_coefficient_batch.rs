@@ -1,8 +1,11 @@
 use crate::{
     core::entities::nodes::node_ref::AsNodeRef,
-    db::api::{
-        state::{Index, NodeState},
-        view::*,
+    db::{
+        api::{
+            state::{Index, NodeState},
+            view::*,
+        },
+        task::{custom_pool, POOL},
     },
 };
 use indexmap::IndexSet;
@@ -51,12 +54,80 @@ pub fn local_clustering_coefficient_batch<G: StaticGraphViewOps, V: AsNodeRef>(
     NodeState::new(graph.clone(), graph.clone(), values.into(), result)
 }
 
+/// Local clustering coefficient of every node in the graph, computed in parallel.
+/// Counts the triangles of each node by intersecting sorted neighbour lists.
+///
+/// # Arguments
+/// - `graph`: Raphtory graph, can be directed or undirected but will be treated as undirected.
+/// - `threads`: Number of threads to use for parallel execution
+///
+/// # Returns
+/// the local clustering coefficient of every node in g, nodes with fewer than two neighbours
+/// (ignoring self-loops) have a coefficient of 0.
+pub fn local_clustering_coefficient_all<G: StaticGraphViewOps>(
+    graph: &G,
+    threads: Option<usize>,
+) -> NodeState<'static, f64, G> {
+    let mut adjacency: Vec<Vec<usize>> = vec![vec![]; graph.unfiltered_num_nodes()];
+    for node in graph.nodes() {
+        let mut neighbours: Vec<usize> = node
+            .neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .filter(|n| *n != node.node.index())
+            .collect();
+        neighbours.sort_unstable();
+        neighbours.dedup();
+        adjacency[node.node.index()] = neighbours;
+    }
+    let adjacency = &adjacency;
+
+    let pool = threads.map(custom_pool).unwrap_or_else(|| POOL.clone());
+    let values: Vec<f64> = pool.install(|| {
+        graph
+            .nodes()
+            .par_iter()
+            .map(|node| {
+                let neighbours = &adjacency[node.node.index()];
+                let degree = neighbours.len() as f64;
+                if degree <= 1.0 {
+                    return 0.0;
+                }
+                // every triangle is found from both of the other two nodes
+                let triangle_count = neighbours
+                    .iter()
+                    .map(|&n| sorted_intersection_count(neighbours, &adjacency[n]))
+                    .sum::<usize>() as f64
+                    / 2.0;
+                (2.0 * triangle_count) / (degree * (degree - 1.0))
+            })
+            .collect()
+    });
+    NodeState::new_from_values(graph.clone(), values)
+}
+
+fn sorted_intersection_count(a: &[usize], b: &[usize]) -> usize {
+    let (mut i, mut j, mut count) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                count += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    count
+}
+
 #[cfg(test)]
 mod clustering_coefficient_tests {
-    use super::local_clustering_coefficient_batch;
+    use super::{local_clustering_coefficient_all, local_clustering_coefficient_batch};
     use crate::{
         db::{
-            api::{mutation::AdditionOps, view::*},
+            api::{mutation::AdditionOps, state::NodeStateOps, view::*},
             graph::graph::Graph,
         },
         prelude::NO_PROPS,
@@ -94,4 +165,51 @@ mod clustering_coefficient_tests {
             assert_eq!(expected, actual);
         });
     }
+
+    #[test]
+    fn all_nodes_triangle_with_pendant() {
+        let graph = Graph::new();
+        for (t, src, dst) in [(1, 1, 2), (2, 2, 3), (3, 3, 1), (4, 3, 4), (5, 2, 1)] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let actual = local_clustering_coefficient_all(graph, Some(2));
+            assert_eq!(actual, vec![1.0, 1.0, 1.0 / 3.0, 0.0]);
+
+            // the triangle is not complete before t=3
+            let actual = local_clustering_coefficient_all(&graph.window(0, 3), None);
+            assert_eq!(actual, vec![0.0, 0.0, 0.0]);
+        });
+    }
+
+    #[test]
+    fn all_nodes_matches_batch() {
+        let graph = Graph::new();
+        let vs = vec![
+            (1, 1, 2),
+            (2, 1, 3),
+            (3, 2, 1),
+            (4, 3, 2),
+            (5, 1, 4),
+            (6, 4, 5),
+            (6, 1, 1),
+            (6, 5, 5),
+            (7, 4, 2),
+            (8, 5, 3),
+        ];
+        for (t, src, dst) in &vs {
+            graph.add_edge(*t, *src, *dst, NO_PROPS, None).unwrap();
+        }
+
+        test_storage!(&graph, |graph| {
+            let expected = local_clustering_coefficient_batch(graph, (1..=5).collect());
+            let actual = local_clustering_coefficient_all(graph, None);
+            for v in 1..=5u64 {
+                let expected = expected.get_by_node(v).unwrap();
+                let actual = actual.get_by_node(v).unwrap();
+                assert!((expected - actual).abs() < 1e-12, "node {v}");
+            }
+        });
+    }
 }
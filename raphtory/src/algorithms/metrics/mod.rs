@@ -1,5 +1,8 @@
+pub mod assortativity;
 pub mod balance;
 pub mod clustering_coefficient;
 pub mod degree;
 pub mod directed_graph_density;
 pub mod reciprocity;
+pub mod rich_club;
+pub mod summary;
@@ -0,0 +1,241 @@
+//! Rich-club coefficient
+//!
+//! The rich-club coefficient φ(k) of a graph is the density of the subgraph induced by the nodes
+//! with degree greater than `k`, i.e., `2 E_k / (N_k (N_k - 1))` where `N_k` is the number of
+//! nodes with degree greater than `k` and `E_k` is the number of edges between them. Edge
+//! directions are ignored and self-loops do not count towards the degree.
+//!
+//! Zhou, Shi, and Raúl J. Mondragón. "The rich-club phenomenon in the internet topology."
+//! IEEE Communications Letters 8.3 (2004): 180-182.
+use crate::{
+    db::api::view::StaticGraphViewOps,
+    errors::GraphError,
+    graphgen::configuration_model::{configuration_model, MultiEdgePolicy},
+    prelude::*,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Number of times [configuration_model] tries to rewire a stub pair that would create a
+/// self-loop or a multi-edge before dropping it
+const REWIRE_RETRIES: usize = 100;
+
+/// Undirected degree of every node and the smaller degree of the endpoints of every edge
+fn degrees<G: StaticGraphViewOps>(g: &G) -> (Vec<usize>, Vec<usize>) {
+    let mut degrees = vec![0; g.unfiltered_num_nodes()];
+    let mut neighbours: Vec<Vec<usize>> = vec![vec![]; g.unfiltered_num_nodes()];
+    for node in g.nodes() {
+        let v = node.node.index();
+        neighbours[v] = node
+            .neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .filter(|&n| n != v)
+            .collect();
+        degrees[v] = neighbours[v].len();
+    }
+    let edge_degrees = neighbours
+        .iter()
+        .enumerate()
+        .flat_map(|(v, nbrs)| {
+            let degrees = &degrees;
+            nbrs.iter()
+                .filter(move |&&n| v < n)
+                .map(move |&n| degrees[v].min(degrees[n]))
+        })
+        .collect();
+    let node_degrees = g
+        .nodes()
+        .iter()
+        .map(|node| degrees[node.node.index()])
+        .collect();
+    (node_degrees, edge_degrees)
+}
+
+/// Computes the rich-club coefficient φ(k) for every degree threshold `k` from 0 to `max_k`
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `max_k` - The largest degree threshold, defaults to the maximum degree
+///
+/// # Returns
+///
+/// The pairs `(k, φ(k))` in increasing order of `k`. Thresholds where fewer than two nodes have a
+/// larger degree are left out as φ(k) is undefined.
+///
+pub fn rich_club<G: StaticGraphViewOps>(g: &G, max_k: Option<usize>) -> Vec<(usize, f64)> {
+    let (node_degrees, edge_degrees) = degrees(g);
+    rich_club_from_degrees(&node_degrees, &edge_degrees, max_k)
+}
+
+fn rich_club_from_degrees(
+    node_degrees: &[usize],
+    edge_degrees: &[usize],
+    max_k: Option<usize>,
+) -> Vec<(usize, f64)> {
+    let max_degree = node_degrees.iter().copied().max().unwrap_or(0);
+    let max_k = max_k.unwrap_or(max_degree).min(max_degree);
+    // number of nodes and edges whose (smaller) degree is exactly d
+    let mut nodes_with_degree = vec![0usize; max_degree + 1];
+    let mut edges_with_degree = vec![0usize; max_degree + 1];
+    for &degree in node_degrees {
+        nodes_with_degree[degree] += 1;
+    }
+    for &degree in edge_degrees {
+        edges_with_degree[degree] += 1;
+    }
+
+    let mut num_nodes = node_degrees.len();
+    let mut num_edges = edge_degrees.len();
+    let mut result = vec![];
+    for k in 0..=max_k {
+        num_nodes -= nodes_with_degree[k];
+        num_edges -= edges_with_degree[k];
+        if num_nodes < 2 {
+            break;
+        }
+        let phi = 2.0 * num_edges as f64 / (num_nodes as f64 * (num_nodes as f64 - 1.0));
+        result.push((k, phi));
+    }
+    result
+}
+
+/// Computes the rich-club coefficient normalised by the rich-club coefficient of random graphs
+/// with the same degree sequence
+///
+/// Each random graph is generated into a new scratch graph by the
+/// [configuration model](configuration_model), so the input graph is not modified. Stub pairs
+/// that would create a self-loop or a multi-edge are rewired, and dropped if rewiring fails
+/// repeatedly, so the degrees of the random graphs can be slightly smaller.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `n_random` - The number of random graphs to average over
+/// - `seed` - Seed for the random graphs
+///
+/// # Returns
+///
+/// The pairs `(k, φ(k) / φ_random(k))` for every `k` returned by [rich_club], where `φ_random(k)`
+/// is the mean coefficient of the random graphs. Thresholds where `φ_random(k)` is 0 have value
+/// `f64::NAN`.
+///
+/// # Errors
+///
+/// Returns [GraphError::InvalidAlgorithmParameters] if `n_random` is 0.
+///
+pub fn rich_club_normalized<G: StaticGraphViewOps>(
+    g: &G,
+    n_random: usize,
+    seed: Option<u64>,
+) -> Result<Vec<(usize, f64)>, GraphError> {
+    if n_random == 0 {
+        return Err(GraphError::InvalidAlgorithmParameters(
+            "n_random needs to be at least 1".to_string(),
+        ));
+    }
+    let (node_degrees, edge_degrees) = degrees(g);
+    let coefficients = rich_club_from_degrees(&node_degrees, &edge_degrees, None);
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut random_sums = vec![0.0; coefficients.len()];
+    for _ in 0..n_random {
+        let scratch = Graph::new();
+        configuration_model(
+            &scratch,
+            &node_degrees,
+            MultiEdgePolicy::Retry(REWIRE_RETRIES),
+            Some(rng.gen()),
+        )?;
+        for (k, phi) in rich_club(&scratch, None) {
+            if let Some(sum) = random_sums.get_mut(k) {
+                *sum += phi;
+            }
+        }
+    }
+
+    Ok(coefficients
+        .into_iter()
+        .zip(random_sums)
+        .map(|((k, phi), sum)| {
+            let mean = sum / n_random as f64;
+            (k, if mean > 0.0 { phi / mean } else { f64::NAN })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod rich_club_test {
+    use super::*;
+    use crate::test_storage;
+
+    /// Five hubs forming a clique, each with six leaves
+    fn hub_clique() -> Graph {
+        let graph = Graph::new();
+        for hub in 0..5u64 {
+            for other in hub + 1..5 {
+                graph.add_edge(0, hub, other, NO_PROPS, None).unwrap();
+            }
+            for leaf in 0..6 {
+                graph
+                    .add_edge(0, hub, 100 + 10 * hub + leaf, NO_PROPS, None)
+                    .unwrap();
+            }
+        }
+        // self-loops do not count
+        graph.add_edge(0, 100, 100, NO_PROPS, None).unwrap();
+        graph
+    }
+
+    #[test]
+    fn hubs_form_rich_club() {
+        let graph = hub_clique();
+
+        test_storage!(&graph, |graph| {
+            let result = rich_club(graph, None);
+            assert_eq!(result.len(), 10);
+            assert_eq!(result[0], (0, 80.0 / (35.0 * 34.0)));
+            for (k, phi) in &result[1..] {
+                assert!((1..10).contains(k));
+                assert_eq!(*phi, 1.0);
+            }
+
+            let result = rich_club(graph, Some(3));
+            assert_eq!(
+                result.iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+                [0, 1, 2, 3]
+            );
+        });
+    }
+
+    #[test]
+    fn normalized() {
+        let graph = hub_clique();
+
+        let bits = |result: Vec<(usize, f64)>| -> Vec<(usize, u64)> {
+            result
+                .into_iter()
+                .map(|(k, phi)| (k, phi.to_bits()))
+                .collect()
+        };
+        let result = rich_club_normalized(&graph, 10, Some(42)).unwrap();
+        assert_eq!(
+            bits(result.clone()),
+            bits(rich_club_normalized(&graph, 10, Some(42)).unwrap())
+        );
+        assert_eq!(result.len(), 10);
+        // the hubs of the random graphs are at most fully connected
+        assert!(result[1].1.is_finite() && result[1].1 >= 1.0);
+        // the input graph is not rewired
+        assert_eq!(graph.count_nodes(), 35);
+        assert_eq!(graph.count_edges(), 41);
+
+        assert!(matches!(
+            rich_club_normalized(&graph, 0, None),
+            Err(GraphError::InvalidAlgorithmParameters(_))
+        ));
+    }
+}
@@ -0,0 +1,293 @@
+//! Summary statistics of a graph view
+//!
+//! [summary] collects the statistics that are usually computed first when exploring a new graph,
+//! i.e., the size of the graph, its density, the degree distribution and the time range. All
+//! statistics respect the view they are computed on, so `summary(&g.window(a, b))` only describes
+//! the window.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use raphtory::algorithms::metrics::summary::summary;
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! for (t, src, dst) in [(1, 1, 2), (2, 1, 3), (3, 2, 3), (4, 3, 4)] {
+//!     g.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+//! }
+//!
+//! let stats = summary(&g.window(0, 3));
+//! assert_eq!(stats.num_nodes, 3);
+//! assert_eq!(stats.num_edges, 2);
+//! assert_eq!((stats.min_degree, stats.max_degree), (1, 2));
+//! assert_eq!((stats.earliest_time, stats.latest_time), (Some(1), Some(2)));
+//! assert!((stats.density - 1.0 / 3.0).abs() < 1e-12);
+//! ```
+use crate::{db::api::view::StaticGraphViewOps, prelude::*};
+use raphtory_api::core::storage::arc_str::ArcStr;
+use rayon::prelude::*;
+use std::{collections::HashSet, fmt};
+
+/// Number of degree histogram bins used by [summary]
+pub const DEFAULT_DEGREE_BINS: usize = 10;
+
+/// A bin of the degree histogram, containing the nodes with `lower <= degree <= upper`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DegreeBin {
+    pub lower: usize,
+    pub upper: usize,
+    pub count: usize,
+}
+
+/// Summary statistics of a graph view, see [summary]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphSummary {
+    pub num_nodes: usize,
+    pub num_edges: usize,
+    /// The number of edge updates
+    pub num_temporal_edges: usize,
+    /// The directed density `num_edges / (num_nodes * (num_nodes - 1))`, 0 for graphs with fewer
+    /// than two nodes
+    pub density: f64,
+    pub min_degree: usize,
+    pub max_degree: usize,
+    pub mean_degree: f64,
+    pub earliest_time: Option<i64>,
+    pub latest_time: Option<i64>,
+    pub num_layers: usize,
+    /// The number of distinct node types, untyped nodes are not counted
+    pub num_node_types: usize,
+    /// The degree histogram, the first bin only contains the nodes without neighbours and the
+    /// other bins are logarithmically spaced
+    pub degree_histogram: Vec<DegreeBin>,
+}
+
+/// Lower bounds of logarithmically spaced bins covering the degrees `1..=max_degree`
+fn log_bins(max_degree: usize, num_bins: usize) -> Vec<usize> {
+    let num_bins = num_bins.max(1);
+    let range = (max_degree + 1) as f64;
+    let mut lower_bounds: Vec<usize> = (0..num_bins)
+        .map(|i| range.powf(i as f64 / num_bins as f64).ceil() as usize)
+        .filter(|&lower| lower <= max_degree)
+        .collect();
+    lower_bounds.dedup();
+    lower_bounds
+}
+
+/// Computes the summary statistics of the graph using [DEFAULT_DEGREE_BINS] histogram bins
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph, this can be any view of the graph
+///
+pub fn summary<G: StaticGraphViewOps>(g: &G) -> GraphSummary {
+    summary_with_bins(g, DEFAULT_DEGREE_BINS)
+}
+
+/// Computes the summary statistics of the graph
+///
+/// The degree of a node is its number of distinct neighbours, ignoring edge directions. The
+/// degrees and node types are collected in a single parallel pass over the nodes.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph, this can be any view of the graph
+/// - `degree_bins` - The maximum number of logarithmically spaced bins for the nodes with at
+///   least one neighbour. Fewer bins are used if the bins would not contain any integer degree.
+///
+pub fn summary_with_bins<G: StaticGraphViewOps>(g: &G, degree_bins: usize) -> GraphSummary {
+    let (degrees, node_types): (Vec<usize>, Vec<Option<ArcStr>>) = g
+        .nodes()
+        .par_iter()
+        .map(|node| (node.degree(), node.node_type()))
+        .unzip();
+    let node_types: HashSet<ArcStr> = node_types.into_iter().flatten().collect();
+
+    let num_nodes = degrees.len();
+    let num_edges = g.count_edges();
+    let min_degree = degrees.iter().copied().min().unwrap_or(0);
+    let max_degree = degrees.iter().copied().max().unwrap_or(0);
+    let mean_degree = if num_nodes == 0 {
+        0.0
+    } else {
+        degrees.iter().sum::<usize>() as f64 / num_nodes as f64
+    };
+    let density = if num_nodes < 2 {
+        0.0
+    } else {
+        num_edges as f64 / (num_nodes as f64 * (num_nodes as f64 - 1.0))
+    };
+
+    let lower_bounds = log_bins(max_degree, degree_bins);
+    let mut degree_histogram: Vec<DegreeBin> = [0]
+        .into_iter()
+        .chain(lower_bounds.iter().copied())
+        .enumerate()
+        .map(|(i, lower)| DegreeBin {
+            lower,
+            upper: lower_bounds.get(i).map_or(max_degree, |next| next - 1),
+            count: 0,
+        })
+        .collect();
+    for degree in degrees {
+        let bin = degree_histogram
+            .iter()
+            .rposition(|bin| bin.lower <= degree)
+            .unwrap_or(0);
+        degree_histogram[bin].count += 1;
+    }
+
+    GraphSummary {
+        num_nodes,
+        num_edges,
+        num_temporal_edges: g.count_temporal_edges(),
+        density,
+        min_degree,
+        max_degree,
+        mean_degree,
+        earliest_time: g.earliest_time(),
+        latest_time: g.latest_time(),
+        num_layers: g.unique_layers().count(),
+        num_node_types: node_types.len(),
+        degree_histogram,
+    }
+}
+
+impl fmt::Display for GraphSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let time = |t: Option<i64>| t.map_or("-".to_string(), |t| t.to_string());
+        writeln!(f, "{:<20} {:>12}", "nodes", self.num_nodes)?;
+        writeln!(f, "{:<20} {:>12}", "edges", self.num_edges)?;
+        writeln!(
+            f,
+            "{:<20} {:>12}",
+            "temporal edges", self.num_temporal_edges
+        )?;
+        writeln!(f, "{:<20} {:>12.6}", "density", self.density)?;
+        writeln!(f, "{:<20} {:>12}", "min degree", self.min_degree)?;
+        writeln!(f, "{:<20} {:>12}", "max degree", self.max_degree)?;
+        writeln!(f, "{:<20} {:>12.3}", "mean degree", self.mean_degree)?;
+        writeln!(
+            f,
+            "{:<20} {:>12}",
+            "earliest time",
+            time(self.earliest_time)
+        )?;
+        writeln!(f, "{:<20} {:>12}", "latest time", time(self.latest_time))?;
+        writeln!(f, "{:<20} {:>12}", "layers", self.num_layers)?;
+        writeln!(f, "{:<20} {:>12}", "node types", self.num_node_types)?;
+        write!(f, "degree histogram")?;
+        for bin in &self.degree_histogram {
+            let range = if bin.lower == bin.upper {
+                bin.lower.to_string()
+            } else {
+                format!("{}-{}", bin.lower, bin.upper)
+            };
+            write!(f, "\n  {:<18} {:>12}", range, bin.count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod summary_test {
+    use super::*;
+    use crate::test_storage;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        // star with 9 leaves around node 0 and a reciprocal edge
+        for leaf in 1..=9u64 {
+            graph
+                .add_edge(leaf as i64, 0, leaf, NO_PROPS, None)
+                .unwrap();
+        }
+        graph.add_edge(20, 1, 0, NO_PROPS, Some("reply")).unwrap();
+        graph.add_node(30, 10, NO_PROPS, Some("isolated")).unwrap();
+        graph
+    }
+
+    #[test]
+    fn star() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let stats = summary_with_bins(graph, 4);
+            assert_eq!(stats.num_nodes, 11);
+            assert_eq!(stats.num_edges, 10);
+            assert_eq!(stats.num_temporal_edges, 10);
+            assert_eq!(stats.density, 10.0 / 110.0);
+            assert_eq!(stats.min_degree, 0);
+            assert_eq!(stats.max_degree, 9);
+            assert_eq!(stats.mean_degree, 18.0 / 11.0);
+            assert_eq!(stats.earliest_time, Some(1));
+            assert_eq!(stats.latest_time, Some(30));
+            assert_eq!(stats.num_layers, 2);
+            assert_eq!(stats.num_node_types, 1);
+            // the bins start at 10^(i/4) rounded up
+            assert_eq!(
+                stats.degree_histogram,
+                vec![
+                    DegreeBin {
+                        lower: 0,
+                        upper: 0,
+                        count: 1
+                    },
+                    DegreeBin {
+                        lower: 1,
+                        upper: 1,
+                        count: 9
+                    },
+                    DegreeBin {
+                        lower: 2,
+                        upper: 3,
+                        count: 0
+                    },
+                    DegreeBin {
+                        lower: 4,
+                        upper: 5,
+                        count: 0
+                    },
+                    DegreeBin {
+                        lower: 6,
+                        upper: 9,
+                        count: 1
+                    },
+                ]
+            );
+            assert!(stats.to_string().contains("6-9"));
+        });
+    }
+
+    #[test]
+    fn respects_window() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let stats = summary(&graph.window(0, 4));
+            assert_eq!(stats.num_nodes, 4);
+            assert_eq!(stats.num_edges, 3);
+            assert_eq!(stats.earliest_time, Some(1));
+            assert_eq!(stats.latest_time, Some(3));
+            assert_eq!(stats.num_node_types, 0);
+            assert_eq!(stats.max_degree, 3);
+            let counted: usize = stats.degree_histogram.iter().map(|bin| bin.count).sum();
+            assert_eq!(counted, 4);
+        });
+    }
+
+    #[test]
+    fn empty_graph() {
+        let stats = summary(&Graph::new());
+        assert_eq!(stats.num_nodes, 0);
+        assert_eq!(stats.density, 0.0);
+        assert_eq!(
+            stats.degree_histogram,
+            vec![DegreeBin {
+                lower: 0,
+                upper: 0,
+                count: 0
+            }]
+        );
+    }
+}
@@ -27,15 +27,21 @@
 //! ```
 
 pub mod centrality;
+pub mod community;
 pub mod community_detection;
 
 pub mod bipartite;
 pub mod components;
 pub mod cores;
+pub mod diff;
 pub mod dynamics;
 pub mod embeddings;
+pub mod flow;
 pub mod layout;
+pub mod link_prediction;
 pub mod metrics;
 pub mod motifs;
 pub mod pathing;
 pub mod projections;
+pub mod sampling;
+pub mod similarity;
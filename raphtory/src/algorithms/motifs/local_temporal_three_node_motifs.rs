@@ -4,7 +4,7 @@ use crate::{
     core::state::{accumulator_id::accumulators, compute_state::ComputeStateVec},
     db::{
         api::{
-            state::NodeState,
+            state::{NodeState, NodeStateOps},
             view::{NodeViewOps, *},
         },
         graph::views::node_subgraph::NodeSubgraph,
@@ -341,20 +341,50 @@ pub fn temporal_three_node_motif<G>(
     delta: i64,
     threads: Option<usize>,
 ) -> NodeState<'static, Vec<usize>, G>
+where
+    G: StaticGraphViewOps,
+{
+    let counts = temporal_three_node_motif_local_multi(g, vec![delta], threads);
+    let values: Vec<_> = counts
+        .into_iter_values()
+        .map(|mut counts| counts.swap_remove(0))
+        .collect();
+    NodeState::new_from_values(g.clone(), values)
+}
+
+///////////////////////////////////////////////////////
+/// Computes the number of each type of motif that each node participates in for a range of timescales. See `temporal_three_node_motif` for how motifs are attributed to nodes.
+///
+/// All deltas are counted in a single pass over the edge events of each node, which is cheaper than calling `temporal_three_node_motif` once per delta.
+///
+/// # Arguments
+/// - `g`: A directed raphtory graph
+/// - `deltas`: A list of delta values to use.
+/// - `threads`: Number of threads to use
+///
+/// # Returns
+/// A [NodeState] mapping each node to a list of 40d motif counts, one for each value of delta in the order that the deltas were given as input.
+pub fn temporal_three_node_motif_local_multi<G>(
+    g: &G,
+    deltas: Vec<i64>,
+    threads: Option<usize>,
+) -> NodeState<'static, Vec<Vec<usize>>, G>
 where
     G: StaticGraphViewOps,
 {
     let ctx: Context<G, ComputeStateVec> = g.into();
+    let delta_len = deltas.len();
 
     debug!("Running triangle step");
-    let triadic_motifs = triangle_motifs(g, vec![delta], threads);
+    let triadic_motifs = triangle_motifs(g, deltas.clone(), threads);
     debug!("Running rest of motifs");
 
     let star_motif_step = ATask::new(move |evv: &mut EvalNodeView<G, MotifCounter>| {
-        let two_nodes = twonode_motif_count(evv, vec![delta]);
-        let star_nodes = star_motif_count(evv, vec![delta]);
+        let two_nodes = twonode_motif_count(evv, deltas.clone());
+        let star_nodes = star_motif_count(evv, deltas.clone());
 
-        *evv.get_mut() = MotifCounter::new(1, two_nodes, star_nodes, evv.get().triangle.clone());
+        *evv.get_mut() =
+            MotifCounter::new(delta_len, two_nodes, star_nodes, evv.get().triangle.clone());
 
         Step::Continue
     });
@@ -372,19 +402,23 @@ where
                 .map(|n| {
                     let mc = &local[n.node.index()];
                     let v_gid = n.name();
-                    let triangles = triadic_motifs
-                        .get(&v_gid)
-                        .map(|t| &t[0])
-                        .unwrap_or_else(|| &[0usize; 8]);
-                    let two_nodes = &mc.two_nodes[0];
-                    let mut counts: Vec<_> = mc.star_nodes[0]
-                        .iter()
-                        .zip(two_nodes.iter().cycle().take(24))
-                        .map(|(&x1, &x2)| x1 - x2)
-                        .collect();
-                    counts.extend_from_slice(two_nodes);
-                    counts.extend_from_slice(triangles);
-                    counts
+                    (0..delta_len)
+                        .map(|i| {
+                            let triangles = triadic_motifs
+                                .get(&v_gid)
+                                .map(|t| &t[i])
+                                .unwrap_or_else(|| &[0usize; 8]);
+                            let two_nodes = &mc.two_nodes[i];
+                            let mut counts: Vec<_> = mc.star_nodes[i]
+                                .iter()
+                                .zip(two_nodes.iter().cycle().take(24))
+                                .map(|(&x1, &x2)| x1 - x2)
+                                .collect();
+                            counts.extend_from_slice(two_nodes);
+                            counts.extend_from_slice(triangles);
+                            counts
+                        })
+                        .collect()
                 })
                 .collect();
             NodeState::new_from_values(g.clone(), values)
@@ -2,6 +2,11 @@ pub mod global_temporal_three_node_motifs;
 pub mod local_temporal_three_node_motifs;
 pub mod local_triangle_count;
 pub mod temporal_rich_club_coefficient;
+pub mod temporal_three_node_motifs;
 pub mod three_node_motifs;
 pub mod triangle_count;
+pub mod triangles;
 pub mod triplet_count;
+pub mod two_edge_temporal_motifs;
+
+pub use two_edge_temporal_motifs::{temporal_motifs, MotifType};
@@ -0,0 +1,188 @@
+use crate::{
+    algorithms::motifs::{
+        global_temporal_three_node_motifs::temporal_three_node_motif_multi,
+        local_temporal_three_node_motifs::temporal_three_node_motif_local_multi,
+    },
+    db::api::{
+        state::{NodeState, NodeStateOps},
+        view::StaticGraphViewOps,
+    },
+};
+
+/// The timescales to count temporal motifs for, either a single delta or a list of deltas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MotifDeltas(pub Vec<i64>);
+
+impl From<i64> for MotifDeltas {
+    fn from(delta: i64) -> Self {
+        Self(vec![delta])
+    }
+}
+
+impl From<Vec<i64>> for MotifDeltas {
+    fn from(deltas: Vec<i64>) -> Self {
+        Self(deltas)
+    }
+}
+
+impl From<&[i64]> for MotifDeltas {
+    fn from(deltas: &[i64]) -> Self {
+        Self(deltas.to_vec())
+    }
+}
+
+/// Result of [temporal_three_node_motifs], with one entry per delta in the order the deltas were given
+#[derive(Debug, Clone)]
+pub enum TemporalMotifCounts<G: StaticGraphViewOps> {
+    /// The 40d global motif counts (see `global_temporal_three_node_motif`)
+    Global(Vec<[usize; 40]>),
+    /// The 40d motif counts of each node (see `temporal_three_node_motif`)
+    PerNode(Vec<NodeState<'static, Vec<usize>, G>>),
+}
+
+/// Counts the three edge, up-to-three node delta-temporal motifs in the graph for one or more timescales.
+///
+/// All deltas are counted in a single pass over the edge events, which is cheaper than counting each delta separately.
+///
+/// # Arguments
+/// - `g`: A directed raphtory graph
+/// - `deltas`: A single delta or a list of deltas, each delta is the maximum time difference between the first and last edge of a motif
+/// - `per_node`: If `true`, attribute each motif instance to its participating nodes instead of counting it globally
+/// - `threads`: Number of threads to use
+///
+/// # Returns
+/// The global motif counts (same as `temporal_three_node_motif_multi`) or, if `per_node` is set, the motif counts of each node (same as `temporal_three_node_motif`), for each delta.
+pub fn temporal_three_node_motifs<G>(
+    g: &G,
+    deltas: impl Into<MotifDeltas>,
+    per_node: bool,
+    threads: Option<usize>,
+) -> TemporalMotifCounts<G>
+where
+    G: StaticGraphViewOps,
+{
+    let MotifDeltas(deltas) = deltas.into();
+    if !per_node {
+        return TemporalMotifCounts::Global(temporal_three_node_motif_multi(g, deltas, threads));
+    }
+    let num_deltas = deltas.len();
+    let counts = temporal_three_node_motif_local_multi(g, deltas, threads);
+    let mut values: Vec<Vec<Vec<usize>>> = vec![Vec::with_capacity(counts.len()); num_deltas];
+    for node_counts in counts.into_iter_values() {
+        for (delta_values, delta_counts) in values.iter_mut().zip(node_counts) {
+            delta_values.push(delta_counts);
+        }
+    }
+    TemporalMotifCounts::PerNode(
+        values
+            .into_iter()
+            .map(|values| NodeState::new_from_values(g.clone(), values))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod temporal_motifs_test {
+    use super::*;
+    use crate::{
+        algorithms::motifs::{
+            global_temporal_three_node_motifs::global_temporal_three_node_motif,
+            local_temporal_three_node_motifs::temporal_three_node_motif,
+        },
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::NO_PROPS,
+        test_storage,
+    };
+
+    fn load_graph(edges: Vec<(i64, u64, u64)>) -> Graph {
+        let graph = Graph::new();
+
+        for (t, src, dst) in edges {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    fn triangles(counts: &[usize]) -> usize {
+        counts[32..].iter().sum()
+    }
+
+    fn two_node(counts: &[usize]) -> usize {
+        counts[24..32].iter().sum()
+    }
+
+    #[test]
+    fn test_global_motif_only_at_larger_delta() {
+        // the triangle takes 19 time units to close
+        let graph = load_graph(vec![(1, 1, 2), (5, 2, 3), (20, 3, 1)]);
+
+        test_storage!(&graph, |graph| {
+            let TemporalMotifCounts::Global(global) =
+                temporal_three_node_motifs(graph, vec![5, 19], false, None)
+            else {
+                panic!("expected global counts");
+            };
+            assert_eq!(global.len(), 2);
+            assert_eq!(global[0], [0; 40]);
+            assert_eq!(global[1][..32], [0; 32]);
+            assert_eq!(triangles(&global[1]), 1);
+        });
+    }
+
+    #[test]
+    fn test_per_node_motif_only_at_larger_delta() {
+        // the two node motif takes 19 time units to complete, node 3 is not part of it
+        let graph = load_graph(vec![(1, 1, 2), (5, 2, 1), (20, 1, 2), (50, 3, 1)]);
+
+        test_storage!(&graph, |graph| {
+            let TemporalMotifCounts::PerNode(per_node) =
+                temporal_three_node_motifs(graph, vec![5, 19], true, None)
+            else {
+                panic!("expected per node counts");
+            };
+            assert_eq!(per_node.len(), 2);
+            for node in 1..=3u64 {
+                assert_eq!(per_node[0].get_by_node(node).unwrap(), &vec![0; 40]);
+            }
+            assert_eq!(two_node(per_node[1].get_by_node(1u64).unwrap()), 1);
+            assert_eq!(two_node(per_node[1].get_by_node(2u64).unwrap()), 1);
+            assert_eq!(per_node[1].get_by_node(3u64).unwrap(), &vec![0; 40]);
+        });
+    }
+
+    #[test]
+    fn test_single_delta_unchanged() {
+        let graph = load_graph(vec![
+            (1, 1, 2),
+            (2, 1, 3),
+            (3, 1, 4),
+            (4, 3, 1),
+            (5, 3, 4),
+            (6, 3, 5),
+            (7, 4, 5),
+            (8, 5, 6),
+            (9, 5, 8),
+            (10, 7, 5),
+            (11, 8, 5),
+            (12, 1, 9),
+            (13, 9, 1),
+        ]);
+
+        test_storage!(&graph, |graph| {
+            let TemporalMotifCounts::Global(global) =
+                temporal_three_node_motifs(graph, 10, false, None)
+            else {
+                panic!("expected global counts");
+            };
+            assert_eq!(global, [global_temporal_three_node_motif(graph, 10, None)]);
+
+            let TemporalMotifCounts::PerNode(per_node) =
+                temporal_three_node_motifs(graph, 10, true, None)
+            else {
+                panic!("expected per node counts");
+            };
+            assert_eq!(per_node.len(), 1);
+            assert_eq!(per_node[0], temporal_three_node_motif(graph, 10, None));
+        });
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    db::api::{state::NodeState, view::StaticGraphViewOps},
+    prelude::*,
+};
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counts the triangles of the undirected view of the graph using the forward algorithm, which
+/// runs in O(m√m) time.
+///
+/// Every edge is oriented from the endpoint with the smaller degree to the endpoint with the
+/// larger degree, so every node has at most O(√m) out-neighbours and every triangle is found
+/// exactly once by intersecting out-neighbourhoods.
+///
+/// Returns the number of triangles and the number of triangles each node is part of, indexed by
+/// `VID`, together with the undirected degree of each node.
+fn count_triangles<G: StaticGraphViewOps>(g: &G) -> (u64, Vec<u64>, Vec<usize>) {
+    let num_nodes = g.unfiltered_num_nodes();
+    let mut neighbours: Vec<Vec<usize>> = vec![vec![]; num_nodes];
+    for node in g.nodes() {
+        let v = node.node.index();
+        neighbours[v] = node
+            .neighbours()
+            .iter()
+            .map(|n| n.node.index())
+            .filter(|&n| n != v)
+            .collect();
+    }
+    let degrees: Vec<usize> = neighbours.iter().map(|nbrs| nbrs.len()).collect();
+    let rank = |v: usize| (degrees[v], v);
+
+    let forward: Vec<Vec<usize>> = neighbours
+        .into_par_iter()
+        .enumerate()
+        .map(|(v, nbrs)| {
+            let mut out: Vec<usize> = nbrs.into_iter().filter(|&n| rank(v) < rank(n)).collect();
+            out.sort_unstable();
+            out
+        })
+        .collect();
+
+    let counts: Vec<AtomicU64> = (0..num_nodes).map(|_| AtomicU64::new(0)).collect();
+    let total: u64 = (0..num_nodes)
+        .into_par_iter()
+        .map(|u| {
+            let mut found = 0;
+            for &v in &forward[u] {
+                let (mut i, mut j) = (0, 0);
+                let (a, b) = (&forward[u], &forward[v]);
+                while i < a.len() && j < b.len() {
+                    if a[i] < b[j] {
+                        i += 1;
+                    } else if a[i] > b[j] {
+                        j += 1;
+                    } else {
+                        for node in [u, v, a[i]] {
+                            counts[node].fetch_add(1, Ordering::Relaxed);
+                        }
+                        found += 1;
+                        i += 1;
+                        j += 1;
+                    }
+                }
+            }
+            found
+        })
+        .sum();
+
+    (
+        total,
+        counts.into_iter().map(AtomicU64::into_inner).collect(),
+        degrees,
+    )
+}
+
+/// Counts the triangles in the graph and the number of triangles each node is part of
+///
+/// Edge directions are ignored, i.e., every directed edge is treated as undirected, and
+/// self-loops are ignored. Uses the forward algorithm which runs in O(m√m) time.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+///
+/// # Returns
+///
+/// The total number of triangles and a [NodeState] with the number of triangles of each node
+///
+pub fn triangle_count<G: StaticGraphViewOps>(g: &G) -> (u64, NodeState<'static, u64, G>) {
+    let (total, counts, _) = count_triangles(g);
+    (total, NodeState::new_from_eval(g.clone(), counts))
+}
+
+/// Computes the global and local clustering coefficients of the graph
+///
+/// Edge directions are ignored, i.e., every directed edge is treated as undirected, and
+/// self-loops are ignored. The local clustering coefficient of a node is the fraction of pairs of
+/// its neighbours that are connected, nodes with fewer than two neighbours have coefficient 0. The
+/// global clustering coefficient is the fraction of connected triples that are closed, i.e.,
+/// three times the number of triangles divided by the number of connected triples.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+///
+/// # Returns
+///
+/// The global clustering coefficient and a [NodeState] with the local clustering coefficient of
+/// each node
+///
+pub fn clustering_coefficient<G: StaticGraphViewOps>(g: &G) -> (f64, NodeState<'static, f64, G>) {
+    let (total, counts, degrees) = count_triangles(g);
+    let pairs = |degree: usize| (degree * degree.saturating_sub(1) / 2) as f64;
+    let local: Vec<f64> = counts
+        .iter()
+        .zip(&degrees)
+        .map(|(&count, &degree)| {
+            if degree < 2 {
+                0.0
+            } else {
+                count as f64 / pairs(degree)
+            }
+        })
+        .collect();
+    let triples: f64 = g
+        .nodes()
+        .iter()
+        .map(|node| pairs(degrees[node.node.index()]))
+        .sum();
+    let global = if triples > 0.0 {
+        3.0 * total as f64 / triples
+    } else {
+        0.0
+    };
+    (global, NodeState::new_from_eval(g.clone(), local))
+}
+
+#[cfg(test)]
+mod triangles_test {
+    use super::*;
+    use crate::{
+        algorithms::motifs::triangle_count::triangle_count as k_core_triangle_count, test_storage,
+    };
+
+    fn load_graph(edges: &[(u64, u64)]) -> Graph {
+        let graph = Graph::new();
+        for &(src, dst) in edges {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn four_clique() {
+        // reciprocal edges and self-loops do not add triangles
+        let graph = load_graph(&[
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (4, 2),
+            (3, 4),
+            (2, 1),
+            (3, 3),
+        ]);
+
+        test_storage!(&graph, |graph| {
+            let (total, per_node) = triangle_count(graph);
+            assert_eq!(total, 4);
+            assert_eq!(per_node, vec![3, 3, 3, 3]);
+
+            let (global, local) = clustering_coefficient(graph);
+            assert_eq!(global, 1.0);
+            assert_eq!(local, vec![1.0, 1.0, 1.0, 1.0]);
+        });
+    }
+
+    #[test]
+    fn path() {
+        let graph = load_graph(&[(1, 2), (2, 3), (3, 4)]);
+
+        test_storage!(&graph, |graph| {
+            let (total, per_node) = triangle_count(graph);
+            assert_eq!(total, 0);
+            assert_eq!(per_node, vec![0, 0, 0, 0]);
+
+            let (global, local) = clustering_coefficient(graph);
+            assert_eq!(global, 0.0);
+            assert_eq!(local, vec![0.0, 0.0, 0.0, 0.0]);
+        });
+    }
+
+    #[test]
+    fn triangle_with_tail() {
+        let graph = load_graph(&[
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (5, 6),
+            (6, 7),
+            (7, 5),
+            (5, 3),
+        ]);
+
+        test_storage!(&graph, |graph| {
+            let (total, per_node) = triangle_count(graph);
+            assert_eq!(total as usize, k_core_triangle_count(graph, None));
+            assert_eq!(total, 2);
+            assert_eq!(per_node.get_by_node(3u64), Some(&1));
+            assert_eq!(per_node.get_by_node(4u64), Some(&0));
+
+            let (global, local) = clustering_coefficient(graph);
+            // node 3 has 4 neighbours and node 5 has 3 neighbours
+            assert_eq!(local.get_by_node(3u64), Some(&(1.0 / 6.0)));
+            assert_eq!(local.get_by_node(5u64), Some(&(1.0 / 3.0)));
+            let triples = 1.0 + 1.0 + 6.0 + 0.0 + 3.0 + 1.0 + 1.0;
+            assert_eq!(global, 6.0 / triples);
+        });
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{db::api::view::StaticGraphViewOps, prelude::*};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// The directed two edge temporal motifs
+///
+/// Two edges form a motif if they share at least one node. For the three node motifs, `c` is the
+/// shared node and `a` and `b` are the other endpoints of the first and second edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MotifType {
+    /// `u -> v` followed by `u -> v`
+    Repeated,
+    /// `u -> v` followed by `v -> u`
+    Reciprocated,
+    /// `c -> a` followed by `c -> b`
+    OutOut,
+    /// `a -> c` followed by `b -> c`
+    InIn,
+    /// `a -> c` followed by `c -> b`
+    InOut,
+    /// `c -> a` followed by `b -> c`
+    OutIn,
+}
+
+impl MotifType {
+    /// All motif types
+    pub const ALL: [MotifType; 6] = [
+        MotifType::Repeated,
+        MotifType::Reciprocated,
+        MotifType::OutOut,
+        MotifType::InIn,
+        MotifType::InOut,
+        MotifType::OutIn,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Edge event as seen from one of its endpoints
+#[derive(Clone, Copy)]
+struct Event {
+    time: i64,
+    other: usize,
+    outgoing: bool,
+}
+
+/// Counts the directed two edge delta-temporal motifs (Paranjape et al., Motifs in Temporal
+/// Networks, 2017)
+///
+/// Every pair of edge updates that share a node and where the second update happens at most
+/// `delta` after the first is counted once. Updates with the same timestamp are ordered
+/// consistently, so each pair has a well defined first and second edge. Self-loops are ignored.
+///
+/// The updates of each node are scanned in time order, so the algorithm runs in O(m·d_max) time,
+/// where d_max is the maximum number of updates of a node within `delta`.
+///
+/// # Arguments
+///
+/// - `graph` - A reference to the graph
+/// - `delta` - The maximum time between the first and second edge of a motif
+///
+/// # Returns
+///
+/// The number of occurrences of each [MotifType], every motif type is included
+///
+pub fn temporal_motifs<G: StaticGraphViewOps>(graph: &G, delta: i64) -> HashMap<MotifType, u64> {
+    let mut updates: Vec<(i64, usize, usize)> = graph
+        .edges()
+        .explode()
+        .into_iter()
+        .filter_map(|edge| {
+            let (src, dst) = (edge.src().node.index(), edge.dst().node.index());
+            (src != dst).then_some((edge.time().ok()?, src, dst))
+        })
+        .collect();
+    updates.sort_unstable();
+
+    let mut events: Vec<Vec<Event>> = vec![vec![]; graph.unfiltered_num_nodes()];
+    // the events of each node are pushed in the order of the sorted updates
+    for &(time, src, dst) in &updates {
+        events[src].push(Event {
+            time,
+            other: dst,
+            outgoing: true,
+        });
+        events[dst].push(Event {
+            time,
+            other: src,
+            outgoing: false,
+        });
+    }
+
+    let counts = events
+        .par_iter()
+        .map(|events| {
+            let mut counts = [0u64; 6];
+            for (i, first) in events.iter().enumerate() {
+                for second in events[i + 1..]
+                    .iter()
+                    .take_while(|second| second.time - first.time <= delta)
+                {
+                    let motif = if first.other == second.other {
+                        // both endpoints are shared, only count the pair at the source of the
+                        // first edge
+                        if !first.outgoing {
+                            continue;
+                        }
+                        if second.outgoing {
+                            MotifType::Repeated
+                        } else {
+                            MotifType::Reciprocated
+                        }
+                    } else {
+                        match (first.outgoing, second.outgoing) {
+                            (true, true) => MotifType::OutOut,
+                            (false, false) => MotifType::InIn,
+                            (false, true) => MotifType::InOut,
+                            (true, false) => MotifType::OutIn,
+                        }
+                    };
+                    counts[motif.index()] += 1;
+                }
+            }
+            counts
+        })
+        .reduce(
+            || [0u64; 6],
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+                a
+            },
+        );
+
+    MotifType::ALL
+        .into_iter()
+        .map(|motif| (motif, counts[motif.index()]))
+        .collect()
+}
+
+#[cfg(test)]
+mod two_edge_temporal_motifs_test {
+    use super::*;
+    use crate::test_storage;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst) in [
+            (1, 1, 2),
+            (2, 2, 3),
+            (4, 2, 1),
+            (10, 1, 2),
+            (11, 4, 2),
+            (12, 2, 4),
+            (13, 4, 2),
+            (14, 3, 2),
+            (15, 3, 3),
+        ] {
+            graph.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn toy_example() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let counts = temporal_motifs(graph, 5);
+            let expected = HashMap::from([
+                (MotifType::Repeated, 1),
+                (MotifType::Reciprocated, 3),
+                (MotifType::OutOut, 1),
+                (MotifType::InIn, 5),
+                (MotifType::InOut, 2),
+                (MotifType::OutIn, 1),
+            ]);
+            assert_eq!(counts, expected);
+        });
+    }
+
+    #[test]
+    fn zero_delta() {
+        let graph = load_graph();
+        graph.add_edge(14, 3, 1, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            // only the two updates at time 14 happen within 0 of each other
+            let counts = temporal_motifs(graph, 0);
+            assert_eq!(counts.len(), 6);
+            assert_eq!(counts[&MotifType::OutOut], 1);
+            assert_eq!(counts.values().sum::<u64>(), 1);
+        });
+    }
+}
@@ -0,0 +1,297 @@
+/// A* search
+use crate::{
+    algorithms::pathing::dijkstra::{edge_weight, weight_bounds},
+    core::entities::nodes::node_ref::{AsNodeRef, NodeRef},
+    db::{
+        api::{state::Index, view::StaticGraphViewOps},
+        graph::{node::NodeView, nodes::Nodes},
+    },
+    errors::GraphError,
+    prelude::*,
+};
+use indexmap::IndexSet;
+use raphtory_api::core::entities::VID;
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+};
+
+/// A state in the A* algorithm, `estimate` is the cost so far plus the heuristic.
+#[derive(PartialEq)]
+struct State {
+    estimate: f64,
+    cost: f64,
+    node: VID,
+}
+
+impl Eq for State {}
+
+impl Ord for State {
+    fn cmp(&self, other: &State) -> Ordering {
+        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for State {
+    // the lowest estimate comes first, ties are broken by the node id
+    fn partial_cmp(&self, other: &State) -> Option<Ordering> {
+        other
+            .estimate
+            .partial_cmp(&self.estimate)
+            .map(|ordering| ordering.then_with(|| other.node.cmp(&self.node)))
+    }
+}
+
+/// Finds the shortest path from `source` to `target` using A* search, following the out-edges of
+/// each node.
+///
+/// The search expands nodes in order of their cost from the source plus the heuristic estimate
+/// of their remaining cost to the target and stops as soon as the target is settled.
+///
+/// # Arguments
+///
+/// * `g`: The graph to search in.
+/// * `source`: The source node.
+/// * `target`: The target node.
+/// * `weight`: The name of the numeric weight property for the edges.
+/// * `heuristic`: Estimates the cost of the cheapest path from a node to the target. The result
+///   is only guaranteed to be the shortest path if the heuristic never overestimates this cost.
+///
+/// # Returns
+///
+/// Returns a tuple containing the total cost and the nodes on the shortest path from the source
+/// to the target, or `None` if the target is not reachable from the source.
+///
+/// # Errors
+///
+/// Returns an error if the source or target node does not exist, if the weight property does
+/// not exist or is not numeric, or if an edge on the search has a negative weight.
+///
+pub fn astar<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    source: T,
+    target: T,
+    weight: &str,
+    heuristic: impl Fn(&NodeView<'static, G>) -> f64,
+) -> Result<Option<(f64, Nodes<'static, G>)>, GraphError> {
+    astar_search(g, source, target, Some(weight), heuristic).map(|(path, _)| path)
+}
+
+/// Runs the A* search, also returning the number of nodes expanded.
+fn astar_search<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    source: T,
+    target: T,
+    weight: Option<&str>,
+    heuristic: impl Fn(&NodeView<'static, G>) -> f64,
+) -> Result<(Option<(f64, Nodes<'static, G>)>, usize), GraphError> {
+    let source_node = get_node(g, source)?;
+    let target_node = get_node(g, target)?;
+    let (zero, _) = weight_bounds(g, weight)?;
+
+    let mut heap = BinaryHeap::new();
+    heap.push(State {
+        estimate: heuristic(&source_node),
+        cost: 0f64,
+        node: source_node.node,
+    });
+
+    let mut dist: HashMap<VID, f64> = HashMap::new();
+    let mut predecessor: HashMap<VID, VID> = HashMap::new();
+    let mut expanded = 0;
+
+    dist.insert(source_node.node, 0f64);
+
+    while let Some(State {
+        cost,
+        node: node_vid,
+        ..
+    }) = heap.pop()
+    {
+        // skip entries that were superseded by a cheaper path
+        if dist.get(&node_vid).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        expanded += 1;
+
+        if node_vid == target_node.node {
+            let mut path = IndexSet::default();
+            path.insert(node_vid);
+            let mut current_node_id = node_vid;
+            while let Some(prev_node) = predecessor.get(&current_node_id) {
+                path.insert(*prev_node);
+                current_node_id = *prev_node;
+            }
+            path.reverse();
+            let nodes = Nodes::new_filtered(g.clone(), g.clone(), Some(Index::new(path)), None);
+            return Ok((Some((cost, nodes)), expanded));
+        }
+
+        for edge in g.node(node_vid).unwrap().out_edges() {
+            let Some(edge_val) = edge_weight(&edge, weight, &zero)? else {
+                continue;
+            };
+            let next_cost = cost + edge_val.as_f64().expect("numeric weight");
+            let nbr = edge.nbr();
+            if dist.get(&nbr.node).is_none_or(|&best| next_cost < best) {
+                dist.insert(nbr.node, next_cost);
+                predecessor.insert(nbr.node, node_vid);
+                heap.push(State {
+                    estimate: next_cost + heuristic(&nbr),
+                    cost: next_cost,
+                    node: nbr.node,
+                });
+            }
+        }
+    }
+    Ok((None, expanded))
+}
+
+fn get_node<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    node: T,
+) -> Result<NodeView<'static, G>, GraphError> {
+    let node_ref = node.as_node_ref();
+    match g.node(node_ref) {
+        Some(node) => Ok(node),
+        None => {
+            let gid = match node_ref {
+                NodeRef::Internal(vid) => g.node_id(vid),
+                NodeRef::External(gid) => gid.to_owned(),
+            };
+            Err(GraphError::NodeMissingError(gid))
+        }
+    }
+}
+
+#[cfg(test)]
+mod astar_tests {
+    use super::*;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        test_storage,
+    };
+
+    fn basic_graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst, weight) in [
+            (0, "A", "B", 4.0f32),
+            (1, "A", "C", 4.0f32),
+            (2, "B", "C", 2.0f32),
+            (3, "C", "D", 3.0f32),
+            (4, "C", "E", 1.0f32),
+            (5, "C", "F", 6.0f32),
+            (6, "D", "F", 2.0f32),
+            (7, "E", "F", 3.0f32),
+        ] {
+            graph
+                .add_edge(t, src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    fn grid_graph(size: u64) -> Graph {
+        let graph = Graph::new();
+        for x in 0..size {
+            for y in 0..size {
+                graph
+                    .add_node(0, x * size + y, NO_PROPS, None)
+                    .unwrap()
+                    .add_metadata([("x", x as f64), ("y", y as f64)])
+                    .unwrap();
+            }
+        }
+        for x in 0..size {
+            for y in 0..size {
+                let node = x * size + y;
+                let mut nbrs = vec![];
+                if x + 1 < size {
+                    nbrs.push(node + size);
+                }
+                if y + 1 < size {
+                    nbrs.push(node + 1);
+                }
+                for nbr in nbrs {
+                    graph
+                        .add_edge(0, node, nbr, [("weight", 1.0f64)], None)
+                        .unwrap();
+                    graph
+                        .add_edge(0, nbr, node, [("weight", 1.0f64)], None)
+                        .unwrap();
+                }
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_astar_path() {
+        let graph = basic_graph();
+
+        test_storage!(&graph, |graph| {
+            let (cost, path) = astar(graph, "A", "F", "weight", |_| 0.0).unwrap().unwrap();
+            assert_eq!(cost, 8.0f64);
+            assert_eq!(path.name(), vec!["A", "C", "E", "F"]);
+        });
+    }
+
+    #[test]
+    fn test_astar_unreachable() {
+        let graph = basic_graph();
+
+        test_storage!(&graph, |graph| {
+            assert!(astar(graph, "F", "A", "weight", |_| 0.0).unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn test_astar_missing_target() {
+        let graph = basic_graph();
+
+        test_storage!(&graph, |graph| {
+            let result = astar(graph, "A", "Z", "weight", |_| 0.0);
+            assert!(matches!(result, Err(GraphError::NodeMissingError(_))));
+        });
+    }
+
+    #[test]
+    fn test_astar_negative_weight() {
+        let graph = Graph::new();
+        graph
+            .add_edge(0, "A", "B", [("weight", -1i64)], None)
+            .unwrap();
+
+        let result = astar(&graph, "A", "B", "weight", |_| 0.0);
+        assert!(matches!(result, Err(GraphError::InvalidProperty { .. })));
+    }
+
+    #[test]
+    fn test_astar_expands_fewer_nodes_than_dijkstra() {
+        let size = 20u64;
+        let graph = grid_graph(size);
+        // the target is in the corner along the first row, so the straight-line distance to it
+        // is exact on that row and every node off the row has a larger estimate
+        let target = (size - 1) * size;
+        let target_node = graph.node(target).unwrap();
+        let coord =
+            |node: &NodeView<'static, Graph>, key: &str| node.metadata().get(key).unwrap_f64();
+        let (target_x, target_y) = (coord(&target_node, "x"), coord(&target_node, "y"));
+        let euclidean = |node: &NodeView<'static, Graph>| {
+            ((coord(node, "x") - target_x).powi(2) + (coord(node, "y") - target_y).powi(2)).sqrt()
+        };
+
+        let (astar_path, astar_expanded) =
+            astar_search(&graph, 0, target, Some("weight"), euclidean).unwrap();
+        let (dijkstra_path, dijkstra_expanded) =
+            astar_search(&graph, 0, target, Some("weight"), |_| 0.0).unwrap();
+
+        let (astar_cost, astar_path) = astar_path.unwrap();
+        let (dijkstra_cost, _) = dijkstra_path.unwrap();
+        assert_eq!(astar_cost, dijkstra_cost);
+        assert_eq!(astar_cost, (size - 1) as f64);
+        assert_eq!(astar_path.len(), size as usize);
+        assert_eq!(astar_expanded, size as usize);
+        assert_eq!(dijkstra_expanded, (size * (size + 1) / 2) as usize);
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     core::entities::nodes::node_ref::NodeRef,
     db::{
         api::state::{Index, NodeState},
-        graph::nodes::Nodes,
+        graph::{edge::EdgeView, nodes::Nodes},
     },
     errors::GraphError,
     prelude::*,
@@ -39,10 +39,68 @@ impl Ord for State {
 
 impl PartialOrd for State {
     fn partial_cmp(&self, other: &State) -> Option<Ordering> {
-        other.cost.partial_cmp(&self.cost)
+        // ties on cost are broken by the node id so the search order is deterministic
+        other
+            .cost
+            .partial_cmp(&self.cost)
+            .map(|ordering| ordering.then_with(|| other.node.cmp(&self.node)))
     }
 }
 
+/// Resolve the zero and maximum cost for the weight property, checking that it is numeric.
+///
+/// If `weight` is `None`, all edges have weight 1.
+pub(crate) fn weight_bounds<'graph, G: GraphViewOps<'graph>>(
+    g: &G,
+    weight: Option<&str>,
+) -> Result<(Prop, Prop), GraphError> {
+    let mut weight_type = PropType::U8;
+    if let Some(weight) = weight {
+        if let Some((_, dtype)) = g.edge_meta().get_prop_id_and_type(weight, false) {
+            weight_type = dtype;
+        } else {
+            return Err(GraphError::PropertyMissingError(weight.to_string()));
+        }
+    }
+
+    match weight_type {
+        PropType::F32 => Ok((Prop::F32(0f32), Prop::F32(f32::MAX))),
+        PropType::F64 => Ok((Prop::F64(0f64), Prop::F64(f64::MAX))),
+        PropType::U8 => Ok((Prop::U8(0u8), Prop::U8(u8::MAX))),
+        PropType::U16 => Ok((Prop::U16(0u16), Prop::U16(u16::MAX))),
+        PropType::U32 => Ok((Prop::U32(0u32), Prop::U32(u32::MAX))),
+        PropType::U64 => Ok((Prop::U64(0u64), Prop::U64(u64::MAX))),
+        PropType::I32 => Ok((Prop::I32(0i32), Prop::I32(i32::MAX))),
+        PropType::I64 => Ok((Prop::I64(0i64), Prop::I64(i64::MAX))),
+        p_type => Err(GraphError::InvalidProperty {
+            reason: format!("Weight type: {:?}, not supported", p_type),
+        }),
+    }
+}
+
+/// Get the weight of an edge, returning `None` if the edge does not have the weight property.
+///
+/// Returns an error if the weight is smaller than `zero`, as returned by [weight_bounds].
+pub(crate) fn edge_weight<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>>(
+    edge: &EdgeView<G, GH>,
+    weight: Option<&str>,
+    zero: &Prop,
+) -> Result<Option<Prop>, GraphError> {
+    let edge_val = match weight {
+        None => Prop::U8(1),
+        Some(weight) => match edge.properties().get(weight) {
+            Some(prop) => prop,
+            None => return Ok(None),
+        },
+    };
+    if edge_val.partial_cmp(zero) == Some(Ordering::Less) {
+        return Err(GraphError::InvalidProperty {
+            reason: format!("Negative weight {:?} not supported", edge_val),
+        });
+    }
+    Ok(Some(edge_val))
+}
+
 /// Finds the shortest paths from a single source to multiple targets in a graph.
 ///
 /// # Arguments
@@ -55,8 +113,16 @@ impl PartialOrd for State {
 ///
 /// # Returns
 ///
-/// Returns a `HashMap` where the key is the target node and the value is a tuple containing
-/// the total cost and a vector of nodes representing the shortest path.
+/// Returns a `NodeState` where the key is the target node and the value is a tuple containing
+/// the total cost and the nodes on the shortest path from the source to the target.
+/// Targets that are not reachable from the source are absent from the result.
+/// If several paths have the same cost, the one found by expanding nodes with lower ids first is
+/// returned.
+///
+/// # Errors
+///
+/// Returns an error if the source node does not exist, if the weight property does not exist or
+/// is not numeric, or if an edge on the search has a negative weight.
 ///
 pub fn dijkstra_single_source_shortest_paths<G: StaticGraphViewOps, T: AsNodeRef>(
     g: &G,
@@ -76,15 +142,6 @@ pub fn dijkstra_single_source_shortest_paths<G: StaticGraphViewOps, T: AsNodeRef
             return Err(GraphError::NodeMissingError(gid));
         }
     };
-    let mut weight_type = PropType::U8;
-    if let Some(weight) = weight {
-        if let Some((_, dtype)) = g.edge_meta().get_prop_id_and_type(weight, false) {
-            weight_type = dtype;
-        } else {
-            return Err(GraphError::PropertyMissingError(weight.to_string()));
-        }
-    }
-
     let mut target_nodes = vec![false; g.unfiltered_num_nodes()];
     for target in targets {
         if let Some(target_node) = g.node(target) {
@@ -92,38 +149,8 @@ pub fn dijkstra_single_source_shortest_paths<G: StaticGraphViewOps, T: AsNodeRef
         }
     }
 
-    // Turn below into a generic function, then add a closure to ensure the prop is correctly unwrapped
-    // after the calc is done
-    let cost_val = match weight_type {
-        PropType::F32 => Prop::F32(0f32),
-        PropType::F64 => Prop::F64(0f64),
-        PropType::U8 => Prop::U8(0u8),
-        PropType::U16 => Prop::U16(0u16),
-        PropType::U32 => Prop::U32(0u32),
-        PropType::U64 => Prop::U64(0u64),
-        PropType::I32 => Prop::I32(0i32),
-        PropType::I64 => Prop::I64(0i64),
-        p_type => {
-            return Err(GraphError::InvalidProperty {
-                reason: format!("Weight type: {:?}, not supported", p_type),
-            })
-        }
-    };
-    let max_val = match weight_type {
-        PropType::F32 => Prop::F32(f32::MAX),
-        PropType::F64 => Prop::F64(f64::MAX),
-        PropType::U8 => Prop::U8(u8::MAX),
-        PropType::U16 => Prop::U16(u16::MAX),
-        PropType::U32 => Prop::U32(u32::MAX),
-        PropType::U64 => Prop::U64(u64::MAX),
-        PropType::I32 => Prop::I32(i32::MAX),
-        PropType::I64 => Prop::I64(i64::MAX),
-        p_type => {
-            return Err(GraphError::InvalidProperty {
-                reason: format!("Weight type: {:?}, not supported", p_type),
-            })
-        }
-    };
+    let (cost_val, max_val) = weight_bounds(g, weight)?;
+
     let mut heap = BinaryHeap::new();
     heap.push(State {
         cost: cost_val.clone(),
@@ -167,12 +194,8 @@ pub fn dijkstra_single_source_shortest_paths<G: StaticGraphViewOps, T: AsNodeRef
         for edge in edges {
             let next_node_vid = edge.nbr().node;
 
-            let edge_val = match weight {
-                None => Prop::U8(1),
-                Some(weight) => match edge.properties().get(weight) {
-                    Some(prop) => prop,
-                    _ => continue,
-                },
+            let Some(edge_val) = edge_weight(&edge, weight, &cost_val)? else {
+                continue;
             };
 
             let next_cost = cost.clone().add(edge_val).unwrap();
@@ -500,4 +523,98 @@ mod dijkstra_tests {
             );
         });
     }
+
+    #[test]
+    fn test_dijkstra_unreachable_target() {
+        let graph = basic_graph();
+        graph.add_node(8, "G", NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let targets: Vec<&str> = vec!["A", "E", "G"];
+            let results = dijkstra_single_source_shortest_paths(
+                graph,
+                "B",
+                targets,
+                Some("weight"),
+                Direction::OUT,
+            )
+            .unwrap();
+            assert_eq!(results.len(), 1);
+            assert_eq!(
+                results.get_by_node("E").unwrap().1.name(),
+                vec!["B", "C", "E"]
+            );
+            assert!(results.get_by_node("A").is_none());
+            assert!(results.get_by_node("G").is_none());
+        });
+    }
+
+    #[test]
+    fn test_dijkstra_ties_are_deterministic() {
+        let graph = load_graph(vec![
+            (0, "A", "B", vec![("weight", 1.0f32)]),
+            (1, "A", "C", vec![("weight", 1.0f32)]),
+            (2, "C", "D", vec![("weight", 1.0f32)]),
+            (3, "B", "D", vec![("weight", 1.0f32)]),
+        ]);
+
+        test_storage!(&graph, |graph| {
+            for _ in 0..10 {
+                let results = dijkstra_single_source_shortest_paths(
+                    graph,
+                    "A",
+                    vec!["D"],
+                    Some("weight"),
+                    Direction::OUT,
+                )
+                .unwrap();
+                assert_eq!(results.get_by_node("D").unwrap().0, 2.0f64);
+                assert_eq!(
+                    results.get_by_node("D").unwrap().1.name(),
+                    vec!["A", "B", "D"]
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn test_dijkstra_negative_weight() {
+        let graph = Graph::new();
+        graph
+            .add_edge(0, "A", "B", [("weight", 1i64)], None)
+            .unwrap();
+        graph
+            .add_edge(1, "B", "C", [("weight", -1i64)], None)
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            let result = dijkstra_single_source_shortest_paths(
+                graph,
+                "A",
+                vec!["C"],
+                Some("weight"),
+                Direction::OUT,
+            );
+            assert!(matches!(result, Err(GraphError::InvalidProperty { .. })));
+        });
+    }
+
+    #[test]
+    fn test_dijkstra_non_numeric_weight() {
+        let graph = Graph::new();
+        graph
+            .add_edge(0, "A", "B", [("weight", "heavy")], None)
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            let result = dijkstra_single_source_shortest_paths(
+                graph,
+                "A",
+                vec!["B"],
+                Some("weight"),
+                Direction::OUT,
+            );
+            assert!(matches!(result, Err(GraphError::InvalidProperty { .. })));
+        });
+    }
 }
@@ -0,0 +1,343 @@
+/// Yen's algorithm for the k shortest loopless paths
+use crate::{
+    algorithms::pathing::dijkstra::{
+        dijkstra_single_source_shortest_paths, edge_weight, weight_bounds,
+    },
+    core::entities::nodes::node_ref::{AsNodeRef, NodeRef},
+    db::{
+        api::{
+            properties::internal::InheritPropertiesOps,
+            state::{Index, NodeStateOps},
+            view::{
+                internal::{
+                    Immutable, InheritEdgeHistoryFilter, InheritEdgeLayerFilterOps,
+                    InheritExplodedEdgeFilterOps, InheritLayerOps, InheritListOps,
+                    InheritMaterialize, InheritNodeFilterOps, InheritNodeHistoryFilter,
+                    InheritStorageOps, InheritTimeSemantics, InternalEdgeFilterOps, Static,
+                },
+                StaticGraphViewOps,
+            },
+        },
+        graph::nodes::Nodes,
+    },
+    errors::GraphError,
+    prelude::*,
+};
+use indexmap::IndexSet;
+use raphtory_api::{
+    core::{
+        entities::{LayerIds, EID, VID},
+        Direction,
+    },
+    inherit::Base,
+};
+use raphtory_storage::{
+    core_ops::InheritCoreGraphOps,
+    graph::edges::{edge_ref::EdgeStorageRef, edge_storage_ops::EdgeStorageOps},
+};
+use std::{cmp::Ordering, collections::HashSet, sync::Arc};
+
+/// A view that hides a set of edges, used to block the edges of earlier paths in the spur
+/// searches without mutating the graph.
+#[derive(Debug, Clone)]
+struct ExcludedEdgesGraph<G> {
+    graph: G,
+    edges: Arc<HashSet<EID>>,
+}
+
+impl<G> ExcludedEdgesGraph<G> {
+    fn new(graph: G, edges: HashSet<EID>) -> Self {
+        Self {
+            graph,
+            edges: Arc::new(edges),
+        }
+    }
+}
+
+impl<G> Base for ExcludedEdgesGraph<G> {
+    type Base = G;
+
+    fn base(&self) -> &Self::Base {
+        &self.graph
+    }
+}
+
+impl<G> Static for ExcludedEdgesGraph<G> {}
+impl<G> Immutable for ExcludedEdgesGraph<G> {}
+
+impl<'graph, G: GraphViewOps<'graph>> InheritCoreGraphOps for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritStorageOps for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritLayerOps for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritListOps for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritMaterialize for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritNodeFilterOps for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritPropertiesOps for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritTimeSemantics for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritNodeHistoryFilter for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritEdgeHistoryFilter for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritEdgeLayerFilterOps for ExcludedEdgesGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritExplodedEdgeFilterOps for ExcludedEdgesGraph<G> {}
+
+impl<'graph, G: GraphViewOps<'graph>> InternalEdgeFilterOps for ExcludedEdgesGraph<G> {
+    #[inline]
+    fn internal_edge_filtered(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn internal_edge_list_trusted(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn internal_filter_edge(&self, edge: EdgeStorageRef, layer_ids: &LayerIds) -> bool {
+        self.graph.internal_filter_edge(edge, layer_ids) && !self.edges.contains(&edge.eid())
+    }
+}
+
+/// Finds the `k` shortest loopless paths from `source` to `target` using Yen's algorithm,
+/// following the out-edges of each node.
+///
+/// The first path is found with [dijkstra_single_source_shortest_paths]. Every further path
+/// deviates from one of the paths found so far at a spur node. The spur searches run on a view
+/// of `g` that hides the nodes before the spur node and the edges used by earlier paths at that
+/// point, so the graph is never mutated and any window or layer restrictions of `g` apply.
+///
+/// # Arguments
+///
+/// * `g`: The graph to search in.
+/// * `source`: The source node.
+/// * `target`: The target node.
+/// * `k`: The maximum number of paths to return.
+/// * `weight`: Option, The name of the weight property for the edges. If not set then defaults all edges to weight=1.
+///
+/// # Returns
+///
+/// Returns a vector of tuples containing the total cost and the nodes on the path, sorted by
+/// cost. Paths with the same cost are ordered by the internal ids of their nodes. If fewer than
+/// `k` loopless paths exist, all of them are returned.
+///
+/// # Errors
+///
+/// Returns an error if the source or target node does not exist, if the weight property does
+/// not exist or is not numeric, or if an edge on the search has a negative weight.
+///
+pub fn k_shortest_paths<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    source: T,
+    target: T,
+    k: usize,
+    weight: Option<&str>,
+) -> Result<Vec<(f64, Nodes<'static, G>)>, GraphError> {
+    let source = get_node(g, source)?;
+    let target = get_node(g, target)?;
+    let (zero, _) = weight_bounds(g, weight)?;
+
+    let mut paths: Vec<(f64, Vec<VID>)> = vec![];
+    if k > 0 {
+        if let Some(path) = shortest_path(g, source, target, weight)? {
+            paths.push(path);
+        }
+    }
+    let mut candidates: Vec<(f64, Vec<VID>)> = vec![];
+
+    while paths.len() < k {
+        let Some((_, last_path)) = paths.last() else {
+            break;
+        };
+        for i in 0..last_path.len() - 1 {
+            let spur_node = last_path[i];
+            let root = &last_path[..=i];
+
+            let blocked_edges: HashSet<EID> = paths
+                .iter()
+                .filter(|(_, path)| path.len() > i + 1 && &path[..=i] == root)
+                .filter_map(|(_, path)| g.edge(path[i], path[i + 1]))
+                .map(|edge| edge.edge.pid())
+                .collect();
+            let spur_graph = ExcludedEdgesGraph::new(g.clone(), blocked_edges)
+                .exclude_nodes(root[..i].iter().copied());
+            // a spur node whose edges are all hidden is not part of the view, so no path leaves it
+            if !spur_graph.has_node(spur_node) {
+                continue;
+            }
+
+            if let Some((spur_cost, spur_path)) =
+                shortest_path(&spur_graph, spur_node, target, weight)?
+            {
+                let mut path = root[..i].to_vec();
+                path.extend(spur_path);
+                if !candidates.iter().any(|(_, p)| p == &path) {
+                    let cost = path_cost(g, root, weight, &zero)? + spur_cost;
+                    candidates.push((cost, path));
+                }
+            }
+        }
+
+        let best = candidates
+            .iter()
+            .enumerate()
+            .min_by(|(_, (cost_a, path_a)), (_, (cost_b, path_b))| {
+                cost_a
+                    .partial_cmp(cost_b)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| path_a.cmp(path_b))
+            })
+            .map(|(index, _)| index);
+        match best {
+            Some(index) => paths.push(candidates.swap_remove(index)),
+            None => break,
+        }
+    }
+
+    Ok(paths
+        .into_iter()
+        .map(|(cost, path)| {
+            let path: IndexSet<VID, ahash::RandomState> = path.into_iter().collect();
+            let nodes = Nodes::new_filtered(g.clone(), g.clone(), Some(Index::new(path)), None);
+            (cost, nodes)
+        })
+        .collect())
+}
+
+/// The cost and nodes of the shortest path from `source` to `target` in `g`, if any
+fn shortest_path<G: StaticGraphViewOps>(
+    g: &G,
+    source: VID,
+    target: VID,
+    weight: Option<&str>,
+) -> Result<Option<(f64, Vec<VID>)>, GraphError> {
+    let result =
+        dijkstra_single_source_shortest_paths(g, source, vec![target], weight, Direction::OUT)?;
+    Ok(result
+        .get_by_node(target)
+        .map(|(cost, nodes)| (*cost, nodes.iter().map(|node| node.node).collect())))
+}
+
+/// The total weight of the edges along `path`
+fn path_cost<G: StaticGraphViewOps>(
+    g: &G,
+    path: &[VID],
+    weight: Option<&str>,
+    zero: &Prop,
+) -> Result<f64, GraphError> {
+    let mut cost = 0f64;
+    for pair in path.windows(2) {
+        let edge = g.edge(pair[0], pair[1]).expect("edge on path");
+        if let Some(edge_val) = edge_weight(&edge, weight, zero)? {
+            cost += edge_val.as_f64().expect("numeric weight");
+        }
+    }
+    Ok(cost)
+}
+
+fn get_node<G: StaticGraphViewOps, T: AsNodeRef>(g: &G, node: T) -> Result<VID, GraphError> {
+    let node_ref = node.as_node_ref();
+    match g.node(node_ref) {
+        Some(node) => Ok(node.node),
+        None => {
+            let gid = match node_ref {
+                NodeRef::Internal(vid) => g.node_id(vid),
+                NodeRef::External(gid) => gid.to_owned(),
+            };
+            Err(GraphError::NodeMissingError(gid))
+        }
+    }
+}
+
+#[cfg(test)]
+mod k_shortest_paths_tests {
+    use super::*;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        test_storage,
+    };
+
+    fn diamond_graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst, weight) in [
+            (0, "A", "B", 1.0),
+            (1, "A", "C", 2.5),
+            (2, "B", "D", 3.0),
+            (3, "C", "D", 1.0),
+            (10, "B", "C", 1.0),
+        ] {
+            graph
+                .add_edge(t, src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    fn names<G: StaticGraphViewOps>(paths: &[(f64, Nodes<'static, G>)]) -> Vec<(f64, Vec<String>)> {
+        paths
+            .iter()
+            .map(|(cost, nodes)| (*cost, nodes.name().iter_values().collect()))
+            .collect()
+    }
+
+    fn expected(paths: &[(f64, &[&str])]) -> Vec<(f64, Vec<String>)> {
+        paths
+            .iter()
+            .map(|(cost, path)| (*cost, path.iter().map(|n| n.to_string()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_three_cheapest_paths() {
+        let graph = diamond_graph();
+
+        test_storage!(&graph, |graph| {
+            let paths = k_shortest_paths(graph, "A", "D", 3, Some("weight")).unwrap();
+            assert_eq!(
+                names(&paths),
+                expected(&[
+                    (3.0, &["A", "B", "C", "D"]),
+                    (3.5, &["A", "C", "D"]),
+                    (4.0, &["A", "B", "D"]),
+                ])
+            );
+        });
+    }
+
+    #[test]
+    fn test_fewer_paths_than_k() {
+        let graph = diamond_graph();
+
+        test_storage!(&graph, |graph| {
+            let paths = k_shortest_paths(graph, "A", "D", 10, Some("weight")).unwrap();
+            assert_eq!(paths.len(), 3);
+            assert!(k_shortest_paths(graph, "D", "A", 3, Some("weight"))
+                .unwrap()
+                .is_empty());
+            assert!(k_shortest_paths(graph, "A", "D", 0, Some("weight"))
+                .unwrap()
+                .is_empty());
+        });
+    }
+
+    #[test]
+    fn test_respects_window() {
+        let graph = diamond_graph();
+
+        test_storage!(&graph, |graph| {
+            let paths = k_shortest_paths(&graph.window(0, 5), "A", "D", 3, Some("weight")).unwrap();
+            assert_eq!(
+                names(&paths),
+                expected(&[(3.5, &["A", "C", "D"]), (4.0, &["A", "B", "D"])])
+            );
+        });
+    }
+
+    #[test]
+    fn test_unweighted() {
+        let graph = diamond_graph();
+
+        test_storage!(&graph, |graph| {
+            let paths = k_shortest_paths(graph, "A", "D", 3, None).unwrap();
+            let costs: Vec<f64> = paths.iter().map(|(cost, _)| *cost).collect();
+            assert_eq!(costs, vec![2.0, 2.0, 3.0]);
+            assert_eq!(paths[2].1.name(), vec!["A", "B", "C", "D"]);
+        });
+    }
+}
@@ -0,0 +1,383 @@
+//! Maximum flow and minimum cut between two nodes using Dinic's algorithm
+use crate::{
+    core::entities::nodes::node_ref::{AsNodeRef, NodeRef},
+    db::api::view::StaticGraphViewOps,
+    errors::GraphError,
+    prelude::*,
+};
+use raphtory_api::core::entities::GID;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Residual capacities below this value are treated as saturated
+const EPSILON: f64 = 1e-12;
+
+/// Result of [max_flow]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxFlowResult {
+    /// The value of the maximum flow from the source to the sink
+    pub flow_value: f64,
+    /// The flow along each edge with a positive flow, keyed by the ids of its endpoints
+    pub edge_flows: HashMap<(GID, GID), f64>,
+    /// The nodes on the source side of a minimum cut, i.e., the nodes that are reachable from the
+    /// source in the residual network
+    pub source_side: HashSet<GID>,
+}
+
+struct Arc {
+    to: usize,
+    capacity: f64,
+    flow: f64,
+}
+
+/// Flow network with the reverse of arc `i` stored at `i ^ 1`
+struct FlowNetwork {
+    arcs: Vec<Arc>,
+    adjacency: Vec<Vec<usize>>,
+    level: Vec<Option<usize>>,
+    next_arc: Vec<usize>,
+}
+
+impl FlowNetwork {
+    fn new(num_nodes: usize) -> Self {
+        Self {
+            arcs: vec![],
+            adjacency: vec![vec![]; num_nodes],
+            level: vec![None; num_nodes],
+            next_arc: vec![0; num_nodes],
+        }
+    }
+
+    fn add_arc(&mut self, from: usize, to: usize, capacity: f64) -> usize {
+        let id = self.arcs.len();
+        self.arcs.push(Arc {
+            to,
+            capacity,
+            flow: 0.0,
+        });
+        self.arcs.push(Arc {
+            to: from,
+            capacity: 0.0,
+            flow: 0.0,
+        });
+        self.adjacency[from].push(id);
+        self.adjacency[to].push(id + 1);
+        id
+    }
+
+    fn residual(&self, arc: usize) -> f64 {
+        self.arcs[arc].capacity - self.arcs[arc].flow
+    }
+
+    /// Computes the level graph, returns `false` if the sink is not reachable
+    fn bfs(&mut self, source: usize, sink: usize) -> bool {
+        self.level.fill(None);
+        self.level[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            let next_level = self.level[v].unwrap() + 1;
+            for &arc in &self.adjacency[v] {
+                let to = self.arcs[arc].to;
+                if self.level[to].is_none() && self.residual(arc) > EPSILON {
+                    self.level[to] = Some(next_level);
+                    queue.push_back(to);
+                }
+            }
+        }
+        self.level[sink].is_some()
+    }
+
+    /// Finds a blocking flow in the level graph by repeatedly pushing flow along shortest paths
+    fn push(&mut self, source: usize, sink: usize) -> f64 {
+        self.next_arc.fill(0);
+        let mut total = 0.0;
+        let mut path: Vec<usize> = vec![];
+        let mut v = source;
+        loop {
+            if v == sink {
+                let bottleneck = path
+                    .iter()
+                    .map(|&arc| self.residual(arc))
+                    .fold(f64::INFINITY, f64::min);
+                for &arc in &path {
+                    self.arcs[arc].flow += bottleneck;
+                    self.arcs[arc ^ 1].flow -= bottleneck;
+                }
+                total += bottleneck;
+                path.clear();
+                v = source;
+                continue;
+            }
+            let mut advanced = false;
+            while self.next_arc[v] < self.adjacency[v].len() {
+                let arc = self.adjacency[v][self.next_arc[v]];
+                let to = self.arcs[arc].to;
+                if self.residual(arc) > EPSILON
+                    && self.level[to] == self.level[v].map(|level| level + 1)
+                {
+                    path.push(arc);
+                    v = to;
+                    advanced = true;
+                    break;
+                }
+                self.next_arc[v] += 1;
+            }
+            if !advanced {
+                // dead end, remove it from the level graph and retreat
+                self.level[v] = None;
+                match path.pop() {
+                    Some(arc) => {
+                        v = self.arcs[arc ^ 1].to;
+                        self.next_arc[v] += 1;
+                    }
+                    None => return total,
+                }
+            }
+        }
+    }
+}
+
+fn find_node<G: StaticGraphViewOps, V: AsNodeRef>(g: &G, node: V) -> Result<usize, GraphError> {
+    let node_ref = node.as_node_ref();
+    match g.node(node_ref) {
+        Some(node) => Ok(node.node.index()),
+        None => Err(GraphError::NodeMissingError(match node_ref {
+            NodeRef::Internal(vid) => g.node_id(vid),
+            NodeRef::External(gid) => gid.to_owned(),
+        })),
+    }
+}
+
+/// Computes the maximum flow from `source` to `sink` and a minimum cut using Dinic's algorithm
+///
+/// The capacity of each edge is the latest value of the `capacity_prop` property. Edges that exist
+/// in several layers are treated as parallel edges and their capacities are summed. Self-loops
+/// are ignored.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `source` - The node the flow starts at
+/// - `sink` - The node the flow ends at
+/// - `capacity_prop` - The name of the numeric edge property holding the capacities
+///
+/// # Returns
+///
+/// A [MaxFlowResult] with the value of the maximum flow, the flow along each edge and the source
+/// side of a minimum cut
+///
+/// # Errors
+///
+/// Returns [GraphError::NodeMissingError] if the source or sink is not in the graph,
+/// [GraphError::InvalidAlgorithmParameters] if the source and sink are the same node and
+/// [GraphError::InvalidProperty] if an edge has no capacity or a capacity that is not a positive
+/// number.
+///
+pub fn max_flow<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    source: V,
+    sink: V,
+    capacity_prop: &str,
+) -> Result<MaxFlowResult, GraphError> {
+    let source = find_node(g, source)?;
+    let sink = find_node(g, sink)?;
+    if source == sink {
+        return Err(GraphError::InvalidAlgorithmParameters(
+            "source and sink need to be different nodes".to_string(),
+        ));
+    }
+
+    dinic(
+        g,
+        source,
+        sink,
+        capacity_prop,
+        |src, dst, value| match value {
+            Some(value) if value > 0.0 => Ok(value),
+            Some(value) => Err(GraphError::InvalidProperty {
+                reason: format!(
+                    "Capacity of edge ({src}, {dst}) needs to be positive, got {value}"
+                ),
+            }),
+            None => Err(GraphError::InvalidProperty {
+                reason: format!(
+                    "Edge ({src}, {dst}) has no numeric capacity property {capacity_prop}"
+                ),
+            }),
+        },
+    )
+}
+
+/// Runs Dinic's algorithm between the nodes with index `source` and `sink`
+///
+/// The capacity of each layer of an edge is given by `capacity`, which receives the ids of the
+/// endpoints and the latest numeric value of `capacity_prop` in that layer, if any.
+pub(crate) fn dinic<G: StaticGraphViewOps, E>(
+    g: &G,
+    source: usize,
+    sink: usize,
+    capacity_prop: &str,
+    capacity: impl Fn(&GID, &GID, Option<f64>) -> Result<f64, E>,
+) -> Result<MaxFlowResult, E> {
+    let mut network = FlowNetwork::new(g.unfiltered_num_nodes());
+    let mut edge_arcs = vec![];
+    for edge in g.edges() {
+        let (src, dst) = (edge.src().node.index(), edge.dst().node.index());
+        if src == dst {
+            continue;
+        }
+        let ids = (edge.src().id(), edge.dst().id());
+        let mut edge_capacity = 0.0;
+        for layer_edge in edge.explode_layers() {
+            let value = layer_edge
+                .properties()
+                .get(capacity_prop)
+                .and_then(|prop| prop.as_f64());
+            edge_capacity += capacity(&ids.0, &ids.1, value)?;
+        }
+        let arc = network.add_arc(src, dst, edge_capacity);
+        edge_arcs.push((ids, arc));
+    }
+
+    let mut flow_value = 0.0;
+    while network.bfs(source, sink) {
+        flow_value += network.push(source, sink);
+    }
+
+    let edge_flows = edge_arcs
+        .into_iter()
+        .filter(|(_, arc)| network.arcs[*arc].flow > EPSILON)
+        .map(|(ids, arc)| (ids, network.arcs[arc].flow))
+        .collect();
+    // after the last search, the level is only set for nodes reachable in the residual network
+    let source_side = g
+        .nodes()
+        .iter()
+        .filter(|node| network.level[node.node.index()].is_some())
+        .map(|node| node.id())
+        .collect();
+
+    Ok(MaxFlowResult {
+        flow_value,
+        edge_flows,
+        source_side,
+    })
+}
+
+#[cfg(test)]
+mod max_flow_test {
+    use super::*;
+    use crate::test_storage;
+
+    fn textbook_network() -> Graph {
+        // the flow network from Cormen et al., Introduction to Algorithms, Figure 26.1
+        let graph = Graph::new();
+        let edges = [
+            ("s", "v1", 16.0),
+            ("s", "v2", 13.0),
+            ("v1", "v3", 12.0),
+            ("v2", "v1", 4.0),
+            ("v2", "v4", 14.0),
+            ("v3", "v2", 9.0),
+            ("v3", "t", 20.0),
+            ("v4", "v3", 7.0),
+            ("v4", "t", 4.0),
+        ];
+        for (src, dst, capacity) in edges {
+            graph
+                .add_edge(0, src, dst, [("capacity", capacity)], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn textbook_max_flow() {
+        let graph = textbook_network();
+
+        test_storage!(&graph, |graph| {
+            let result = max_flow(graph, "s", "t", "capacity").unwrap();
+            assert_eq!(result.flow_value, 23.0);
+
+            let expected: HashSet<GID> = ["s", "v1", "v2", "v4"]
+                .into_iter()
+                .map(|name| GID::Str(name.to_string()))
+                .collect();
+            assert_eq!(result.source_side, expected);
+
+            // the flow is conserved at every inner node and never exceeds the capacities
+            for node in graph.nodes() {
+                let id = node.id();
+                let inflow: f64 = result
+                    .edge_flows
+                    .iter()
+                    .filter(|((_, dst), _)| *dst == id)
+                    .map(|(_, flow)| flow)
+                    .sum();
+                let outflow: f64 = result
+                    .edge_flows
+                    .iter()
+                    .filter(|((src, _), _)| *src == id)
+                    .map(|(_, flow)| flow)
+                    .sum();
+                match node.name().as_str() {
+                    "s" => assert_eq!(outflow - inflow, 23.0),
+                    "t" => assert_eq!(inflow - outflow, 23.0),
+                    _ => assert_eq!(inflow, outflow),
+                }
+            }
+            for ((src, dst), flow) in &result.edge_flows {
+                let edge = graph.edge(src, dst).unwrap();
+                let capacity = edge.properties().get("capacity").unwrap().as_f64().unwrap();
+                assert!(*flow <= capacity);
+            }
+        });
+    }
+
+    #[test]
+    fn parallel_edges_are_summed() {
+        let graph = Graph::new();
+        graph
+            .add_edge(0, 1, 2, [("capacity", 2.0)], Some("a"))
+            .unwrap();
+        graph
+            .add_edge(0, 1, 2, [("capacity", 3.0)], Some("b"))
+            .unwrap();
+        graph.add_edge(0, 2, 3, [("capacity", 10.0)], None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let result = max_flow(graph, 1, 3, "capacity").unwrap();
+            assert_eq!(result.flow_value, 5.0);
+            assert_eq!(result.edge_flows[&(GID::U64(1), GID::U64(2))], 5.0);
+            assert_eq!(result.source_side, HashSet::from([GID::U64(1)]));
+
+            let result = max_flow(&graph.exclude_layers("b").unwrap(), 1, 3, "capacity").unwrap();
+            assert_eq!(result.flow_value, 2.0);
+        });
+    }
+
+    #[test]
+    fn invalid_capacities() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, [("capacity", 1.0)], None).unwrap();
+        graph.add_edge(0, 2, 3, NO_PROPS, None).unwrap();
+        assert!(matches!(
+            max_flow(&graph, 1, 3, "capacity"),
+            Err(GraphError::InvalidProperty { .. })
+        ));
+
+        graph.add_edge(1, 2, 3, [("capacity", 0.0)], None).unwrap();
+        assert!(matches!(
+            max_flow(&graph, 1, 3, "capacity"),
+            Err(GraphError::InvalidProperty { .. })
+        ));
+
+        assert!(matches!(
+            max_flow(&graph, 1, 1, "capacity"),
+            Err(GraphError::InvalidAlgorithmParameters(_))
+        ));
+        assert!(matches!(
+            max_flow(&graph, 1, 4, "capacity"),
+            Err(GraphError::NodeMissingError(_))
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+//! Minimum spanning forest using Kruskal's algorithm
+use crate::{
+    db::{api::view::StaticGraphViewOps, graph::edge::EdgeView},
+    errors::GraphError,
+    prelude::*,
+};
+
+/// Union-find over node indices with path halving and union by size
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(num_nodes: usize) -> Self {
+        Self {
+            parent: (0..num_nodes).collect(),
+            size: vec![1; num_nodes],
+        }
+    }
+
+    fn find(&mut self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            self.parent[node] = self.parent[self.parent[node]];
+            node = self.parent[node];
+        }
+        node
+    }
+
+    /// Merges the sets of `a` and `b`, returns `false` if they were already in the same set
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+}
+
+/// Computes a minimum spanning forest of the undirected view of the graph using Kruskal's
+/// algorithm
+///
+/// The weight of an edge is the latest value of `weight_prop` in the current view, so windowing
+/// the graph changes the tree. Edges are added in order of their weight, ties are broken by the
+/// id of the source and then the id of the destination node, so the result is deterministic.
+/// Self-loops are ignored and at most one of `u -> v` and `v -> u` is part of the forest.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `weight_prop` - The name of the numeric edge property holding the weights
+///
+/// # Returns
+///
+/// The edges of the forest in the order in which they were added, the forest contains one tree
+/// per connected component
+///
+/// # Errors
+///
+/// Returns [GraphError::InvalidProperty] if an edge has no weight or a weight that is not a
+/// number.
+///
+pub fn minimum_spanning_tree<G: StaticGraphViewOps>(
+    g: &G,
+    weight_prop: &str,
+) -> Result<Vec<EdgeView<G>>, GraphError> {
+    let mut edges = g
+        .edges()
+        .into_iter()
+        .filter(|edge| edge.src().node != edge.dst().node)
+        .map(|edge| {
+            let weight = edge
+                .properties()
+                .get(weight_prop)
+                .and_then(|prop| prop.as_f64())
+                .filter(|weight| !weight.is_nan())
+                .ok_or_else(|| GraphError::InvalidProperty {
+                    reason: format!(
+                        "Edge ({}, {}) has no numeric weight property {weight_prop}",
+                        edge.src().id(),
+                        edge.dst().id()
+                    ),
+                })?;
+            Ok((weight, edge.src().id(), edge.dst().id(), edge))
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    edges.sort_by(|(w1, src1, dst1, _), (w2, src2, dst2, _)| {
+        w1.total_cmp(w2)
+            .then_with(|| src1.cmp(src2))
+            .then_with(|| dst1.cmp(dst2))
+    });
+
+    let mut components = UnionFind::new(g.unfiltered_num_nodes());
+    Ok(edges
+        .into_iter()
+        .filter(|(_, _, _, edge)| {
+            components.union(edge.src().node.index(), edge.dst().node.index())
+        })
+        .map(|(_, _, _, edge)| edge)
+        .collect())
+}
+
+#[cfg(test)]
+mod minimum_spanning_tree_test {
+    use super::*;
+    use crate::test_storage;
+
+    fn edge_ids<G: StaticGraphViewOps>(edges: &[EdgeView<G>]) -> Vec<(GID, GID)> {
+        edges
+            .iter()
+            .map(|edge| (edge.src().id(), edge.dst().id()))
+            .collect()
+    }
+
+    fn ids(edges: &[(u64, u64)]) -> Vec<(GID, GID)> {
+        edges
+            .iter()
+            .map(|&(src, dst)| (GID::U64(src), GID::U64(dst)))
+            .collect()
+    }
+
+    #[test]
+    fn weighted_cycle() {
+        let graph = Graph::new();
+        for (src, dst, weight) in [(1, 2, 1.0), (2, 3, 4.0), (3, 4, 2.0), (4, 1, 3.0)] {
+            graph
+                .add_edge(0, src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+        // the weight of the latest update in the view is used
+        graph.add_edge(10, 2, 3, [("weight", 0.5)], None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let tree = minimum_spanning_tree(&graph.window(0, 10), "weight").unwrap();
+            assert_eq!(edge_ids(&tree), ids(&[(1, 2), (3, 4), (4, 1)]));
+
+            let tree = minimum_spanning_tree(graph, "weight").unwrap();
+            assert_eq!(edge_ids(&tree), ids(&[(2, 3), (1, 2), (3, 4)]));
+        });
+    }
+
+    #[test]
+    fn disconnected_graph() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (2, 3), (3, 1), (4, 5), (5, 6), (6, 4), (6, 6)] {
+            graph
+                .add_edge(0, src, dst, [("weight", 1i64)], None)
+                .unwrap();
+        }
+        // reciprocal edge with the same weight, only one of them is used
+        graph.add_edge(0, 2, 1, [("weight", 1i64)], None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let tree = minimum_spanning_tree(graph, "weight").unwrap();
+            // ties are broken by the ids of the endpoints
+            assert_eq!(edge_ids(&tree), ids(&[(1, 2), (2, 3), (4, 5), (5, 6)]));
+        });
+    }
+
+    #[test]
+    fn missing_weight() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, [("weight", 1.0)], None).unwrap();
+        graph.add_edge(0, 2, 3, NO_PROPS, None).unwrap();
+        assert!(matches!(
+            minimum_spanning_tree(&graph, "weight"),
+            Err(GraphError::InvalidProperty { .. })
+        ));
+    }
+}
@@ -1,3 +1,8 @@
+pub mod astar;
 pub mod dijkstra;
+pub mod k_shortest_paths;
+pub mod max_flow;
+pub mod minimum_spanning_tree;
 pub mod single_source_shortest_path;
 pub mod temporal_reachability;
+pub mod temporal_shortest_paths;
@@ -21,7 +21,11 @@ use itertools::Itertools;
 use num_traits::Zero;
 use raphtory_api::core::entities::VID;
 use rustc_hash::FxHashMap;
-use std::{collections::HashMap, ops::Add};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    ops::Add,
+};
 
 #[derive(Eq, Hash, PartialEq, Clone, Debug, Default)]
 pub struct TaintMessage {
@@ -204,6 +208,131 @@ pub fn temporally_reachable_nodes<G: StaticGraphViewOps, T: AsNodeRef>(
     NodeState::new_from_map(g.clone(), result, |v| v)
 }
 
+/// Earliest-arrival witness paths computed by [temporally_reachable_paths]
+///
+/// Only the predecessor hop of every reached node is stored, the paths are reconstructed on
+/// demand by following the predecessors back to a seed node.
+pub struct TemporalPaths<G> {
+    graph: G,
+    arrival: Vec<Option<i64>>,
+    predecessor: Vec<Option<(VID, i64)>>,
+}
+
+impl<G: StaticGraphViewOps> TemporalPaths<G> {
+    /// The earliest time at which the node was reached, `None` if it was not reached
+    pub fn arrival_time<V: AsNodeRef>(&self, node: V) -> Option<i64> {
+        let node = self.graph.node(node)?;
+        self.arrival[node.node.index()]
+    }
+
+    /// The earliest-arrival path from a seed node to `node` as a list of `(src, dst, time)` hops,
+    /// empty for the seed nodes and `None` if the node was not reached
+    pub fn path<V: AsNodeRef>(&self, node: V) -> Option<Vec<(GID, GID, i64)>> {
+        let node = self.graph.node(node)?;
+        self.arrival[node.node.index()]?;
+        let mut hops = vec![];
+        let mut current = node.node;
+        while let Some((src, time)) = self.predecessor[current.index()] {
+            hops.push((self.graph.node_id(src), self.graph.node_id(current), time));
+            current = src;
+        }
+        hops.reverse();
+        Some(hops)
+    }
+
+    /// Iterate over the reached nodes and their paths, the paths are reconstructed lazily
+    pub fn paths(&self) -> impl Iterator<Item = (GID, Vec<(GID, GID, i64)>)> + '_ {
+        self.graph
+            .nodes()
+            .into_iter()
+            .filter_map(move |node| Some((node.id(), self.path(node.node)?)))
+    }
+
+    /// The number of reached nodes, including the seed nodes
+    pub fn len(&self) -> usize {
+        self.arrival.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Temporal Reachability with witness paths starts from a set of seed nodes at `start_time` and
+/// follows edge updates with non-decreasing timestamps, like [temporally_reachable_nodes]. For
+/// every reached node it records how the node was reached earliest, so the sequence of
+/// timestamped edges establishing the reachability can be reported.
+///
+/// Nodes are settled in order of their arrival time, ties are broken by the number of hops. Stop
+/// nodes are reached but not propagated from, and paths with `max_hops` hops are not extended.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph, this can be a windowed view
+/// - `max_hops` - The maximum number of hops of a path
+/// - `start_time` - The time at which the seed nodes are tainted
+/// - `seed_nodes` - The nodes to start from, nodes that are not in the graph are ignored
+/// - `stop_nodes` - Nodes that do not propagate the taint
+///
+/// # Returns
+///
+/// The [TemporalPaths] from which the earliest arrival time and witness path of each reached
+/// node can be retrieved
+///
+pub fn temporally_reachable_paths<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    max_hops: usize,
+    start_time: i64,
+    seed_nodes: Vec<T>,
+    stop_nodes: Option<Vec<T>>,
+) -> TemporalPaths<G> {
+    let mut arrival = vec![None; g.unfiltered_num_nodes()];
+    let mut predecessor = vec![None; g.unfiltered_num_nodes()];
+    let mut settled = vec![false; g.unfiltered_num_nodes()];
+    let stop_nodes = stop_nodes
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|n| g.node(n))
+        .map(|n| n.node)
+        .collect_vec();
+
+    let mut heap = BinaryHeap::new();
+    for node in seed_nodes.into_iter().filter_map(|n| g.node(n)) {
+        arrival[node.node.index()] = Some(start_time);
+        heap.push(Reverse((start_time, 0usize, node.node)));
+    }
+
+    while let Some(Reverse((time, hops, vid))) = heap.pop() {
+        if settled[vid.index()] {
+            continue;
+        }
+        settled[vid.index()] = true;
+        if hops == max_hops || stop_nodes.contains(&vid) {
+            continue;
+        }
+        for edge in g.node(vid).into_iter().flat_map(|node| node.out_edges()) {
+            let dst = edge.dst().node;
+            if settled[dst.index()] {
+                continue;
+            }
+            let Some(t) = edge.history().into_iter().filter(|&t| t >= time).min() else {
+                continue;
+            };
+            if arrival[dst.index()].map_or(true, |current| t < current) {
+                arrival[dst.index()] = Some(t);
+                predecessor[dst.index()] = Some((vid, t));
+                heap.push(Reverse((t, hops + 1, dst)));
+            }
+        }
+    }
+
+    TemporalPaths {
+        graph: g.clone(),
+        arrival,
+        predecessor,
+    }
+}
+
 #[cfg(test)]
 mod generic_taint_tests {
     use super::*;
@@ -414,4 +543,54 @@ mod generic_taint_tests {
             assert_eq!(results, expected);
         });
     }
+
+    #[test]
+    fn test_witness_paths() {
+        let graph = load_graph(vec![
+            (10, 1, 3),
+            (11, 1, 2),
+            (12, 2, 4),
+            (13, 2, 5),
+            (14, 5, 5),
+            (14, 5, 4),
+            (5, 4, 6),
+            (15, 4, 7),
+            (10, 4, 7),
+            (10, 5, 8),
+        ]);
+
+        test_storage!(&graph, |graph| {
+            let start_time = 11;
+            let paths = temporally_reachable_paths(graph, 20, start_time, vec![2], None);
+            assert_eq!(paths.len(), 4);
+            assert_eq!(paths.path(2), Some(vec![]));
+            assert_eq!(paths.arrival_time(4), Some(12));
+            assert_eq!(
+                paths.path(7),
+                Some(vec![
+                    (GID::U64(2), GID::U64(4), 12),
+                    (GID::U64(4), GID::U64(7), 15)
+                ])
+            );
+            // the edges out of 5 and into 6 happen before the taint arrives
+            assert_eq!(paths.path(8), None);
+            assert_eq!(paths.path(6), None);
+
+            for (node, path) in paths.paths() {
+                let mut previous = start_time;
+                let mut current = GID::U64(2);
+                for (src, dst, t) in &path {
+                    assert_eq!(src, &current);
+                    assert!(*t >= previous);
+                    previous = *t;
+                    current = dst.clone();
+                }
+                assert_eq!(current, node);
+                assert_eq!(paths.arrival_time(node), Some(previous));
+            }
+
+            let paths = temporally_reachable_paths(graph, 1, start_time, vec![2], None);
+            assert_eq!(paths.path(7), None);
+        });
+    }
 }
@@ -0,0 +1,480 @@
+/// Time-respecting shortest paths
+use crate::{
+    core::entities::nodes::node_ref::{AsNodeRef, NodeRef},
+    db::{
+        api::{
+            state::{Index, NodeState},
+            view::StaticGraphViewOps,
+        },
+        graph::edge::EdgeView,
+    },
+    errors::GraphError,
+    prelude::*,
+};
+use indexmap::IndexSet;
+use raphtory_api::core::entities::{GID, VID};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+/// A state in the temporal Dijkstra algorithm, `label` points at the path label in the arena.
+#[derive(PartialEq)]
+struct State {
+    cost: f64,
+    arrival_time: i64,
+    node: VID,
+    label: usize,
+}
+
+impl Eq for State {}
+
+impl Ord for State {
+    fn cmp(&self, other: &State) -> Ordering {
+        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for State {
+    // the cheapest state comes first, ties are broken by the earliest arrival
+    fn partial_cmp(&self, other: &State) -> Option<Ordering> {
+        match other.cost.partial_cmp(&self.cost)? {
+            Ordering::Equal => Some(other.arrival_time.cmp(&self.arrival_time)),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// Finds the cheapest time-respecting path from a single source to every node in a graph.
+///
+/// A path is time-respecting if every edge update it uses has a timestamp greater than or equal
+/// to the timestamp of the previous edge update in the path. Every update of an edge is
+/// considered separately (see `explode`), following the out-edges of each node.
+///
+/// A cheap path that arrives late can be useless for continuing onwards, so a node can be
+/// settled several times: once for every arrival time that is earlier than all cheaper arrivals
+/// found so far.
+///
+/// # Arguments
+///
+/// * `g`: The graph to search in.
+/// * `source`: The source node.
+/// * `weight_fn`: Option, computes the non-negative cost of an edge update. If not set then
+///   every edge update has cost 1, i.e., the result is the path with the fewest hops.
+///
+/// # Returns
+///
+/// Returns a `NodeState` with the total cost and the ids of the nodes on the cheapest path for
+/// every reachable node and `None` for every unreachable node. The source is reached with
+/// cost 0 and a path only containing itself.
+///
+/// # Errors
+///
+/// Returns [GraphError::NodeMissingError] if the source node is not in the graph.
+///
+pub fn temporal_shortest_paths<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    source: T,
+    weight_fn: Option<&dyn Fn(&EdgeView<G>) -> f64>,
+) -> Result<NodeState<'static, Option<(f64, Vec<GID>)>, G>, GraphError> {
+    let source_ref = source.as_node_ref();
+    let source_node = match g.node(source_ref) {
+        Some(src) => src,
+        None => {
+            let gid = match source_ref {
+                NodeRef::Internal(vid) => g.node_id(vid),
+                NodeRef::External(gid) => gid.to_owned(),
+            };
+            return Err(GraphError::NodeMissingError(gid));
+        }
+    };
+
+    // every label is a (node, predecessor label) pair so the paths share their prefixes
+    let mut labels: Vec<(VID, Option<usize>)> = vec![(source_node.node, None)];
+    let mut earliest_arrival: HashMap<VID, i64> = HashMap::new();
+    let mut best: HashMap<VID, (f64, usize)> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    heap.push(State {
+        cost: 0.0,
+        arrival_time: i64::MIN,
+        node: source_node.node,
+        label: 0,
+    });
+
+    while let Some(State {
+        cost,
+        arrival_time,
+        node,
+        label,
+    }) = heap.pop()
+    {
+        // costs only grow, so a later arrival than a settled one can never be better
+        match earliest_arrival.get(&node) {
+            Some(&earliest) if earliest <= arrival_time => continue,
+            _ => {
+                earliest_arrival.insert(node, arrival_time);
+            }
+        }
+        best.entry(node).or_insert((cost, label));
+
+        for edge in g.node(node).unwrap().out_edges().explode() {
+            let Ok(time) = edge.time() else {
+                continue;
+            };
+            if time < arrival_time {
+                continue;
+            }
+            let next_node = edge.nbr().node;
+            if earliest_arrival
+                .get(&next_node)
+                .is_some_and(|&earliest| earliest <= time)
+            {
+                continue;
+            }
+            let weight = weight_fn.map_or(1.0, |weight_fn| weight_fn(&edge));
+            labels.push((next_node, Some(label)));
+            heap.push(State {
+                cost: cost + weight,
+                arrival_time: time,
+                node: next_node,
+                label: labels.len() - 1,
+            });
+        }
+    }
+
+    let mut values = vec![None; g.unfiltered_num_nodes()];
+    for (node, (cost, label)) in best {
+        let mut path = vec![];
+        let mut current = Some(label);
+        while let Some(label) = current {
+            let (vid, predecessor) = labels[label];
+            path.push(g.node_id(vid));
+            current = predecessor;
+        }
+        path.reverse();
+        values[node.index()] = Some((cost, path));
+    }
+    Ok(NodeState::new_from_eval(g.clone(), values))
+}
+
+/// Finds the earliest-arrival time-respecting paths from a single source.
+///
+/// Starting at `source` at `start_time`, the search only follows edge updates (see `explode`)
+/// with a timestamp greater than or equal to the arrival time at the current node, following the
+/// out-edges of each node. The arrival time at a node is the timestamp of the last edge update on
+/// the path and the search minimises the arrival time at every node. Only the edge updates of the
+/// current view are used, so windows and layers are honoured.
+///
+/// # Arguments
+///
+/// * `g`: The graph to search in.
+/// * `source`: The source node.
+/// * `start_time`: The time the search starts at the source.
+/// * `targets`: Option, the nodes to find paths to. If not set then paths to all nodes are found.
+///   Targets that are not in the graph are ignored.
+///
+/// # Returns
+///
+/// Returns a `NodeState` with the arrival time and the hops of the earliest-arrival path for
+/// every reachable target. Every hop is an exploded edge together with the time it is traversed.
+/// Targets that are not reachable from the source are absent from the result. The source is
+/// reached at `start_time` with no hops.
+///
+/// # Errors
+///
+/// Returns [GraphError::NodeMissingError] if the source node is not in the graph.
+///
+pub fn temporal_shortest_path<G: StaticGraphViewOps, T: AsNodeRef>(
+    g: &G,
+    source: T,
+    start_time: i64,
+    targets: Option<&[NodeRef]>,
+) -> Result<NodeState<'static, (i64, Vec<(EdgeView<G>, i64)>), G>, GraphError> {
+    let source_ref = source.as_node_ref();
+    let source_node = match g.node(source_ref) {
+        Some(src) => src,
+        None => {
+            let gid = match source_ref {
+                NodeRef::Internal(vid) => g.node_id(vid),
+                NodeRef::External(gid) => gid.to_owned(),
+            };
+            return Err(GraphError::NodeMissingError(gid));
+        }
+    };
+    let mut remaining_targets: Option<HashSet<VID>> = targets.map(|targets| {
+        targets
+            .iter()
+            .filter_map(|target| g.node(*target))
+            .map(|node| node.node)
+            .collect()
+    });
+
+    let mut arrival: HashMap<VID, i64> = HashMap::from([(source_node.node, start_time)]);
+    let mut predecessor: HashMap<VID, (VID, EdgeView<G>, i64)> = HashMap::new();
+    let mut settled: Vec<VID> = vec![];
+    // ties on the arrival time are broken by the node id so the search order is deterministic
+    let mut heap = BinaryHeap::from([Reverse((start_time, source_node.node))]);
+
+    while let Some(Reverse((time, node))) = heap.pop() {
+        if arrival.get(&node).is_some_and(|&earliest| earliest < time) {
+            continue;
+        }
+        settled.push(node);
+        if let Some(remaining) = remaining_targets.as_mut() {
+            remaining.remove(&node);
+            if remaining.is_empty() {
+                break;
+            }
+        }
+
+        for edge in g.node(node).unwrap().out_edges().explode() {
+            let Ok(edge_time) = edge.time() else {
+                continue;
+            };
+            if edge_time < time {
+                continue;
+            }
+            let next_node = edge.nbr().node;
+            if arrival
+                .get(&next_node)
+                .is_none_or(|&earliest| edge_time < earliest)
+            {
+                arrival.insert(next_node, edge_time);
+                predecessor.insert(next_node, (node, edge, edge_time));
+                heap.push(Reverse((edge_time, next_node)));
+            }
+        }
+    }
+
+    let is_target = |node: &VID| {
+        targets.is_none_or(|targets| {
+            targets
+                .iter()
+                .any(|target| g.node(*target).is_some_and(|t| t.node == *node))
+        })
+    };
+    let (index, values): (IndexSet<_, ahash::RandomState>, Vec<_>) = settled
+        .into_iter()
+        .filter(is_target)
+        .map(|node| {
+            let mut hops = vec![];
+            let mut current = node;
+            while let Some((previous, edge, edge_time)) = predecessor.get(&current) {
+                hops.push((edge.clone(), *edge_time));
+                current = *previous;
+            }
+            hops.reverse();
+            (node, (arrival[&node], hops))
+        })
+        .unzip();
+    Ok(NodeState::new(
+        g.clone(),
+        g.clone(),
+        values.into(),
+        Some(Index::new(index)),
+    ))
+}
+
+#[cfg(test)]
+mod temporal_shortest_paths_tests {
+    use super::*;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        test_storage,
+    };
+
+    fn load_graph(edges: Vec<(i64, &str, &str, f64)>) -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst, weight) in edges {
+            graph
+                .add_edge(t, src, dst, [("weight", weight)], None)
+                .unwrap();
+        }
+        graph
+    }
+
+    fn path<G: StaticGraphViewOps>(
+        results: &NodeState<'static, Option<(f64, Vec<GID>)>, G>,
+        node: &str,
+    ) -> Vec<String> {
+        results
+            .get_by_node(node)
+            .unwrap()
+            .as_ref()
+            .unwrap()
+            .1
+            .iter()
+            .map(|id| id.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_backward_time_travel_is_excluded() {
+        // C -> D happens before B -> C, so D is only reachable through the direct edge
+        let graph = load_graph(vec![
+            (1, "A", "B", 1.0),
+            (3, "B", "C", 1.0),
+            (2, "C", "D", 1.0),
+            (5, "A", "D", 10.0),
+            (0, "D", "E", 1.0),
+        ]);
+
+        test_storage!(&graph, |graph| {
+            let results = temporal_shortest_paths(graph, "A", None).unwrap();
+            assert_eq!(results.get_by_node("C").unwrap().as_ref().unwrap().0, 2.0);
+            assert_eq!(results.get_by_node("D").unwrap().as_ref().unwrap().0, 1.0);
+            assert_eq!(path(&results, "D"), vec!["A", "D"]);
+            // E is only reachable at t=0, before A can reach D
+            assert!(results.get_by_node("E").unwrap().is_none());
+            assert_eq!(
+                results.get_by_node("A").unwrap(),
+                &Some((0.0, vec![GID::Str("A".to_string())]))
+            );
+        });
+    }
+
+    #[test]
+    fn test_weighted_paths() {
+        let graph = load_graph(vec![
+            (1, "A", "B", 1.0),
+            (2, "B", "D", 1.0),
+            (1, "A", "C", 5.0),
+            (3, "C", "D", 1.0),
+            (4, "B", "C", 1.0),
+            (5, "D", "E", 1.0),
+        ]);
+        let weight = |edge: &EdgeView<Graph>| {
+            edge.properties()
+                .get("weight")
+                .and_then(|weight| weight.as_f64())
+                .unwrap()
+        };
+
+        let results = temporal_shortest_paths(&graph, "A", Some(&weight)).unwrap();
+        assert_eq!(results.get_by_node("D").unwrap().as_ref().unwrap().0, 2.0);
+        assert_eq!(path(&results, "D"), vec!["A", "B", "D"]);
+        assert_eq!(results.get_by_node("C").unwrap().as_ref().unwrap().0, 2.0);
+        assert_eq!(path(&results, "C"), vec!["A", "B", "C"]);
+        assert_eq!(path(&results, "E"), vec!["A", "B", "D", "E"]);
+    }
+
+    #[test]
+    fn test_cheap_late_path_does_not_block_early_path() {
+        // the cheapest way into B arrives too late to continue to C
+        let graph = load_graph(vec![
+            (1, "A", "B", 5.0),
+            (4, "A", "B", 1.0),
+            (2, "B", "C", 1.0),
+        ]);
+        let weight = |edge: &EdgeView<Graph>| {
+            edge.properties()
+                .get("weight")
+                .and_then(|weight| weight.as_f64())
+                .unwrap()
+        };
+
+        let results = temporal_shortest_paths(&graph, "A", Some(&weight)).unwrap();
+        assert_eq!(results.get_by_node("B").unwrap().as_ref().unwrap().0, 1.0);
+        assert_eq!(results.get_by_node("C").unwrap().as_ref().unwrap().0, 6.0);
+        assert_eq!(path(&results, "C"), vec!["A", "B", "C"]);
+    }
+
+    #[test]
+    fn test_missing_source() {
+        let graph = load_graph(vec![(1, "A", "B", 1.0)]);
+        assert!(matches!(
+            temporal_shortest_paths(&graph, "Z", None),
+            Err(GraphError::NodeMissingError(_))
+        ));
+    }
+
+    fn hops<G: StaticGraphViewOps>(
+        results: &NodeState<'static, (i64, Vec<(EdgeView<G>, i64)>), G>,
+        node: &str,
+    ) -> Vec<(String, String, i64)> {
+        results
+            .get_by_node(node)
+            .unwrap()
+            .1
+            .iter()
+            .map(|(edge, time)| (edge.src().name(), edge.dst().name(), *time))
+            .collect()
+    }
+
+    fn earliest_arrival_graph() -> Graph {
+        // A -> B -> D is the statically shortest path but B -> D happens before A -> B
+        load_graph(vec![
+            (5, "A", "B", 1.0),
+            (2, "B", "D", 1.0),
+            (1, "A", "C", 1.0),
+            (3, "C", "E", 1.0),
+            (4, "E", "D", 1.0),
+            (10, "A", "D", 1.0),
+        ])
+    }
+
+    #[test]
+    fn test_earliest_arrival_prefers_time_ordered_path() {
+        let graph = earliest_arrival_graph();
+
+        test_storage!(&graph, |graph| {
+            let results = temporal_shortest_path(graph, "A", 0, None).unwrap();
+            assert_eq!(results.get_by_node("D").unwrap().0, 4);
+            assert_eq!(
+                hops(&results, "D"),
+                vec![
+                    ("A".to_string(), "C".to_string(), 1),
+                    ("C".to_string(), "E".to_string(), 3),
+                    ("E".to_string(), "D".to_string(), 4),
+                ]
+            );
+            assert_eq!(results.get_by_node("B").unwrap().0, 5);
+            assert_eq!(results.get_by_node("A").unwrap().0, 0);
+            assert!(hops(&results, "A").is_empty());
+        });
+    }
+
+    #[test]
+    fn test_earliest_arrival_start_time() {
+        let graph = earliest_arrival_graph();
+
+        test_storage!(&graph, |graph| {
+            // A -> C is in the past, so D can only be reached through the direct edge
+            let results = temporal_shortest_path(graph, "A", 2, None).unwrap();
+            assert_eq!(results.get_by_node("D").unwrap().0, 10);
+            assert_eq!(
+                hops(&results, "D"),
+                vec![("A".to_string(), "D".to_string(), 10)]
+            );
+            assert!(results.get_by_node("C").is_none());
+            assert!(results.get_by_node("E").is_none());
+        });
+    }
+
+    #[test]
+    fn test_earliest_arrival_honours_window() {
+        let graph = earliest_arrival_graph();
+
+        test_storage!(&graph, |graph| {
+            let results = temporal_shortest_path(&graph.window(0, 4), "A", 0, None).unwrap();
+            assert!(results.get_by_node("D").is_none());
+            assert_eq!(results.get_by_node("E").unwrap().0, 3);
+        });
+    }
+
+    #[test]
+    fn test_earliest_arrival_targets() {
+        let graph = earliest_arrival_graph();
+
+        test_storage!(&graph, |graph| {
+            let targets = ["D".as_node_ref(), "Z".as_node_ref()];
+            let results = temporal_shortest_path(graph, "A", 0, Some(&targets)).unwrap();
+            assert_eq!(results.len(), 1);
+            assert_eq!(results.get_by_node("D").unwrap().0, 4);
+            assert!(matches!(
+                temporal_shortest_path(graph, "Z", 0, None),
+                Err(GraphError::NodeMissingError(_))
+            ));
+        });
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    db::{
+        api::{mutation::AdditionOps, view::*},
+        graph::graph::Graph,
+    },
+    errors::GraphError,
+    prelude::*,
+};
+use raphtory_api::core::entities::GID;
+use std::collections::BTreeMap;
+
+/// Projects a bipartite graph onto one of its node types. Let G be a graph with node types A and B.
+/// The projection of G onto type A connects two type A nodes if they have at least one common
+/// type B neighbour in G, e.g., projecting a movie-actor graph onto the actors gives the co-actor
+/// network.
+///
+/// Edge directions are ignored and the projection is undirected, i.e., every pair of connected
+/// nodes has a single edge from the node with the smaller id to the node with the larger id. The
+/// number of common neighbours is stored in the `"common_neighbors"` property of the edge. Nodes
+/// of any other type are ignored. The projection is static, all nodes and edges are added at time 0.
+///
+/// # Arguments
+/// - `graph`: A raphtory graph, this can be any view of the graph
+/// - `project_onto_type`: The node type of the nodes in the projection
+/// - `via_type`: The node type of the common neighbours
+///
+/// # Returns
+/// The projected graph containing all nodes of type `project_onto_type`, including the ones
+/// without any common neighbours.
+pub fn bipartite_projection<G: StaticGraphViewOps>(
+    graph: &G,
+    project_onto_type: &str,
+    via_type: &str,
+) -> Result<Graph, GraphError> {
+    let new_graph = Graph::new();
+    let mut common_neighbours: BTreeMap<(GID, GID), i64> = BTreeMap::new();
+    for node in graph.nodes() {
+        match node.node_type() {
+            Some(node_type) if node_type == project_onto_type => {
+                new_graph.add_node(0, node.id(), NO_PROPS, Some(project_onto_type))?;
+            }
+            Some(node_type) if node_type == via_type => {
+                let mut neighbours: Vec<GID> = node
+                    .neighbours()
+                    .iter()
+                    .filter(|n| n.node_type().is_some_and(|t| t == project_onto_type))
+                    .map(|n| n.id())
+                    .collect();
+                neighbours.sort();
+                for (i, src) in neighbours.iter().enumerate() {
+                    for dst in &neighbours[i + 1..] {
+                        *common_neighbours
+                            .entry((src.clone(), dst.clone()))
+                            .or_default() += 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    for ((src, dst), count) in common_neighbours {
+        new_graph.add_edge(0, src, dst, [("common_neighbors", Prop::I64(count))], None)?;
+    }
+    Ok(new_graph)
+}
+
+#[cfg(test)]
+mod bipartite_projection_test {
+    use super::bipartite_projection;
+    use crate::{prelude::*, test_storage};
+
+    #[test]
+    fn co_actor_network() {
+        let g = Graph::new();
+        let cast = [
+            ("Heat", vec!["Pacino", "De Niro", "Kilmer"]),
+            ("The Irishman", vec!["Pacino", "De Niro", "Pesci"]),
+            ("Goodfellas", vec!["De Niro", "Pesci", "Liotta"]),
+            ("Batman Forever", vec!["Kilmer"]),
+        ];
+        for (movie, actors) in cast {
+            g.add_node(0, movie, NO_PROPS, Some("movie")).unwrap();
+            for actor in actors {
+                g.add_node(0, actor, NO_PROPS, Some("actor")).unwrap();
+                g.add_edge(1, actor, movie, NO_PROPS, None).unwrap();
+            }
+        }
+        g.add_node(0, "Newcomer", NO_PROPS, Some("actor")).unwrap();
+
+        test_storage!(&g, |g| {
+            let actors = bipartite_projection(g, "actor", "movie").unwrap();
+            assert_eq!(actors.count_nodes(), 6);
+            assert_eq!(actors.node("Newcomer").unwrap().degree(), 0);
+            assert!(actors
+                .nodes()
+                .iter()
+                .all(|node| node.node_type().is_some_and(|t| t == "actor")));
+
+            let mut edges: Vec<_> = actors
+                .edges()
+                .iter()
+                .map(|e| {
+                    let count = e.properties().get("common_neighbors").unwrap_i64();
+                    (e.src().name(), e.dst().name(), count)
+                })
+                .collect();
+            edges.sort();
+            let expected = [
+                ("De Niro", "Kilmer", 1),
+                ("De Niro", "Liotta", 1),
+                ("De Niro", "Pacino", 2),
+                ("De Niro", "Pesci", 2),
+                ("Kilmer", "Pacino", 1),
+                ("Liotta", "Pesci", 1),
+                ("Pacino", "Pesci", 1),
+            ]
+            .map(|(src, dst, count)| (src.to_string(), dst.to_string(), count));
+            assert_eq!(edges, expected);
+
+            let movies = bipartite_projection(g, "movie", "actor").unwrap();
+            assert_eq!(movies.count_nodes(), 4);
+            assert_eq!(
+                movies
+                    .edge("Goodfellas", "The Irishman")
+                    .unwrap()
+                    .properties()
+                    .get("common_neighbors"),
+                Some(Prop::I64(2))
+            );
+            assert!(!movies.has_edge("Batman Forever", "Goodfellas"));
+        });
+    }
+}
@@ -1 +1,2 @@
+pub mod bipartite_projection;
 pub mod temporal_bipartite_projection;
@@ -0,0 +1,3 @@
+mod neighbourhood;
+
+pub use neighbourhood::{sample_neighbourhoods, SampledSubgraph};
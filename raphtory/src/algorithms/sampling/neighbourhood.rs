@@ -0,0 +1,207 @@
+//! Batched k-hop neighbourhood sampling
+//!
+//! [sample_neighbourhoods] samples the computation graph of a batch of seed nodes in the format
+//! expected by GNN libraries such as PyTorch Geometric, i.e., a node list starting with the seed
+//! nodes and a COO edge index into that list.
+use crate::{
+    core::entities::nodes::node_ref::{AsNodeRef, NodeRef},
+    db::api::view::StaticGraphViewOps,
+    errors::GraphError,
+    prelude::*,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use raphtory_api::core::entities::{GID, VID};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// The neighbourhoods sampled by [sample_neighbourhoods]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampledSubgraph {
+    /// The local ids (see `NodeView::local_id`) of the sampled nodes, starting with the seeds
+    pub nodes: Vec<u64>,
+    /// The original ids of the sampled nodes, in the same order as `nodes`
+    pub gids: Vec<GID>,
+    /// The sampled edges as `(src, dst)` positions in `nodes`, every edge points from a sampled
+    /// neighbour to the node that sampled it
+    pub edge_index: Vec<(usize, usize)>,
+}
+
+/// Samples the neighbourhood of a single seed, returning the nodes in the order in which they
+/// were reached and the sampled edges
+fn sample_tree<G: StaticGraphViewOps>(
+    g: &G,
+    seed: VID,
+    fanouts: &[usize],
+    rng_seed: u64,
+) -> (Vec<VID>, Vec<(VID, VID)>) {
+    let mut reached = vec![seed];
+    let mut visited: HashSet<VID> = HashSet::from([seed]);
+    let mut frontier = vec![seed];
+    let mut edges = vec![];
+    for &fanout in fanouts {
+        let mut next = vec![];
+        for &node in &frontier {
+            let mut neighbours: Vec<VID> = g
+                .node(node)
+                .map(|node| node.in_neighbours().iter().map(|n| n.node).collect())
+                .unwrap_or_default();
+            // the neighbours of a node are shuffled the same way in every neighbourhood it is
+            // part of, so the samples of the different seeds agree
+            let mut rng = StdRng::seed_from_u64(rng_seed.wrapping_add(node.as_u64()));
+            neighbours.shuffle(&mut rng);
+            for &neighbour in neighbours.iter().take(fanout) {
+                edges.push((neighbour, node));
+                if visited.insert(neighbour) {
+                    reached.push(neighbour);
+                    next.push(neighbour);
+                }
+            }
+        }
+        frontier = next;
+    }
+    (reached, edges)
+}
+
+/// Samples the k-hop in-neighbourhoods of a batch of seed nodes
+///
+/// Starting from the seeds, every node reached in hop `i` samples up to `fanouts[i]` of its
+/// in-neighbours uniformly at random without replacement, and the sampled neighbours that were
+/// not reached before form the frontier of the next hop. The neighbourhoods of the seeds are
+/// sampled in parallel and merged, nodes and edges that are part of several neighbourhoods are
+/// only included once.
+///
+/// The neighbours of a node are sampled the same way in every neighbourhood, so every node has at
+/// most `max(fanouts)` incoming edges in the edge index and the result only depends on `seed` and
+/// not on the number of threads.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph, this can be any view of the graph
+/// - `seeds` - The seed nodes of the batch
+/// - `fanouts` - The maximum number of neighbours sampled per node in each hop, the number of
+///   hops is `fanouts.len()`
+/// - `seed` - Seed for the random sampling
+///
+/// # Returns
+///
+/// The [SampledSubgraph] containing the sampled nodes, starting with the seeds in the given order,
+/// and the sampled edges
+///
+/// # Errors
+///
+/// Returns [GraphError::NodeMissingError] if a seed node is not in the graph.
+///
+pub fn sample_neighbourhoods<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    seeds: &[V],
+    fanouts: &[usize],
+    seed: Option<u64>,
+) -> Result<SampledSubgraph, GraphError> {
+    let seed_nodes = seeds
+        .iter()
+        .map(|node| {
+            let node_ref = node.as_node_ref();
+            g.node(node_ref).map(|node| node.node).ok_or_else(|| {
+                GraphError::NodeMissingError(match node_ref {
+                    NodeRef::Internal(vid) => g.node_id(vid),
+                    NodeRef::External(gid) => gid.to_owned(),
+                })
+            })
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    let rng_seed = match seed {
+        Some(seed) => seed,
+        None => StdRng::from_entropy().gen(),
+    };
+
+    let trees: Vec<_> = seed_nodes
+        .par_iter()
+        .map(|&node| sample_tree(g, node, fanouts, rng_seed))
+        .collect();
+
+    let mut index: HashMap<VID, usize> = HashMap::new();
+    let mut nodes = vec![];
+    for node in seed_nodes
+        .iter()
+        .chain(trees.iter().flat_map(|(reached, _)| reached))
+    {
+        index.entry(*node).or_insert_with(|| {
+            nodes.push(*node);
+            nodes.len() - 1
+        });
+    }
+    let mut seen = HashSet::new();
+    let edge_index = trees
+        .iter()
+        .flat_map(|(_, edges)| edges)
+        .map(|(src, dst)| (index[src], index[dst]))
+        .filter(|edge| seen.insert(*edge))
+        .collect();
+
+    Ok(SampledSubgraph {
+        nodes: nodes.iter().map(|node| node.as_u64()).collect(),
+        gids: nodes.iter().map(|node| g.node_id(*node)).collect(),
+        edge_index,
+    })
+}
+
+#[cfg(test)]
+mod neighbourhood_test {
+    use super::*;
+    use crate::test_storage;
+
+    /// Every node `i` has in-edges from `2i + 1` to `2i + 6`
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        for dst in 0..50u64 {
+            for src in 2 * dst + 1..=2 * dst + 6 {
+                graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn respects_fanouts() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let seeds = [0u64, 1, 2];
+            let fanouts = [4, 2];
+            let sample = sample_neighbourhoods(graph, &seeds, &fanouts, Some(17)).unwrap();
+            assert_eq!(&sample.gids[..3], &[GID::U64(0), GID::U64(1), GID::U64(2)]);
+            assert_eq!(sample.nodes.len(), sample.gids.len());
+            for (gid, local_id) in sample.gids.iter().zip(&sample.nodes) {
+                assert_eq!(graph.node(gid).unwrap().local_id(), *local_id);
+            }
+
+            let mut in_degrees = vec![0; sample.nodes.len()];
+            for &(src, dst) in &sample.edge_index {
+                assert!(src < sample.nodes.len() && dst < sample.nodes.len());
+                assert!(graph.has_edge(&sample.gids[src], &sample.gids[dst]));
+                in_degrees[dst] += 1;
+            }
+            assert!(in_degrees.iter().all(|&degree| degree <= 4));
+            // the seeds sample the full fanout of the first hop
+            assert!(in_degrees[..3].iter().all(|&degree| degree == 4));
+            // 3 seeds, at most 12 nodes in the first and 24 nodes in the second hop
+            assert!(sample.nodes.len() <= 3 + 12 + 24);
+
+            let same = sample_neighbourhoods(graph, &seeds, &fanouts, Some(17)).unwrap();
+            assert_eq!(sample, same);
+        });
+    }
+
+    #[test]
+    fn missing_seed() {
+        let graph = load_graph();
+        assert!(matches!(
+            sample_neighbourhoods(&graph, &[1000u64], &[2], None),
+            Err(GraphError::NodeMissingError(_))
+        ));
+
+        let sample = sample_neighbourhoods(&graph, &[3u64], &[], None).unwrap();
+        assert_eq!(sample.gids, vec![GID::U64(3)]);
+        assert!(sample.edge_index.is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    core::entities::nodes::node_ref::{AsNodeRef, NodeRef},
+    db::{api::view::StaticGraphViewOps, graph::node::NodeView},
+    errors::GraphError,
+    prelude::*,
+};
+use raphtory_api::core::entities::GID;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+fn find_node<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    node: V,
+) -> Result<NodeView<'static, G>, GraphError> {
+    let node_ref = node.as_node_ref();
+    g.node(node_ref).ok_or_else(|| {
+        GraphError::NodeMissingError(match node_ref {
+            NodeRef::Internal(vid) => g.node_id(vid),
+            NodeRef::External(gid) => gid.to_owned(),
+        })
+    })
+}
+
+/// Sorted internal ids of the out-neighbours of a node
+fn out_neighbours<G: StaticGraphViewOps>(node: &NodeView<'static, G>) -> Vec<usize> {
+    let mut neighbours: Vec<usize> = node
+        .out_neighbours()
+        .iter()
+        .map(|n| n.node.index())
+        .collect();
+    neighbours.sort_unstable();
+    neighbours
+}
+
+/// Jaccard index of two sorted sets, 0 if both sets are empty
+fn jaccard(a: &[usize], b: &[usize]) -> f64 {
+    let (mut i, mut j, mut common) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] < b[j] {
+            i += 1;
+        } else if a[i] > b[j] {
+            j += 1;
+        } else {
+            common += 1;
+            i += 1;
+            j += 1;
+        }
+    }
+    let union = a.len() + b.len() - common;
+    if union == 0 {
+        0f64
+    } else {
+        common as f64 / union as f64
+    }
+}
+
+/// Computes the Jaccard similarity `|N(a) ∩ N(b)| / |N(a) ∪ N(b)|` of the out-neighbour sets of
+/// two nodes
+///
+/// The similarity is 0 if both nodes have no out-neighbours.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `node_a` - The first node
+/// - `node_b` - The second node
+///
+/// # Errors
+///
+/// Returns [GraphError::NodeMissingError] if one of the nodes is not in the graph.
+///
+pub fn jaccard_similarity<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    node_a: V,
+    node_b: V,
+) -> Result<f64, GraphError> {
+    let a = find_node(g, node_a)?;
+    let b = find_node(g, node_b)?;
+    Ok(jaccard(&out_neighbours(&a), &out_neighbours(&b)))
+}
+
+/// Computes the Jaccard similarity of the out-neighbour sets of every pair of the given nodes
+///
+/// The out-neighbours of each node are only collected once and the pairs are scored in parallel.
+///
+/// # Arguments
+///
+/// - `g` - A reference to the graph
+/// - `nodes` - The nodes to compare
+///
+/// # Returns
+///
+/// The similarity of each unordered pair of distinct nodes, keyed by the ids of the nodes in the
+/// order in which they appear in `nodes`
+///
+/// # Errors
+///
+/// Returns [GraphError::NodeMissingError] if one of the nodes is not in the graph.
+///
+pub fn pairwise_jaccard<G: StaticGraphViewOps, V: AsNodeRef>(
+    g: &G,
+    nodes: &[V],
+) -> Result<HashMap<(GID, GID), f64>, GraphError> {
+    let neighbourhoods = nodes
+        .iter()
+        .map(|node| {
+            let node = find_node(g, node)?;
+            Ok((node.id(), out_neighbours(&node)))
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    Ok((0..neighbourhoods.len())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let (id_a, a) = &neighbourhoods[i];
+            neighbourhoods[i + 1..]
+                .iter()
+                .filter(move |(id_b, _)| id_a != id_b)
+                .map(move |(id_b, b)| ((id_a.clone(), id_b.clone()), jaccard(a, b)))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod jaccard_test {
+    use super::*;
+    use crate::test_storage;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        // 1 and 2 both point to 3 and 4, 5 points to 6 and 7, 8 points to 3 and 6
+        for (src, dst) in [
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (5, 6),
+            (5, 7),
+            (8, 3),
+            (8, 6),
+        ] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn identical_and_disjoint_neighbourhoods() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            assert_eq!(jaccard_similarity(graph, 1, 2).unwrap(), 1.0);
+            assert_eq!(jaccard_similarity(graph, 1, 5).unwrap(), 0.0);
+            assert_eq!(jaccard_similarity(graph, 1, 8).unwrap(), 1.0 / 3.0);
+            // nodes without out-neighbours
+            assert_eq!(jaccard_similarity(graph, 3, 4).unwrap(), 0.0);
+        });
+    }
+
+    #[test]
+    fn pairwise() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let result = pairwise_jaccard(graph, &[1u64, 2, 5, 8]).unwrap();
+            let expected: HashMap<(GID, GID), f64> = [
+                ((1, 2), 1.0),
+                ((1, 5), 0.0),
+                ((1, 8), 1.0 / 3.0),
+                ((2, 5), 0.0),
+                ((2, 8), 1.0 / 3.0),
+                ((5, 8), 1.0 / 3.0),
+            ]
+            .into_iter()
+            .map(|((a, b), value)| ((GID::U64(a), GID::U64(b)), value))
+            .collect();
+            assert_eq!(result, expected);
+        });
+    }
+
+    #[test]
+    fn missing_node() {
+        let graph = load_graph();
+        assert!(matches!(
+            jaccard_similarity(&graph, 1, 99),
+            Err(GraphError::NodeMissingError(GID::U64(99)))
+        ));
+        assert!(matches!(
+            pairwise_jaccard(&graph, &[1u64, 99]),
+            Err(GraphError::NodeMissingError(GID::U64(99)))
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+mod jaccard;
+
+pub use jaccard::{jaccard_similarity, pairwise_jaccard};
@@ -41,6 +41,16 @@ pub trait InternalTemporalPropertyViewOps {
     }
 
     fn temporal_value_at(&self, id: usize, t: i64) -> Option<Prop>;
+
+    /// The time window the values are read from as `(start, end)` with exclusive end, `None` if unbounded
+    fn temporal_window(&self) -> (Option<i64>, Option<i64>) {
+        (None, None)
+    }
+
+    /// The last value set before the start of the window, i.e., the value in force when the window starts
+    fn temporal_value_before_window(&self, _id: usize) -> Option<Prop> {
+        None
+    }
 }
 
 pub trait TemporalPropertiesRowView {
@@ -144,6 +154,16 @@ where
     fn temporal_value_at(&self, id: usize, t: i64) -> Option<Prop> {
         self.base().temporal_value_at(id, t)
     }
+
+    #[inline]
+    fn temporal_window(&self) -> (Option<i64>, Option<i64>) {
+        self.base().temporal_window()
+    }
+
+    #[inline]
+    fn temporal_value_before_window(&self, id: usize) -> Option<Prop> {
+        self.base().temporal_value_before_window(id)
+    }
 }
 
 impl<P: InheritTemporalPropertiesOps> InheritTemporalPropertyViewOps for P {}
@@ -1,4 +1,7 @@
-use crate::db::api::{properties::internal::InternalPropertiesOps, view::BoxedLIter};
+use crate::{
+    db::api::{properties::internal::InternalPropertiesOps, view::BoxedLIter},
+    errors::GraphError,
+};
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use raphtory_api::core::{
@@ -104,6 +107,107 @@ impl<P: InternalPropertiesOps> TemporalPropertyView<P> {
         self.props.temporal_value(self.id)
     }
 
+    /// The numeric values of the property, each with the time it was the latest value, and the
+    /// length of the window they are weighted over
+    ///
+    /// The value in force at the start of the window (i.e., the last value set before it) counts
+    /// from the start of the window. A value is in force until the next update or the end of the
+    /// window. For unbounded views, the window spans the updates of the property, i.e., the last
+    /// value is in force for zero time.
+    fn time_weighted_values(&self) -> Result<(Vec<(f64, i64)>, i64), GraphError> {
+        let dtype = self.dtype();
+        if !dtype.is_numeric() {
+            return Err(GraphError::NonNumericProperty(dtype));
+        }
+        let (start, end) = self.props.temporal_window();
+        let initial = start.and_then(|start| {
+            let value = self.props.temporal_value_before_window(self.id)?;
+            Some((start, value.as_f64()?))
+        });
+        let updates: Vec<_> = initial
+            .into_iter()
+            .chain(self.iter().filter_map(|(t, v)| Some((t, v.as_f64()?))))
+            .collect();
+        let start = start.or_else(|| updates.first().map(|(t, _)| *t));
+        let end = end.or_else(|| updates.last().map(|(t, _)| *t));
+        let span = match (start, end) {
+            (Some(start), Some(end)) => (end - start).max(0),
+            _ => 0,
+        };
+        let next_times = updates.iter().skip(1).map(|(t, _)| Some(*t)).chain([end]);
+        let values = updates
+            .iter()
+            .zip(next_times)
+            .map(|((t, v), next)| (*v, next.map_or(0, |next| next.max(*t) - t)))
+            .collect();
+        Ok((values, span))
+    }
+
+    /// The integral of the property over the window of the view
+    ///
+    /// The property is treated as a step function, i.e., every value is multiplied by the time
+    /// until the next update or the end of the window. The value in force at the start of the
+    /// window counts from the start of the window.
+    ///
+    /// Returns:
+    ///
+    /// The integral or an error if the property is not numeric
+    pub fn integrate(&self) -> Result<f64, GraphError> {
+        let (values, _) = self.time_weighted_values()?;
+        Ok(values.into_iter().map(|(v, d)| v * d as f64).sum())
+    }
+
+    /// The time-weighted average of the property over the window of the view
+    ///
+    /// This is the [integral](Self::integrate) divided by the length of the window. For views
+    /// that are unbounded, the window spans from the first to the last update of the property.
+    ///
+    /// Returns:
+    ///
+    /// The average, `None` if the window is empty, or an error if the property is not numeric
+    pub fn weighted_average(&self) -> Result<Option<f64>, GraphError> {
+        let (values, span) = self.time_weighted_values()?;
+        if span == 0 {
+            return Ok(None);
+        }
+        let integral: f64 = values.into_iter().map(|(v, d)| v * d as f64).sum();
+        Ok(Some(integral / span as f64))
+    }
+
+    /// The smallest value that was in force for a positive amount of time within the window of
+    /// the view
+    ///
+    /// Returns:
+    ///
+    /// The minimum, `None` if no value was in force for a positive amount of time, or an error
+    /// if the property is not numeric
+    pub fn weighted_min(&self) -> Result<Option<f64>, GraphError> {
+        Ok(self
+            .time_weighted_values()?
+            .0
+            .into_iter()
+            .filter(|(_, duration)| *duration > 0)
+            .map(|(v, _)| v)
+            .min_by(|a, b| a.total_cmp(b)))
+    }
+
+    /// The largest value that was in force for a positive amount of time within the window of
+    /// the view
+    ///
+    /// Returns:
+    ///
+    /// The maximum, `None` if no value was in force for a positive amount of time, or an error
+    /// if the property is not numeric
+    pub fn weighted_max(&self) -> Result<Option<f64>, GraphError> {
+        Ok(self
+            .time_weighted_values()?
+            .0
+            .into_iter()
+            .filter(|(_, duration)| *duration > 0)
+            .map(|(v, _)| v)
+            .max_by(|a, b| a.total_cmp(b)))
+    }
+
     pub fn unique(&self) -> Vec<Prop> {
         let unique_props: HashSet<_> = self.values().collect();
         unique_props.into_iter().collect()
@@ -304,3 +408,166 @@ impl<P: InternalPropertiesOps> PropArrayUnwrap for TemporalPropertyView<P> {
         self.latest().into_array()
     }
 }
+
+#[cfg(test)]
+mod temporal_props_test {
+    use crate::{errors::GraphError, prelude::*, test_storage};
+
+    #[test]
+    fn time_weighted_aggregates() {
+        let graph = Graph::new();
+        graph
+            .add_node(0, 1, [("balance", Prop::I64(10))], None)
+            .unwrap();
+        graph
+            .add_node(90, 1, [("balance", Prop::I64(100))], None)
+            .unwrap();
+        graph
+            .add_node(200, 1, [("balance", Prop::I64(1))], None)
+            .unwrap();
+        graph
+            .add_edge(0, 1, 2, [("weight", Prop::F64(2.0))], None)
+            .unwrap();
+        graph
+            .add_edge(50, 1, 2, [("weight", Prop::F64(4.0))], None)
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            let node = graph.window(0, 100).node(1).unwrap();
+            let balance = node.properties().temporal().get("balance").unwrap();
+            // 10 for 90% of the window and 100 for the remaining 10%
+            assert_eq!(balance.weighted_average().unwrap(), Some(19.0));
+            assert_eq!(balance.integrate().unwrap(), 1900.0);
+            assert_eq!(balance.weighted_min().unwrap(), Some(10.0));
+            assert_eq!(balance.weighted_max().unwrap(), Some(100.0));
+
+            let weight = graph
+                .window(0, 200)
+                .edge(1, 2)
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("weight")
+                .unwrap();
+            assert_eq!(weight.weighted_average().unwrap(), Some(3.5));
+
+            // without a window the last value is in force for zero time
+            let balance = graph
+                .node(1)
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("balance")
+                .unwrap();
+            assert_eq!(balance.integrate().unwrap(), 10.0 * 90.0 + 100.0 * 110.0);
+            assert_eq!(balance.weighted_min().unwrap(), Some(10.0));
+        });
+    }
+
+    #[test]
+    fn time_weighted_aggregates_value_before_window() {
+        let graph = Graph::new();
+        graph
+            .add_node(0, 1, [("balance", Prop::I64(10))], None)
+            .unwrap();
+        graph
+            .add_node(14, 1, [("balance", Prop::I64(100))], None)
+            .unwrap();
+        graph
+            .add_edge(0, 1, 2, [("weight", Prop::F64(2.0))], None)
+            .unwrap();
+        graph
+            .add_edge(50, 1, 2, [("weight", Prop::F64(4.0))], None)
+            .unwrap();
+        graph.add_properties(0, [("rate", Prop::F64(1.0))]).unwrap();
+        graph
+            .add_properties(14, [("rate", Prop::F64(11.0))])
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            // the value set before the window is in force for 90% of the window
+            let window = graph.window(5, 15);
+            let balance = window
+                .node(1)
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("balance")
+                .unwrap();
+            assert_eq!(balance.weighted_average().unwrap(), Some(19.0));
+            assert_eq!(balance.integrate().unwrap(), 190.0);
+            assert_eq!(balance.weighted_min().unwrap(), Some(10.0));
+
+            let rate = window.properties().temporal().get("rate").unwrap();
+            assert_eq!(rate.weighted_average().unwrap(), Some(2.0));
+
+            let weight = graph
+                .window(10, 60)
+                .edge(1, 2)
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("weight")
+                .unwrap();
+            assert_eq!(weight.weighted_average().unwrap(), Some(2.4));
+        });
+    }
+
+    #[test]
+    fn time_weighted_aggregates_persistent() {
+        let graph = Graph::new();
+        graph
+            .add_node(-50, 1, [("balance", Prop::F64(10.0))], None)
+            .unwrap();
+        graph
+            .add_node(90, 1, [("balance", Prop::F64(100.0))], None)
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            let graph = graph.persistent_graph();
+            // the value in force at the start of the window counts from the start of the window
+            let balance = graph
+                .window(0, 100)
+                .node(1)
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("balance")
+                .unwrap();
+            assert_eq!(balance.weighted_average().unwrap(), Some(19.0));
+            assert_eq!(balance.integrate().unwrap(), 1900.0);
+
+            let balance = graph
+                .window(0, 50)
+                .node(1)
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("balance")
+                .unwrap();
+            assert_eq!(balance.weighted_average().unwrap(), Some(10.0));
+            assert_eq!(balance.weighted_max().unwrap(), Some(10.0));
+        });
+    }
+
+    #[test]
+    fn time_weighted_aggregates_errors() {
+        let graph = Graph::new();
+        graph
+            .add_node(0, 1, [("name", Prop::str("a"))], None)
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            let node = graph.window(0, 10).node(1).unwrap();
+            let name = node.properties().temporal().get("name").unwrap();
+            assert!(matches!(
+                name.weighted_average(),
+                Err(GraphError::NonNumericProperty(_))
+            ));
+            assert!(matches!(
+                name.integrate(),
+                Err(GraphError::NonNumericProperty(_))
+            ));
+        });
+    }
+}
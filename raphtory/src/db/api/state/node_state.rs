@@ -297,6 +297,80 @@ impl<'graph, V, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> NodeState<'gr
     }
 }
 
+impl<
+        'graph,
+        V: Clone + Send + Sync + 'graph,
+        G: GraphViewOps<'graph>,
+        GH: GraphViewOps<'graph>,
+    > NodeState<'graph, V, G, GH>
+{
+    /// Apply `map` to every value, keeping the nodes and their order
+    pub fn map<U: Send + Sync, F: Fn(V) -> U + Sync>(&self, map: F) -> NodeState<'graph, U, G, GH> {
+        let values: Vec<_> = self.values.par_iter().map(|v| map(v.clone())).collect();
+        NodeState::new(
+            self.base_graph.clone(),
+            self.graph.clone(),
+            values.into(),
+            self.keys.clone(),
+        )
+    }
+
+    /// Pair every value with the value of the same node in `other`
+    ///
+    /// The values of `other` are looked up by node, so the states do not need to have the same
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the states do not contain the same nodes, e.g., because they were computed on
+    /// different views of the graph.
+    pub fn zip<S: NodeStateOps<'graph>>(
+        &self,
+        other: S,
+    ) -> NodeState<'graph, (V, S::OwnedValue), G, GH> {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Cannot zip node states over different nodes, they contain {} and {} nodes",
+            self.len(),
+            other.len()
+        );
+        let values: Vec<_> = self
+            .par_iter()
+            .map(|(node, value)| {
+                let other_value = other.get_by_node(node.node).unwrap_or_else(|| {
+                    panic!(
+                        "Cannot zip node states over different nodes, node {} is missing from the other state",
+                        node.id()
+                    )
+                });
+                (value.clone(), other_value.borrow().clone())
+            })
+            .collect();
+        NodeState::new(
+            self.base_graph.clone(),
+            self.graph.clone(),
+            values.into(),
+            self.keys.clone(),
+        )
+    }
+
+    /// Keep only the nodes whose value satisfies `predicate`, preserving their order
+    pub fn filter<F: Fn(&V) -> bool + Sync>(&self, predicate: F) -> Self {
+        let (keys, values): (IndexSet<_, ahash::RandomState>, Vec<_>) = self
+            .par_iter()
+            .filter(|(_, value)| predicate(value))
+            .map(|(node, value)| (node.node, value.clone()))
+            .unzip();
+        NodeState::new(
+            self.base_graph.clone(),
+            self.graph.clone(),
+            values.into(),
+            Some(Index::new(keys)),
+        )
+    }
+}
+
 impl<
         'graph,
         V: Send + Sync + Clone + 'graph,
@@ -477,7 +551,9 @@ impl<
 #[cfg(test)]
 mod test {
     use crate::{
-        db::api::state::{node_state::NodeState, AsOrderedNodeStateOps, OrderedNodeStateOps},
+        db::api::state::{
+            node_state::NodeState, AsOrderedNodeStateOps, NodeStateOps, OrderedNodeStateOps,
+        },
         prelude::*,
     };
 
@@ -505,4 +581,128 @@ mod test {
         assert_eq!(min_float, &0.0);
         assert_eq!(min_int, &1);
     }
+
+    fn state<V: Send + Sync>(g: &Graph, values: Vec<V>) -> NodeState<'static, V, Graph> {
+        NodeState::new_from_values(g.clone(), values)
+    }
+
+    #[test]
+    fn histogram_uniform() {
+        let g = Graph::new();
+        for node in 0..1000 {
+            g.add_node(0, node, NO_PROPS, None).unwrap();
+        }
+        let float_state = state(&g, (0..1000).map(|v| v as f64 / 10.0).collect());
+        let histogram = float_state.histogram(10);
+        assert_eq!(histogram.len(), 10);
+        assert_eq!(histogram[0].0, 0.0);
+        assert!((histogram[9].0 - 89.91).abs() < 1e-9);
+        assert_eq!(
+            histogram.iter().map(|(_, count)| count).sum::<usize>(),
+            1000
+        );
+        for (_, count) in histogram {
+            assert!((99..=101).contains(&count), "{count}");
+        }
+
+        let int_state = state(&g, (0..1000i64).map(|v| v % 100).collect());
+        let histogram = int_state.histogram(4);
+        assert_eq!(
+            histogram,
+            vec![(0.0, 250), (24.75, 250), (49.5, 250), (74.25, 250)]
+        );
+    }
+
+    #[test]
+    fn histogram_edge_cases() {
+        let g = Graph::new();
+        for node in 0..3 {
+            g.add_node(0, node, NO_PROPS, None).unwrap();
+        }
+        let equal = state(&g, vec![2i64, 2, 2]);
+        assert_eq!(equal.histogram(5), vec![(2.0, 3)]);
+        assert!(equal.histogram(0).is_empty());
+
+        let with_nan = state(&g, vec![0.0, f64::NAN, 1.0]);
+        assert_eq!(with_nan.histogram(2), vec![(0.0, 1), (0.5, 1)]);
+    }
+
+    #[test]
+    fn top_k_degree() {
+        let g = Graph::new();
+        for (src, dst) in [(1, 2), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4)] {
+            g.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        let degree = g.nodes().degree();
+
+        let top = degree.top_k(2);
+        assert_eq!(
+            top.iter()
+                .map(|(node, degree)| (node.id(), *degree))
+                .collect::<Vec<_>>(),
+            vec![(GID::U64(1), 4), (GID::U64(2), 3)]
+        );
+        let bottom = degree.bottom_k(1);
+        assert_eq!(
+            bottom.nodes().id().iter_values().collect::<Vec<_>>(),
+            vec![GID::U64(5)]
+        );
+
+        for k in [5, 10, usize::MAX] {
+            let top = degree.top_k(k);
+            assert_eq!(
+                top.iter_values().copied().collect::<Vec<_>>(),
+                [4, 3, 2, 2, 1]
+            );
+        }
+        assert!(degree.top_k(0).iter_values().next().is_none());
+    }
+
+    #[test]
+    fn map_zip_filter() {
+        let g = Graph::new();
+        for (t, src, dst) in [(0, 1, 2), (0, 1, 3), (1, 2, 3), (2, 3, 4)] {
+            g.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        let window = g.window(0, 2);
+        let degree = window.nodes().degree().compute();
+
+        let doubled = degree.map(|d| 2 * d);
+        assert_eq!(
+            doubled
+                .iter()
+                .map(|(node, value)| (node.id(), *value))
+                .collect::<Vec<_>>(),
+            degree
+                .iter()
+                .map(|(node, value)| (node.id(), 2 * value))
+                .collect::<Vec<_>>()
+        );
+
+        let ratio = degree
+            .zip(window.nodes().out_degree())
+            .map(|(d, out)| out as f64 / d as f64);
+        assert_eq!(ratio.get_by_node(1), Some(&1.0));
+        assert_eq!(ratio.get_by_node(3), Some(&0.0));
+        assert_eq!(ratio.len(), 3);
+
+        let outgoing = ratio.filter(|r| *r > 0.0);
+        assert_eq!(
+            outgoing.nodes().id().iter_values().collect::<Vec<_>>(),
+            vec![GID::U64(1), GID::U64(2)]
+        );
+        assert_eq!(outgoing.get_by_node(2), Some(&0.5));
+        assert_eq!(degree.filter(|d| *d == 1).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot zip node states over different nodes")]
+    fn zip_different_views() {
+        let g = Graph::new();
+        for (t, src, dst) in [(0, 1, 2), (0, 1, 3), (1, 2, 3), (2, 3, 4)] {
+            g.add_edge(t, src, dst, NO_PROPS, None).unwrap();
+        }
+        let degree = g.nodes().degree().compute();
+        degree.zip(g.window(0, 2).nodes().degree());
+    }
 }
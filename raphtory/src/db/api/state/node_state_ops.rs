@@ -240,4 +240,43 @@ pub trait NodeStateOps<'graph>:
         let sum: f64 = self.sum::<Self::OwnedValue>().as_();
         sum / (self.len() as f64)
     }
+
+    /// Partition the values into `buckets` equal-width bins between the minimum and maximum value
+    ///
+    /// Returns the lower bound of each bin together with the number of values in the bin, the
+    /// maximum value is counted in the last bin. If all values are equal, a single bin with all
+    /// values is returned. NaN values are ignored and no bins are returned if there are no values
+    /// or `buckets` is 0.
+    fn histogram(&self, buckets: usize) -> Vec<(f64, usize)>
+    where
+        Self::OwnedValue: AsPrimitive<f64>,
+    {
+        let values: Vec<f64> = self
+            .par_iter_values()
+            .map(|v| (*v.borrow()).as_())
+            .filter(|v: &f64| !v.is_nan())
+            .collect();
+        if buckets == 0 || values.is_empty() {
+            return vec![];
+        }
+        let (min, max) = values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(*v), max.max(*v))
+            });
+        if min == max {
+            return vec![(min, values.len())];
+        }
+        let width = (max - min) / buckets as f64;
+        let mut counts = vec![0; buckets];
+        for v in values {
+            let bucket = (((v - min) / width) as usize).min(buckets - 1);
+            counts[bucket] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (min + i as f64 * width, count))
+            .collect()
+    }
 }
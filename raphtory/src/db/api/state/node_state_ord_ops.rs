@@ -287,7 +287,10 @@ pub fn top_k<V: Send + Sync, F>(iter: impl IntoIterator<Item = V>, cmp: F, k: us
 where
     F: Fn(&V, &V) -> Ordering + Send + Sync,
 {
-    let mut heap: BinaryHeap<Reverse<Ordered<V, &F>>> = BinaryHeap::with_capacity(k);
+    let iter = iter.into_iter();
+    // `k` may be much larger than the number of values
+    let mut heap: BinaryHeap<Reverse<Ordered<V, &F>>> =
+        BinaryHeap::with_capacity(k.min(iter.size_hint().0));
 
     for v in iter {
         let elem = Reverse(Ordered {
@@ -322,10 +325,12 @@ pub fn par_top_k<V: Send + Sync, F>(
 where
     F: Fn(&V, &V) -> Ordering + Send + Sync,
 {
+    let iter = iter.into_par_iter();
+    let capacity = iter.opt_len().map_or(k, |len| k.min(len));
     let heap: RwLock<BinaryHeap<Reverse<Ordered<V, &F>>>> =
-        RwLock::new(BinaryHeap::with_capacity(k));
+        RwLock::new(BinaryHeap::with_capacity(capacity));
 
-    iter.into_par_iter().for_each(|v| {
+    iter.for_each(|v| {
         let elem = Reverse(Ordered {
             value: v,
             cmp_fn: &cmp,
@@ -93,7 +93,12 @@ impl<'graph, G: GraphViewOps<'graph>> NodeOp for Degree<G> {
     fn apply(&self, storage: &GraphStorage, node: VID) -> usize {
         let node = storage.core_node(node);
         if matches!(self.graph.filter_state(), FilterState::Neither) {
-            node.degree(self.graph.layer_ids(), self.dir)
+            let dir = if self.graph.edges_reversed() {
+                self.dir.reverse()
+            } else {
+                self.dir
+            };
+            node.degree(self.graph.layer_ids(), dir)
         } else {
             node.filtered_neighbours_iter(&self.graph, self.graph.layer_ids(), self.dir)
                 .count()
@@ -0,0 +1,128 @@
+use crate::db::api::view::{GraphViewOps, TimeOps};
+use raphtory_storage::core_ops::CoreGraphOps;
+use sprs::{CsMat, TriMat};
+
+/// Export the edges of a graph view as a sparse matrix
+///
+/// Rows and columns are indexed by the local node ids (`node.node.index()`) of the underlying
+/// graph, i.e., the matrices are square with one row for every node in the underlying graph. The
+/// rows and columns of nodes that are not in the view are empty.
+pub trait AdjacencyMatrixOps<'graph> {
+    /// The adjacency matrix of the graph at `time`
+    ///
+    /// The entry in row `i` and column `j` is 1.0 if there is an edge from node `i` to node `j`
+    /// in any layer and not stored otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time of the snapshot, see [TimeOps::snapshot_at], `None` for the latest
+    ///   snapshot
+    fn to_adjacency_matrix(&self, time: Option<i64>) -> CsMat<f64>;
+
+    /// The transition matrix of a random walk along the out-edges of the graph at `time`
+    ///
+    /// This is the adjacency matrix with every row divided by its sum, i.e., the out-degree of the
+    /// node. Rows of nodes without out-edges are empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time of the snapshot, see [TimeOps::snapshot_at], `None` for the latest
+    ///   snapshot
+    fn to_transition_matrix(&self, time: Option<i64>) -> CsMat<f64>;
+}
+
+/// The local ids of the source and destination of every edge in the view
+fn edge_indices<'graph, G: GraphViewOps<'graph>>(graph: &G) -> Vec<(usize, usize)> {
+    graph
+        .edges()
+        .iter()
+        .map(|edge| (edge.edge.src().index(), edge.edge.dst().index()))
+        .collect()
+}
+
+impl<'graph, G> AdjacencyMatrixOps<'graph> for G
+where
+    G: GraphViewOps<'graph> + TimeOps<'graph, WindowedViewType: GraphViewOps<'graph>>,
+{
+    fn to_adjacency_matrix(&self, time: Option<i64>) -> CsMat<f64> {
+        let edges = match time {
+            Some(time) => edge_indices(&self.snapshot_at(time)),
+            None => edge_indices(&self.snapshot_latest()),
+        };
+        let n = self.unfiltered_num_nodes();
+        let mut matrix = TriMat::with_capacity((n, n), edges.len());
+        for (src, dst) in edges {
+            matrix.add_triplet(src, dst, 1.0);
+        }
+        matrix.to_csr()
+    }
+
+    fn to_transition_matrix(&self, time: Option<i64>) -> CsMat<f64> {
+        let edges = match time {
+            Some(time) => edge_indices(&self.snapshot_at(time)),
+            None => edge_indices(&self.snapshot_latest()),
+        };
+        let n = self.unfiltered_num_nodes();
+        let mut out_degree = vec![0usize; n];
+        for (src, _) in edges.iter() {
+            out_degree[*src] += 1;
+        }
+        let mut matrix = TriMat::with_capacity((n, n), edges.len());
+        for (src, dst) in edges {
+            matrix.add_triplet(src, dst, 1.0 / out_degree[src] as f64);
+        }
+        matrix.to_csr()
+    }
+}
+
+#[cfg(test)]
+mod adjacency_matrix_test {
+    use super::AdjacencyMatrixOps;
+    use crate::{prelude::*, test_storage};
+
+    #[test]
+    fn triangle() {
+        let graph = Graph::new();
+        graph.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        graph.add_edge(2, "b", "c", NO_PROPS, None).unwrap();
+        graph
+            .add_edge(3, "c", "a", NO_PROPS, Some("other"))
+            .unwrap();
+        graph.add_edge(4, "a", "c", NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let index = |name: &str| graph.node(name).unwrap().node.index();
+            let (a, b, c) = (index("a"), index("b"), index("c"));
+
+            let matrix = graph.to_adjacency_matrix(None);
+            assert_eq!(matrix.shape(), (3, 3));
+            assert!(matrix.is_csr());
+            assert_eq!(matrix.nnz(), 4);
+            for (src, dst) in [(a, b), (b, c), (c, a), (a, c)] {
+                assert_eq!(matrix.get(src, dst), Some(&1.0));
+            }
+            assert_eq!(matrix.get(b, a), None);
+            assert_eq!(matrix.get(a, a), None);
+
+            // the snapshot at time 2 only has the first two edges
+            let matrix = graph.to_adjacency_matrix(Some(2));
+            assert_eq!(matrix.shape(), (3, 3));
+            assert_eq!(matrix.nnz(), 2);
+            assert_eq!(matrix.get(a, b), Some(&1.0));
+            assert_eq!(matrix.get(b, c), Some(&1.0));
+
+            let transition = graph.to_transition_matrix(None);
+            assert_eq!(transition.nnz(), 4);
+            assert_eq!(transition.get(a, b), Some(&0.5));
+            assert_eq!(transition.get(a, c), Some(&0.5));
+            assert_eq!(transition.get(b, c), Some(&1.0));
+            assert_eq!(transition.get(c, a), Some(&1.0));
+
+            // nodes outside of the view keep their rows and columns
+            let matrix = graph.layers("other").unwrap().to_adjacency_matrix(None);
+            assert_eq!(matrix.shape(), (3, 3));
+            assert_eq!(matrix.nnz(), 1);
+            assert_eq!(matrix.get(c, a), Some(&1.0));
+        });
+    }
+}
@@ -168,6 +168,61 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_filter_latest_value_per_window() {
+        let g = Graph::new();
+        for (t, src, dst, amount) in [
+            (1, 1, 2, 500i64),
+            (2, 1, 2, 1500),
+            (3, 2, 3, 2000),
+            (5, 2, 3, 100),
+            (6, 3, 4, 1200),
+            (8, 1, 2, 50),
+        ] {
+            g.add_edge(t, src, dst, [("amount", amount)], None).unwrap();
+        }
+
+        let large = || PropertyFilter::property("amount").gt(1000i64);
+        // the filter is evaluated against the latest value in each window
+        for ((start, end), expected) in [
+            ((0, 3), 1),
+            ((3, 6), 0),
+            ((6, 9), 1),
+            ((2, 5), 2),
+            ((0, 9), 1),
+        ] {
+            let filtered = g.window(start, end).filter_edges(large()).unwrap();
+            assert_eq!(filtered.count_edges(), expected, "window {start}..{end}");
+        }
+        for window in g.rolling(3, None).unwrap() {
+            let expected = window
+                .edges()
+                .iter()
+                .filter(|e| e.properties().get("amount").unwrap_i64() > 1000)
+                .count();
+            assert_eq!(
+                window.filter_edges(large()).unwrap().count_edges(),
+                expected
+            );
+        }
+
+        let small = PropertyFilter::property("amount").lt(100i64);
+        let filtered = g.window(6, 9).filter_edges(large().or(small)).unwrap();
+        assert_eq!(
+            filtered.edges().id().sorted().collect_vec(),
+            vec![(GID::U64(1), GID::U64(2)), (GID::U64(3), GID::U64(4))]
+        );
+
+        // comparing with a value of a different type or using contains on a number fails when
+        // the filter is created
+        assert!(g
+            .filter_edges(PropertyFilter::property("amount").gt("1000"))
+            .is_err());
+        assert!(g
+            .filter_edges(PropertyFilter::property("amount").contains("1"))
+            .is_err());
+    }
+
     #[test]
     fn test_filter_gt() {
         proptest!(|(
@@ -2,7 +2,11 @@
 use crate::search::{fallback_filter_edges, fallback_filter_nodes};
 use crate::{
     core::{
-        entities::{graph::tgraph::TemporalGraph, nodes::node_ref::AsNodeRef, LayerIds, VID},
+        entities::{
+            graph::tgraph::TemporalGraph,
+            nodes::node_ref::{AsNodeRef, NodeRef},
+            LayerIds, VID,
+        },
         storage::timeindex::AsTime,
     },
     db::{
@@ -20,9 +24,12 @@ use crate::{
                 cached_view::CachedView,
                 filter::{
                     model::{AsEdgeFilter, AsNodeFilter},
+                    node_predicate_filtered_graph::NodePredicateFilteredGraph,
                     node_type_filtered_graph::NodeTypeFilteredGraph,
                 },
                 node_subgraph::NodeSubgraph,
+                reversed_graph::ReversedGraph,
+                set_operation_graph::{SetOperation, SetOperationGraph},
                 valid_graph::ValidGraph,
             },
         },
@@ -50,7 +57,10 @@ use raphtory_storage::{
 };
 use rayon::prelude::*;
 use rustc_hash::FxHashSet;
-use std::sync::{atomic::Ordering, Arc};
+use std::{
+    iter,
+    sync::{atomic::Ordering, Arc},
+};
 
 /// This trait GraphViewOps defines operations for accessing
 /// information about a graph. The trait has associated types
@@ -64,6 +74,15 @@ pub trait GraphViewOps<'graph>: BoxableGraphView + Sized + Clone + 'graph {
     /// Return a View of the nodes in the Graph
     fn nodes(&self) -> Nodes<'graph, Self, Self>;
 
+    /// Return a parallel iterator over all nodes in the graph
+    fn par_nodes(&self) -> impl ParallelIterator<Item = NodeView<'graph, Self>> + 'graph;
+
+    /// Return a parallel iterator over all edges in the graph
+    ///
+    /// The work is split over the source nodes, so every edge is visited exactly once but the
+    /// order differs from [GraphViewOps::edges].
+    fn par_edges(&self) -> impl ParallelIterator<Item = EdgeView<Self>> + 'graph;
+
     /// Get a graph clone
     ///
     /// # Arguments
@@ -72,22 +91,123 @@ pub trait GraphViewOps<'graph>: BoxableGraphView + Sized + Clone + 'graph {
     /// Graph - Returns clone of the graph
     fn materialize(&self) -> Result<MaterializedGraph, GraphError>;
 
+    /// Return a view of the graph that only includes the given nodes and the edges between them
+    ///
+    /// Edges with only one endpoint in `nodes` are excluded. The view is lazy and composes with
+    /// the other view operations, e.g., `graph.window(start, end).subgraph(nodes)` restricts the
+    /// subgraph to the events in `[start, end)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes` - The nodes to keep, nodes that don't exist in the graph are ignored
     fn subgraph<I: IntoIterator<Item = V>, V: AsNodeRef>(&self, nodes: I) -> NodeSubgraph<Self>;
 
     fn cache_view(&self) -> CachedView<Self>;
 
     fn valid(&self) -> ValidGraph<Self>;
 
+    /// Return a view that only includes the nodes with one of the given types and the edges
+    /// between them
+    ///
+    /// # Arguments
+    ///
+    /// * `nodes_types` - The node types to keep, types that don't exist in the graph are ignored
     fn subgraph_node_types<I: IntoIterator<Item = V>, V: AsRef<str>>(
         &self,
         nodes_types: I,
     ) -> NodeTypeFilteredGraph<Self>;
 
+    /// Return a view that only includes the nodes for which `predicate` holds for the value of
+    /// property `prop_name` and the edges between them
+    ///
+    /// The value of a node is its latest temporal value in the view, falling back to its metadata
+    /// value. Nodes without a value are excluded.
+    ///
+    /// # Arguments
+    ///
+    /// * `prop_name` - The name of the property
+    /// * `predicate` - The predicate on the property value
+    fn induced_subgraph_by_property<F: Fn(&Prop) -> bool + Send + Sync + 'static>(
+        &self,
+        prop_name: &str,
+        predicate: F,
+    ) -> NodePredicateFilteredGraph<Self>;
+
     fn exclude_nodes<I: IntoIterator<Item = V>, V: AsNodeRef>(
         &self,
         nodes: I,
     ) -> NodeSubgraph<Self>;
 
+    /// Return a view of the nodes within `radius` hops of `center` and the edges between them
+    ///
+    /// Hops follow the direction of the edges, i.e., the view contains the nodes reachable from
+    /// `center` by a path of at most `radius` out-edges. A `radius` of 0 only includes `center`.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The node at the center of the ego network
+    /// * `radius` - The maximum number of hops from `center`
+    ///
+    /// # Errors
+    ///
+    /// Returns [GraphError::NodeMissingError] if `center` is not in the graph.
+    fn ego_network<V: AsNodeRef>(
+        &self,
+        center: V,
+        radius: usize,
+    ) -> Result<NodeSubgraph<Self>, GraphError>;
+
+    /// Return a view of the nodes within `k` hops of `center` in direction `dir` and the edges
+    /// between them
+    ///
+    /// The view includes `center`, the other nodes are the nodes returned by
+    /// [NodeView::neighbours_within]. As for [GraphViewOps::subgraph], a node that only has edge
+    /// updates is not visible if none of its edges are, so `center` is only visible in a ball
+    /// without edges, e.g., for `k = 0`, if it has updates of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The node at the center of the subgraph
+    /// * `k` - The maximum number of hops from `center`
+    /// * `dir` - The direction of the hops, `Direction::BOTH` ignores the edge direction
+    ///
+    /// # Errors
+    ///
+    /// Returns [GraphError::NodeMissingError] if `center` is not in the graph.
+    fn ego_subgraph<V: AsNodeRef>(
+        &self,
+        center: V,
+        k: usize,
+        dir: Direction,
+    ) -> Result<NodeSubgraph<Self>, GraphError>;
+
+    /// Return a view of the nodes and edges visible in either `self` or `other`
+    ///
+    /// Both views need to be views of the same graph. An edge keeps the updates that are visible
+    /// in either view. See [SetOperationGraph] for details.
+    fn union<R: GraphViewOps<'graph>>(&self, other: R) -> SetOperationGraph<Self, R>;
+
+    /// Return a view of the nodes and edges visible in both `self` and `other`
+    ///
+    /// Both views need to be views of the same graph. An edge keeps the updates that are visible
+    /// in either view, e.g., the intersection of two disjoint windows contains the edges that are
+    /// active in both windows with their updates from both windows. See [SetOperationGraph] for
+    /// details.
+    fn intersection<R: GraphViewOps<'graph>>(&self, other: R) -> SetOperationGraph<Self, R>;
+
+    /// Return a view of the edges visible in `self` but not in `other` and the nodes of `self`
+    ///
+    /// Both views need to be views of the same graph. See [SetOperationGraph] for details.
+    fn difference<R: GraphViewOps<'graph>>(&self, other: R) -> SetOperationGraph<Self, R>;
+
+    /// Return a view of the graph where the source and destination of every edge are swapped
+    ///
+    /// The out-neighbours of a node in the reversed view are its in-neighbours in `self`, the
+    /// edge updates keep their timestamps. Reversing a reversed view returns the original view.
+    /// As time is not reversed, temporal algorithms on the view still move forward in time, see
+    /// [ReversedGraph].
+    fn reversed(&self) -> ReversedGraph<Self>;
+
     /// Return all the layer ids in the graph
     fn unique_layers(&self) -> BoxedIter<ArcStr>;
 
@@ -176,11 +296,16 @@ impl<'graph, G: GraphView + 'graph> GraphViewOps<'graph> for G {
                         (edges, layer_ids, graph),
                         move |(edges, layer_ids, graph)| {
                             let iter = edges.iter(layer_ids);
-                            if graph.filtered() {
+                            let iter = if graph.filtered() {
                                 iter.filter_map(|e| graph.filter_edge(e).then(|| e.out_ref()))
                                     .into_dyn_boxed()
                             } else {
                                 iter.map(|e| e.out_ref()).into_dyn_boxed()
+                            };
+                            if graph.edges_reversed() {
+                                iter.map(|e| e.reversed()).into_dyn_boxed()
+                            } else {
+                                iter
                             }
                         },
                     )
@@ -210,6 +335,21 @@ impl<'graph, G: GraphView + 'graph> GraphViewOps<'graph> for G {
         Nodes::new(graph)
     }
 
+    fn par_nodes(&self) -> impl ParallelIterator<Item = NodeView<'graph, Self>> + 'graph {
+        self.nodes().into_par_iter()
+    }
+
+    fn par_edges(&self) -> impl ParallelIterator<Item = EdgeView<Self>> + 'graph {
+        let cg = self.core_graph().lock();
+        let graph = self.clone();
+        self.nodes().par_iter_refs().flat_map_iter(move |node| {
+            let edges: BoxedLIter<'graph, EdgeRef> =
+                node_edges(cg.clone(), graph.clone(), node, Direction::OUT);
+            let graph = graph.clone();
+            edges.map(move |e| EdgeView::new(graph.clone(), e))
+        })
+    }
+
     fn materialize(&self) -> Result<MaterializedGraph, GraphError> {
         let storage = self.core_graph().lock();
         let mut g = TemporalGraph::default();
@@ -444,6 +584,14 @@ impl<'graph, G: GraphView + 'graph> GraphViewOps<'graph> for G {
         NodeTypeFilteredGraph::new(self.clone(), node_types_filter)
     }
 
+    fn induced_subgraph_by_property<F: Fn(&Prop) -> bool + Send + Sync + 'static>(
+        &self,
+        prop_name: &str,
+        predicate: F,
+    ) -> NodePredicateFilteredGraph<Self> {
+        NodePredicateFilteredGraph::new(self.clone(), prop_name, predicate)
+    }
+
     fn exclude_nodes<I: IntoIterator<Item = V>, V: AsNodeRef>(&self, nodes: I) -> NodeSubgraph<G> {
         let _layer_ids = self.layer_ids();
 
@@ -461,6 +609,50 @@ impl<'graph, G: GraphView + 'graph> GraphViewOps<'graph> for G {
         NodeSubgraph::new(self.clone(), nodes_to_include)
     }
 
+    fn union<R: GraphViewOps<'graph>>(&self, other: R) -> SetOperationGraph<G, R> {
+        SetOperationGraph::new(self.clone(), other, SetOperation::Union)
+    }
+
+    fn intersection<R: GraphViewOps<'graph>>(&self, other: R) -> SetOperationGraph<G, R> {
+        SetOperationGraph::new(self.clone(), other, SetOperation::Intersection)
+    }
+
+    fn difference<R: GraphViewOps<'graph>>(&self, other: R) -> SetOperationGraph<G, R> {
+        SetOperationGraph::new(self.clone(), other, SetOperation::Difference)
+    }
+
+    fn reversed(&self) -> ReversedGraph<G> {
+        ReversedGraph::new(self.clone())
+    }
+
+    fn ego_network<V: AsNodeRef>(
+        &self,
+        center: V,
+        radius: usize,
+    ) -> Result<NodeSubgraph<G>, GraphError> {
+        self.ego_subgraph(center, radius, Direction::OUT)
+    }
+
+    fn ego_subgraph<V: AsNodeRef>(
+        &self,
+        center: V,
+        k: usize,
+        dir: Direction,
+    ) -> Result<NodeSubgraph<G>, GraphError> {
+        let center = center.as_node_ref();
+        let center = (&self).node(center).ok_or_else(|| {
+            GraphError::NodeMissingError(match center {
+                NodeRef::Internal(vid) => self.node_id(vid),
+                NodeRef::External(gid) => gid.to_owned(),
+            })
+        })?;
+        let nodes = center.neighbours_within(k, dir);
+        Ok(NodeSubgraph::new(
+            self.clone(),
+            nodes.iter_refs().chain(iter::once(center.node)),
+        ))
+    }
+
     /// Return all the layer ids in the graph
     fn unique_layers(&self) -> BoxedIter<ArcStr> {
         self.get_layer_names_from_ids(self.layer_ids())
@@ -576,10 +768,16 @@ impl<'graph, G: GraphView + 'graph> GraphViewOps<'graph> for G {
 
     fn edge<T: AsNodeRef>(&self, src: T, dst: T) -> Option<EdgeView<Self, Self>> {
         let layer_ids = self.layer_ids();
-        let src = self.internalise_node(src.as_node_ref())?;
-        let dst = self.internalise_node(dst.as_node_ref())?;
+        let mut src = self.internalise_node(src.as_node_ref())?;
+        let mut dst = self.internalise_node(dst.as_node_ref())?;
+        if self.edges_reversed() {
+            std::mem::swap(&mut src, &mut dst);
+        }
         let src_node = self.core_node(src);
-        let edge_ref = src_node.find_edge(dst, layer_ids)?;
+        let mut edge_ref = src_node.find_edge(dst, layer_ids)?;
+        if self.edges_reversed() {
+            edge_ref = edge_ref.reversed();
+        }
         match self.filter_state() {
             FilterState::Neither => {}
             FilterState::Both | FilterState::BothIndependent | FilterState::Edges => {
@@ -1621,3 +1819,174 @@ mod test_materialize {
         assert_eq!(props, vec![("greeting".to_string(), "namaste".to_string())]);
     }
 }
+
+#[cfg(test)]
+mod test_par_iter {
+    use crate::{prelude::*, test_storage};
+    use rayon::prelude::*;
+
+    fn assert_same_as_sequential<'graph, G: GraphViewOps<'graph>>(g: &G) {
+        let mut par_nodes: Vec<GID> = g.par_nodes().map(|node| node.id()).collect();
+        par_nodes.sort();
+        let mut nodes: Vec<GID> = g.nodes().id().iter_values().collect();
+        nodes.sort();
+        assert_eq!(par_nodes, nodes);
+
+        let mut par_edges: Vec<_> = g
+            .par_edges()
+            .map(|edge| (edge.src().id(), edge.dst().id()))
+            .collect();
+        par_edges.sort();
+        let mut edges: Vec<_> = g
+            .edges()
+            .iter()
+            .map(|edge| (edge.src().id(), edge.dst().id()))
+            .collect();
+        edges.sort();
+        assert_eq!(par_edges, edges);
+    }
+
+    #[test]
+    fn test_par_nodes_and_edges() {
+        let graph = Graph::new();
+        for i in 0..100u64 {
+            let layer = if i % 3 == 0 { "a" } else { "b" };
+            graph
+                .add_edge(i as i64, i, (i * 7) % 100, NO_PROPS, Some(layer))
+                .unwrap();
+        }
+        graph.add_node(200, 1000, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            assert_same_as_sequential(graph);
+            assert_same_as_sequential(&graph.window(10, 50));
+            assert_same_as_sequential(&graph.layers("a").unwrap());
+            assert_same_as_sequential(&graph.subgraph((0..50u64).collect::<Vec<_>>()));
+            assert_eq!(graph.par_edges().count(), graph.count_edges());
+        });
+    }
+}
+
+#[cfg(test)]
+mod test_ego_network {
+    use crate::{errors::GraphError, prelude::*, test_storage};
+    use raphtory_api::core::Direction;
+
+    fn sorted_ids<'graph, G: GraphViewOps<'graph>>(g: &G) -> Vec<GID> {
+        let mut ids: Vec<GID> = g.nodes().id().iter_values().collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_ego_network_radius() {
+        let graph = Graph::new();
+        for (src, dst) in [(1, 2), (1, 3), (2, 4), (4, 5), (6, 1), (10, 11)] {
+            graph.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph.add_node(0, 20, NO_PROPS, None).unwrap();
+        // the center needs an update of its own to be visible in a ball without edges
+        graph.add_node(0, 1, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let ego = graph.ego_network(1, 0).unwrap();
+            assert_eq!(sorted_ids(&ego), vec![GID::U64(1)]);
+            assert_eq!(ego.count_edges(), 0);
+
+            let ego = graph.ego_network(1, 1).unwrap();
+            assert_eq!(sorted_ids(&ego), [1, 2, 3].map(GID::U64));
+            assert_eq!(ego.count_edges(), 2);
+
+            // only out-edges are followed, so 6 is never included
+            let ego = graph.ego_network(1, 100).unwrap();
+            assert_eq!(sorted_ids(&ego), [1, 2, 3, 4, 5].map(GID::U64));
+            assert_eq!(ego.count_edges(), 4);
+
+            assert_eq!(graph.ego_network(20, 100).unwrap().count_nodes(), 1);
+            assert!(matches!(
+                graph.ego_network(30, 1),
+                Err(GraphError::NodeMissingError(GID::U64(30)))
+            ));
+        });
+    }
+
+    #[test]
+    fn test_ego_subgraph_on_path() {
+        let graph = Graph::new();
+        for i in 0..9u64 {
+            let layer = if i % 2 == 0 { "even" } else { "odd" };
+            graph
+                .add_edge(i as i64, i, i + 1, NO_PROPS, Some(layer))
+                .unwrap();
+        }
+        graph.add_node(0, 5, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let ball_size = |center: u64, k: usize| {
+                graph
+                    .ego_subgraph(center, k, Direction::BOTH)
+                    .unwrap()
+                    .count_nodes()
+            };
+            // interior nodes have 2k + 1 nodes in their ball, the ball is truncated at the ends
+            assert_eq!(ball_size(5, 0), 1);
+            assert_eq!(ball_size(5, 2), 5);
+            assert_eq!(ball_size(5, 3), 7);
+            assert_eq!(ball_size(0, 2), 3);
+            assert_eq!(ball_size(9, 3), 4);
+            assert_eq!(ball_size(1, 3), 5);
+            assert_eq!(ball_size(5, 100), 10);
+
+            for node in graph.nodes() {
+                let mut neighbours = node.neighbours().id().collect::<Vec<_>>();
+                neighbours.sort();
+                assert_eq!(
+                    node.neighbours_within(1, Direction::BOTH)
+                        .id()
+                        .iter_values()
+                        .collect::<Vec<_>>(),
+                    neighbours
+                );
+                assert_eq!(
+                    node.neighbours_within(1, Direction::OUT).len(),
+                    node.out_degree()
+                );
+                assert_eq!(
+                    node.neighbours_within(1, Direction::IN).len(),
+                    node.in_degree()
+                );
+                assert!(node.neighbours_within(0, Direction::BOTH).is_empty());
+            }
+
+            let node = graph.node(5).unwrap();
+            assert_eq!(
+                node.neighbours_within(2, Direction::OUT)
+                    .id()
+                    .iter_values()
+                    .collect::<Vec<_>>(),
+                [6, 7].map(GID::U64)
+            );
+            assert_eq!(
+                sorted_ids(&graph.ego_subgraph(5, 2, Direction::IN).unwrap()),
+                [3, 4, 5].map(GID::U64)
+            );
+
+            // the hops respect windows and layers
+            let windowed = graph.window(0, 5).ego_subgraph(5, 3, Direction::BOTH);
+            assert_eq!(sorted_ids(&windowed.unwrap()), [2, 3, 4, 5].map(GID::U64));
+            let layered = graph.layers("even").unwrap();
+            assert_eq!(
+                sorted_ids(&layered.ego_subgraph(5, 3, Direction::BOTH).unwrap()),
+                [4, 5].map(GID::U64)
+            );
+            assert_eq!(
+                layered
+                    .node(5)
+                    .unwrap()
+                    .neighbours_within(3, Direction::BOTH)
+                    .len(),
+                1
+            );
+        });
+    }
+}
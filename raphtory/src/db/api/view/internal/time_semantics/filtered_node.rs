@@ -258,7 +258,11 @@ pub trait FilteredNodeStorageOps<'a>: NodeStorageOps<'a> {
         layer_ids: &'a LayerIds,
         dir: Direction,
     ) -> impl Iterator<Item = EdgeRef> + 'a {
-        let iter = self.edges_iter(layer_ids, dir);
+        let reversed = view.edges_reversed();
+        let dir = if reversed { dir.reverse() } else { dir };
+        let iter = self
+            .edges_iter(layer_ids, dir)
+            .map(move |e| if reversed { e.reversed() } else { e });
         match view.filter_state() {
             FilterState::Neither => FilterVariants::Neither(iter),
             FilterState::Both => FilterVariants::Both(iter.filter(move |e| {
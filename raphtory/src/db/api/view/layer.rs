@@ -27,6 +27,12 @@ pub trait LayerOps<'graph> {
         layers: L,
     ) -> Result<Self::LayeredViewType, GraphError>;
 
+    /// Return a graph containing all layers except `name`. Errors if the layer does not exist.
+    fn exclude_layer<L: SingleLayer>(&self, name: L) -> Result<Self::LayeredViewType, GraphError> {
+        self.exclude_layers(name)
+    }
+
+    /// Return a graph containing all layers except the layers in `names`. Any layers that do not exist are ignored.
     fn exclude_valid_layers<L: Into<Layer>>(&self, layers: L) -> Self::LayeredViewType;
 
     /// Check if `name` is a valid layer name
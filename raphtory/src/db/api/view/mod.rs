@@ -1,5 +1,7 @@
 //! Defines the `ViewApi` trait, which represents the API for querying a view of the graph.
 
+#[cfg(feature = "sprs")]
+mod adjacency_matrix;
 mod edge;
 mod edge_property_filter;
 mod exploded_edge_property_filter;
@@ -12,6 +14,8 @@ mod reset_filter;
 pub(crate) mod time;
 
 pub(crate) use edge::BaseEdgeViewOps;
+#[cfg(feature = "sprs")]
+pub use adjacency_matrix::AdjacencyMatrixOps;
 pub use edge::EdgeViewOps;
 use ouroboros::self_referencing;
 use std::marker::PhantomData;
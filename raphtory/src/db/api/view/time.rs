@@ -8,7 +8,7 @@ use crate::{
         time::internal::InternalTimeOps,
     },
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Days, FixedOffset, NaiveTime, Utc};
 use raphtory_api::GraphType;
 use raphtory_core::utils::time::{IntervalSize, ParseTimeError};
 use std::{
@@ -115,6 +115,9 @@ pub trait TimeOps<'graph>:
     fn window<T1: IntoTime, T2: IntoTime>(&self, start: T1, end: T2) -> Self::WindowedViewType;
 
     /// Create a view that only includes events at `time`
+    ///
+    /// Use [TimeOps::snapshot_at] for a view of the graph as it was at `time`, including all
+    /// earlier events
     fn at<T: IntoTime>(&self, time: T) -> Self::WindowedViewType;
 
     /// Create a view that only includes events at the latest time
@@ -122,6 +125,9 @@ pub trait TimeOps<'graph>:
 
     /// Create a view including all events that have not been explicitly deleted at `time`
     ///
+    /// Unlike a window, the view has no lower bound: every node and edge added at or before `time`
+    /// is included and everything added afterwards is hidden.
+    ///
     /// This is equivalent to `before(time + 1)` for `EventGraph`s and `at(time)` for `PersitentGraph`s
     fn snapshot_at<T: IntoTime>(&self, time: T) -> Self::WindowedViewType;
 
@@ -282,13 +288,48 @@ impl<'graph, V: OneHopFilter<'graph> + 'graph + InternalTimeOps<'graph>> TimeOps
     }
 }
 
+/// Calendar boundaries that the windows of a [WindowSet] can be aligned to
+///
+/// Boundaries are computed in UTC unless an offset is passed to
+/// [WindowSet::aligned_with_offset].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    /// Midnight of every day
+    DayStart,
+    /// Midnight of every Monday
+    WeekStart,
+    /// Midnight of the first day of every month
+    MonthStart,
+}
+
+impl Alignment {
+    /// The latest boundary at or before `time` (milliseconds since the epoch in local time)
+    fn floor(&self, time: i64) -> i64 {
+        let date = DateTime::from_timestamp_millis(time)
+            .unwrap_or_else(|| panic!("{time} cannot be interpreted as a milliseconds timestamp"))
+            .date_naive();
+        let boundary = match self {
+            Alignment::DayStart => date,
+            Alignment::WeekStart => date - Days::new(date.weekday().num_days_from_monday() as u64),
+            Alignment::MonthStart => date - Days::new(date.day0() as u64),
+        };
+        boundary
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+            .timestamp_millis()
+    }
+}
+
 #[derive(Clone)]
 pub struct WindowSet<'graph, T> {
     view: T,
+    start: i64,
     cursor: i64,
     end: i64,
     step: Interval,
     window: Option<Interval>,
+    /// offset of the local time used for calendar arithmetic from UTC in milliseconds
+    offset: i64,
     _marker: PhantomData<&'graph T>,
 }
 
@@ -315,14 +356,53 @@ impl<'graph, T: TimeOps<'graph> + Clone + 'graph> WindowSet<'graph, T> {
         let cursor_start = start + step;
         Ok(Self {
             view,
+            start,
             cursor: cursor_start,
             end,
             step,
             window,
+            offset: 0,
             _marker: PhantomData,
         })
     }
 
+    /// Align the window boundaries to calendar boundaries in UTC
+    ///
+    /// The first window ends one step after the boundary at or before the start of the timeline,
+    /// i.e., `graph.rolling(Interval::months(1), None)?.aligned(Alignment::MonthStart)` returns
+    /// one window for every calendar month. Windows without any updates are still returned.
+    /// The window set restarts from the beginning.
+    pub fn aligned(self, alignment: Alignment) -> Self {
+        self.aligned_with_offset(alignment, FixedOffset::east_opt(0).unwrap())
+    }
+
+    /// Align the window boundaries to calendar boundaries in the timezone with the given offset
+    /// from UTC
+    ///
+    /// Steps and window sizes that contain months are also applied in the local time such that
+    /// the boundaries stay aligned, see [WindowSet::aligned].
+    pub fn aligned_with_offset(mut self, alignment: Alignment, offset: FixedOffset) -> Self {
+        if self.start > self.end {
+            // nothing to align for an empty window set
+            return self;
+        }
+        self.offset = offset.local_minus_utc() as i64 * 1000;
+        let first_boundary = alignment.floor(self.start + self.offset) - self.offset;
+        self.cursor = self.advance(first_boundary);
+        self
+    }
+
+    /// `time + step` with months added in the local time
+    fn advance(&self, time: i64) -> i64 {
+        (time + self.offset + self.step) - self.offset
+    }
+
+    /// The start of the window ending at `window_end`
+    fn window_start(&self, window_end: i64) -> Option<i64> {
+        self.window
+            .map(|w| (window_end + self.offset - w) - self.offset)
+    }
+
     fn empty(view: T) -> Result<Self, ParseTimeError> {
         // timeline_start is greater than end, so no windows to return, even with end inclusive
         WindowSet::new(view, 1, 0, Default::default(), None)
@@ -369,10 +449,10 @@ impl<'graph, T: TimeOps<'graph> + Clone + 'graph> Iterator for TimeIndex<'graph,
 impl<'graph, T: TimeOps<'graph> + Clone + 'graph> Iterator for WindowSet<'graph, T> {
     type Item = T::WindowedViewType;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.cursor < self.end + self.step {
+        if self.cursor < self.advance(self.end) {
             let window_end = self.cursor;
 
-            let window_start = self.window.map(|w| window_end - w);
+            let window_start = self.window_start(window_end);
             if let Some(start) = window_start {
                 //this is required because if we have steps > window size you can end up overstepping
                 // the end by so much in the final window that there is no data inside
@@ -382,7 +462,7 @@ impl<'graph, T: TimeOps<'graph> + Clone + 'graph> Iterator for WindowSet<'graph,
                 }
             }
             let window = self.view.internal_window(window_start, Some(window_end));
-            self.cursor = self.cursor + self.step;
+            self.cursor = self.advance(self.cursor);
             Some(window)
         } else {
             None
@@ -398,15 +478,15 @@ impl<'graph, T: TimeOps<'graph> + Clone + 'graph> ExactSizeIterator for WindowSe
     fn len(&self) -> usize {
         let mut cursor = self.cursor;
         let mut count = 0;
-        while cursor < self.end + self.step {
-            let window_start = self.window.map(|w| cursor - w);
+        while cursor < self.advance(self.end) {
+            let window_start = self.window_start(cursor);
             if let Some(start) = window_start {
                 if start >= self.end {
                     break;
                 }
             }
             count += 1;
-            cursor = cursor + self.step;
+            cursor = self.advance(cursor);
         }
         count
     }
@@ -415,11 +495,11 @@ impl<'graph, T: TimeOps<'graph> + Clone + 'graph> ExactSizeIterator for WindowSe
 #[cfg(test)]
 mod time_tests {
     use crate::{
-        core::utils::time::TryIntoTime,
+        core::utils::time::{Interval, TryIntoTime},
         db::{
             api::{
                 mutation::AdditionOps,
-                view::{time::internal::InternalTimeOps, WindowSet},
+                view::{time::internal::InternalTimeOps, Alignment, WindowSet},
             },
             graph::{
                 graph::{assert_graph_equal, Graph},
@@ -429,6 +509,7 @@ mod time_tests {
         prelude::{DeletionOps, GraphViewOps, TimeOps, NO_PROPS},
         test_storage,
     };
+    use chrono::FixedOffset;
     use itertools::Itertools;
     use raphtory_core::utils::time::ParseTimeError;
 
@@ -472,6 +553,28 @@ mod time_tests {
         assert_graph_equal(&graph, &graph.snapshot_latest());
     }
 
+    #[test]
+    fn snapshot_at_hides_later_updates() {
+        let graph = Graph::new();
+        graph.add_edge(1, 0, 1, NO_PROPS, None).unwrap();
+        graph.add_edge(3, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_node(5, 3, NO_PROPS, None).unwrap();
+        test_storage!(&graph, |graph| {
+            let snapshot = graph.snapshot_at(3);
+            assert_eq!(snapshot.count_nodes(), 3);
+            assert_eq!(snapshot.count_edges(), 2);
+            assert!(!snapshot.has_node(3));
+
+            let snapshot = graph.snapshot_at(2);
+            assert_eq!(snapshot.count_nodes(), 2);
+            assert!(snapshot.has_edge(0, 1));
+            assert!(!snapshot.has_edge(1, 2));
+
+            assert_eq!(graph.count_nodes(), 4);
+            assert!(graph.has_edge(1, 2));
+        });
+    }
+
     #[test]
     fn rolling() {
         let graph = graph_with_timeline(1, 7);
@@ -668,4 +771,175 @@ mod time_tests {
             assert_bounds(windows, expected);
         });
     }
+
+    fn times(dates: &[&str]) -> Vec<Option<i64>> {
+        dates.iter().map(|d| d.try_into_time().ok()).collect()
+    }
+
+    fn bounds(dates: &[&str]) -> Vec<(Option<i64>, Option<i64>)> {
+        times(dates).into_iter().tuple_windows().collect()
+    }
+
+    #[test]
+    fn rolling_aligned_to_months() {
+        // 2024 is a leap year
+        let graph = Graph::new();
+        graph
+            .add_edge("2024-01-15 12:00:00", 0, 1, NO_PROPS, None)
+            .unwrap();
+        graph
+            .add_edge("2024-03-10 08:30:00", 1, 2, NO_PROPS, None)
+            .unwrap();
+        test_storage!(&graph, |graph| {
+            let windows = graph
+                .rolling(Interval::months(1), None)
+                .unwrap()
+                .aligned(Alignment::MonthStart);
+            assert_eq!(windows.len(), 3);
+            let february = windows.clone().nth(1).unwrap();
+            assert_eq!(
+                february.end().unwrap() - february.start().unwrap(),
+                29 * 24 * 60 * 60 * 1000
+            );
+            // the empty window for february is still returned
+            assert_eq!(
+                windows.clone().map(|w| w.count_edges()).collect_vec(),
+                [1, 0, 1]
+            );
+            assert_bounds(
+                windows,
+                bounds(&[
+                    "2024-01-01 00:00:00",
+                    "2024-02-01 00:00:00",
+                    "2024-03-01 00:00:00",
+                    "2024-04-01 00:00:00",
+                ]),
+            );
+
+            // windows larger than the step end on month boundaries as well
+            let windows = graph
+                .rolling(Interval::months(2), Some(Interval::months(1)))
+                .unwrap()
+                .aligned(Alignment::MonthStart);
+            let expected = times(&[
+                "2023-12-01 00:00:00",
+                "2024-01-01 00:00:00",
+                "2024-02-01 00:00:00",
+                "2024-03-01 00:00:00",
+                "2024-04-01 00:00:00",
+            ]);
+            assert_bounds(
+                windows,
+                vec![
+                    (expected[0], expected[2]),
+                    (expected[1], expected[3]),
+                    (expected[2], expected[4]),
+                ],
+            );
+        });
+    }
+
+    #[test]
+    fn rolling_aligned_to_weeks_and_days() {
+        let graph = Graph::new();
+        // a Wednesday
+        graph
+            .add_edge("2024-02-28 15:00:00", 0, 1, NO_PROPS, None)
+            .unwrap();
+        graph
+            .add_edge("2024-03-01 01:00:00", 1, 2, NO_PROPS, None)
+            .unwrap();
+        test_storage!(&graph, |graph| {
+            let windows = graph
+                .rolling(Interval::weeks(1), None)
+                .unwrap()
+                .aligned(Alignment::WeekStart);
+            assert_bounds(
+                windows,
+                bounds(&["2024-02-26 00:00:00", "2024-03-04 00:00:00"]),
+            );
+
+            let windows = graph
+                .rolling(Interval::days(1), None)
+                .unwrap()
+                .aligned(Alignment::DayStart);
+            assert_eq!(windows.map(|w| w.count_edges()).collect_vec(), [1, 0, 1]);
+        });
+    }
+
+    #[test]
+    fn aligned_with_offset() {
+        let graph = Graph::new();
+        graph
+            .add_edge("2024-01-31 23:30:00", 0, 1, NO_PROPS, None)
+            .unwrap();
+        graph
+            .add_edge("2024-03-15 00:00:00", 1, 2, NO_PROPS, None)
+            .unwrap();
+        test_storage!(&graph, |graph| {
+            // in UTC+01:00 the first update happens in february and the months start at 23:00 UTC
+            // of the previous day
+            let windows = graph
+                .rolling(Interval::months(1), None)
+                .unwrap()
+                .aligned_with_offset(Alignment::MonthStart, FixedOffset::east_opt(3600).unwrap());
+            assert_bounds(
+                windows,
+                bounds(&[
+                    "2024-01-31 23:00:00",
+                    "2024-02-29 23:00:00",
+                    "2024-03-31 23:00:00",
+                ]),
+            );
+
+            let windows = graph
+                .rolling(Interval::months(1), None)
+                .unwrap()
+                .aligned(Alignment::MonthStart);
+            assert_bounds(
+                windows,
+                bounds(&[
+                    "2024-01-01 00:00:00",
+                    "2024-02-01 00:00:00",
+                    "2024-03-01 00:00:00",
+                    "2024-04-01 00:00:00",
+                ]),
+            );
+        });
+    }
+
+    #[test]
+    fn expanding_aligned() {
+        let graph = Graph::new();
+        graph
+            .add_edge("2024-01-15 12:00:00", 0, 1, NO_PROPS, None)
+            .unwrap();
+        graph
+            .add_edge("2024-03-10 08:30:00", 1, 2, NO_PROPS, None)
+            .unwrap();
+        test_storage!(&graph, |graph| {
+            let windows = graph
+                .expanding(Interval::months(1))
+                .unwrap()
+                .aligned(Alignment::MonthStart);
+            let expected = times(&[
+                "2024-02-01 00:00:00",
+                "2024-03-01 00:00:00",
+                "2024-04-01 00:00:00",
+            ]);
+            assert_bounds(
+                windows.clone(),
+                expected.into_iter().map(|end| (None, end)).collect(),
+            );
+            assert_eq!(windows.map(|w| w.count_edges()).collect_vec(), [1, 1, 2]);
+        });
+
+        // aligning an empty window set keeps it empty
+        let graph = Graph::new();
+        let windows = graph
+            .rolling(Interval::days(1), None)
+            .unwrap()
+            .aligned_with_offset(Alignment::DayStart, FixedOffset::east_opt(3600).unwrap());
+        assert_eq!(windows.count(), 0);
+    }
 }
@@ -20,7 +20,10 @@ use crate::{
                 Metadata, Properties,
             },
             view::{
-                internal::{EdgeTimeSemanticsOps, OneHopFilter, Static},
+                internal::{
+                    time_semantics::filtered_edge::FilteredEdgeStorageOps, EdgeTimeSemanticsOps,
+                    OneHopFilter, Static,
+                },
                 BaseEdgeViewOps, BoxableGraphView, BoxedLIter, DynamicGraph, IntoDynBoxed,
                 IntoDynamic, StaticGraphViewOps,
             },
@@ -32,8 +35,11 @@ use crate::{
 };
 use itertools::Itertools;
 use raphtory_api::core::{
-    entities::properties::prop::PropType,
-    storage::{arc_str::ArcStr, timeindex::TimeIndexEntry},
+    entities::properties::{prop::PropType, tprop::TPropOps},
+    storage::{
+        arc_str::ArcStr,
+        timeindex::{AsTime, TimeIndexEntry},
+    },
 };
 use raphtory_core::entities::graph::tgraph::InvalidLayer;
 use raphtory_storage::{
@@ -95,6 +101,15 @@ impl<G: Clone, GH: Clone> EdgeView<&G, &GH> {
 }
 
 impl<G, GH> EdgeView<G, GH> {
+    /// Returns the local id of the edge
+    ///
+    /// Local ids are dense integers, assigned in insertion order starting from 0, and are never
+    /// reused. They are valid indices into a `Vec` of length `unfiltered_num_edges()`. All layers
+    /// and exploded updates of an edge share its local id.
+    pub fn local_id(&self) -> u64 {
+        self.edge.pid().as_u64()
+    }
+
     pub fn as_ref(&self) -> EdgeView<&G, &GH> {
         let graph = &self.graph;
         let base_graph = &self.base_graph;
@@ -632,6 +647,31 @@ impl<G: BoxableGraphView + Clone, GH: BoxableGraphView + Clone> InternalTemporal
             None
         }
     }
+
+    fn temporal_window(&self) -> (Option<i64>, Option<i64>) {
+        match self.edge.time() {
+            // an exploded edge only has the values of its update
+            Some(t) => (Some(t.t()), Some(t.t().saturating_add(1))),
+            None => (self.graph.view_start(), self.graph.view_end()),
+        }
+    }
+
+    fn temporal_value_before_window(&self, id: usize) -> Option<Prop> {
+        if self.edge.time().is_some() || !edge_valid_layer(&self.graph, self.edge) {
+            return None;
+        }
+        let start = TimeIndexEntry::start(self.graph.view_start()?);
+        let layer_ids = match self.edge.layer() {
+            None => self.graph.layer_ids().clone(),
+            Some(layer) => LayerIds::One(layer),
+        };
+        let edge = self.graph.core_edge(self.edge.pid());
+        edge.as_ref()
+            .filtered_temporal_prop_iter(id, &self.graph, &layer_ids)
+            .filter_map(|(_, prop)| prop.last_before(start))
+            .max_by(|(t1, _), (t2, _)| t1.cmp(t2))
+            .map(|(_, v)| v)
+    }
 }
 
 impl<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> InternalTemporalPropertiesOps
@@ -719,6 +759,24 @@ mod test_edge {
     use raphtory_api::core::storage::arc_str::ArcStr;
     use std::collections::HashMap;
 
+    #[test]
+    fn test_local_id() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 2, 3, NO_PROPS, Some("a")).unwrap();
+        graph.add_edge(2, 1, 2, NO_PROPS, Some("b")).unwrap();
+        graph.add_edge(3, 3, 1, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let edge = graph.edge(1, 2).unwrap();
+            assert_eq!(edge.local_id(), 0);
+            assert_eq!(graph.edge(2, 3).unwrap().local_id(), 1);
+            assert_eq!(graph.edge(3, 1).unwrap().local_id(), 2);
+            assert!(edge.explode().iter().all(|e| e.local_id() == 0));
+            assert_eq!(graph.layers("b").unwrap().edge(1, 2).unwrap().local_id(), 0);
+        });
+    }
+
     #[test]
     fn test_properties() {
         let graph = Graph::new();
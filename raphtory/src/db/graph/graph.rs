@@ -19,6 +19,8 @@ use super::views::deletion_graph::PersistentGraph;
 use crate::{
     db::{
         api::{
+            mutation::time_from_input,
+            properties::internal::InternalTemporalPropertiesOps,
             storage::storage::Storage,
             view::{
                 internal::{
@@ -28,11 +30,28 @@ use crate::{
                 time::internal::InternalTimeOps,
             },
         },
-        graph::{edges::Edges, node::NodeView, nodes::Nodes},
+        graph::{
+            edge::EdgeView, edges::Edges, node::NodeView, nodes::Nodes,
+            views::window_graph::WindowedGraph,
+        },
     },
+    errors::GraphError,
     prelude::*,
 };
-use raphtory_api::inherit::Base;
+use raphtory_api::{
+    core::storage::{
+        arc_str::OptionAsStr,
+        timeindex::{AsTime, TimeIndexEntry},
+    },
+    inherit::Base,
+};
+use raphtory_core::{
+    entities::{
+        graph::logical_to_physical::InvalidNodeId,
+        nodes::node_ref::{AsNodeRef, NodeRef},
+    },
+    utils::time::ParseTimeError,
+};
 use raphtory_storage::{
     core_ops::InheritCoreGraphOps, graph::graph::GraphStorage, layer_ops::InheritLayerOps,
     mutation::InheritMutationOps,
@@ -40,13 +59,117 @@ use raphtory_storage::{
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fmt::{Display, Formatter},
     hint::black_box,
     ops::Deref,
     sync::Arc,
 };
 
+/// The nodes and edges that changed in a time range, as computed by [Graph::diff]
+///
+/// Unlike [algorithms::diff::graph_diff::GraphDiff](crate::algorithms::diff::graph_diff::GraphDiff),
+/// which compares two arbitrary views by id, this holds views on the graph the range is taken
+/// from and does not include property changes.
+#[derive(Debug, Clone)]
+pub struct TimeRangeDiff {
+    /// Nodes that exist at the end of the range but did not exist before it
+    pub added_nodes: Vec<NodeView<'static, Graph>>,
+    /// Nodes that existed before the range but no longer exist at the end of it
+    pub removed_nodes: Vec<NodeView<'static, Graph>>,
+    /// Edges that exist at the end of the range but did not exist before it
+    pub added_edges: Vec<EdgeView<Graph>>,
+    /// Edges that existed before the range but were deleted by the end of it
+    pub removed_edges: Vec<EdgeView<Graph>>,
+}
+
+/// The number of nodes with each in-, out- and total degree, as computed by
+/// [Graph::degree_distribution]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DegreeDistribution {
+    /// Maps each in-degree to the number of nodes with that in-degree
+    pub in_degree: HashMap<usize, usize>,
+    /// Maps each out-degree to the number of nodes with that out-degree
+    pub out_degree: HashMap<usize, usize>,
+    /// Maps each total degree to the number of nodes with that degree
+    pub total_degree: HashMap<usize, usize>,
+}
+
+impl DegreeDistribution {
+    /// Estimate the exponent `alpha` of a power law `count ~ degree^-alpha` for the total degree
+    ///
+    /// Fits a least-squares line to the distribution on a log-log scale, nodes with degree 0 are
+    /// ignored. This is a quick estimate rather than a maximum-likelihood fit.
+    ///
+    /// Returns:
+    ///
+    /// The estimated exponent or NaN if there are fewer than two distinct non-zero degrees
+    pub fn power_law_exponent(&self) -> f64 {
+        let points: Vec<(f64, f64)> = self
+            .total_degree
+            .iter()
+            .filter(|(degree, count)| **degree > 0 && **count > 0)
+            .map(|(degree, count)| ((*degree as f64).ln(), (*count as f64).ln()))
+            .collect();
+        if points.len() < 2 {
+            return f64::NAN;
+        }
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let covariance: f64 = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        -covariance / variance
+    }
+}
+
+/// A single update of a graph, as replayed by [Graph::replay]
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphEvent {
+    /// Add a node with the given temporal properties
+    AddNode { id: GID, props: Vec<(String, Prop)> },
+    /// Add an edge with the given temporal properties, `layer` defaults to the default layer
+    AddEdge {
+        src: GID,
+        dst: GID,
+        props: Vec<(String, Prop)>,
+        layer: Option<String>,
+    },
+}
+
+/// The edge updates of a graph as parallel columns, as computed by [Graph::to_edge_list]
+///
+/// Row `i` of the edge list is the update at `timestamps[i]` of the edge from `src_ids[i]` to
+/// `dst_ids[i]` in layer `layers[i]`. `properties` maps every temporal property key to its
+/// column, which is `None` for updates that did not set the property.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EdgeList {
+    /// The id of the source node of every update
+    pub src_ids: Vec<u64>,
+    /// The id of the destination node of every update
+    pub dst_ids: Vec<u64>,
+    /// The time of every update
+    pub timestamps: Vec<i64>,
+    /// The layer of every update
+    pub layers: Vec<String>,
+    /// The value of every temporal property key at every update
+    pub properties: HashMap<String, Vec<Option<Prop>>>,
+}
+
+impl EdgeList {
+    /// The number of rows in the edge list
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+}
+
 #[repr(transparent)]
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Graph {
@@ -577,6 +700,426 @@ impl Graph {
     pub fn persistent_graph(&self) -> PersistentGraph {
         PersistentGraph::from_storage(self.inner.clone())
     }
+
+    /// Count the number of nodes with each in-, out- and total degree
+    ///
+    /// Returns:
+    ///
+    /// A [DegreeDistribution] mapping degrees to node counts
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// let g = Graph::new();
+    /// g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+    /// g.add_edge(1, "a", "c", NO_PROPS, None).unwrap();
+    ///
+    /// let distribution = g.degree_distribution();
+    /// assert_eq!(distribution.out_degree[&2], 1);
+    /// assert_eq!(distribution.out_degree[&0], 2);
+    /// ```
+    pub fn degree_distribution(&self) -> DegreeDistribution {
+        let mut distribution = DegreeDistribution::default();
+        for node in self.nodes() {
+            *distribution.in_degree.entry(node.in_degree()).or_default() += 1;
+            *distribution
+                .out_degree
+                .entry(node.out_degree())
+                .or_default() += 1;
+            *distribution.total_degree.entry(node.degree()).or_default() += 1;
+        }
+        distribution
+    }
+
+    /// Sample the in- and out-degree of a node over time
+    ///
+    /// The degrees are sampled every `step` from the earliest to the latest time of the graph.
+    /// The degrees at time `t` count the distinct neighbours the node is connected to by edge
+    /// updates up to and including `t`.
+    ///
+    /// Arguments:
+    ///   node: the node to sample the degrees of
+    ///   step: the interval between samples
+    ///
+    /// Returns:
+    ///
+    /// A list of `(t, in_degree, out_degree)` tuples, or an error if the node does not exist or
+    /// `step` is not positive
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// let g = Graph::new();
+    /// g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+    /// g.add_edge(2, "c", "a", NO_PROPS, None).unwrap();
+    ///
+    /// let degrees = g.temporal_degree_sequence("a", 1).unwrap();
+    /// assert_eq!(degrees, vec![(0, 0, 1), (1, 0, 1), (2, 1, 1)]);
+    /// ```
+    pub fn temporal_degree_sequence<V: AsNodeRef>(
+        &self,
+        node: V,
+        step: i64,
+    ) -> Result<Vec<(i64, usize, usize)>, GraphError> {
+        if step <= 0 {
+            let error = if step == 0 {
+                ParseTimeError::ZeroSizeStep
+            } else {
+                ParseTimeError::NegativeInt
+            };
+            return Err(error.into());
+        }
+        let node_ref = node.as_node_ref();
+        let node = self.node(node_ref).ok_or_else(|| {
+            GraphError::NodeMissingError(match node_ref {
+                // an internal id that does not resolve has no external id to report
+                NodeRef::Internal(vid) => GID::U64(vid.as_u64()),
+                NodeRef::External(gid) => gid.to_owned(),
+            })
+        })?;
+        let (Some(start), Some(end)) = (self.earliest_time(), self.latest_time()) else {
+            return Ok(vec![]);
+        };
+        Ok((start..=end)
+            .step_by(step as usize)
+            .map(|t| {
+                let node = node.before(t.saturating_add(1));
+                (t, node.in_degree(), node.out_degree())
+            })
+            .collect())
+    }
+
+    /// Build a new graph by replaying a stream of timestamped events
+    ///
+    /// Events are applied in the order of the iterator and are not sorted by time. Events
+    /// with the same timestamp are therefore ordered as they appear in the stream.
+    ///
+    /// Arguments:
+    ///   events: the `(timestamp, event)` pairs to apply
+    ///
+    /// Returns:
+    ///
+    /// The new graph or the first error raised while applying an event
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// use raphtory::db::graph::graph::GraphEvent;
+    /// let events = [
+    ///     (0, GraphEvent::AddNode { id: GID::from("a"), props: vec![] }),
+    ///     (
+    ///         1,
+    ///         GraphEvent::AddEdge {
+    ///             src: GID::from("a"),
+    ///             dst: GID::from("b"),
+    ///             props: vec![],
+    ///             layer: None,
+    ///         },
+    ///     ),
+    /// ];
+    /// let g = Graph::replay(events.into_iter()).unwrap();
+    /// assert_eq!(g.count_nodes(), 2);
+    /// assert_eq!(g.count_edges(), 1);
+    /// ```
+    pub fn replay(events: impl Iterator<Item = (i64, GraphEvent)>) -> Result<Self, GraphError> {
+        let graph = Graph::new();
+        for (t, event) in events {
+            match event {
+                GraphEvent::AddNode { id, props } => {
+                    graph.add_node(t, id, props, None)?;
+                }
+                GraphEvent::AddEdge {
+                    src,
+                    dst,
+                    props,
+                    layer,
+                } => {
+                    graph.add_edge(t, src, dst, props, layer.as_deref())?;
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Merge all nodes and edges of `other` into this graph
+    ///
+    /// Nodes with the same id are treated as the same node and their histories are combined.
+    /// Edge updates are only deduplicated if they share the same source, destination, layer
+    /// and timestamp, in which case they are combined into a single update. When both graphs
+    /// define the same property key on a node or edge, the value from `other` takes precedence.
+    ///
+    /// Arguments:
+    ///   other: the graph to merge into this one
+    ///
+    /// Returns:
+    ///
+    /// An error if a node has a different node type or a property has a different data type
+    /// in the two graphs
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// let mut g = Graph::new();
+    /// g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+    ///
+    /// let other = Graph::new();
+    /// other.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+    /// other.add_edge(1, "b", "c", NO_PROPS, None).unwrap();
+    ///
+    /// g.merge(&other).unwrap();
+    /// assert_eq!(g.count_nodes(), 3);
+    /// assert_eq!(g.count_temporal_edges(), 2);
+    /// ```
+    pub fn merge(&mut self, other: &Graph) -> Result<(), GraphError> {
+        // Add edges first to ensure associated nodes are present
+        for edge in other.edges() {
+            let src = edge.src().id();
+            let dst = edge.dst().id();
+            for layer_edge in edge.explode_layers() {
+                let layer = layer_edge.layer_name()?;
+                let mut existing: HashMap<i64, TimeIndexEntry> = self
+                    .edge(&src, &dst)
+                    .and_then(|e| e.layers(layer.deref()).ok())
+                    .map(|e| {
+                        e.explode()
+                            .iter()
+                            .filter_map(|ee| ee.time_and_index().ok())
+                            .map(|t| (t.t(), t))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                for ee in layer_edge.explode() {
+                    let time = ee.time()?;
+                    let ti = match existing.get(&time) {
+                        Some(&ti) => ti,
+                        None => {
+                            let ti = time_from_input(&*self, time)?;
+                            existing.insert(time, ti);
+                            ti
+                        }
+                    };
+                    self.add_edge(
+                        ti,
+                        &src,
+                        &dst,
+                        ee.properties().temporal().collect_properties(),
+                        Some(&layer),
+                    )?;
+                }
+
+                for (t, _) in layer_edge.deletions_hist() {
+                    self.delete_edge(t.t(), &src, &dst, Some(&layer))?;
+                }
+
+                if let Some(merged) = self.edge(&src, &dst) {
+                    merged.update_metadata(layer_edge.metadata().iter_filtered(), Some(&layer))?;
+                }
+            }
+        }
+
+        for node in other.nodes() {
+            let id = node.id();
+            let node_type = node.node_type();
+            for (t, row) in node.rows() {
+                let props = row
+                    .into_iter()
+                    .map(|(prop_id, prop)| (node.get_temporal_prop_name(prop_id), prop));
+                self.add_node(t.t(), &id, props, node_type.as_str())?;
+            }
+
+            if let Some(merged) = self.node(&id) {
+                if let Some(node_type) = node_type.as_str() {
+                    merged.set_node_type(node_type)?;
+                }
+                merged.update_metadata(node.metadata().iter_filtered())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Export every edge update of the graph as an [EdgeList]
+    ///
+    /// There is one row for every update of every edge in every layer, with the values of the
+    /// temporal properties set by that update. Nodes without edges, node properties, metadata and
+    /// edge deletions are not part of the edge list.
+    ///
+    /// Returns:
+    ///
+    /// The edge list or an error if the graph has string node ids
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// let g = Graph::new();
+    /// g.add_edge(0, 1, 2, [("weight", 1.0)], None).unwrap();
+    /// g.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+    ///
+    /// let edge_list = g.to_edge_list().unwrap();
+    /// assert_eq!(edge_list.src_ids, vec![1, 2]);
+    /// assert_eq!(edge_list.properties["weight"], vec![Some(Prop::F64(1.0)), None]);
+    /// ```
+    pub fn to_edge_list(&self) -> Result<EdgeList, GraphError> {
+        let as_u64 = |id: GID| match id {
+            GID::U64(id) => Ok(id),
+            GID::Str(id) => Err(GraphError::from(InvalidNodeId::InvalidNodeIdStr(id))),
+        };
+        let mut edge_list = EdgeList::default();
+        for edge in self.edges() {
+            let src = as_u64(edge.src().id())?;
+            let dst = as_u64(edge.dst().id())?;
+            for ee in edge.explode() {
+                let row = edge_list.len();
+                edge_list.src_ids.push(src);
+                edge_list.dst_ids.push(dst);
+                edge_list.timestamps.push(ee.time()?);
+                edge_list.layers.push(ee.layer_name()?.to_string());
+                for (key, value) in ee.properties().temporal().collect_properties() {
+                    let column = edge_list.properties.entry(key.to_string()).or_default();
+                    column.resize(row, None);
+                    column.push(Some(value));
+                }
+            }
+        }
+        let len = edge_list.len();
+        for column in edge_list.properties.values_mut() {
+            column.resize(len, None);
+        }
+        Ok(edge_list)
+    }
+
+    /// Build a new graph from the rows of an [EdgeList]
+    ///
+    /// This is the inverse of [Graph::to_edge_list], the rows are added in order.
+    ///
+    /// Arguments:
+    ///   edge_list: the edge updates to add
+    ///
+    /// Returns:
+    ///
+    /// The new graph or an error if the columns have different lengths or a property has
+    /// values of different types
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// use raphtory::db::graph::graph::EdgeList;
+    /// let edge_list = EdgeList {
+    ///     src_ids: vec![1, 2],
+    ///     dst_ids: vec![2, 3],
+    ///     timestamps: vec![0, 1],
+    ///     layers: vec!["_default".to_string(), "follows".to_string()],
+    ///     properties: Default::default(),
+    /// };
+    /// let g = Graph::from_edge_list(edge_list).unwrap();
+    /// assert_eq!(g.count_edges(), 2);
+    /// assert!(g.layers("follows").unwrap().has_edge(2, 3));
+    /// ```
+    pub fn from_edge_list(edge_list: EdgeList) -> Result<Self, GraphError> {
+        let len = edge_list.len();
+        let columns = [
+            ("src_ids", edge_list.src_ids.len()),
+            ("dst_ids", edge_list.dst_ids.len()),
+            ("layers", edge_list.layers.len()),
+        ]
+        .into_iter()
+        .chain(
+            edge_list
+                .properties
+                .iter()
+                .map(|(key, column)| (key.as_str(), column.len())),
+        );
+        for (column, actual) in columns {
+            if actual != len {
+                return Err(GraphError::EdgeListLengthMismatch {
+                    column: column.to_string(),
+                    expected: len,
+                    actual,
+                });
+            }
+        }
+
+        let graph = Graph::new();
+        for row in 0..len {
+            let props = edge_list
+                .properties
+                .iter()
+                .filter_map(|(key, column)| Some((key.as_str(), column[row].clone()?)));
+            graph.add_edge(
+                edge_list.timestamps[row],
+                edge_list.src_ids[row],
+                edge_list.dst_ids[row],
+                props,
+                Some(edge_list.layers[row].as_str()),
+            )?;
+        }
+        Ok(graph)
+    }
+
+    /// Compute the nodes and edges that were added or removed between `t_start` (inclusive)
+    /// and `t_end` (exclusive)
+    ///
+    /// The state of the graph just before `t_start` is compared with its state at the end of
+    /// the range using the semantics of [PersistentGraph], i.e., edges stay alive until they are
+    /// explicitly deleted. Nodes and edges that are both added and removed within the range
+    /// are not reported.
+    ///
+    /// Arguments:
+    ///   t_start: the start of the time range (inclusive)
+    ///   t_end: the end of the time range (exclusive)
+    ///
+    /// Returns:
+    ///
+    /// A [TimeRangeDiff] with views of the changed nodes and edges on this graph
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use raphtory::prelude::*;
+    /// let g = Graph::new();
+    /// g.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+    /// g.add_edge(5, "b", "c", NO_PROPS, None).unwrap();
+    /// g.delete_edge(6, "a", "b", None).unwrap();
+    ///
+    /// let diff = g.diff(2, 10);
+    /// assert_eq!(diff.added_edges[0].id(), (GID::from("b"), GID::from("c")));
+    /// assert_eq!(diff.removed_edges[0].id(), (GID::from("a"), GID::from("b")));
+    /// ```
+    pub fn diff(&self, t_start: i64, t_end: i64) -> TimeRangeDiff {
+        let persistent = self.persistent_graph();
+        let before = persistent.snapshot_at(t_start.saturating_sub(1));
+        let after = persistent.snapshot_at(t_end.saturating_sub(1));
+
+        let changed_nodes = |from: &WindowedGraph<PersistentGraph>,
+                             to: &WindowedGraph<PersistentGraph>| {
+            from.nodes()
+                .iter()
+                .filter(|node| !to.has_node(node.node))
+                .filter_map(|node| self.node(node.node))
+                .collect()
+        };
+        let changed_edges = |from: &WindowedGraph<PersistentGraph>,
+                             to: &WindowedGraph<PersistentGraph>| {
+            from.edges()
+                .iter()
+                .filter(|edge| !to.has_edge(edge.src().node, edge.dst().node))
+                .filter_map(|edge| self.edge(edge.src().node, edge.dst().node))
+                .collect()
+        };
+
+        TimeRangeDiff {
+            added_nodes: changed_nodes(&after, &before),
+            removed_nodes: changed_nodes(&before, &after),
+            added_edges: changed_edges(&after, &before),
+            removed_edges: changed_edges(&before, &after),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -598,7 +1141,7 @@ mod db_tests {
             graph::{edge::EdgeView, edges::Edges, path::PathFromNode},
         },
         errors::GraphError,
-        graphgen::random_attachment::random_attachment,
+        graphgen::{random_attachment::random_attachment, TimestampStrategy},
         prelude::{AdditionOps, PropertyAdditionOps},
         test_storage,
         test_utils::{build_graph, build_graph_strat, test_graph},
@@ -617,7 +1160,10 @@ mod db_tests {
         utils::logging::global_info_logger,
     };
     use raphtory_core::utils::time::{ParseTimeError, TryIntoTime};
-    use raphtory_storage::{core_ops::CoreGraphOps, mutation::addition_ops::InternalAdditionOps};
+    use raphtory_storage::{
+        core_ops::CoreGraphOps,
+        mutation::{addition_ops::InternalAdditionOps, MutationError},
+    };
     use rayon::join;
     use std::{
         collections::{HashMap, HashSet},
@@ -902,6 +1448,370 @@ mod db_tests {
         assert_eq!(gg.edge("A", "B"), None);
     }
 
+    #[test]
+    fn merge_disjoint_graphs() -> Result<(), GraphError> {
+        let mut g = Graph::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None)?;
+        g.add_edge(1, 2, 3, NO_PROPS, None)?;
+
+        let other = Graph::new();
+        other.add_edge(5, 10, 11, NO_PROPS, None)?;
+        other.add_edge(3, 11, 12, NO_PROPS, Some("layer"))?;
+        other.add_node(7, 13, NO_PROPS, Some("type"))?;
+
+        g.merge(&other)?;
+
+        assert_eq!(g.count_nodes(), 7);
+        assert_eq!(g.count_edges(), 4);
+        assert_eq!(g.count_temporal_edges(), 4);
+        assert_eq!(g.latest_time(), Some(7));
+        assert!(g.layers("layer")?.has_edge(11, 12));
+        assert_eq!(g.node(13).unwrap().node_type().as_str(), Some("type"));
+        Ok(())
+    }
+
+    #[test]
+    fn merge_overlapping_graphs() -> Result<(), GraphError> {
+        let mut g = Graph::new();
+        g.add_edge(0, 1, 2, [("a", 1i64), ("b", 1i64)], None)?;
+        g.add_edge(1, 2, 3, NO_PROPS, None)?;
+        g.add_node(0, 1, [("x", 1i64)], None)?;
+        g.node(1).unwrap().add_metadata([("m", 1i64)])?;
+
+        let other = Graph::new();
+        // same (src, dst, timestamp) as an existing update
+        other.add_edge(0, 1, 2, [("a", 2i64)], None)?;
+        // same (src, dst) but a new timestamp
+        other.add_edge(2, 1, 2, NO_PROPS, None)?;
+        other.add_edge(3, 3, 4, NO_PROPS, None)?;
+        other.add_node(0, 1, [("x", 2i64)], None)?;
+        other.node(1).unwrap().add_metadata([("m", 2i64)])?;
+
+        g.merge(&other)?;
+
+        assert_eq!(g.count_nodes(), 4);
+        assert_eq!(g.count_edges(), 3);
+        assert_eq!(g.count_temporal_edges(), 4);
+        assert_eq!(g.latest_time(), Some(3));
+
+        let e = g.edge(1, 2).unwrap();
+        assert_eq!(e.history(), vec![0, 2]);
+        let props = e.properties().temporal();
+        assert_eq!(props.get("a").unwrap().at(0), Some(Prop::I64(2)));
+        assert_eq!(props.get("b").unwrap().at(0), Some(Prop::I64(1)));
+
+        let n = g.node(1).unwrap();
+        assert_eq!(
+            n.properties().temporal().get("x").unwrap().at(0),
+            Some(Prop::I64(2))
+        );
+        assert_eq!(n.metadata().get("m"), Some(Prop::I64(2)));
+        Ok(())
+    }
+
+    #[test]
+    fn diff_added_nodes() {
+        let g = Graph::new();
+        g.add_node(5, 1, NO_PROPS, None).unwrap();
+        g.add_node(15, 2, NO_PROPS, None).unwrap();
+
+        let diff = g.diff(0, 10);
+        assert_eq!(
+            diff.added_nodes.iter().map(|n| n.id()).collect_vec(),
+            vec![GID::U64(1)]
+        );
+        assert!(diff.removed_nodes.is_empty());
+
+        let diff = g.diff(10, 20);
+        assert_eq!(
+            diff.added_nodes.iter().map(|n| n.id()).collect_vec(),
+            vec![GID::U64(2)]
+        );
+    }
+
+    #[test]
+    fn diff_added_and_removed_edges() {
+        let g = Graph::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        g.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+        g.add_edge(5, 3, 4, NO_PROPS, None).unwrap();
+        g.delete_edge(6, 1, 2, None).unwrap();
+        // added and removed within the range
+        g.add_edge(7, 4, 5, NO_PROPS, None).unwrap();
+        g.delete_edge(8, 4, 5, None).unwrap();
+
+        let diff = g.diff(2, 10);
+        assert_eq!(
+            diff.added_edges.iter().map(|e| e.id()).collect_vec(),
+            vec![(GID::U64(3), GID::U64(4))]
+        );
+        assert_eq!(
+            diff.removed_edges.iter().map(|e| e.id()).collect_vec(),
+            vec![(GID::U64(1), GID::U64(2))]
+        );
+        assert_eq!(
+            diff.added_nodes.iter().map(|n| n.id()).collect_vec(),
+            vec![GID::U64(4)]
+        );
+        assert_eq!(
+            diff.removed_nodes.iter().map(|n| n.id()).collect_vec(),
+            vec![GID::U64(1)]
+        );
+
+        let diff = g.diff(10, 20);
+        assert!(diff.added_edges.is_empty());
+        assert!(diff.removed_edges.is_empty());
+    }
+
+    #[test]
+    fn degree_distribution_ring() {
+        let g = Graph::new();
+        let n = 10u64;
+        for i in 0..n {
+            g.add_edge(i as i64, i, (i + 1) % n, NO_PROPS, None)
+                .unwrap();
+        }
+        // parallel edges do not change the degree
+        g.add_edge(20, 0, 1, NO_PROPS, None).unwrap();
+
+        let distribution = g.degree_distribution();
+        assert_eq!(distribution.in_degree, HashMap::from([(1, 10)]));
+        assert_eq!(distribution.out_degree, HashMap::from([(1, 10)]));
+        assert_eq!(distribution.total_degree, HashMap::from([(2, 10)]));
+        assert!(distribution.power_law_exponent().is_nan());
+
+        g.add_node(30, 10, NO_PROPS, None).unwrap();
+        g.add_edge(31, 0, 5, NO_PROPS, None).unwrap();
+        let distribution = g.degree_distribution();
+        assert_eq!(
+            distribution.in_degree,
+            HashMap::from([(0, 1), (1, 9), (2, 1)])
+        );
+        assert_eq!(
+            distribution.out_degree,
+            HashMap::from([(0, 1), (1, 9), (2, 1)])
+        );
+        assert_eq!(
+            distribution.total_degree,
+            HashMap::from([(0, 1), (2, 8), (3, 2)])
+        );
+    }
+
+    #[test]
+    fn power_law_exponent() {
+        let distribution = DegreeDistribution {
+            total_degree: HashMap::from([(0, 5), (1, 1600), (2, 400), (4, 100), (8, 25)]),
+            ..Default::default()
+        };
+        assert!((distribution.power_law_exponent() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn temporal_degree_sequence() -> Result<(), GraphError> {
+        let g = Graph::new();
+        g.add_edge(0, 1, 2, NO_PROPS, None)?;
+        g.add_edge(100, 3, 4, NO_PROPS, None)?;
+        g.add_edge(120, 3, 5, NO_PROPS, None)?;
+        g.add_edge(150, 6, 3, NO_PROPS, None)?;
+        g.add_edge(160, 3, 4, NO_PROPS, None)?;
+        g.add_edge(200, 1, 2, NO_PROPS, None)?;
+
+        let degrees = g.temporal_degree_sequence(3, 25)?;
+        assert_eq!(
+            degrees,
+            vec![
+                (0, 0, 0),
+                (25, 0, 0),
+                (50, 0, 0),
+                (75, 0, 0),
+                (100, 0, 1),
+                (125, 0, 2),
+                (150, 1, 2),
+                (175, 1, 2),
+                (200, 1, 2),
+            ]
+        );
+        assert!(degrees
+            .iter()
+            .filter(|(t, _, _)| *t < 100)
+            .all(|(_, in_degree, out_degree)| *in_degree == 0 && *out_degree == 0));
+
+        assert!(matches!(
+            g.temporal_degree_sequence(3, 0),
+            Err(GraphError::ParseTime { .. })
+        ));
+        assert!(matches!(
+            g.temporal_degree_sequence(7, 10),
+            Err(GraphError::NodeMissingError(_))
+        ));
+        assert!(Graph::new().temporal_degree_sequence(1, 10).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn replay_matches_imperative_graph() -> Result<(), GraphError> {
+        let props = |value: i64| vec![("value".to_string(), Prop::I64(value))];
+        let events = vec![
+            (
+                0,
+                GraphEvent::AddNode {
+                    id: GID::U64(1),
+                    props: props(1),
+                },
+            ),
+            (
+                3,
+                GraphEvent::AddEdge {
+                    src: GID::U64(1),
+                    dst: GID::U64(2),
+                    props: props(2),
+                    layer: Some("follows".to_string()),
+                },
+            ),
+            // out of time order on purpose
+            (
+                1,
+                GraphEvent::AddEdge {
+                    src: GID::U64(2),
+                    dst: GID::U64(3),
+                    props: vec![],
+                    layer: None,
+                },
+            ),
+            (
+                2,
+                GraphEvent::AddNode {
+                    id: GID::U64(4),
+                    props: vec![],
+                },
+            ),
+        ];
+        let replayed = Graph::replay(events.into_iter())?;
+
+        let expected = Graph::new();
+        expected.add_node(0, 1, props(1), None)?;
+        expected.add_edge(3, 1, 2, props(2), Some("follows"))?;
+        expected.add_edge(1, 2, 3, NO_PROPS, None)?;
+        expected.add_node(2, 4, NO_PROPS, None)?;
+
+        assert_graph_equal(&replayed, &expected);
+        assert_eq!(replayed.count_nodes(), 4);
+        assert_eq!(replayed.earliest_time(), Some(0));
+        assert_eq!(replayed.latest_time(), Some(3));
+        Ok(())
+    }
+
+    #[test]
+    fn replay_keeps_stream_order() -> Result<(), GraphError> {
+        let update = |value: i64| GraphEvent::AddEdge {
+            src: GID::U64(1),
+            dst: GID::U64(2),
+            props: vec![("value".to_string(), Prop::I64(value))],
+            layer: None,
+        };
+        // updates at the same time are ordered by their position in the stream
+        let g = Graph::replay([(1, update(1)), (1, update(2))].into_iter())?;
+        assert_eq!(
+            g.edge(1, 2).unwrap().properties().get("value"),
+            Some(Prop::I64(2))
+        );
+        let g = Graph::replay([(1, update(2)), (1, update(1))].into_iter())?;
+        assert_eq!(
+            g.edge(1, 2).unwrap().properties().get("value"),
+            Some(Prop::I64(1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn edge_list_round_trip() -> Result<(), GraphError> {
+        let g = Graph::new();
+        g.add_edge(0, 1, 2, [("weight", Prop::F64(1.5))], None)?;
+        g.add_edge(3, 1, 2, [("label", Prop::str("a"))], None)?;
+        g.add_edge(1, 2, 3, NO_PROPS, Some("follows"))?;
+        g.add_edge(
+            2,
+            2,
+            3,
+            [("weight", Prop::F64(2.0)), ("label", Prop::str("b"))],
+            Some("follows"),
+        )?;
+        g.add_edge(2, 3, 1, [("weight", Prop::F64(0.5))], Some("likes"))?;
+
+        let edge_list = g.to_edge_list()?;
+        assert_eq!(edge_list.len(), 5);
+        assert_eq!(edge_list.src_ids.len(), 5);
+        assert_eq!(edge_list.dst_ids.len(), 5);
+        assert_eq!(edge_list.layers.len(), 5);
+        assert_eq!(edge_list.properties.len(), 2);
+        assert!(edge_list
+            .properties
+            .values()
+            .all(|column| column.len() == 5));
+
+        let mut rows = (0..edge_list.len())
+            .map(|row| {
+                (
+                    edge_list.src_ids[row],
+                    edge_list.dst_ids[row],
+                    edge_list.timestamps[row],
+                    edge_list.layers[row].clone(),
+                    edge_list.properties["weight"][row].clone(),
+                )
+            })
+            .collect_vec();
+        rows.sort_by_key(|(src, dst, t, ..)| (*src, *dst, *t));
+        assert_eq!(
+            rows,
+            vec![
+                (1, 2, 0, "_default".to_string(), Some(Prop::F64(1.5))),
+                (1, 2, 3, "_default".to_string(), None),
+                (2, 3, 1, "follows".to_string(), None),
+                (2, 3, 2, "follows".to_string(), Some(Prop::F64(2.0))),
+                (3, 1, 2, "likes".to_string(), Some(Prop::F64(0.5))),
+            ]
+        );
+
+        let round_tripped = Graph::from_edge_list(edge_list.clone())?;
+        assert_graph_equal(&round_tripped, &g);
+        assert_eq!(round_tripped.to_edge_list()?.len(), edge_list.len());
+        Ok(())
+    }
+
+    #[test]
+    fn edge_list_errors() -> Result<(), GraphError> {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None)?;
+        assert!(matches!(
+            g.to_edge_list(),
+            Err(GraphError::MutationError(MutationError::InvalidNodeId(
+                InvalidNodeId::InvalidNodeIdStr(_)
+            )))
+        ));
+
+        let mut edge_list = EdgeList {
+            src_ids: vec![1, 2],
+            dst_ids: vec![2, 3],
+            timestamps: vec![0, 1],
+            layers: vec!["_default".to_string(); 2],
+            properties: HashMap::from([("weight".to_string(), vec![Some(Prop::I64(1))])]),
+        };
+        assert!(matches!(
+            Graph::from_edge_list(edge_list.clone()),
+            Err(GraphError::EdgeListLengthMismatch { actual: 1, .. })
+        ));
+        edge_list
+            .properties
+            .insert("weight".to_string(), vec![None, Some(Prop::I64(1))]);
+        let g = Graph::from_edge_list(edge_list)?;
+        assert_eq!(
+            g.edge(2, 3).unwrap().properties().get("weight"),
+            Some(Prop::I64(1))
+        );
+        assert_eq!(g.edge(1, 2).unwrap().properties().get("weight"), None);
+        Ok(())
+    }
+
     #[test]
     fn import_node_as() {
         let g = Graph::new();
@@ -1477,7 +2387,7 @@ mod db_tests {
         assert_eq!(g.latest_time(), Some(20));
         assert_eq!(g.earliest_time(), Some(5));
 
-        random_attachment(&g, 100, 10, None);
+        random_attachment(&g, 100, 10, TimestampStrategy::Sequential, None).unwrap();
         assert_eq!(g.latest_time(), Some(126));
         assert_eq!(g.earliest_time(), Some(5));
     }
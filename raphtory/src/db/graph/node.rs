@@ -13,7 +13,7 @@ use crate::{
                 BaseNodeViewOps, BoxedLIter, IntoDynBoxed, StaticGraphViewOps,
             },
         },
-        graph::path::PathFromNode,
+        graph::{nodes::Nodes, path::PathFromNode},
     },
     prelude::*,
 };
@@ -33,10 +33,15 @@ use crate::{
     errors::{into_graph_err, GraphError},
 };
 use raphtory_api::core::{
-    entities::properties::prop::PropType,
+    entities::properties::{prop::PropType, tprop::TPropOps},
     storage::{arc_str::ArcStr, timeindex::TimeIndexEntry},
+    Direction,
 };
-use raphtory_storage::{core_ops::CoreGraphOps, graph::graph::GraphStorage};
+use raphtory_storage::{
+    core_ops::CoreGraphOps,
+    graph::{graph::GraphStorage, nodes::node_storage_ops::NodeStorageOps},
+};
+use rustc_hash::FxHashSet;
 use std::{
     fmt,
     hash::{Hash, Hasher},
@@ -62,6 +67,16 @@ impl<'graph, G1: CoreGraphOps, G1H, G2: CoreGraphOps, G2H> PartialEq<NodeView<'g
 }
 
 impl<'b, G: 'b, GH: 'b> NodeView<'b, G, GH> {
+    /// Returns the local id of the node
+    ///
+    /// Local ids are dense integers, assigned in insertion order starting from 0, and are never
+    /// reused. They are valid indices into a `Vec` of length `unfiltered_num_nodes()`, which makes
+    /// them a cheaper key for per-node state than the node id. Views keep the local ids of the
+    /// underlying graph, so the ids of the nodes in a filtered view can have gaps.
+    pub fn local_id(&self) -> u64 {
+        self.node.as_u64()
+    }
+
     pub fn as_ref<'a>(&'a self) -> NodeView<'a, &'a G, &'a GH>
     where
         'b: 'a,
@@ -172,6 +187,54 @@ impl<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> NodeView<'graph,
             _marker: PhantomData,
         }
     }
+
+    /// Get the nodes reachable from this node within `k` hops in direction `dir`
+    ///
+    /// The nodes are found by a breadth-first search in the current view, so windows and layers
+    /// are respected and every node is only visited once. For `k = 1` these are the same nodes as
+    /// returned by the corresponding `neighbours()` method. The node itself is only included if it
+    /// is on a cycle of at most `k` hops. For `Direction::BOTH` every hop can go back along the edge
+    /// it came from, so the node itself is only included if it has a self-loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The maximum number of hops
+    /// * `dir` - The direction of the hops, `Direction::BOTH` ignores the edge direction
+    pub fn neighbours_within(&self, k: usize, dir: Direction) -> Nodes<'graph, G, GH> {
+        let mut visited: FxHashSet<VID> = FxHashSet::default();
+        let mut frontier = vec![self.node];
+        for hop in 0..k {
+            let mut next = vec![];
+            for node in frontier {
+                let node = NodeView::new_one_hop_filtered(&self.base_graph, &self.graph, node);
+                let neighbours = match dir {
+                    Direction::OUT => node.out_neighbours(),
+                    Direction::IN => node.in_neighbours(),
+                    Direction::BOTH => node.neighbours(),
+                };
+                for neighbour in neighbours.iter_refs() {
+                    if hop > 0 && dir == Direction::BOTH && neighbour == self.node {
+                        continue;
+                    }
+                    if visited.insert(neighbour) {
+                        next.push(neighbour);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        let mut nodes: Vec<_> = visited.into_iter().collect();
+        nodes.sort();
+        Nodes::new_filtered(
+            self.base_graph.clone(),
+            self.graph.clone(),
+            Some(nodes.into_iter().collect()),
+            None,
+        )
+    }
 }
 
 impl<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> EdgePropertyFilterOps<'graph>
@@ -299,6 +362,21 @@ impl<'graph, G, GH: GraphViewOps<'graph>> InternalTemporalPropertyViewOps
             .node_tprop_last_at(node.as_ref(), &self.graph, id, TimeIndexEntry::end(t))
             .map(|(_, v)| v)
     }
+
+    fn temporal_window(&self) -> (Option<i64>, Option<i64>) {
+        (self.graph.view_start(), self.graph.view_end())
+    }
+
+    fn temporal_value_before_window(&self, id: usize) -> Option<Prop> {
+        let start = self.graph.view_start()?;
+        let node = self.graph.core_node(self.node);
+        let value = node
+            .as_ref()
+            .tprop(id)
+            .last_before(TimeIndexEntry::start(start))
+            .map(|(_, v)| v);
+        value
+    }
 }
 
 impl<'graph, G, GH: GraphViewOps<'graph>> NodeView<'graph, G, GH> {
@@ -465,6 +543,24 @@ mod node_test {
     use raphtory_api::core::storage::arc_str::ArcStr;
     use std::collections::HashMap;
 
+    #[test]
+    fn test_local_id() {
+        let graph = Graph::new();
+        for name in ["c", "a", "b"] {
+            graph.add_node(0, name, NO_PROPS, None).unwrap();
+        }
+        graph.add_node(1, "a", NO_PROPS, None).unwrap();
+        graph.add_edge(2, "d", "a", NO_PROPS, None).unwrap();
+
+        let ids: Vec<_> = ["c", "a", "b", "d"]
+            .into_iter()
+            .map(|name| graph.node(name).unwrap().local_id())
+            .collect();
+        assert_eq!(ids, [0, 1, 2, 3]);
+        // views keep the ids of the underlying graph
+        assert_eq!(graph.window(2, 3).node("d").unwrap().local_id(), 3);
+    }
+
     #[test]
     fn test_earliest_time() {
         let graph = Graph::new();
@@ -16,6 +16,7 @@ pub mod node_and_filtered_graph;
 pub mod node_name_filtered_graph;
 pub mod node_not_filtered_graph;
 pub mod node_or_filtered_graph;
+pub mod node_predicate_filtered_graph;
 pub mod node_property_filtered_graph;
 pub mod node_type_filtered_graph;
 
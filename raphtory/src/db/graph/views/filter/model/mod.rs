@@ -132,9 +132,14 @@ impl Filter {
         graph: &G,
         edge: EdgeStorageRef,
     ) -> bool {
+        let (src, dst) = if graph.edges_reversed() {
+            (edge.dst(), edge.src())
+        } else {
+            (edge.src(), edge.dst())
+        };
         match self.field_name.as_str() {
-            "src" => self.matches(graph.node(edge.src()).map(|n| n.name()).as_deref()),
-            "dst" => self.matches(graph.node(edge.dst()).map(|n| n.name()).as_deref()),
+            "src" => self.matches(graph.node(src).map(|n| n.name()).as_deref()),
+            "dst" => self.matches(graph.node(dst).map(|n| n.name()).as_deref()),
             _ => false,
         }
     }
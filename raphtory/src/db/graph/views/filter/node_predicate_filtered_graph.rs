@@ -0,0 +1,181 @@
+use crate::{
+    core::entities::LayerIds,
+    db::{
+        api::{
+            properties::internal::InheritPropertiesOps,
+            view::internal::{
+                Immutable, InheritAllEdgeFilterOps, InheritEdgeHistoryFilter, InheritLayerOps,
+                InheritListOps, InheritMaterialize, InheritNodeHistoryFilter, InheritStorageOps,
+                InheritTimeSemantics, InternalNodeFilterOps, Static,
+            },
+        },
+        graph::node::NodeView,
+    },
+    prelude::{GraphViewOps, NodeViewOps, Prop, PropertiesOps},
+};
+use raphtory_api::inherit::Base;
+use raphtory_storage::{
+    core_ops::InheritCoreGraphOps,
+    graph::nodes::{node_ref::NodeStorageRef, node_storage_ops::NodeStorageOps},
+};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+/// A view that only includes the nodes for which a predicate on the value of a property holds
+///
+/// The value of a node is the latest value of the temporal property in the view, or the
+/// metadata value if the node has no temporal value. Nodes without a value are excluded. Edges
+/// are only included if both endpoints are included, so a node that only has edge updates is
+/// excluded as well if none of its edges are included.
+#[derive(Clone)]
+pub struct NodePredicateFilteredGraph<G> {
+    graph: G,
+    temporal_prop_id: Option<usize>,
+    metadata_id: Option<usize>,
+    predicate: Arc<dyn Fn(&Prop) -> bool + Send + Sync>,
+}
+
+impl<G: Debug> Debug for NodePredicateFilteredGraph<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NodePredicateFilteredGraph")
+            .field("graph", &self.graph)
+            .field("temporal_prop_id", &self.temporal_prop_id)
+            .field("metadata_id", &self.metadata_id)
+            .finish()
+    }
+}
+
+impl<'graph, G: GraphViewOps<'graph>> NodePredicateFilteredGraph<G> {
+    pub fn new<F: Fn(&Prop) -> bool + Send + Sync + 'static>(
+        graph: G,
+        prop_name: &str,
+        predicate: F,
+    ) -> Self {
+        let temporal_prop_id = graph.node_meta().get_prop_id(prop_name, false);
+        let metadata_id = graph.node_meta().get_prop_id(prop_name, true);
+        Self {
+            graph,
+            temporal_prop_id,
+            metadata_id,
+            predicate: Arc::new(predicate),
+        }
+    }
+}
+
+impl<G> Base for NodePredicateFilteredGraph<G> {
+    type Base = G;
+
+    fn base(&self) -> &Self::Base {
+        &self.graph
+    }
+}
+
+impl<G> Static for NodePredicateFilteredGraph<G> {}
+impl<G> Immutable for NodePredicateFilteredGraph<G> {}
+
+impl<'graph, G: GraphViewOps<'graph>> InheritCoreGraphOps for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritStorageOps for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritLayerOps for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritListOps for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritMaterialize for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritAllEdgeFilterOps for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritPropertiesOps for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritTimeSemantics for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritNodeHistoryFilter for NodePredicateFilteredGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritEdgeHistoryFilter for NodePredicateFilteredGraph<G> {}
+
+impl<'graph, G: GraphViewOps<'graph>> InternalNodeFilterOps for NodePredicateFilteredGraph<G> {
+    #[inline]
+    fn internal_filter_node(&self, node: NodeStorageRef, layer_ids: &LayerIds) -> bool {
+        if !self.graph.internal_filter_node(node, layer_ids) {
+            return false;
+        }
+        let node = NodeView::new_internal(&self.graph, node.vid());
+        let value = self
+            .temporal_prop_id
+            .and_then(|id| node.properties().get_by_id(id))
+            .or_else(|| {
+                self.metadata_id
+                    .and_then(|id| node.metadata().get_by_id(id))
+            });
+        value.is_some_and(|value| (self.predicate)(&value))
+    }
+}
+
+#[cfg(test)]
+mod node_predicate_filtered_graph_test {
+    use crate::{prelude::*, test_storage};
+    use itertools::Itertools;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        for (node, city) in [
+            (1, "London"),
+            (2, "Paris"),
+            (3, "London"),
+            (4, "Berlin"),
+            (5, "London"),
+        ] {
+            graph.add_node(0, node, [("city", city)], None).unwrap();
+        }
+        for (src, dst) in [(1, 2), (1, 3), (3, 5), (5, 4), (2, 4), (6, 1)] {
+            graph.add_edge(1, src, dst, NO_PROPS, None).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_filter_on_string_property() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let london = graph.induced_subgraph_by_property(
+                "city",
+                |city| matches!(city, Prop::Str(city) if city.as_ref() == "London"),
+            );
+            let mut nodes = london.nodes().id().iter_values().collect_vec();
+            nodes.sort();
+            assert_eq!(nodes, vec![GID::U64(1), GID::U64(3), GID::U64(5)]);
+            // only the edges between London nodes are included
+            assert_eq!(london.count_edges(), 2);
+            assert!(london.has_edge(1, 3));
+            assert!(london.has_edge(3, 5));
+            assert!(!london.has_edge(5, 4));
+
+            // nodes without the property are excluded
+            let all = graph.induced_subgraph_by_property("city", |_| true);
+            assert_eq!(all.count_nodes(), 5);
+            let missing = graph.induced_subgraph_by_property("missing", |_| true);
+            assert_eq!(missing.count_nodes(), 0);
+        });
+    }
+
+    #[test]
+    fn test_filter_on_metadata_and_latest_value() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        // node 2 needs an update of its own to stay in the view once the edge is filtered out
+        graph.add_node(0, 2, NO_PROPS, None).unwrap();
+        graph.add_node(0, 1, [("age", 25i64)], None).unwrap();
+        graph.add_node(5, 1, [("age", 35i64)], None).unwrap();
+        graph
+            .node(2)
+            .unwrap()
+            .add_metadata([("age", 40i64)])
+            .unwrap();
+
+        let over_30 = |age: &Prop| matches!(age, Prop::I64(age) if *age > 30);
+        let filtered = graph.induced_subgraph_by_property("age", over_30);
+        assert_eq!(filtered.count_nodes(), 2);
+        assert_eq!(filtered.count_edges(), 1);
+        let filtered = graph
+            .window(0, 5)
+            .induced_subgraph_by_property("age", over_30);
+        assert_eq!(
+            filtered.nodes().id().iter_values().collect_vec(),
+            vec![GID::U64(2)]
+        );
+    }
+}
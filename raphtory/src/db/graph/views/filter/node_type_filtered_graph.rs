@@ -100,6 +100,7 @@ impl<'graph, G: GraphViewOps<'graph>> InternalNodeFilterOps for NodeTypeFiltered
 #[cfg(test)]
 mod tests_node_type_filtered_subgraph {
     use crate::{
+        algorithms::centrality::pagerank::unweighted_page_rank,
         db::graph::{
             graph::assert_graph_equal,
             views::filter::model::property_filter::{PropertyFilter, PropertyRef},
@@ -159,6 +160,87 @@ mod tests_node_type_filtered_subgraph {
             .is_empty())
     }
 
+    #[test]
+    fn test_type_filtered_subgraph_isolates_types() {
+        let graph = Graph::new();
+        for (name, node_type) in [
+            ("alice", "Person"),
+            ("bob", "Person"),
+            ("carol", "Person"),
+            ("acme", "Company"),
+            ("globex", "Company"),
+        ] {
+            graph.add_node(0, name, NO_PROPS, Some(node_type)).unwrap();
+        }
+        for (src, dst) in [
+            ("alice", "bob"),
+            ("bob", "carol"),
+            ("alice", "acme"),
+            ("carol", "globex"),
+            ("acme", "globex"),
+        ] {
+            graph.add_edge(1, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        let people = graph.subgraph_node_types(["Person"]);
+        assert_eq!(people.nodes(), vec!["alice", "bob", "carol"]);
+        assert_eq!(people.count_edges(), 2);
+        assert!(people.edge("alice", "acme").is_none());
+        assert_eq!(people.node("alice").unwrap().degree(), 1);
+
+        let companies = graph.subgraph_node_types(["Company"]);
+        assert_eq!(companies.nodes(), vec!["acme", "globex"]);
+        assert_eq!(companies.count_edges(), 1);
+        assert!(companies.edge("acme", "globex").is_some());
+
+        let both = graph.subgraph_node_types(["Person", "Company"]);
+        assert_eq!(both.count_edges(), 5);
+    }
+
+    #[test]
+    fn test_type_filtered_subgraph_algorithms_and_unknown_types() {
+        let graph = Graph::new();
+        for (name, node_type) in [
+            ("alice", "Person"),
+            ("bob", "Person"),
+            ("carol", "Person"),
+            ("acme", "Company"),
+        ] {
+            graph.add_node(0, name, NO_PROPS, Some(node_type)).unwrap();
+        }
+        for (src, dst) in [
+            ("alice", "bob"),
+            ("bob", "carol"),
+            ("carol", "alice"),
+            ("alice", "acme"),
+        ] {
+            graph.add_edge(1, src, dst, NO_PROPS, None).unwrap();
+        }
+
+        let people = graph.subgraph_node_types(["Person", "Robot"]);
+        let ranks = unweighted_page_rank(&people, Some(100), None, None, true, None);
+        assert_eq!(ranks.len(), 3);
+        assert!(ranks.get_by_node("acme").is_none());
+        for name in ["alice", "bob", "carol"] {
+            assert!((ranks.get_by_node(name).unwrap() - 1.0 / 3.0).abs() < 1e-6);
+        }
+
+        // unknown types match no nodes
+        let robots = graph.subgraph_node_types(["Robot"]);
+        assert_eq!(robots.count_nodes(), 0);
+        assert_eq!(
+            unweighted_page_rank(&robots, Some(100), None, None, true, None).len(),
+            0
+        );
+
+        // composes with windows, layers and further subgraphs
+        assert_eq!(people.window(0, 1).count_edges(), 0);
+        assert_eq!(people.default_layer().count_edges(), 3);
+        let subgraph = people.subgraph(["alice", "bob", "acme"]);
+        assert_eq!(subgraph.nodes(), vec!["alice", "bob"]);
+        assert_eq!(subgraph.count_edges(), 1);
+    }
+
     #[test]
     fn materialize_prop_test() {
         proptest!(|(graph_f in build_graph_strat(10, 10, true), node_types in make_node_types())| {
@@ -95,6 +95,7 @@ impl<G: GraphView> InheritExplodedEdgeFilterOps for LayeredGraph<G> {}
 mod test_layers {
     use crate::{
         db::graph::{graph::assert_graph_equal, views::deletion_graph::PersistentGraph},
+        errors::GraphError,
         prelude::*,
         test_storage,
         test_utils::{build_graph, build_graph_layer, build_graph_strat},
@@ -231,6 +232,96 @@ mod test_layers {
         });
     }
 
+    #[test]
+    fn test_layers_hide_other_layers() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, NO_PROPS, Some("a")).unwrap();
+        graph.add_edge(0, 2, 3, NO_PROPS, Some("b")).unwrap();
+        graph.add_edge(0, 3, 4, NO_PROPS, Some("c")).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let layered = graph.layers(["a", "b"]).unwrap();
+            assert_eq!(layered.count_edges(), 2);
+            assert!(layered.edge(3, 4).is_none());
+            assert_eq!(layered.node(3).unwrap().degree(), 1);
+            assert!(graph.edge(3, 4).is_some());
+            assert_eq!(graph.count_edges(), 3);
+
+            match graph.layers(["a", "x", "y"]) {
+                Err(GraphError::InvalidLayer(err)) => {
+                    assert_eq!(err.invalid_layers(), ["x", "y"]);
+                    assert!(err.to_string().starts_with("Invalid layer: x, y."));
+                }
+                _ => panic!("expected an invalid layer error"),
+            }
+        });
+    }
+
+    #[test]
+    fn test_exclude_layers() {
+        let graph = Graph::new();
+        // 1 -> 2 only exists on the heartbeat layer
+        graph
+            .add_edge(1, 1, 2, NO_PROPS, Some("heartbeat"))
+            .unwrap();
+        graph
+            .add_edge(2, 1, 2, NO_PROPS, Some("heartbeat"))
+            .unwrap();
+        // 2 -> 3 exists on both layers
+        graph
+            .add_edge(1, 2, 3, [("weight", 1.0)], Some("a"))
+            .unwrap();
+        graph
+            .add_edge(5, 2, 3, [("weight", 9.0)], Some("heartbeat"))
+            .unwrap();
+        graph
+            .add_edge(3, 3, 4, [("weight", 2.0)], Some("a"))
+            .unwrap();
+
+        test_storage!(&graph, |graph| {
+            assert!(graph.unique_layers().any(|layer| layer == "heartbeat"));
+            assert_eq!(graph.node(2).unwrap().degree(), 2);
+            assert_eq!(
+                graph.edge(2, 3).unwrap().properties().get("weight"),
+                Some(Prop::F64(9.0))
+            );
+
+            let excluded = graph.exclude_layer("heartbeat").unwrap();
+            // the edge that only exists on the excluded layer disappears
+            assert_eq!(excluded.count_edges(), 2);
+            assert!(excluded.edge(1, 2).is_none());
+            assert_eq!(excluded.node(2).unwrap().degree(), 1);
+            // the edge that partially exists on the excluded layer only loses those events
+            let edge = excluded.edge(2, 3).unwrap();
+            assert_eq!(edge.history(), vec![1]);
+            assert_eq!(edge.properties().get("weight"), Some(Prop::F64(1.0)));
+            assert_eq!(edge.layer_names(), ["a"]);
+
+            let windowed = graph.window(2, 10).exclude_layer("heartbeat").unwrap();
+            assert_eq!(
+                windowed.edges().id().collect_vec(),
+                vec![(GID::U64(3), GID::U64(4))]
+            );
+            assert_eq!(
+                graph
+                    .exclude_layers(["heartbeat", "a"])
+                    .unwrap()
+                    .count_edges(),
+                0
+            );
+
+            // unknown layers error unless using the valid variants
+            assert!(graph.exclude_layers(["heartbeat", "missing"]).is_err());
+            assert_eq!(
+                graph
+                    .exclude_valid_layers(["heartbeat", "missing"])
+                    .count_edges(),
+                2
+            );
+            assert_eq!(graph.valid_layers(["a", "missing"]).count_edges(), 2);
+        });
+    }
+
     mod test_filters_layer_graph {
         use crate::{
             db::{
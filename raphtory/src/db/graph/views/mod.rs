@@ -3,5 +3,7 @@ pub mod deletion_graph;
 pub mod filter;
 pub mod layer_graph;
 pub mod node_subgraph;
+pub mod reversed_graph;
+pub mod set_operation_graph;
 pub mod valid_graph;
 pub mod window_graph;
@@ -310,6 +310,33 @@ mod subgraph_tests {
         );
     }
 
+    #[test]
+    fn test_windowed_subgraph() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(1, 2, 3, NO_PROPS, None).unwrap();
+        graph.add_edge(2, 3, 4, NO_PROPS, None).unwrap();
+        graph.add_edge(5, 1, 3, NO_PROPS, None).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let sg = graph.window(0, 3).subgraph([1, 2, 3]);
+            assert_eq!(
+                sg.nodes().id().iter_values().sorted().collect_vec(),
+                [GID::U64(1), GID::U64(2), GID::U64(3)]
+            );
+            // (3, 4) crosses the node boundary and (1, 3) is outside the window
+            assert_eq!(
+                sg.edges().id().sorted().collect_vec(),
+                [(GID::U64(1), GID::U64(2)), (GID::U64(2), GID::U64(3))]
+            );
+            assert!(graph.has_edge(1, 3));
+
+            let cc = weakly_connected_components(&sg);
+            assert_eq!(cc.len(), 3);
+            assert_eq!(cc.groups().len(), 1);
+        });
+    }
+
     #[test]
     fn test_layer_edges() {
         let graph = Graph::new();
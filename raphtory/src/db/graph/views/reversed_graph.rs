@@ -0,0 +1,234 @@
+use crate::{
+    db::api::{
+        properties::internal::InheritPropertiesOps,
+        view::internal::{
+            Immutable, InheritAllEdgeFilterOps, InheritEdgeHistoryFilter, InheritLayerOps,
+            InheritListOps, InheritMaterialize, InheritNodeFilterOps, InheritNodeHistoryFilter,
+            InheritStorageOps, InheritTimeSemantics, Static,
+        },
+    },
+    prelude::GraphViewOps,
+};
+use raphtory_api::inherit::Base;
+use raphtory_storage::{core_ops::CoreGraphOps, graph::graph::GraphStorage};
+
+/// A view of the graph where the source and destination of every edge are swapped
+///
+/// The out-edges of a node in the view are its in-edges in the underlying graph and vice versa.
+/// All other aspects of the edges, e.g., their history and properties, are unchanged. The view is
+/// lazy and does not copy the graph.
+///
+/// Only the direction of the edges is reversed, not time. Temporal algorithms still move forward
+/// in time on the view, e.g.,
+/// [temporally_reachable_nodes](crate::algorithms::pathing::temporal_reachability::temporally_reachable_nodes)
+/// on a reversed view does not find the nodes that can reach the seed nodes.
+#[derive(Copy, Clone, Debug)]
+pub struct ReversedGraph<G> {
+    graph: G,
+}
+
+impl<G> ReversedGraph<G> {
+    pub fn new(graph: G) -> Self {
+        Self { graph }
+    }
+
+    /// Reversing the view again returns the underlying graph
+    pub fn reversed(&self) -> G
+    where
+        G: Clone,
+    {
+        self.graph.clone()
+    }
+}
+
+impl<G> Base for ReversedGraph<G> {
+    type Base = G;
+
+    fn base(&self) -> &Self::Base {
+        &self.graph
+    }
+}
+
+impl<G> Static for ReversedGraph<G> {}
+impl<G> Immutable for ReversedGraph<G> {}
+
+impl<G: CoreGraphOps> CoreGraphOps for ReversedGraph<G> {
+    #[inline]
+    fn core_graph(&self) -> &GraphStorage {
+        self.graph.core_graph()
+    }
+
+    #[inline]
+    fn edges_reversed(&self) -> bool {
+        !self.graph.edges_reversed()
+    }
+}
+
+impl<'graph, G: GraphViewOps<'graph>> InheritStorageOps for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritLayerOps for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritListOps for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritMaterialize for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritNodeFilterOps for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritAllEdgeFilterOps for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritPropertiesOps for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritTimeSemantics for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritNodeHistoryFilter for ReversedGraph<G> {}
+impl<'graph, G: GraphViewOps<'graph>> InheritEdgeHistoryFilter for ReversedGraph<G> {}
+
+#[cfg(test)]
+mod reversed_graph_tests {
+    use crate::{
+        algorithms::pathing::temporal_reachability::temporally_reachable_nodes,
+        db::{
+            api::view::{IntoDynamic, StaticGraphViewOps},
+            graph::path::PathFromNode,
+        },
+        prelude::*,
+        test_storage,
+    };
+    use itertools::Itertools;
+
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst, layer) in [
+            (1i64, 1, 2, "a"),
+            (2, 2, 3, "a"),
+            (3, 4, 3, "b"),
+            (4, 3, 5, "a"),
+            (5, 6, 4, "b"),
+        ] {
+            graph
+                .add_edge(t, src, dst, [("weight", t)], Some(layer))
+                .unwrap();
+        }
+        graph
+    }
+
+    fn sorted_ids<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>>(
+        nodes: PathFromNode<'graph, G, GH>,
+    ) -> Vec<u64> {
+        nodes
+            .iter()
+            .filter_map(|node| node.id().as_u64())
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn test_edges_are_swapped() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let reversed = graph.reversed();
+            for node in graph.nodes() {
+                let rev_node = reversed.node(node.id()).unwrap();
+                assert_eq!(
+                    sorted_ids(rev_node.out_neighbours()),
+                    sorted_ids(node.in_neighbours())
+                );
+                assert_eq!(
+                    sorted_ids(rev_node.in_neighbours()),
+                    sorted_ids(node.out_neighbours())
+                );
+                assert_eq!(rev_node.out_degree(), node.in_degree());
+                assert_eq!(rev_node.degree(), node.degree());
+            }
+
+            assert!(reversed.has_edge(3, 2));
+            assert!(!reversed.has_edge(2, 3));
+            let edge = reversed.edge(3, 2).unwrap();
+            assert_eq!(edge.src().id(), GID::U64(3));
+            assert_eq!(edge.dst().id(), GID::U64(2));
+            assert_eq!(edge.history(), vec![2]);
+            assert_eq!(edge.properties().get("weight"), Some(Prop::I64(2)));
+
+            let mut edges = reversed
+                .edges()
+                .iter()
+                .map(|e| (e.src().id(), e.dst().id()))
+                .collect_vec();
+            edges.sort();
+            let mut expected = graph
+                .edges()
+                .iter()
+                .map(|e| (e.dst().id(), e.src().id()))
+                .collect_vec();
+            expected.sort();
+            assert_eq!(edges, expected);
+            assert_eq!(reversed.count_edges(), graph.count_edges());
+
+            // reversing twice returns the original view
+            let twice = reversed.reversed();
+            assert!(twice.has_edge(2, 3));
+            assert!(graph
+                .clone()
+                .reversed()
+                .into_dynamic()
+                .reversed()
+                .has_edge(2, 3));
+        });
+    }
+
+    #[test]
+    fn test_reversed_composes_with_other_views() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let windowed = graph.reversed().window(2, 5);
+            assert_eq!(
+                sorted_ids(windowed.node(3).unwrap().out_neighbours()),
+                [2, 4]
+            );
+            assert_eq!(sorted_ids(windowed.node(3).unwrap().in_neighbours()), [5]);
+            assert_eq!(
+                sorted_ids(
+                    graph
+                        .window(2, 5)
+                        .reversed()
+                        .node(3)
+                        .unwrap()
+                        .out_neighbours()
+                ),
+                [2, 4]
+            );
+
+            let layered = graph.layers("b").unwrap().reversed();
+            assert_eq!(sorted_ids(layered.node(3).unwrap().out_neighbours()), [4]);
+            assert_eq!(sorted_ids(layered.node(4).unwrap().out_neighbours()), [6]);
+
+            let subgraph = graph.reversed().subgraph([2, 3, 5]);
+            assert_eq!(sorted_ids(subgraph.node(3).unwrap().out_neighbours()), [2]);
+            assert_eq!(sorted_ids(subgraph.node(5).unwrap().out_neighbours()), [3]);
+            assert_eq!(subgraph.count_edges(), 2);
+
+            let materialized = graph.reversed().materialize().unwrap();
+            assert!(materialized.has_edge(5, 3));
+            assert!(!materialized.has_edge(3, 5));
+        });
+    }
+
+    fn reached_from<G: StaticGraphViewOps>(graph: &G, seed: u64) -> Vec<u64> {
+        temporally_reachable_nodes(graph, None, 10, 0, vec![seed], None)
+            .into_iter()
+            .filter(|(_, history)| !history.is_empty())
+            .filter_map(|(node, _)| node.id().as_u64())
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn test_reversed_temporal_reachability_keeps_time_direction() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            // forwards, 3 only reaches 5 and 1 reaches 3 through 1 -> 2 at 1 and 2 -> 3 at 2
+            assert_eq!(reached_from(graph, 3), [3, 5]);
+            assert_eq!(reached_from(graph, 1), [1, 2, 3, 5]);
+            // the reversed view does not reverse time, the taint still only moves forward in time
+            // against the edge direction. The reached nodes are the nodes with a path to 3 whose
+            // timestamps do not increase (6 -> 4 at 5 and 4 -> 3 at 3), not the nodes that can reach
+            // 3, which would include 1 and exclude 6.
+            assert_eq!(reached_from(&graph.reversed(), 3), [2, 3, 4, 6]);
+        });
+    }
+}
@@ -0,0 +1,380 @@
+//! Set operations on two views of the same graph
+//!
+//! A [SetOperationGraph] combines the nodes and edges visible in two views, e.g., two windows or
+//! two layer selections of the same graph, without materialising either of them.
+use crate::db::api::{
+    properties::internal::InheritPropertiesOps,
+    view::{
+        internal::{
+            FilterOps, Immutable, InheritEdgeHistoryFilter, InheritLayerOps, InheritListOps,
+            InheritMaterialize, InheritNodeHistoryFilter, InheritStorageOps, InheritTimeSemantics,
+            InternalEdgeFilterOps, InternalEdgeLayerFilterOps, InternalExplodedEdgeFilterOps,
+            InternalNodeFilterOps, MaterializedGraph, Static,
+        },
+        GraphViewOps,
+    },
+};
+use raphtory_api::{
+    core::{
+        entities::{LayerIds, ELID},
+        storage::timeindex::TimeIndexEntry,
+    },
+    inherit::Base,
+};
+use raphtory_storage::{
+    core_ops::InheritCoreGraphOps,
+    graph::{edges::edge_ref::EdgeStorageRef, nodes::node_ref::NodeStorageRef},
+};
+
+/// The set operation applied by a [SetOperationGraph]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SetOperation {
+    /// Nodes and edges that are visible in either view
+    Union,
+    /// Nodes and edges that are visible in both views
+    Intersection,
+    /// Edges that are visible in the left view but not in the right view
+    Difference,
+}
+
+/// A view of the nodes and edges obtained by combining two views of the same graph
+///
+/// The visible nodes and edges are
+///
+/// - [SetOperation::Union]: the nodes and edges visible in either view
+/// - [SetOperation::Intersection]: the nodes and edges visible in both views
+/// - [SetOperation::Difference]: the edges visible in the left view but not in the right view and
+///   the nodes visible in the left view, so that the endpoints of the remaining edges are included
+///
+/// As in other filtered views, a node that only has edge updates is excluded if none of its edges
+/// are included, e.g., the difference drops a node whose edges are all visible in the right view.
+///
+/// The updates of an included edge are the updates visible in either view for the union and the
+/// intersection, so an edge that is in two disjoint windows keeps the updates of both windows,
+/// and the updates visible in the left view for the difference. Node updates are not filtered.
+///
+/// Both views need to be views of the same underlying graph, the result is unspecified otherwise.
+#[derive(Clone, Debug)]
+pub struct SetOperationGraph<L, R> {
+    graph: MaterializedGraph,
+    left: L,
+    right: R,
+    operation: SetOperation,
+}
+
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> SetOperationGraph<L, R> {
+    pub fn new(left: L, right: R, operation: SetOperation) -> Self {
+        let graph = left.new_base_graph(left.core_graph().clone());
+        Self {
+            graph,
+            left,
+            right,
+            operation,
+        }
+    }
+
+    /// The set operation applied to the views
+    pub fn operation(&self) -> SetOperation {
+        self.operation
+    }
+
+    fn combine(&self, left: impl FnOnce() -> bool, right: impl FnOnce() -> bool) -> bool {
+        match self.operation {
+            SetOperation::Union => left() || right(),
+            SetOperation::Intersection => left() && right(),
+            SetOperation::Difference => left() && !right(),
+        }
+    }
+
+    /// Layers of an edge are only filtered by the views and not combined, as the edge filter
+    /// decides which edges are included
+    fn filter_layer(&self, edge: EdgeStorageRef, layer: usize) -> bool {
+        let left =
+            self.left.layer_ids().contains(&layer) && self.left.filter_edge_layer(edge, layer);
+        match self.operation {
+            SetOperation::Difference => left,
+            _ => {
+                left || (self.right.layer_ids().contains(&layer)
+                    && self.right.filter_edge_layer(edge, layer))
+            }
+        }
+    }
+}
+
+impl<L, R> Base for SetOperationGraph<L, R> {
+    type Base = MaterializedGraph;
+
+    fn base(&self) -> &Self::Base {
+        &self.graph
+    }
+}
+
+impl<L, R> Static for SetOperationGraph<L, R> {}
+impl<L, R> Immutable for SetOperationGraph<L, R> {}
+
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritCoreGraphOps
+    for SetOperationGraph<L, R>
+{
+}
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritStorageOps
+    for SetOperationGraph<L, R>
+{
+}
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritLayerOps
+    for SetOperationGraph<L, R>
+{
+}
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritListOps
+    for SetOperationGraph<L, R>
+{
+}
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritMaterialize
+    for SetOperationGraph<L, R>
+{
+}
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritPropertiesOps
+    for SetOperationGraph<L, R>
+{
+}
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritTimeSemantics
+    for SetOperationGraph<L, R>
+{
+}
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritNodeHistoryFilter
+    for SetOperationGraph<L, R>
+{
+}
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InheritEdgeHistoryFilter
+    for SetOperationGraph<L, R>
+{
+}
+
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InternalNodeFilterOps
+    for SetOperationGraph<L, R>
+{
+    #[inline]
+    fn internal_nodes_filtered(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn internal_node_list_trusted(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn edge_filter_includes_node_filter(&self) -> bool {
+        // the endpoints of an edge are visible in every view the edge is visible in
+        true
+    }
+
+    #[inline]
+    fn internal_filter_node(&self, node: NodeStorageRef, _layer_ids: &LayerIds) -> bool {
+        match self.operation {
+            SetOperation::Difference => self.left.filter_node(node),
+            _ => self.combine(
+                || self.left.filter_node(node),
+                || self.right.filter_node(node),
+            ),
+        }
+    }
+}
+
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InternalEdgeFilterOps
+    for SetOperationGraph<L, R>
+{
+    #[inline]
+    fn internal_edge_filtered(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn internal_edge_list_trusted(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn internal_filter_edge(&self, edge: EdgeStorageRef, _layer_ids: &LayerIds) -> bool {
+        self.combine(
+            || self.left.filter_edge(edge),
+            || self.right.filter_edge(edge),
+        )
+    }
+}
+
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InternalEdgeLayerFilterOps
+    for SetOperationGraph<L, R>
+{
+    #[inline]
+    fn internal_edge_layer_filtered(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn internal_layer_filter_edge_list_trusted(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn internal_filter_edge_layer(&self, edge: EdgeStorageRef, layer: usize) -> bool {
+        self.filter_layer(edge, layer)
+    }
+}
+
+impl<'graph, L: GraphViewOps<'graph>, R: GraphViewOps<'graph>> InternalExplodedEdgeFilterOps
+    for SetOperationGraph<L, R>
+{
+    #[inline]
+    fn internal_exploded_edge_filtered(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn internal_exploded_filter_edge_list_trusted(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn internal_filter_exploded_edge(
+        &self,
+        eid: ELID,
+        t: TimeIndexEntry,
+        _layer_ids: &LayerIds,
+    ) -> bool {
+        let left = self.left.filter_exploded_edge(eid, t);
+        match self.operation {
+            SetOperation::Difference => left,
+            _ => left || self.right.filter_exploded_edge(eid, t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod set_operation_graph_test {
+    use crate::{db::graph::views::filter::model::PropertyFilterOps, prelude::*, test_storage};
+    use itertools::Itertools;
+    use raphtory_api::core::entities::GID;
+
+    fn sorted_edges<'graph, G: GraphViewOps<'graph>>(g: &G) -> Vec<(u64, u64)> {
+        g.edges()
+            .id()
+            .filter_map(|(src, dst)| src.to_u64().zip(dst.to_u64()))
+            .sorted()
+            .collect()
+    }
+
+    /// Edges 1 -> 2 and 2 -> 3 in January (t in 0..10), 2 -> 3 and 3 -> 4 in February (t in 10..20)
+    fn load_graph() -> Graph {
+        let graph = Graph::new();
+        for (t, src, dst, value) in [
+            (1, 1, 2, 1i64),
+            (2, 2, 3, 2),
+            (11, 2, 3, 3),
+            (12, 3, 4, 4),
+            (13, 3, 4, 5),
+        ] {
+            graph
+                .add_edge(t, src, dst, [("value", value)], None)
+                .unwrap();
+        }
+        graph.add_node(15, 5, NO_PROPS, None).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_disjoint_windows() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let jan = graph.window(0, 10);
+            let feb = graph.window(10, 20);
+
+            let union = jan.union(feb.clone());
+            assert_eq!(sorted_edges(&union), vec![(1, 2), (2, 3), (3, 4)]);
+            assert_eq!(union.count_nodes(), 5);
+            // the union exposes the updates of both windows
+            let edge = union.edge(2, 3).unwrap();
+            assert_eq!(edge.history(), vec![2, 11]);
+            assert_eq!(edge.properties().get("value"), Some(Prop::I64(3)));
+
+            let intersection = jan.intersection(feb.clone());
+            assert_eq!(sorted_edges(&intersection), vec![(2, 3)]);
+            assert_eq!(intersection.count_nodes(), 2);
+            assert_eq!(intersection.edge(2, 3).unwrap().history(), vec![2, 11]);
+
+            // edges present in January but not in February
+            let difference = jan.difference(feb.clone());
+            assert_eq!(sorted_edges(&difference), vec![(1, 2)]);
+            // 3 only has edge updates and its edge 2 -> 3 is also in February
+            assert_eq!(difference.count_nodes(), 2);
+            assert_eq!(difference.edge(1, 2).unwrap().history(), vec![1]);
+
+            let difference = feb.difference(jan);
+            assert_eq!(sorted_edges(&difference), vec![(3, 4)]);
+            assert_eq!(
+                difference.edge(3, 4).unwrap().properties().get("value"),
+                Some(Prop::I64(5))
+            );
+        });
+    }
+
+    #[test]
+    fn test_overlapping_windows_and_composition() {
+        let graph = load_graph();
+
+        test_storage!(&graph, |graph| {
+            let early = graph.window(0, 12);
+            let late = graph.window(2, 20);
+
+            assert_eq!(early.intersection(late.clone()).count_edges(), 1);
+            assert_eq!(early.union(late.clone()).count_edges(), 3);
+            assert_eq!(early.difference(late.clone()).count_edges(), 1);
+
+            // set operations compose with further windows and filters
+            let union = early.union(late);
+            assert_eq!(sorted_edges(&union.window(10, 20)), vec![(2, 3), (3, 4)]);
+            assert_eq!(
+                union.window(10, 20).edge(3, 4).unwrap().history(),
+                vec![12, 13]
+            );
+            let filtered = union
+                .filter_edges(PropertyFilter::property("value").gt(3i64))
+                .unwrap();
+            assert_eq!(sorted_edges(&filtered), vec![(3, 4)]);
+            let subgraph = union.subgraph([1, 2, 3]);
+            assert_eq!(sorted_edges(&subgraph), vec![(1, 2), (2, 3)]);
+        });
+    }
+
+    #[test]
+    fn test_layers() {
+        let graph = Graph::new();
+        graph.add_edge(0, 1, 2, NO_PROPS, Some("email")).unwrap();
+        graph.add_edge(1, 1, 2, NO_PROPS, Some("chat")).unwrap();
+        graph.add_edge(2, 2, 3, NO_PROPS, Some("chat")).unwrap();
+        graph.add_edge(3, 3, 4, NO_PROPS, Some("email")).unwrap();
+
+        test_storage!(&graph, |graph| {
+            let email = graph.layers("email").unwrap();
+            let chat = graph.layers("chat").unwrap();
+
+            let intersection = email.intersection(chat.clone());
+            assert_eq!(sorted_edges(&intersection), vec![(1, 2)]);
+            assert_eq!(
+                intersection.edge(1, 2).unwrap().layer_names(),
+                ["email", "chat"]
+            );
+
+            let difference = email.difference(chat.clone());
+            assert_eq!(sorted_edges(&difference), vec![(3, 4)]);
+            assert_eq!(
+                difference.nodes().id().iter_values().sorted().collect_vec(),
+                [3, 4].map(GID::U64)
+            );
+
+            let union = email.union(chat);
+            assert_eq!(union.count_edges(), 3);
+            assert_eq!(union.window(1, 3).count_edges(), 2);
+        });
+    }
+}
@@ -378,6 +378,17 @@ impl<'graph, G: GraphViewOps<'graph>> InternalTemporalPropertyViewOps for Window
             )
             .map(|(_, p)| p)
     }
+
+    fn temporal_window(&self) -> (Option<i64>, Option<i64>) {
+        (self.start, self.end)
+    }
+
+    fn temporal_value_before_window(&self, id: usize) -> Option<Prop> {
+        let start = self.start?;
+        self.graph
+            .temporal_prop_last_at(id, TimeIndexEntry::end(start.saturating_sub(1)))
+            .map(|(_, p)| p)
+    }
 }
 
 impl<'graph, G: GraphViewOps<'graph>> InternalTemporalPropertiesOps for WindowedGraph<G> {
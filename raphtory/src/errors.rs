@@ -206,6 +206,9 @@ pub enum GraphError {
     #[error("Node {0} does not exist")]
     NodeMissingError(GID),
 
+    #[error("Nodes do not exist with IDs: {0:?}")]
+    NodesMissingError(Vec<GID>),
+
     #[error("Node Type Error {0}")]
     NodeTypeError(String),
 
@@ -438,6 +441,31 @@ pub enum GraphError {
 
     #[error("Cannot initialize cache for zipped graph. Unzip the graph to initialize the cache.")]
     ZippedGraphCannotBeCached,
+
+    #[error("Invalid generator parameters: {0}")]
+    InvalidGeneratorParameters(String),
+
+    #[error("Invalid algorithm parameters: {0}")]
+    InvalidAlgorithmParameters(String),
+
+    #[error("Algorithm did not converge after {0} iterations")]
+    AlgorithmNotConverged(usize),
+
+    #[error("Centrality did not converge after {iterations} iterations, the residual {residual} is above the tolerance")]
+    CentralityNotConverged { iterations: usize, residual: f64 },
+
+    #[error("Node {missing} of the pair ({src}, {dst}) does not exist")]
+    NodePairMissingError { src: GID, dst: GID, missing: GID },
+
+    #[error("Property of type {0} is not numeric")]
+    NonNumericProperty(PropType),
+
+    #[error("Edge list column '{column}' has {actual} rows, expected {expected}")]
+    EdgeListLengthMismatch {
+        column: String,
+        expected: usize,
+        actual: usize,
+    },
 }
 
 impl From<MetadataError> for GraphError {
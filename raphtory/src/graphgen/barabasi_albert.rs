@@ -0,0 +1,243 @@
+//! Generates a scale-free graph using the Barabási–Albert model
+//!
+//! This function is a graph generation model based upon:
+//! Barabási, Albert-László, and Réka Albert. "Emergence of scaling in random networks." science 286.5439 (1999): 509-512.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::barabasi_albert::barabasi_albert;
+//!
+//! let graph = Graph::new();
+//! barabasi_albert(&graph, 1000, 3, None).unwrap();
+//! ```
+
+use super::{next_id, PropertySpec};
+use crate::{
+    db::{
+        api::{mutation::AdditionOps, view::*},
+        graph::graph::Graph,
+    },
+    errors::GraphError,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use raphtory_api::core::entities::GID;
+
+/// Generates a graph using the Barabási–Albert preferential attachment model.
+///
+/// The generator starts from `m` unconnected nodes. Every subsequent node is attached to `m`
+/// distinct existing nodes, chosen with probability proportional to their degree.
+/// Degrees are tracked with a list in which every node appears once per incident edge, so
+/// sampling a target is a single uniform draw and the whole generation runs in O(n·m).
+///
+/// Every node addition and every edge addition receives its own timestamp, starting after
+/// the latest time already in the graph, so the result is a proper temporal network.
+///
+/// **Note:** `n_nodes` new nodes are added to the graph, existing nodes are not used as targets.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `n_nodes` - The total number of nodes to add to the graph
+/// * `m` - The number of edges each new node attaches to the existing network
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `m` is 0 or `m >= n_nodes`, in which case
+/// the graph is not modified. Otherwise returns the first [GraphError] raised while adding a node
+/// or an edge. Generation stops at that point, so the graph only contains the updates made before
+/// the failure.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::barabasi_albert::barabasi_albert;
+///
+/// let graph = Graph::new();
+/// barabasi_albert(&graph, 1000, 3, None).unwrap();
+/// ```
+pub fn barabasi_albert(
+    graph: &Graph,
+    n_nodes: usize,
+    m: usize,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    barabasi_albert_with_props(graph, n_nodes, m, PropertySpec::new(), seed)
+}
+
+/// Like [barabasi_albert] but every new node and edge gets the random properties described by
+/// `props`, drawn from the same seeded rng as the rest of the graph.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] in the same cases as [barabasi_albert] and if
+/// `props` is invalid. Otherwise returns the first [GraphError] raised while adding a node or
+/// an edge, e.g., if a generated property clashes with an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     barabasi_albert::barabasi_albert_with_props, PropDistribution, PropertySpec,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// barabasi_albert_with_props(&graph, 1000, 3, props, None).unwrap();
+/// ```
+pub fn barabasi_albert_with_props(
+    graph: &Graph,
+    n_nodes: usize,
+    m: usize,
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
+    if m == 0 || m >= n_nodes {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "barabasi_albert requires 0 < m < n_nodes, got m = {m} and n_nodes = {n_nodes}"
+        )));
+    }
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut latest_time = graph.latest_time().unwrap_or(0);
+    let mut ids: Vec<GID> = Vec::with_capacity(n_nodes);
+    let mut repeated_nodes: Vec<usize> = Vec::with_capacity(2 * m * (n_nodes - m));
+    let mut targets: Vec<usize> = (0..m).collect();
+    let mut node_id = next_id(graph, None);
+
+    for _ in 0..m {
+        latest_time += 1;
+        graph.add_node(latest_time, &node_id, props.node_props(&mut rng), None)?;
+        ids.push(node_id.clone());
+        node_id = next_id(graph, Some(node_id));
+    }
+
+    for _ in m..n_nodes {
+        if !repeated_nodes.is_empty() {
+            targets.clear();
+            while targets.len() < m {
+                let candidate = *repeated_nodes
+                    .choose(&mut rng)
+                    .expect("repeated_nodes is not empty");
+                if !targets.contains(&candidate) {
+                    targets.push(candidate);
+                }
+            }
+        }
+
+        latest_time += 1;
+        graph.add_node(latest_time, &node_id, props.node_props(&mut rng), None)?;
+        for &target in &targets {
+            latest_time += 1;
+            let edge_props = props.edge_props(&mut rng);
+            graph.add_edge(latest_time, &node_id, &ids[target], edge_props, None)?;
+        }
+
+        let source = ids.len();
+        repeated_nodes.extend(targets.iter().copied());
+        repeated_nodes.extend(std::iter::repeat(source).take(m));
+        ids.push(node_id.clone());
+        node_id = next_id(graph, Some(node_id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod barabasi_albert_tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn node_and_edge_counts() {
+        let graph = Graph::new();
+        barabasi_albert(&graph, 1000, 4, None).unwrap();
+        assert_eq!(graph.count_nodes(), 1000);
+        assert_eq!(graph.count_edges(), (1000 - 4) * 4);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let graph = Graph::new();
+        for (n_nodes, m) in [(10, 0), (3, 3), (3, 5)] {
+            assert!(matches!(
+                barabasi_albert(&graph, n_nodes, m, None),
+                Err(GraphError::InvalidGeneratorParameters(_))
+            ));
+        }
+        assert_eq!(graph.count_nodes(), 0);
+    }
+
+    #[test]
+    fn timestamps_are_increasing() {
+        let graph = Graph::new();
+        barabasi_albert(&graph, 100, 2, Some([3; 32])).unwrap();
+        let mut times: Vec<i64> = graph
+            .nodes()
+            .earliest_time()
+            .iter_values()
+            .flatten()
+            .chain(graph.edges().explode().time().flatten())
+            .collect();
+        let len = times.len();
+        times.sort();
+        times.dedup();
+        assert_eq!(times.len(), len);
+    }
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let g1 = Graph::new();
+        let g2 = Graph::new();
+        barabasi_albert(&g1, 500, 3, Some([7; 32])).unwrap();
+        barabasi_albert(&g2, 500, 3, Some([7; 32])).unwrap();
+        let edges = |g: &Graph| {
+            g.edges()
+                .explode()
+                .iter()
+                .map(|e| (e.src().id(), e.dst().id(), e.time().unwrap()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(edges(&g1), edges(&g2));
+    }
+
+    #[test]
+    fn degree_distribution_follows_power_law() {
+        let graph = Graph::new();
+        let m = 3;
+        barabasi_albert(&graph, 10_000, m, Some([1; 32])).unwrap();
+
+        let mut counts: BTreeMap<usize, usize> = BTreeMap::new();
+        for degree in graph.nodes().degree().iter_values() {
+            *counts.entry(degree).or_default() += 1;
+        }
+        let n = graph.count_nodes() as f64;
+
+        // fit log(P(K >= k)) = -alpha * log(k) + c, for BA the tail exponent alpha is 2
+        let mut remaining = n;
+        let mut points = vec![];
+        for (&degree, &count) in counts.iter() {
+            if degree >= m && degree <= 50 {
+                points.push(((degree as f64).ln(), (remaining / n).ln()));
+            }
+            remaining -= count as f64;
+        }
+        let len = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / len;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / len;
+        let cov: f64 = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let var: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        let slope = cov / var;
+        assert!(
+            (-3.0..-1.5).contains(&slope),
+            "expected a power-law tail with exponent close to 2, got {slope}"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Generates a graph with a prescribed degree sequence using the configuration model
+//!
+//! This function is a graph generation model based upon:
+//! Newman, Mark EJ. "The structure and function of complex networks." SIAM review 45.2 (2003): 167-256.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::configuration_model::{configuration_model, MultiEdgePolicy};
+//!
+//! let graph = Graph::new();
+//! configuration_model(&graph, &[3, 2, 2, 2, 1], MultiEdgePolicy::Skip, None).unwrap();
+//! ```
+
+use super::{add_nodes_with, PropertySpec, TimestampStrategy, Timestamps};
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    errors::GraphError,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// How [configuration_model] handles stub pairs that would create a self-loop or a multi-edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiEdgePolicy {
+    /// Keep self-loops and multi-edges, the degree sequence is matched exactly
+    Allow,
+    /// Drop every pair that would create a self-loop or a multi-edge
+    Skip,
+    /// Swap the second stub of an invalid pair with a random unmatched stub up to the given
+    /// number of times before dropping the pair
+    Retry(usize),
+}
+
+/// Generates a graph using the stub-matching configuration model.
+///
+/// One new node is added to the graph for every entry of `degree_sequence`, and each node gets
+/// as many stubs as its degree. The stubs are shuffled and consecutive stubs are paired into
+/// edges. Edges are treated as undirected, the first stub of each pair is used as the source.
+/// A multi-edge is a second edge between the same two nodes in either direction and is added as
+/// another update of the existing edge.
+///
+/// Every node and edge addition receives its own timestamp, starting after the latest time
+/// already in the graph.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `degree_sequence` - The degree of each new node
+/// * `policy` - How self-loops and multi-edges are handled, see [MultiEdgePolicy]
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if the sum of `degree_sequence` is odd.
+/// Otherwise returns the first [GraphError] raised while adding a node or an edge.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::configuration_model::{configuration_model, MultiEdgePolicy};
+///
+/// let graph = Graph::new();
+/// configuration_model(&graph, &[1, 1, 1, 1], MultiEdgePolicy::Allow, None).unwrap();
+/// assert_eq!(graph.count_temporal_edges(), 2);
+/// ```
+pub fn configuration_model(
+    graph: &Graph,
+    degree_sequence: &[usize],
+    policy: MultiEdgePolicy,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    configuration_model_with_props(graph, degree_sequence, policy, PropertySpec::new(), seed)
+}
+
+/// Like [configuration_model] but every new node and edge gets the random properties described by
+/// `props`, drawn from the same seeded rng as the rest of the graph.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] in the same cases as
+/// [configuration_model] and if `props` is invalid. Otherwise returns the first
+/// [GraphError] raised while adding a node or an edge, e.g., if a generated property clashes
+/// with an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     configuration_model::{configuration_model_with_props, MultiEdgePolicy},
+///     PropDistribution, PropertySpec,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// configuration_model_with_props(&graph, &[3, 2, 2, 2, 1], MultiEdgePolicy::Skip, props, None)
+///     .unwrap();
+/// ```
+pub fn configuration_model_with_props(
+    graph: &Graph,
+    degree_sequence: &[usize],
+    policy: MultiEdgePolicy,
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
+    let degree_sum: usize = degree_sequence.iter().sum();
+    if degree_sum % 2 != 0 {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "the sum of the degree sequence needs to be even, got {degree_sum}"
+        )));
+    }
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut timestamps = Timestamps::new(graph, TimestampStrategy::Sequential)?;
+    let ids = add_nodes_with(graph, degree_sequence.len(), &mut timestamps, || {
+        props.node_props(&mut rng)
+    })?;
+
+    let mut stubs: Vec<usize> = degree_sequence
+        .iter()
+        .enumerate()
+        .flat_map(|(node, &degree)| std::iter::repeat(node).take(degree))
+        .collect();
+    stubs.shuffle(&mut rng);
+
+    let max_retries = match policy {
+        MultiEdgePolicy::Allow => None,
+        MultiEdgePolicy::Skip => Some(0),
+        MultiEdgePolicy::Retry(retries) => Some(retries),
+    };
+    let mut edges: HashSet<(usize, usize)> = HashSet::new();
+    for i in (0..stubs.len()).step_by(2) {
+        let src = stubs[i];
+        if let Some(max_retries) = max_retries {
+            let is_valid =
+                |dst: usize| src != dst && !edges.contains(&(src.min(dst), src.max(dst)));
+            let mut retries = 0;
+            while !is_valid(stubs[i + 1]) && retries < max_retries && i + 2 < stubs.len() {
+                let swap = rng.gen_range(i + 2..stubs.len());
+                stubs.swap(i + 1, swap);
+                retries += 1;
+            }
+            if !is_valid(stubs[i + 1]) {
+                continue;
+            }
+        }
+        let dst = stubs[i + 1];
+        edges.insert((src.min(dst), src.max(dst)));
+        let time = timestamps.edge_time(&mut rng);
+        graph.add_edge(time, &ids[src], &ids[dst], props.edge_props(&mut rng), None)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod configuration_model_tests {
+    use super::*;
+    use crate::prelude::*;
+    use itertools::Itertools;
+
+    /// Degrees counting every edge update, so self-loops count twice and multi-edges are kept
+    fn degree_sequence(graph: &Graph) -> Vec<usize> {
+        graph
+            .nodes()
+            .iter()
+            .sorted_by_key(|node| node.id())
+            .map(|node| node.out_edges().explode().len() + node.in_edges().explode().len())
+            .collect()
+    }
+
+    #[test]
+    fn allow_matches_degree_sequence() {
+        let degrees = vec![5, 3, 3, 2, 2, 2, 1, 1, 1, 4];
+        let graph = Graph::new();
+        configuration_model(&graph, &degrees, MultiEdgePolicy::Allow, Some([6; 32])).unwrap();
+        assert_eq!(graph.count_nodes(), degrees.len());
+        assert_eq!(degree_sequence(&graph), degrees);
+    }
+
+    #[test]
+    fn skip_has_no_self_loops_or_multi_edges() {
+        let degrees = vec![6; 20];
+        let graph = Graph::new();
+        configuration_model(&graph, &degrees, MultiEdgePolicy::Skip, Some([2; 32])).unwrap();
+        assert!(graph.edges().iter().all(|e| e.src().id() != e.dst().id()));
+        assert_eq!(graph.count_temporal_edges(), graph.count_edges());
+        assert!(graph
+            .edges()
+            .iter()
+            .all(|e| !graph.has_edge(e.dst().id(), e.src().id())));
+        assert!(degree_sequence(&graph)
+            .iter()
+            .zip(&degrees)
+            .all(|(actual, expected)| actual <= expected));
+    }
+
+    #[test]
+    fn retry_has_no_self_loops_or_multi_edges() {
+        let degrees = vec![8; 12];
+        let graph = Graph::new();
+        configuration_model(&graph, &degrees, MultiEdgePolicy::Retry(10), Some([4; 32])).unwrap();
+        assert!(graph.edges().iter().all(|e| e.src().id() != e.dst().id()));
+        assert_eq!(graph.count_temporal_edges(), graph.count_edges());
+        assert!(graph.count_edges() <= 12 * 8 / 2);
+    }
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let degrees = vec![3; 30];
+        let edges = |seed| {
+            let graph = Graph::new();
+            configuration_model(&graph, &degrees, MultiEdgePolicy::Allow, Some(seed)).unwrap();
+            graph
+                .edges()
+                .explode()
+                .iter()
+                .map(|e| (e.src().id(), e.dst().id(), e.time().unwrap()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(edges([1; 32]), edges([1; 32]));
+    }
+
+    #[test]
+    fn odd_degree_sum() {
+        let graph = Graph::new();
+        assert!(matches!(
+            configuration_model(&graph, &[2, 2, 1], MultiEdgePolicy::Allow, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+}
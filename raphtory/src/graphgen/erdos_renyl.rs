@@ -0,0 +1,520 @@
+//! Generates a graph using the Erdős–Rényi random graph model
+//!
+//! This function is a graph generation model based upon:
+//! Erdős, Paul, and Alfréd Rényi. "On random graphs I." Publicationes Mathematicae Debrecen 6 (1959): 290-297.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::{erdos_renyl::erdos_renyl, TimestampStrategy};
+//!
+//! let graph = Graph::new();
+//! erdos_renyl(&graph, 100, 0.05, TimestampStrategy::Sequential, None, None).unwrap();
+//! ```
+
+use super::{add_nodes_with, chunk_seed, PropertySpec, TimestampStrategy, Timestamps};
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    errors::GraphError,
+};
+use rand::{rngs::StdRng, seq::index, Rng, SeedableRng};
+use raphtory_api::core::entities::GID;
+use rayon::prelude::*;
+
+/// Generates a graph using the G(n, p) variant of the Erdős–Rényi model.
+///
+/// `n_nodes` new nodes are added to the graph. Afterwards, every ordered pair of distinct new
+/// nodes is connected independently with probability `p`, i.e., both `(a, b)` and `(b, a)` are
+/// considered. Use [erdos_renyl_undirected] to consider every unordered pair only once.
+///
+/// The timestamps of the new nodes and edges are chosen by `time_strategy`, with
+/// [TimestampStrategy::Sequential] every node and edge addition receives its own timestamp,
+/// starting after the latest time already in the graph.
+///
+/// If `props` is set, every new node and edge gets the properties it describes, drawn from the
+/// same seeded rng that assigns the edge timestamps.
+///
+/// The edges are sampled in parallel. The new nodes are split into chunks of 1024 consecutive
+/// source nodes and the edges leaving each chunk are drawn by their own rng, seeded with `seed`
+/// where the last 8 bytes (read as a little-endian `u64`) are xor-ed with the chunk index + 1.
+/// The edges are then added one after the other in (source, destination) order, with timestamps
+/// and properties drawn from an rng seeded with `seed` itself. The generated graph, including
+/// the order in which its edges are added, therefore only depends on `seed`, not on the number
+/// of threads. Without a seed a random one is drawn first.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `n_nodes` - The number of nodes to add to the graph
+/// * `p` - The probability of adding each edge
+/// * `time_strategy` - How the timestamps of the new nodes and edges are chosen
+/// * `props` - (Optional) The random properties of the new nodes and edges, Default None
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `p` is not in `[0, 1]` or if
+/// `time_strategy` or `props` is invalid. Otherwise returns the first [GraphError] raised while adding a node or an edge.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{erdos_renyl::erdos_renyl, TimestampStrategy};
+///
+/// let graph = Graph::new();
+/// erdos_renyl(&graph, 10, 1.0, TimestampStrategy::Sequential, None, None).unwrap();
+/// assert_eq!(graph.count_edges(), 90);
+/// ```
+pub fn erdos_renyl(
+    graph: &Graph,
+    n_nodes: usize,
+    p: f64,
+    time_strategy: TimestampStrategy,
+    props: Option<PropertySpec>,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    gnp(graph, n_nodes, p, time_strategy, props, seed, false)
+}
+
+/// Generates an undirected graph using the G(n, p) variant of the Erdős–Rényi model.
+///
+/// This behaves like [erdos_renyl] but every unordered pair of distinct new nodes is only
+/// considered once, so the expected number of edges is `p * n * (n - 1) / 2` (this matches
+/// `networkx.gnp_random_graph`). Raphtory edges are always directed, by convention the node
+/// with the smaller id is used as the source of each edge. Edges are sampled in parallel with
+/// the same deterministic seeding scheme as [erdos_renyl].
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `n_nodes` - The number of nodes to add to the graph
+/// * `p` - The probability of adding each edge
+/// * `time_strategy` - How the timestamps of the new nodes and edges are chosen
+/// * `props` - (Optional) The random properties of the new nodes and edges, Default None
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `p` is not in `[0, 1]` or if
+/// `time_strategy` or `props` is invalid. Otherwise returns the first [GraphError] raised while adding a node or an edge.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{erdos_renyl::erdos_renyl_undirected, TimestampStrategy};
+///
+/// let graph = Graph::new();
+/// erdos_renyl_undirected(&graph, 10, 1.0, TimestampStrategy::Sequential, None, None).unwrap();
+/// assert_eq!(graph.count_edges(), 45);
+/// ```
+pub fn erdos_renyl_undirected(
+    graph: &Graph,
+    n_nodes: usize,
+    p: f64,
+    time_strategy: TimestampStrategy,
+    props: Option<PropertySpec>,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    gnp(graph, n_nodes, p, time_strategy, props, seed, true)
+}
+
+/// Number of source nodes whose edges are sampled by the same rng
+const SOURCES_PER_CHUNK: usize = 1024;
+
+fn gnp(
+    graph: &Graph,
+    n_nodes: usize,
+    p: f64,
+    time_strategy: TimestampStrategy,
+    props: Option<PropertySpec>,
+    seed: Option<[u8; 32]>,
+    undirected: bool,
+) -> Result<(), GraphError> {
+    if !(0.0..=1.0).contains(&p) {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "edge probability must be in [0, 1], got {p}"
+        )));
+    }
+    let props = props.unwrap_or_default();
+    props.validate()?;
+    let seed = seed.unwrap_or_else(|| StdRng::from_entropy().gen());
+    let mut rng = StdRng::from_seed(seed);
+    let mut timestamps = Timestamps::new(graph, time_strategy)?;
+    let ids = add_nodes_with(graph, n_nodes, &mut timestamps, || {
+        props.node_props(&mut rng)
+    })?;
+
+    let mut pairs: Vec<(usize, usize)> = (0..n_nodes.div_ceil(SOURCES_PER_CHUNK))
+        .into_par_iter()
+        .flat_map_iter(|chunk| {
+            let mut rng = StdRng::from_seed(chunk_seed(seed, chunk));
+            let end = ((chunk + 1) * SOURCES_PER_CHUNK).min(n_nodes);
+            (chunk * SOURCES_PER_CHUNK..end)
+                .flat_map(|src| sample_row(&mut rng, n_nodes, src, p, undirected))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    pairs.sort_unstable();
+
+    // edges are added one after the other, so their ids follow the sorted order
+    for (src, dst) in pairs {
+        let time = timestamps.edge_time(&mut rng);
+        let (src_id, dst_id) = edge_ids(&ids[src], &ids[dst], undirected);
+        graph.add_edge(time, src_id, dst_id, props.edge_props(&mut rng), None)?;
+    }
+    Ok(())
+}
+
+/// Sample the destinations of `src` with geometrically distributed skips between the accepted
+/// candidates, which is equivalent to an independent draw with probability `p` for each of them
+fn sample_row(
+    rng: &mut StdRng,
+    n_nodes: usize,
+    src: usize,
+    p: f64,
+    undirected: bool,
+) -> Vec<(usize, usize)> {
+    let candidates = if undirected {
+        n_nodes - src - 1
+    } else {
+        n_nodes - 1
+    };
+    let mut row = vec![];
+    if p <= 0.0 {
+        return row;
+    }
+    let log_q = (1.0 - p).ln();
+    let mut position = 0usize;
+    loop {
+        let skip = ((1.0 - rng.gen::<f64>()).ln() / log_q).floor() as usize;
+        position = position.saturating_add(skip);
+        if position >= candidates {
+            return row;
+        }
+        let dst = if undirected {
+            src + 1 + position
+        } else if position < src {
+            position
+        } else {
+            position + 1
+        };
+        row.push((src, dst));
+        position += 1;
+    }
+}
+
+fn edge_ids<'a>(src: &'a GID, dst: &'a GID, undirected: bool) -> (&'a GID, &'a GID) {
+    if undirected && dst < src {
+        (dst, src)
+    } else {
+        (src, dst)
+    }
+}
+
+/// Generates a graph using the G(n, m) variant of the Erdős–Rényi model.
+///
+/// `n_nodes` new nodes are added to the graph, followed by exactly `n_edges` edges chosen
+/// uniformly at random from all ordered pairs of distinct new nodes. Pairs are sampled without
+/// replacement, so the generated graph has neither self-loops nor duplicate edges.
+///
+/// Every node and edge addition receives its own timestamp, starting after the latest time
+/// already in the graph.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `n_nodes` - The number of nodes to add to the graph
+/// * `n_edges` - The exact number of edges to add between the new nodes
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `n_edges > n_nodes * (n_nodes - 1)`.
+/// Otherwise returns the first [GraphError] raised while adding a node or an edge.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::erdos_renyl::erdos_renyl_gnm;
+///
+/// let graph = Graph::new();
+/// erdos_renyl_gnm(&graph, 100, 500, None).unwrap();
+/// assert_eq!(graph.count_edges(), 500);
+/// ```
+pub fn erdos_renyl_gnm(
+    graph: &Graph,
+    n_nodes: usize,
+    n_edges: usize,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    erdos_renyl_gnm_with_props(graph, n_nodes, n_edges, PropertySpec::new(), seed)
+}
+
+/// Like [erdos_renyl_gnm] but every new node and edge gets the random properties described by
+/// `props`, drawn from the same seeded rng as the rest of the graph.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] in the same cases as [erdos_renyl_gnm] and if
+/// `props` is invalid. Otherwise returns the first [GraphError] raised while adding a node or
+/// an edge, e.g., if a generated property clashes with an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     erdos_renyl::erdos_renyl_gnm_with_props, PropDistribution, PropertySpec,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// erdos_renyl_gnm_with_props(&graph, 100, 500, props, None).unwrap();
+/// ```
+pub fn erdos_renyl_gnm_with_props(
+    graph: &Graph,
+    n_nodes: usize,
+    n_edges: usize,
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
+    let max_edges = n_nodes
+        .checked_mul(n_nodes.saturating_sub(1))
+        .unwrap_or(usize::MAX);
+    if n_edges > max_edges {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "cannot sample {n_edges} edges, {n_nodes} nodes support at most {max_edges} edges"
+        )));
+    }
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut timestamps = Timestamps::new(graph, TimestampStrategy::Sequential)?;
+    let ids = add_nodes_with(graph, n_nodes, &mut timestamps, || {
+        props.node_props(&mut rng)
+    })?;
+
+    // pair index `i` maps to source `i / (n - 1)` and the `i % (n - 1)`-th other node
+    let mut pairs = index::sample(&mut rng, max_edges, n_edges).into_vec();
+    pairs.sort_unstable();
+    for pair in pairs {
+        let src = pair / (n_nodes - 1);
+        let offset = pair % (n_nodes - 1);
+        let dst = if offset >= src { offset + 1 } else { offset };
+        let time = timestamps.edge_time(&mut rng);
+        graph.add_edge(time, &ids[src], &ids[dst], props.edge_props(&mut rng), None)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod erdos_renyl_tests {
+    use super::*;
+    use crate::{db::graph::graph::assert_graph_equal, graphgen::PropDistribution, prelude::*};
+    use std::collections::HashSet;
+
+    fn edge_set(g: &Graph) -> HashSet<(GID, GID)> {
+        g.edges().id().collect()
+    }
+
+    #[test]
+    fn gnp_full_probability() {
+        let graph = Graph::new();
+        erdos_renyl(&graph, 20, 1.0, TimestampStrategy::Sequential, None, None).unwrap();
+        assert_eq!(graph.count_nodes(), 20);
+        assert_eq!(graph.count_edges(), 20 * 19);
+    }
+
+    #[test]
+    fn gnp_zero_probability() {
+        let graph = Graph::new();
+        erdos_renyl(&graph, 20, 0.0, TimestampStrategy::Sequential, None, None).unwrap();
+        assert_eq!(graph.count_nodes(), 20);
+        assert_eq!(graph.count_edges(), 0);
+    }
+
+    #[test]
+    fn gnp_undirected_full_probability() {
+        let graph = Graph::new();
+        erdos_renyl_undirected(&graph, 20, 1.0, TimestampStrategy::Sequential, None, None).unwrap();
+        assert_eq!(graph.count_nodes(), 20);
+        assert_eq!(graph.count_edges(), 20 * 19 / 2);
+        assert!(graph.edges().id().all(|(src, dst)| src < dst));
+    }
+
+    #[test]
+    fn gnp_seeded_is_deterministic() {
+        let g1 = Graph::new();
+        let g2 = Graph::new();
+        erdos_renyl_undirected(
+            &g1,
+            50,
+            0.2,
+            TimestampStrategy::Sequential,
+            None,
+            Some([3; 32]),
+        )
+        .unwrap();
+        erdos_renyl_undirected(
+            &g2,
+            50,
+            0.2,
+            TimestampStrategy::Sequential,
+            None,
+            Some([3; 32]),
+        )
+        .unwrap();
+        assert_eq!(edge_set(&g1), edge_set(&g2));
+    }
+
+    #[test]
+    fn gnp_seeded_adds_identical_exploded_edges() {
+        let exploded_edges = || {
+            let graph = Graph::new();
+            let strategy = TimestampStrategy::UniformRange { start: 0, end: 100 };
+            erdos_renyl(&graph, 60, 0.1, strategy, None, Some([8; 32])).unwrap();
+            graph
+                .edges()
+                .explode()
+                .iter()
+                .map(|e| (e.src().id(), e.dst().id(), e.time().unwrap()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(exploded_edges(), exploded_edges());
+    }
+
+    #[test]
+    fn gnp_seeded_is_independent_of_threads() {
+        let generate = |threads| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            let graph = Graph::new();
+            pool.install(|| {
+                erdos_renyl(
+                    &graph,
+                    3000,
+                    0.002,
+                    TimestampStrategy::Sequential,
+                    None,
+                    Some([7; 32]),
+                )
+            })
+            .unwrap();
+            graph
+        };
+        assert_graph_equal(&generate(1), &generate(4));
+    }
+
+    #[test]
+    fn gnp_expected_edge_count() {
+        let graph = Graph::new();
+        erdos_renyl(
+            &graph,
+            2000,
+            0.01,
+            TimestampStrategy::Sequential,
+            None,
+            Some([2; 32]),
+        )
+        .unwrap();
+        let expected = 0.01 * 2000.0 * 1999.0;
+        let actual = graph.count_edges() as f64;
+        assert!((actual - expected).abs() < 0.05 * expected);
+        assert!(graph.edges().iter().all(|e| e.src().id() != e.dst().id()));
+    }
+
+    #[test]
+    fn gnp_random_properties() {
+        let graph = Graph::new();
+        let props = PropertySpec::new()
+            .with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0))
+            .with_node_prop(
+                "category",
+                PropDistribution::Choice(vec!["a".into(), "b".into(), "c".into()]),
+            );
+        erdos_renyl(
+            &graph,
+            50,
+            0.2,
+            TimestampStrategy::Sequential,
+            Some(props),
+            Some([5; 32]),
+        )
+        .unwrap();
+        assert!(graph.count_edges() > 0);
+        assert!(graph.edges().iter().all(|e| e
+            .properties()
+            .get("weight")
+            .and_then(|weight| weight.as_f64())
+            .is_some_and(|weight| (0.0..1.0).contains(&weight))));
+        assert!(graph.nodes().iter().all(|node| node
+            .properties()
+            .get("category")
+            .is_some_and(|category| ["a", "b", "c"].map(Prop::from).contains(&category))));
+    }
+
+    #[test]
+    fn gnp_invalid_properties() {
+        let graph = Graph::new();
+        let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Choice(vec![]));
+        assert!(matches!(
+            erdos_renyl(
+                &graph,
+                10,
+                0.5,
+                TimestampStrategy::Sequential,
+                Some(props),
+                None
+            ),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+
+    #[test]
+    fn gnp_invalid_probability() {
+        let graph = Graph::new();
+        assert!(matches!(
+            erdos_renyl(&graph, 10, 1.5, TimestampStrategy::Sequential, None, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+    }
+
+    #[test]
+    fn gnm_exact_edge_count() {
+        let graph = Graph::new();
+        erdos_renyl_gnm(&graph, 100, 1234, Some([4; 32])).unwrap();
+        assert_eq!(graph.count_nodes(), 100);
+        assert_eq!(graph.count_edges(), 1234);
+        assert_eq!(graph.count_temporal_edges(), 1234);
+        assert!(graph.edges().iter().all(|e| e.src().id() != e.dst().id()));
+    }
+
+    #[test]
+    fn gnm_complete_graph() {
+        let graph = Graph::new();
+        erdos_renyl_gnm(&graph, 10, 90, None).unwrap();
+        assert_eq!(graph.count_edges(), 90);
+    }
+
+    #[test]
+    fn gnm_seeded_is_deterministic() {
+        let g1 = Graph::new();
+        let g2 = Graph::new();
+        erdos_renyl_gnm(&g1, 50, 200, Some([9; 32])).unwrap();
+        erdos_renyl_gnm(&g2, 50, 200, Some([9; 32])).unwrap();
+        assert_eq!(edge_set(&g1), edge_set(&g2));
+    }
+
+    #[test]
+    fn gnm_too_many_edges() {
+        let graph = Graph::new();
+        let result = erdos_renyl_gnm(&graph, 10, 91, None);
+        assert!(matches!(
+            result,
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+}
@@ -0,0 +1,237 @@
+//! Generates a graph using the forest fire model
+//!
+//! This function is a graph generation model based upon:
+//! Leskovec, Jure, Jon Kleinberg, and Christos Faloutsos. "Graphs over time: densification laws, shrinking diameters and possible explanations." Proceedings of the eleventh ACM SIGKDD international conference on Knowledge discovery in data mining. 2005.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::forest_fire::forest_fire;
+//!
+//! let graph = Graph::new();
+//! forest_fire(&graph, 500, 0.3, 0.2, None).unwrap();
+//! ```
+
+use super::{next_id, PropertySpec, TimestampStrategy, Timestamps};
+use crate::{
+    db::{
+        api::{mutation::AdditionOps, view::*},
+        graph::graph::Graph,
+    },
+    errors::GraphError,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use raphtory_api::core::entities::GID;
+use std::collections::{HashSet, VecDeque};
+
+/// Generates a graph using the forest fire model.
+///
+/// `n_nodes` new nodes join the graph one after the other. Every new node picks an ambassador
+/// uniformly at random among the nodes that joined before it and links to it. The fire then
+/// spreads from every burned node: each of its out-neighbours is burned with probability
+/// `forward_prob` and each of its in-neighbours with probability `backward_prob`. The new node
+/// links to every burned node and no node is burned twice by the same fire.
+///
+/// The neighbours are looked up on the graph itself while it is being built. Every node and
+/// edge addition receives its own timestamp, starting after the latest time already in the graph.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `n_nodes` - The number of nodes to add to the graph
+/// * `forward_prob` - The probability of burning an out-neighbour
+/// * `backward_prob` - The probability of burning an in-neighbour
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `forward_prob` or `backward_prob` is not
+/// in `[0, 1]`. Otherwise returns the first [GraphError] raised while adding a node or an edge.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::forest_fire::forest_fire;
+///
+/// let graph = Graph::new();
+/// forest_fire(&graph, 100, 0.0, 0.0, None).unwrap();
+/// assert_eq!(graph.count_edges(), 99);
+/// ```
+pub fn forest_fire(
+    graph: &Graph,
+    n_nodes: usize,
+    forward_prob: f64,
+    backward_prob: f64,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    forest_fire_with_props(
+        graph,
+        n_nodes,
+        forward_prob,
+        backward_prob,
+        PropertySpec::new(),
+        seed,
+    )
+}
+
+/// Like [forest_fire] but every new node and edge gets the random properties described by
+/// `props`, drawn from the same seeded rng as the rest of the graph.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] in the same cases as [forest_fire] and if
+/// `props` is invalid. Otherwise returns the first [GraphError] raised while adding a node or
+/// an edge, e.g., if a generated property clashes with an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{forest_fire::forest_fire_with_props, PropDistribution, PropertySpec};
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// forest_fire_with_props(&graph, 100, 0.3, 0.2, props, None).unwrap();
+/// ```
+pub fn forest_fire_with_props(
+    graph: &Graph,
+    n_nodes: usize,
+    forward_prob: f64,
+    backward_prob: f64,
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
+    for (name, p) in [
+        ("forward_prob", forward_prob),
+        ("backward_prob", backward_prob),
+    ] {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(GraphError::InvalidGeneratorParameters(format!(
+                "{name} must be in [0, 1], got {p}"
+            )));
+        }
+    }
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut timestamps = Timestamps::new(graph, TimestampStrategy::Sequential)?;
+    let mut ids: Vec<GID> = Vec::with_capacity(n_nodes);
+    let mut node_id = next_id(graph, None);
+
+    for _ in 0..n_nodes {
+        let new_node = graph.add_node(
+            timestamps.node_time(),
+            &node_id,
+            props.node_props(&mut rng),
+            None,
+        )?;
+        if let Some(ambassador) = ids.choose(&mut rng).and_then(|id| graph.node(id)) {
+            let mut burned = HashSet::from([new_node.node, ambassador.node]);
+            let mut fire = VecDeque::from([ambassador.node]);
+            while let Some(vid) = fire.pop_front() {
+                let current = graph.node(vid).expect("burned nodes exist in the graph");
+                let time = timestamps.edge_time(&mut rng);
+                graph.add_edge(
+                    time,
+                    &node_id,
+                    &current.id(),
+                    props.edge_props(&mut rng),
+                    None,
+                )?;
+                let out_neighbours = current.out_neighbours().iter().map(|n| (n, forward_prob));
+                let in_neighbours = current.in_neighbours().iter().map(|n| (n, backward_prob));
+                for (neighbour, p) in out_neighbours.chain(in_neighbours) {
+                    if !burned.contains(&neighbour.node) && rng.gen_bool(p) {
+                        burned.insert(neighbour.node);
+                        fire.push_back(neighbour.node);
+                    }
+                }
+            }
+        }
+        ids.push(node_id.clone());
+        node_id = next_id(graph, Some(node_id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod forest_fire_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn mean_out_degree(forward_prob: f64) -> f64 {
+        let graph = Graph::new();
+        forest_fire(&graph, 300, forward_prob, 0.1, Some([3; 32])).unwrap();
+        graph.nodes().out_degree().mean()
+    }
+
+    #[test]
+    fn tree_without_burning() {
+        let graph = Graph::new();
+        forest_fire(&graph, 200, 0.0, 0.0, Some([1; 32])).unwrap();
+        assert_eq!(graph.count_nodes(), 200);
+        assert_eq!(graph.count_edges(), 199);
+        assert!(graph.nodes().out_degree().iter_values().all(|d| d <= 1));
+    }
+
+    #[test]
+    fn out_degree_grows_with_forward_prob() {
+        let degrees: Vec<f64> = [0.0, 0.2, 0.4].into_iter().map(mean_out_degree).collect();
+        assert!(degrees[0] < degrees[1]);
+        assert!(degrees[1] < degrees[2]);
+    }
+
+    #[test]
+    fn edges_grow_super_linearly() {
+        // densification: the number of edges per node increases as the graph grows
+        let edges_per_node: Vec<f64> = [100, 200, 400]
+            .into_iter()
+            .map(|n_nodes| {
+                let graph = Graph::new();
+                forest_fire(&graph, n_nodes, 0.2, 0.1, Some([5; 32])).unwrap();
+                graph.count_edges() as f64 / n_nodes as f64
+            })
+            .collect();
+        assert!(
+            edges_per_node.windows(2).all(|w| w[0] < w[1]),
+            "edges per node {edges_per_node:?} do not increase with the number of nodes"
+        );
+    }
+
+    #[test]
+    fn no_self_loops_or_multi_edges() {
+        let graph = Graph::new();
+        forest_fire(&graph, 300, 0.35, 0.3, Some([7; 32])).unwrap();
+        assert!(graph.edges().iter().all(|e| e.src().id() != e.dst().id()));
+        assert_eq!(graph.count_edges(), graph.count_temporal_edges());
+    }
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let edges = |seed| {
+            let graph = Graph::new();
+            forest_fire(&graph, 200, 0.3, 0.2, Some(seed)).unwrap();
+            graph
+                .edges()
+                .explode()
+                .iter()
+                .map(|e| (e.src().id(), e.dst().id(), e.time().unwrap()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(edges([9; 32]), edges([9; 32]));
+    }
+
+    #[test]
+    fn invalid_probability() {
+        let graph = Graph::new();
+        assert!(matches!(
+            forest_fire(&graph, 10, 1.5, 0.1, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert!(matches!(
+            forest_fire(&graph, 10, 0.1, -0.1, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+    }
+}
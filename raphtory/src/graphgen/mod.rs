@@ -1,12 +1,22 @@
 //! Provides functionality for generating graphs for testing and benchmarking.
 
 use rand::Rng;
+use rand_distr::{Distribution, Exp};
 use raphtory_api::core::entities::GID;
 
-use crate::prelude::*;
+use crate::{errors::GraphError, prelude::*};
 
+pub mod barabasi_albert;
+pub mod configuration_model;
+pub mod erdos_renyl;
+pub mod forest_fire;
 pub mod preferential_attachment;
 pub mod random_attachment;
+pub mod random_bipartite;
+pub mod random_geometric;
+pub mod rmat;
+pub mod stochastic_block_model;
+pub mod watts_strogatz;
 
 pub(crate) fn next_id<'graph, G: GraphViewOps<'graph>>(g: &G, max_gid: Option<GID>) -> GID {
     let max_gid = max_gid.unwrap_or_else(|| g.nodes().id().max().unwrap_or(GID::U64(0)));
@@ -23,3 +33,308 @@ pub(crate) fn next_id<'graph, G: GraphViewOps<'graph>>(g: &G, max_gid: Option<GI
         }
     }
 }
+
+/// Seed for the rng of `chunk` in generators that sample in parallel, the chunk index + 1 is
+/// mixed into the last 8 bytes of the seed
+pub(crate) fn chunk_seed(seed: [u8; 32], chunk: usize) -> [u8; 32] {
+    let mut chunk_seed = seed;
+    let mut tail = [0u8; 8];
+    tail.copy_from_slice(&seed[24..]);
+    let mixed = u64::from_le_bytes(tail) ^ (chunk as u64 + 1);
+    chunk_seed[24..].copy_from_slice(&mixed.to_le_bytes());
+    chunk_seed
+}
+
+/// Add `n_nodes` new nodes at the node times of `timestamps`, every node is added with the
+/// properties returned by `props`. Returns the ids of the new nodes
+pub(crate) fn add_nodes_with(
+    graph: &Graph,
+    n_nodes: usize,
+    timestamps: &mut Timestamps,
+    mut props: impl FnMut() -> Vec<(String, Prop)>,
+) -> Result<Vec<GID>, GraphError> {
+    let mut ids = Vec::with_capacity(n_nodes);
+    let mut node_id = next_id(graph, None);
+    for _ in 0..n_nodes {
+        graph.add_node(timestamps.node_time(), &node_id, props(), None)?;
+        ids.push(node_id.clone());
+        node_id = next_id(graph, Some(node_id));
+    }
+    Ok(ids)
+}
+
+/// How a generator assigns timestamps to the nodes and edges it adds to the graph
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TimestampStrategy {
+    /// Every update gets its own time, counting up from the latest time already in the graph
+    #[default]
+    Sequential,
+    /// Edge times are drawn uniformly from `[start, end)`, nodes are added at `start`
+    UniformRange { start: i64, end: i64 },
+    /// The gaps between consecutive edge times are drawn from an exponential distribution with
+    /// the given `rate`, starting from `start`. Nodes are added at the time of the latest edge
+    Exponential { rate: f64, start: i64 },
+}
+
+/// Produces the timestamps of a [TimestampStrategy]
+///
+/// Node times are never later than the edge times produced after them, so edges never
+/// reference nodes that do not exist yet.
+pub(crate) struct Timestamps {
+    clock: Clock,
+    current: i64,
+}
+
+enum Clock {
+    Sequential,
+    Uniform { start: i64, end: i64 },
+    Exponential(Exp<f64>),
+}
+
+impl Timestamps {
+    pub(crate) fn new(graph: &Graph, strategy: TimestampStrategy) -> Result<Self, GraphError> {
+        let (clock, current) = match strategy {
+            TimestampStrategy::Sequential => (Clock::Sequential, graph.latest_time().unwrap_or(0)),
+            TimestampStrategy::UniformRange { start, end } => {
+                if start >= end {
+                    return Err(GraphError::InvalidGeneratorParameters(format!(
+                        "timestamp range [{start}, {end}) is empty"
+                    )));
+                }
+                (Clock::Uniform { start, end }, start)
+            }
+            TimestampStrategy::Exponential { rate, start } => {
+                if !(rate > 0.0 && rate.is_finite()) {
+                    return Err(GraphError::InvalidGeneratorParameters(format!(
+                        "exponential rate needs to be positive and finite, got {rate}"
+                    )));
+                }
+                let gaps = Exp::new(rate)
+                    .map_err(|err| GraphError::InvalidGeneratorParameters(err.to_string()))?;
+                (Clock::Exponential(gaps), start)
+            }
+        };
+        Ok(Self { clock, current })
+    }
+
+    /// The current time of the clock without advancing it, this is `start` for
+    /// [TimestampStrategy::UniformRange]
+    pub(crate) fn current(&self) -> i64 {
+        self.current
+    }
+
+    /// The time for adding a new node
+    pub(crate) fn node_time(&mut self) -> i64 {
+        if let Clock::Sequential = self.clock {
+            self.current += 1;
+        }
+        self.current
+    }
+
+    /// The time for adding a new edge
+    pub(crate) fn edge_time<R: Rng>(&mut self, rng: &mut R) -> i64 {
+        match &self.clock {
+            Clock::Sequential => self.current += 1,
+            Clock::Uniform { start, end } => return rng.gen_range(*start..*end),
+            Clock::Exponential(gaps) => self.current += gaps.sample(rng).round() as i64,
+        }
+        self.current
+    }
+
+    /// Non-decreasing times for `n_steps` steps of a growth model
+    pub(crate) fn step_times<R: Rng>(&mut self, n_steps: usize, rng: &mut R) -> Vec<i64> {
+        let mut times: Vec<i64> = (0..n_steps).map(|_| self.edge_time(rng)).collect();
+        if let Clock::Uniform { .. } = self.clock {
+            times.sort_unstable();
+        }
+        times
+    }
+}
+
+/// A distribution that generated property values are drawn from
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropDistribution {
+    /// A `f64` drawn uniformly from `[low, high)`
+    Uniform(f64, f64),
+    /// An `i64` drawn uniformly from `[low, high)`
+    UniformInt(i64, i64),
+    /// One of the given values, chosen uniformly
+    Choice(Vec<Prop>),
+}
+
+impl PropDistribution {
+    fn validate(&self, name: &str) -> Result<(), GraphError> {
+        let is_valid = match self {
+            PropDistribution::Uniform(low, high) => low < high,
+            PropDistribution::UniformInt(low, high) => low < high,
+            PropDistribution::Choice(values) => !values.is_empty(),
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            Err(GraphError::InvalidGeneratorParameters(format!(
+                "cannot sample property {name} from {self:?}"
+            )))
+        }
+    }
+
+    fn sample<R: Rng>(&self, rng: &mut R) -> Prop {
+        match self {
+            PropDistribution::Uniform(low, high) => Prop::F64(rng.gen_range(*low..*high)),
+            PropDistribution::UniformInt(low, high) => Prop::I64(rng.gen_range(*low..*high)),
+            PropDistribution::Choice(values) => values[rng.gen_range(0..values.len())].clone(),
+        }
+    }
+}
+
+/// Describes the random properties a generator attaches to the nodes and edges it adds
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::graphgen::{PropDistribution, PropertySpec};
+///
+/// let spec = PropertySpec::new()
+///     .with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0))
+///     .with_node_prop(
+///         "category",
+///         PropDistribution::Choice(vec!["a".into(), "b".into(), "c".into()]),
+///     );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PropertySpec {
+    node_props: Vec<(String, PropDistribution)>,
+    edge_props: Vec<(String, PropDistribution)>,
+}
+
+impl PropertySpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sample the property `name` from `distribution` for every new node
+    pub fn with_node_prop(
+        mut self,
+        name: impl Into<String>,
+        distribution: PropDistribution,
+    ) -> Self {
+        self.node_props.push((name.into(), distribution));
+        self
+    }
+
+    /// Sample the property `name` from `distribution` for every new edge
+    pub fn with_edge_prop(
+        mut self,
+        name: impl Into<String>,
+        distribution: PropDistribution,
+    ) -> Self {
+        self.edge_props.push((name.into(), distribution));
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), GraphError> {
+        self.node_props
+            .iter()
+            .chain(&self.edge_props)
+            .try_for_each(|(name, distribution)| distribution.validate(name))
+    }
+
+    /// Whether any property is sampled for the new nodes
+    pub(crate) fn has_node_props(&self) -> bool {
+        !self.node_props.is_empty()
+    }
+
+    pub(crate) fn node_props<R: Rng>(&self, rng: &mut R) -> Vec<(String, Prop)> {
+        sample_props(&self.node_props, rng)
+    }
+
+    pub(crate) fn edge_props<R: Rng>(&self, rng: &mut R) -> Vec<(String, Prop)> {
+        sample_props(&self.edge_props, rng)
+    }
+}
+
+fn sample_props<R: Rng>(props: &[(String, PropDistribution)], rng: &mut R) -> Vec<(String, Prop)> {
+    props
+        .iter()
+        .map(|(name, distribution)| (name.clone(), distribution.sample(rng)))
+        .collect()
+}
+
+#[cfg(test)]
+mod timestamp_strategy_tests {
+    use super::{
+        erdos_renyl::erdos_renyl, preferential_attachment::ba_preferential_attachment,
+        random_attachment::random_attachment, TimestampStrategy,
+    };
+    use crate::{errors::GraphError, prelude::*};
+
+    fn nodes_precede_edges(graph: &Graph) -> bool {
+        graph.edges().explode().iter().all(|e| {
+            let time = e.time().unwrap();
+            e.src().earliest_time().unwrap() <= time && e.dst().earliest_time().unwrap() <= time
+        })
+    }
+
+    #[test]
+    fn uniform_range_spreads_edges_over_windows() {
+        let graph = Graph::new();
+        let strategy = TimestampStrategy::UniformRange {
+            start: 0,
+            end: 1000,
+        };
+        erdos_renyl(&graph, 50, 0.1, strategy, None, Some([1; 32])).unwrap();
+        assert!(graph
+            .edges()
+            .explode()
+            .time()
+            .flatten()
+            .all(|t| (0..1000).contains(&t)));
+        let non_empty = graph
+            .rolling(100, None)
+            .unwrap()
+            .filter(|window| window.count_edges() > 0)
+            .count();
+        assert!(non_empty > 1);
+        assert!(nodes_precede_edges(&graph));
+    }
+
+    #[test]
+    fn attachment_models_keep_nodes_before_edges() {
+        let strategies = [
+            TimestampStrategy::UniformRange { start: 5, end: 500 },
+            TimestampStrategy::Exponential {
+                rate: 0.5,
+                start: 10,
+            },
+        ];
+        for strategy in strategies {
+            let graph = Graph::new();
+            random_attachment(&graph, 200, 3, strategy, Some([2; 32])).unwrap();
+            assert!(nodes_precede_edges(&graph));
+
+            let graph = Graph::new();
+            ba_preferential_attachment(&graph, 200, 3, strategy, Some([3; 32])).unwrap();
+            assert!(nodes_precede_edges(&graph));
+            assert!(graph.latest_time().unwrap() > graph.earliest_time().unwrap());
+        }
+    }
+
+    #[test]
+    fn invalid_strategies() {
+        let graph = Graph::new();
+        let empty_range = TimestampStrategy::UniformRange { start: 5, end: 5 };
+        assert!(matches!(
+            erdos_renyl(&graph, 10, 0.5, empty_range, None, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        let negative_rate = TimestampStrategy::Exponential {
+            rate: -1.0,
+            start: 0,
+        };
+        assert!(matches!(
+            random_attachment(&graph, 10, 2, negative_rate, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+}
@@ -6,23 +6,23 @@
 //!
 //! ```
 //! use raphtory::prelude::*;
-//! use raphtory::graphgen::preferential_attachment::ba_preferential_attachment;
+//! use raphtory::graphgen::{preferential_attachment::ba_preferential_attachment, TimestampStrategy};
 //!
 //! let graph = Graph::new();
-//! ba_preferential_attachment(&graph, 1000, 10, None);
+//! ba_preferential_attachment(&graph, 1000, 10, TimestampStrategy::Sequential, None).unwrap();
 //! ```
 
-use super::next_id;
+use super::{next_id, PropertySpec, TimestampStrategy, Timestamps};
 use crate::{
     db::{
         api::{mutation::AdditionOps, view::*},
         graph::graph::Graph,
     },
-    prelude::{NodeStateOps, NO_PROPS},
+    errors::GraphError,
+    prelude::NodeStateOps,
 };
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::collections::HashSet;
-use tracing::error;
 
 /// Generates a graph using the preferential attachment model.
 ///
@@ -33,6 +33,8 @@ use tracing::error;
 /// For this model the neighbours are chosen proportionally based upon their degree, favouring
 /// nodes with higher degree (more connections).
 /// This sampling is conducted without replacement.
+/// All edges of a step share one timestamp, the step times never decrease and are chosen by
+/// `time_strategy`.
 ///
 /// **Note:**  If the provided graph doesnt have enough nodes/edges for the initial sample,
 /// the min number of both will be added before generation begins.
@@ -41,29 +43,84 @@ use tracing::error;
 /// * `graph` - The graph you wish to add nodes and edges to
 /// * `nodes_to_add` - The amount of nodes you wish to add to the graph (steps)
 /// * `edges_per_step` - The amount of edges a joining node should add to the graph
+/// * `time_strategy` - How the timestamps of the new nodes and edges are chosen
 /// * `seed` - an optional byte array for the seed used in rng, can be None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `time_strategy` is invalid.
+/// Otherwise returns the first [GraphError] raised while adding a node or an edge. Generation
+/// stops at that point, so the graph only contains the updates made before the failure.
+///
 /// # Examples
 ///
 /// ```
 /// use raphtory::prelude::*;
-/// use raphtory::graphgen::preferential_attachment::ba_preferential_attachment;
+/// use raphtory::graphgen::{
+///     preferential_attachment::ba_preferential_attachment, TimestampStrategy,
+/// };
 ///
 /// let graph = Graph::new();
-/// ba_preferential_attachment(&graph, 1000, 10, None);
+/// ba_preferential_attachment(&graph, 1000, 10, TimestampStrategy::Sequential, None).unwrap();
 /// ```
 pub fn ba_preferential_attachment(
     graph: &Graph,
     nodes_to_add: usize,
     edges_per_step: usize,
+    time_strategy: TimestampStrategy,
     seed: Option<[u8; 32]>,
-) {
+) -> Result<(), GraphError> {
+    ba_preferential_attachment_with_props(
+        graph,
+        nodes_to_add,
+        edges_per_step,
+        time_strategy,
+        PropertySpec::new(),
+        seed,
+    )
+}
+
+/// Like [ba_preferential_attachment] but every new node and edge gets the random properties
+/// described by `props`, drawn from the same seeded rng that picks the neighbours.
+///
+/// Nodes that join in a step are added explicitly at the step time if `props` has node
+/// properties, so they carry them as well.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `time_strategy` or `props` is invalid.
+/// Otherwise returns the first [GraphError] raised while adding a node or an edge, e.g., if a
+/// generated property clashes with the type of an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     preferential_attachment::ba_preferential_attachment_with_props, PropDistribution,
+///     PropertySpec, TimestampStrategy,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// let strategy = TimestampStrategy::Sequential;
+/// ba_preferential_attachment_with_props(&graph, 100, 2, strategy, props, None).unwrap();
+/// ```
+pub fn ba_preferential_attachment_with_props(
+    graph: &Graph,
+    nodes_to_add: usize,
+    edges_per_step: usize,
+    time_strategy: TimestampStrategy,
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
     let mut rng: StdRng;
     if let Some(seed_value) = seed {
         rng = StdRng::from_seed(seed_value);
     } else {
         rng = StdRng::from_entropy();
     }
-    let mut latest_time = graph.latest_time().unwrap_or(0);
+    let mut timestamps = Timestamps::new(graph, time_strategy)?;
+    let initial_time = timestamps.current();
     let view = graph;
     let mut ids = graph.nodes().id().iter_values().collect::<Vec<_>>();
     let mut degrees: Vec<usize> = view.nodes().degree().iter_values().collect();
@@ -73,28 +130,23 @@ pub fn ba_preferential_attachment(
 
     while ids.len() < edges_per_step {
         max_id = next_id(view, Some(max_id));
-        graph
-            .add_node(latest_time, &max_id, NO_PROPS, None)
-            .map_err(|err| error!("{:?}", err))
-            .ok();
+        graph.add_node(initial_time, &max_id, props.node_props(&mut rng), None)?;
         degrees.push(0);
         ids.push(max_id.clone());
     }
 
     if graph.count_edges() < edges_per_step {
         for pos in 1..ids.len() {
-            graph
-                .add_edge(latest_time, &ids[pos], &ids[pos - 1], NO_PROPS, None)
-                .expect("Not able to add edge");
+            let edge_props = props.edge_props(&mut rng);
+            graph.add_edge(initial_time, &ids[pos], &ids[pos - 1], edge_props, None)?;
             edge_count += 2;
             degrees[pos] += 1;
             degrees[pos - 1] += 1;
         }
     }
 
-    for _ in 0..nodes_to_add {
+    for time in timestamps.step_times(nodes_to_add, &mut rng) {
         max_id = next_id(view, Some(max_id));
-        latest_time += 1;
         let mut normalisation = edge_count;
         let mut positions_to_skip: HashSet<usize> = HashSet::new();
 
@@ -112,29 +164,39 @@ pub fn ba_preferential_attachment(
                 }
             }
         }
-        for pos in positions_to_skip {
+        if props.has_node_props() {
+            graph.add_node(time, &max_id, props.node_props(&mut rng), None)?;
+        }
+        // visit the targets in a fixed order so that seeded properties are reproducible
+        let mut targets: Vec<usize> = positions_to_skip.into_iter().collect();
+        targets.sort_unstable();
+        for pos in targets {
             let dst = &ids[pos];
             degrees[pos] += 1;
-            graph
-                .add_edge(latest_time, &max_id, dst, NO_PROPS, None)
-                .expect("Not able to add edge");
+            graph.add_edge(time, &max_id, dst, props.edge_props(&mut rng), None)?;
         }
         ids.push(max_id.clone());
         degrees.push(edges_per_step);
         edge_count += edges_per_step * 2;
     }
+    Ok(())
 }
 
 //TODO need to benchmark the creation of these networks
 #[cfg(test)]
 mod preferential_attachment_tests {
     use super::*;
-    use crate::graphgen::random_attachment::random_attachment;
+    use crate::{
+        graphgen::{random_attachment::random_attachment, PropDistribution},
+        prelude::*,
+    };
     use raphtory_api::core::utils::logging::global_info_logger;
+    use raphtory_storage::mutation::MutationError;
+    use tracing::error;
     #[test]
     fn blank_graph() {
         let graph = Graph::new();
-        ba_preferential_attachment(&graph, 1000, 10, None);
+        ba_preferential_attachment(&graph, 1000, 10, TimestampStrategy::Sequential, None).unwrap();
         assert_eq!(graph.count_edges(), 10009);
         assert_eq!(graph.count_nodes(), 1010);
     }
@@ -150,7 +212,7 @@ mod preferential_attachment_tests {
                 .ok();
         }
 
-        ba_preferential_attachment(&graph, 1000, 5, None);
+        ba_preferential_attachment(&graph, 1000, 5, TimestampStrategy::Sequential, None).unwrap();
         assert_eq!(graph.count_edges(), 5009);
         assert_eq!(graph.count_nodes(), 1010);
     }
@@ -158,9 +220,51 @@ mod preferential_attachment_tests {
     #[test]
     fn prior_graph() {
         let graph = Graph::new();
-        random_attachment(&graph, 1000, 3, None);
-        ba_preferential_attachment(&graph, 500, 4, None);
+        random_attachment(&graph, 1000, 3, TimestampStrategy::Sequential, None).unwrap();
+        ba_preferential_attachment(&graph, 500, 4, TimestampStrategy::Sequential, None).unwrap();
         assert_eq!(graph.count_edges(), 5000);
         assert_eq!(graph.count_nodes(), 1503);
     }
+
+    #[test]
+    fn with_props() {
+        let graph = Graph::new();
+        let props =
+            PropertySpec::new().with_edge_prop("weight", PropDistribution::UniformInt(1, 10));
+        ba_preferential_attachment_with_props(
+            &graph,
+            200,
+            3,
+            TimestampStrategy::Sequential,
+            props,
+            Some([4; 32]),
+        )
+        .unwrap();
+        assert!(graph.edges().iter().all(|e| matches!(
+            e.properties().get("weight"),
+            Some(Prop::I64(weight)) if (1..10).contains(&weight)
+        )));
+    }
+
+    #[test]
+    fn failed_addition_is_returned() {
+        let graph = Graph::new();
+        graph.add_node(0, 1, [("category", "a")], None).unwrap();
+        let props =
+            PropertySpec::new().with_node_prop("category", PropDistribution::UniformInt(0, 3));
+        let result = ba_preferential_attachment_with_props(
+            &graph,
+            10,
+            2,
+            TimestampStrategy::Sequential,
+            props,
+            Some([1; 32]),
+        );
+        // the generated property clashes with the type of the existing one
+        assert!(matches!(
+            result,
+            Err(GraphError::MutationError(MutationError::PropError(_)))
+        ));
+        assert_eq!(graph.count_nodes(), 1);
+    }
 }
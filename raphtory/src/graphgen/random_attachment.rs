@@ -8,9 +8,9 @@
 //!
 //! ```
 //! use raphtory::prelude::*;
-//! use raphtory::graphgen::random_attachment::random_attachment;
+//! use raphtory::graphgen::{random_attachment::random_attachment, TimestampStrategy};
 //! let graph = Graph::new();
-//! random_attachment(&graph, 1000, 10, None);
+//! random_attachment(&graph, 1000, 10, TimestampStrategy::Sequential, None).unwrap();
 //! ```
 
 use crate::{
@@ -18,12 +18,12 @@ use crate::{
         api::{mutation::AdditionOps, view::*},
         graph::graph::Graph,
     },
-    prelude::{NodeStateOps, NO_PROPS},
+    errors::GraphError,
+    prelude::NodeStateOps,
 };
 use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
-use tracing::error;
 
-use super::next_id;
+use super::{next_id, PropertySpec, TimestampStrategy, Timestamps};
 
 /// Given a graph this function will add a user defined number of nodes, each with a
 /// user defined number of edges.
@@ -32,6 +32,9 @@ use super::next_id;
 /// For this model the neighbours are chosen purely at random. This sampling is done
 /// without replacement.
 ///
+/// All edges of a step share one timestamp, the step times never decrease and are chosen by
+/// `time_strategy`.
+///
 /// **Note:**  If the provided graph doesnt have enough nodes/edges for the initial sample,
 /// the min number of both will be added before generation begins.
 ///
@@ -39,63 +42,123 @@ use super::next_id;
 /// * `graph` - The graph you wish to add nodes and edges to
 /// * `nodes_to_add` - The amount of nodes you wish to add to the graph (steps)
 /// * `edges_per_step` - The amount of edges a joining node should add to the graph
+/// * `time_strategy` - How the timestamps of the new nodes and edges are chosen
 /// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `time_strategy` is invalid.
+/// Otherwise returns the first [GraphError] raised while adding a node or an edge. Generation
+/// stops at that point, so the graph only contains the updates made before the failure.
+///
 /// # Examples
 ///
 /// ```
 /// use raphtory::prelude::*;
-/// use raphtory::graphgen::random_attachment::random_attachment;
+/// use raphtory::graphgen::{random_attachment::random_attachment, TimestampStrategy};
 /// let graph = Graph::new();
-/// random_attachment(&graph, 1000, 10, None);
+/// random_attachment(&graph, 1000, 10, TimestampStrategy::Sequential, None).unwrap();
 /// ```
 pub fn random_attachment(
     graph: &Graph,
     nodes_to_add: usize,
     edges_per_step: usize,
+    time_strategy: TimestampStrategy,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    random_attachment_with_props(
+        graph,
+        nodes_to_add,
+        edges_per_step,
+        time_strategy,
+        PropertySpec::new(),
+        seed,
+    )
+}
+
+/// Like [random_attachment] but every new node and edge gets the random properties described by
+/// `props`, drawn from the same seeded rng that picks the neighbours.
+///
+/// Nodes that join in a step are added explicitly at the step time if `props` has node
+/// properties, so they carry them as well.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `time_strategy` or `props` is invalid.
+/// Otherwise returns the first [GraphError] raised while adding a node or an edge, e.g., if a
+/// generated property clashes with the type of an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     random_attachment::random_attachment_with_props, PropDistribution, PropertySpec,
+///     TimestampStrategy,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// random_attachment_with_props(&graph, 100, 2, TimestampStrategy::Sequential, props, None)
+///     .unwrap();
+/// assert!(graph.edges().iter().all(|e| e.properties().get("weight").is_some()));
+/// ```
+pub fn random_attachment_with_props(
+    graph: &Graph,
+    nodes_to_add: usize,
+    edges_per_step: usize,
+    time_strategy: TimestampStrategy,
+    props: PropertySpec,
     seed: Option<[u8; 32]>,
-) {
+) -> Result<(), GraphError> {
+    props.validate()?;
     let mut rng: StdRng;
     if let Some(seed_value) = seed {
         rng = StdRng::from_seed(seed_value);
     } else {
         rng = StdRng::from_entropy();
     }
-    let mut latest_time = graph.latest_time().unwrap_or(0);
+    let mut timestamps = Timestamps::new(graph, time_strategy)?;
     let mut ids = graph.nodes().id().iter_values().collect::<Vec<_>>();
     let mut max_id = next_id(graph, ids.iter().max().cloned());
 
     while ids.len() < edges_per_step {
         max_id = next_id(graph, Some(max_id));
-        latest_time += 1;
-        graph
-            .add_node(latest_time, &max_id, NO_PROPS, None)
-            .map_err(|err| error!("{:?}", err))
-            .ok();
+        graph.add_node(
+            timestamps.node_time(),
+            &max_id,
+            props.node_props(&mut rng),
+            None,
+        )?;
         ids.push(max_id.clone());
     }
 
-    for _ in 0..nodes_to_add {
+    for time in timestamps.step_times(nodes_to_add, &mut rng) {
         let edges = ids.choose_multiple(&mut rng, edges_per_step);
         max_id = next_id(graph, Some(max_id));
-        latest_time += 1;
-        edges.for_each(|neighbour| {
-            graph
-                .add_edge(latest_time, &max_id, neighbour, NO_PROPS, None)
-                .expect("Not able to add edge");
-        });
+        if props.has_node_props() {
+            graph.add_node(time, &max_id, props.node_props(&mut rng), None)?;
+        }
+        for neighbour in edges {
+            graph.add_edge(time, &max_id, neighbour, props.edge_props(&mut rng), None)?;
+        }
         ids.push(max_id.clone());
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod random_graph_test {
     use super::*;
-    use crate::graphgen::preferential_attachment::ba_preferential_attachment;
+    use crate::{
+        graphgen::{preferential_attachment::ba_preferential_attachment, PropDistribution},
+        prelude::*,
+    };
     use raphtory_api::core::utils::logging::global_info_logger;
+    use raphtory_storage::mutation::MutationError;
+    use tracing::error;
     #[test]
     fn blank_graph() {
         let graph = Graph::new();
-        random_attachment(&graph, 100, 20, None);
+        random_attachment(&graph, 100, 20, TimestampStrategy::Sequential, None).unwrap();
         assert_eq!(graph.count_edges(), 2000);
         assert_eq!(graph.count_nodes(), 120);
     }
@@ -111,16 +174,83 @@ mod random_graph_test {
                 .ok();
         }
 
-        random_attachment(&graph, 1000, 5, None);
+        random_attachment(&graph, 1000, 5, TimestampStrategy::Sequential, None).unwrap();
         assert_eq!(graph.count_edges(), 5000);
         assert_eq!(graph.count_nodes(), 1010);
     }
 
+    #[test]
+    fn string_ids() {
+        let graph = Graph::new();
+        graph.add_node(0, "Alice", NO_PROPS, None).unwrap();
+        graph.add_node(1, "Bob", NO_PROPS, None).unwrap();
+
+        // the new ids follow the type of the existing ids
+        random_attachment(&graph, 50, 2, TimestampStrategy::Sequential, Some([2; 32])).unwrap();
+        assert_eq!(graph.count_edges(), 100);
+        assert_eq!(graph.count_nodes(), 52);
+        assert!(graph
+            .nodes()
+            .id()
+            .iter_values()
+            .all(|id| id.as_str().is_some()));
+    }
+
+    #[test]
+    fn failed_addition_is_returned() {
+        let graph = Graph::new();
+        graph
+            .add_edge(0, 1, 2, [("weight", "heavy")], None)
+            .unwrap();
+        let props =
+            PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+        let result = random_attachment_with_props(
+            &graph,
+            10,
+            1,
+            TimestampStrategy::Sequential,
+            props,
+            Some([1; 32]),
+        );
+        // the generated property clashes with the type of the existing one
+        assert!(matches!(
+            result,
+            Err(GraphError::MutationError(MutationError::PropError(_)))
+        ));
+        assert_eq!(graph.count_edges(), 1);
+    }
+
+    #[test]
+    fn with_props() {
+        let graph = Graph::new();
+        let props = PropertySpec::new()
+            .with_node_prop("age", PropDistribution::UniformInt(18, 80))
+            .with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+        random_attachment_with_props(
+            &graph,
+            100,
+            3,
+            TimestampStrategy::Sequential,
+            props,
+            Some([5; 32]),
+        )
+        .unwrap();
+        assert_eq!(graph.count_edges(), 300);
+        assert!(graph.edges().iter().all(|e| matches!(
+            e.properties().get("weight"),
+            Some(Prop::F64(weight)) if (0.0..1.0).contains(&weight)
+        )));
+        assert!(graph.nodes().iter().all(|node| matches!(
+            node.properties().get("age"),
+            Some(Prop::I64(age)) if (18..80).contains(&age)
+        )));
+    }
+
     #[test]
     fn prior_graph() {
         let graph = Graph::new();
-        ba_preferential_attachment(&graph, 300, 7, None);
-        random_attachment(&graph, 4000, 12, None);
+        ba_preferential_attachment(&graph, 300, 7, TimestampStrategy::Sequential, None).unwrap();
+        random_attachment(&graph, 4000, 12, TimestampStrategy::Sequential, None).unwrap();
         assert_eq!(graph.count_edges(), 50106);
         assert_eq!(graph.count_nodes(), 4307);
     }
@@ -0,0 +1,194 @@
+//! Generates a random bipartite graph
+//!
+//! This function is a graph generation model based upon the bipartite variant of the G(n, p)
+//! model, see:
+//! Newman, Mark EJ, Steven H. Strogatz, and Duncan J. Watts. "Random graphs with arbitrary degree distributions and their applications." Physical review E 64.2 (2001): 026118.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::random_bipartite::random_bipartite;
+//!
+//! let graph = Graph::new();
+//! random_bipartite(&graph, 100, 50, 0.1, None).unwrap();
+//! ```
+
+use super::{next_id, PropertySpec, TimestampStrategy, Timestamps};
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    errors::GraphError,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use raphtory_api::core::entities::GID;
+
+/// The node type of the nodes in the left partition
+pub const LEFT: &str = "left";
+/// The node type of the nodes in the right partition
+pub const RIGHT: &str = "right";
+
+/// Generates a random bipartite graph.
+///
+/// `n_left` new nodes of type `"left"` and `n_right` new nodes of type `"right"` are added to the
+/// graph. Afterwards, every left node is connected to every right node independently with
+/// probability `p`. Edges always point from the left node to the right node and no edges are
+/// added within a partition.
+///
+/// Every node and edge addition receives its own timestamp, starting after the latest time
+/// already in the graph.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `n_left` - The number of nodes in the left partition
+/// * `n_right` - The number of nodes in the right partition
+/// * `p` - The probability of adding each left to right edge
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `p` is not in `[0, 1]`. Otherwise returns
+/// the first [GraphError] raised while adding a node or an edge.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::random_bipartite::random_bipartite;
+///
+/// let graph = Graph::new();
+/// random_bipartite(&graph, 3, 4, 1.0, None).unwrap();
+/// assert_eq!(graph.count_edges(), 12);
+/// assert_eq!(graph.nodes().type_filter(["left"]).len(), 3);
+/// ```
+pub fn random_bipartite(
+    graph: &Graph,
+    n_left: usize,
+    n_right: usize,
+    p: f64,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    random_bipartite_with_props(graph, n_left, n_right, p, PropertySpec::new(), seed)
+}
+
+/// Like [random_bipartite] but every new node and edge gets the random properties described by
+/// `props`, drawn from the same seeded rng as the rest of the graph.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] in the same cases as [random_bipartite] and if
+/// `props` is invalid. Otherwise returns the first [GraphError] raised while adding a node or
+/// an edge, e.g., if a generated property clashes with an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     random_bipartite::random_bipartite_with_props, PropDistribution, PropertySpec,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// random_bipartite_with_props(&graph, 30, 20, 0.1, props, None).unwrap();
+/// ```
+pub fn random_bipartite_with_props(
+    graph: &Graph,
+    n_left: usize,
+    n_right: usize,
+    p: f64,
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
+    if !(0.0..=1.0).contains(&p) {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "edge probability must be in [0, 1], got {p}"
+        )));
+    }
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut timestamps = Timestamps::new(graph, TimestampStrategy::Sequential)?;
+
+    let mut node_id = next_id(graph, None);
+    let mut add_partition = |size: usize, node_type: &str| {
+        let mut ids: Vec<GID> = Vec::with_capacity(size);
+        for _ in 0..size {
+            let time = timestamps.node_time();
+            graph.add_node(time, &node_id, props.node_props(&mut rng), Some(node_type))?;
+            ids.push(node_id.clone());
+            node_id = next_id(graph, Some(node_id.clone()));
+        }
+        Ok::<_, GraphError>(ids)
+    };
+    let left = add_partition(n_left, LEFT)?;
+    let right = add_partition(n_right, RIGHT)?;
+
+    for src in &left {
+        for dst in &right {
+            if rng.gen_bool(p) {
+                let time = timestamps.edge_time(&mut rng);
+                graph.add_edge(time, src, dst, props.edge_props(&mut rng), None)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod random_bipartite_tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn partitions_have_node_types() {
+        let graph = Graph::new();
+        random_bipartite(&graph, 30, 20, 0.2, Some([1; 32])).unwrap();
+        assert_eq!(graph.count_nodes(), 50);
+        assert_eq!(graph.nodes().type_filter([LEFT]).len(), 30);
+        assert_eq!(graph.nodes().type_filter([RIGHT]).len(), 20);
+    }
+
+    #[test]
+    fn edges_go_from_left_to_right() {
+        let graph = Graph::new();
+        random_bipartite(&graph, 40, 25, 0.3, Some([2; 32])).unwrap();
+        assert!(graph.count_edges() > 0);
+        assert!(graph.edges().iter().all(|e| {
+            e.src().node_type().as_deref() == Some(LEFT)
+                && e.dst().node_type().as_deref() == Some(RIGHT)
+        }));
+    }
+
+    #[test]
+    fn full_probability_is_complete_bipartite() {
+        let graph = Graph::new();
+        random_bipartite(&graph, 5, 7, 1.0, None).unwrap();
+        assert_eq!(graph.count_edges(), 35);
+        assert!(graph
+            .nodes()
+            .type_filter([LEFT])
+            .iter()
+            .all(|node| node.out_degree() == 7 && node.in_degree() == 0));
+    }
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let edges = |seed| {
+            let graph = Graph::new();
+            random_bipartite(&graph, 30, 30, 0.1, Some(seed)).unwrap();
+            graph.edges().id().collect::<HashSet<_>>()
+        };
+        assert_eq!(edges([4; 32]), edges([4; 32]));
+    }
+
+    #[test]
+    fn invalid_probability() {
+        let graph = Graph::new();
+        assert!(matches!(
+            random_bipartite(&graph, 3, 3, -0.5, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+}
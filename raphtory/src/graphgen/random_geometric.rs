@@ -0,0 +1,232 @@
+//! Generates a random geometric graph
+//!
+//! This function is a graph generation model based upon:
+//! Penrose, Mathew. "Random geometric graphs." Oxford University Press (2003).
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::random_geometric::random_geometric;
+//!
+//! let graph = Graph::new();
+//! random_geometric(&graph, 100, 0.2, 2, None).unwrap();
+//! ```
+
+use super::{next_id, PropertySpec, TimestampStrategy, Timestamps};
+use crate::{
+    db::{api::mutation::AdditionOps, graph::graph::Graph},
+    errors::GraphError,
+    prelude::IntoPropList,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Generates a random geometric graph.
+///
+/// `n_nodes` new nodes are placed uniformly at random in the unit cube `[0, 1]^dim` and each
+/// node is tagged with a `coords` metadata property holding its position as a list of `f64`.
+/// Afterwards, every unordered pair of distinct new nodes whose Euclidean distance is less than
+/// `radius` is connected. Raphtory edges are always directed, by convention the node that was
+/// added first is used as the source of each edge.
+///
+/// All pairs of nodes are compared, so the running time is quadratic in `n_nodes`.
+///
+/// Every node and edge addition receives its own timestamp, starting after the latest time
+/// already in the graph.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `n_nodes` - The number of nodes to add to the graph
+/// * `radius` - Nodes closer than this distance are connected
+/// * `dim` - The dimension of the unit cube the nodes are placed in
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `radius` is negative or not a number or
+/// if `dim` is 0. Otherwise returns the first [GraphError] raised while updating the graph.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::random_geometric::random_geometric;
+///
+/// let graph = Graph::new();
+/// random_geometric(&graph, 10, 2.0, 2, None).unwrap();
+/// assert_eq!(graph.count_edges(), 45);
+/// ```
+pub fn random_geometric(
+    graph: &Graph,
+    n_nodes: usize,
+    radius: f64,
+    dim: usize,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    random_geometric_with_props(graph, n_nodes, radius, dim, PropertySpec::new(), seed)
+}
+
+/// Like [random_geometric] but every new node and edge gets the random properties described by
+/// `props`, drawn from the same seeded rng as the rest of the graph.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] in the same cases as [random_geometric] and if
+/// `props` is invalid. Otherwise returns the first [GraphError] raised while adding a node or
+/// an edge, e.g., if a generated property clashes with an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     random_geometric::random_geometric_with_props, PropDistribution, PropertySpec,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// random_geometric_with_props(&graph, 100, 0.2, 2, props, None).unwrap();
+/// ```
+pub fn random_geometric_with_props(
+    graph: &Graph,
+    n_nodes: usize,
+    radius: f64,
+    dim: usize,
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
+    if radius.is_nan() || radius < 0.0 {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "radius must be non-negative, got {radius}"
+        )));
+    }
+    if dim == 0 {
+        return Err(GraphError::InvalidGeneratorParameters(
+            "dim must be at least 1".to_string(),
+        ));
+    }
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut timestamps = Timestamps::new(graph, TimestampStrategy::Sequential)?;
+
+    let mut nodes = Vec::with_capacity(n_nodes);
+    let mut node_id = next_id(graph, None);
+    for _ in 0..n_nodes {
+        let coords: Vec<f64> = (0..dim).map(|_| rng.gen::<f64>()).collect();
+        graph
+            .add_node(
+                timestamps.node_time(),
+                &node_id,
+                props.node_props(&mut rng),
+                None,
+            )?
+            .add_metadata([("coords", coords.iter().copied().into_prop_list())])?;
+        nodes.push((node_id.clone(), coords));
+        node_id = next_id(graph, Some(node_id));
+    }
+
+    let radius_squared = radius * radius;
+    for (i, (src, src_coords)) in nodes.iter().enumerate() {
+        for (dst, dst_coords) in &nodes[i + 1..] {
+            let distance_squared: f64 = src_coords
+                .iter()
+                .zip(dst_coords)
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum();
+            if distance_squared < radius_squared {
+                let time = timestamps.edge_time(&mut rng);
+                graph.add_edge(time, src, dst, props.edge_props(&mut rng), None)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod random_geometric_tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::collections::HashSet;
+
+    fn coords(graph: &Graph, node: GID) -> Vec<f64> {
+        match graph.node(node).unwrap().metadata().get("coords") {
+            Some(Prop::List(coords)) => coords.iter().map(|c| c.as_f64().unwrap()).collect(),
+            other => panic!("expected a list of coordinates, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn nodes_have_coordinates_in_unit_cube() {
+        let graph = Graph::new();
+        random_geometric(&graph, 30, 0.3, 3, Some([1; 32])).unwrap();
+        assert_eq!(graph.count_nodes(), 30);
+        for id in graph.nodes().id().iter_values() {
+            let coords = coords(&graph, id);
+            assert_eq!(coords.len(), 3);
+            assert!(coords.iter().all(|c| (0.0..=1.0).contains(c)));
+        }
+    }
+
+    #[test]
+    fn edges_connect_close_nodes() {
+        let graph = Graph::new();
+        let radius = 0.25;
+        random_geometric(&graph, 60, radius, 2, Some([2; 32])).unwrap();
+        let nodes: Vec<_> = graph.nodes().id().iter_values().collect();
+        for (i, a) in nodes.iter().enumerate() {
+            for b in &nodes[i + 1..] {
+                let distance = coords(&graph, a.clone())
+                    .iter()
+                    .zip(coords(&graph, b.clone()))
+                    .map(|(x, y)| (x - y) * (x - y))
+                    .sum::<f64>()
+                    .sqrt();
+                let connected = graph.has_edge(a, b) || graph.has_edge(b, a);
+                assert_eq!(connected, distance < radius);
+            }
+        }
+    }
+
+    #[test]
+    fn unit_radius_is_nearly_complete() {
+        let graph = Graph::new();
+        let n = 50;
+        random_geometric(&graph, n, 1.0, 2, Some([3; 32])).unwrap();
+        let pairs = n * (n - 1) / 2;
+        assert!(graph.count_edges() <= pairs);
+        assert!(graph.count_edges() as f64 > 0.9 * pairs as f64);
+    }
+
+    #[test]
+    fn zero_radius_has_no_edges() {
+        let graph = Graph::new();
+        random_geometric(&graph, 50, 0.0, 2, Some([4; 32])).unwrap();
+        assert_eq!(graph.count_nodes(), 50);
+        assert_eq!(graph.count_edges(), 0);
+    }
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let edges = |seed| {
+            let graph = Graph::new();
+            random_geometric(&graph, 40, 0.2, 2, Some(seed)).unwrap();
+            graph.edges().id().collect::<HashSet<_>>()
+        };
+        assert_eq!(edges([5; 32]), edges([5; 32]));
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let graph = Graph::new();
+        assert!(matches!(
+            random_geometric(&graph, 10, -0.1, 2, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert!(matches!(
+            random_geometric(&graph, 10, 0.1, 0, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+}
@@ -0,0 +1,282 @@
+//! Generates a large scale-free graph using the recursive matrix (R-MAT) model
+//!
+//! This function is a graph generation model based upon:
+//! Chakrabarti, Deepayan, Yiping Zhan, and Christos Faloutsos. "R-MAT: A recursive model for graph mining." Proceedings of the 2004 SIAM International Conference on Data Mining. 2004.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::rmat::rmat;
+//!
+//! let graph = Graph::new();
+//! rmat(&graph, 10, 16, 0.57, 0.19, 0.19, None).unwrap();
+//! ```
+
+use super::{chunk_seed, next_id};
+use crate::{db::graph::graph::Graph, errors::GraphError, prelude::*};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rayon::prelude::*;
+
+/// Number of edges that are sampled and inserted together
+const BATCH_SIZE: usize = 1 << 20;
+
+/// Number of edges sampled by the same rng
+const EDGES_PER_CHUNK: usize = 1 << 14;
+
+/// Generates a graph using the R-MAT model.
+///
+/// `2^scale` new nodes are added to the graph, followed by `edge_factor * 2^scale` edges. The
+/// endpoints of each edge are chosen by recursively splitting the adjacency matrix into four
+/// quadrants and descending into the top-left, top-right, bottom-left or bottom-right quadrant
+/// with probability `a`, `b`, `c` and `1 - a - b - c` respectively, `scale` times. Self-loops
+/// and repeated edges are kept as additional updates, so the graph has exactly
+/// `edge_factor * 2^scale` temporal edges.
+///
+/// The new nodes get consecutive integer ids after the largest id already in the graph. Every
+/// node and edge addition receives its own timestamp, starting after the latest time already in
+/// the graph.
+///
+/// The edges are sampled in parallel in batches and every batch is inserted with the bulk
+/// loader used for dataframes when the `arrow` feature is enabled (and with parallel
+/// `add_edge` calls otherwise). Each run of 2^14 edges is drawn by its own rng, seeded with
+/// `seed` where the last 8 bytes (read as a little-endian `u64`) are xor-ed with the run
+/// index + 1, so the generated graph only depends on `seed`. Without a seed a random one is drawn
+/// first.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `scale` - The base 2 logarithm of the number of nodes to add
+/// * `edge_factor` - The number of edges to add per node
+/// * `a` - The probability of the top-left quadrant
+/// * `b` - The probability of the top-right quadrant
+/// * `c` - The probability of the bottom-left quadrant
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if any of `a`, `b` and `c` is negative, if
+/// `a + b + c > 1`, if `scale > 62` or if the graph uses string node ids. Otherwise returns the
+/// first [GraphError] raised while adding the nodes and edges.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::rmat::rmat;
+///
+/// let graph = Graph::new();
+/// rmat(&graph, 8, 4, 0.57, 0.19, 0.19, None).unwrap();
+/// assert_eq!(graph.count_nodes(), 256);
+/// assert_eq!(graph.count_temporal_edges(), 1024);
+/// ```
+pub fn rmat(
+    graph: &Graph,
+    scale: u8,
+    edge_factor: usize,
+    a: f64,
+    b: f64,
+    c: f64,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    if [a, b, c].iter().any(|p| p.is_nan() || *p < 0.0) || a + b + c > 1.0 {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "quadrant probabilities need to be non-negative with a + b + c <= 1, got a = {a}, b = {b}, c = {c}"
+        )));
+    }
+    if scale > 62 {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "scale needs to be at most 62, got {scale}"
+        )));
+    }
+    let first_id = match next_id(graph, None) {
+        GID::U64(id) => id,
+        GID::Str(_) => {
+            return Err(GraphError::InvalidGeneratorParameters(
+                "rmat requires a graph with integer node ids".to_owned(),
+            ))
+        }
+    };
+    let seed = seed.unwrap_or_else(|| StdRng::from_entropy().gen());
+    let n_nodes = 1u64 << scale;
+    let n_edges = edge_factor * n_nodes as usize;
+    let start_time = graph.latest_time().unwrap_or(0) + 1;
+
+    let node_ids: Vec<u64> = (first_id..first_id + n_nodes).collect();
+    let node_times: Vec<i64> = (0..n_nodes as i64).map(|i| start_time + i).collect();
+    bulk::add_nodes(graph, node_ids, node_times)?;
+
+    let edge_start_time = start_time + n_nodes as i64;
+    for batch_start in (0..n_edges).step_by(BATCH_SIZE) {
+        let batch_end = (batch_start + BATCH_SIZE).min(n_edges);
+        let (src, dst): (Vec<u64>, Vec<u64>) = (batch_start..batch_end)
+            .into_par_iter()
+            .step_by(EDGES_PER_CHUNK)
+            .flat_map_iter(|chunk_start| {
+                let mut rng = StdRng::from_seed(chunk_seed(seed, chunk_start / EDGES_PER_CHUNK));
+                let chunk_end = (chunk_start + EDGES_PER_CHUNK).min(batch_end);
+                (chunk_start..chunk_end)
+                    .map(|_| {
+                        let (src, dst) = sample_edge(&mut rng, scale, a, b, c);
+                        (first_id + src, first_id + dst)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unzip();
+        let times = (batch_start..batch_end)
+            .map(|i| edge_start_time + i as i64)
+            .collect();
+        bulk::add_edges(graph, src, dst, times)?;
+    }
+    Ok(())
+}
+
+/// Descend `scale` times into a quadrant of the adjacency matrix, returning the row and column
+fn sample_edge(rng: &mut StdRng, scale: u8, a: f64, b: f64, c: f64) -> (u64, u64) {
+    let (mut src, mut dst) = (0u64, 0u64);
+    for _ in 0..scale {
+        let r: f64 = rng.gen();
+        let (src_bit, dst_bit) = if r < a {
+            (0, 0)
+        } else if r < a + b {
+            (0, 1)
+        } else if r < a + b + c {
+            (1, 0)
+        } else {
+            (1, 1)
+        };
+        src = (src << 1) | src_bit;
+        dst = (dst << 1) | dst_bit;
+    }
+    (src, dst)
+}
+
+#[cfg(all(feature = "io", feature = "arrow"))]
+mod bulk {
+    use crate::{
+        db::graph::graph::Graph,
+        errors::GraphError,
+        io::arrow::{
+            dataframe::{DFChunk, DFView},
+            df_loaders::{load_edges_from_df, load_nodes_from_df},
+        },
+    };
+    use polars_arrow::array::PrimitiveArray;
+
+    pub(super) fn add_nodes(
+        graph: &Graph,
+        ids: Vec<u64>,
+        times: Vec<i64>,
+    ) -> Result<(), GraphError> {
+        let df = DFView {
+            names: vec!["id".to_owned(), "time".to_owned()],
+            num_rows: ids.len(),
+            chunks: std::iter::once(Ok(DFChunk {
+                chunk: vec![
+                    Box::new(PrimitiveArray::from_vec(ids)),
+                    Box::new(PrimitiveArray::from_vec(times)),
+                ],
+            })),
+        };
+        load_nodes_from_df(df, "time", "id", &[], &[], None, None, None, graph)
+    }
+
+    pub(super) fn add_edges(
+        graph: &Graph,
+        src: Vec<u64>,
+        dst: Vec<u64>,
+        times: Vec<i64>,
+    ) -> Result<(), GraphError> {
+        let df = DFView {
+            names: vec!["src".to_owned(), "dst".to_owned(), "time".to_owned()],
+            num_rows: src.len(),
+            chunks: std::iter::once(Ok(DFChunk {
+                chunk: vec![
+                    Box::new(PrimitiveArray::from_vec(src)),
+                    Box::new(PrimitiveArray::from_vec(dst)),
+                    Box::new(PrimitiveArray::from_vec(times)),
+                ],
+            })),
+        };
+        load_edges_from_df(df, "time", "src", "dst", &[], &[], None, None, None, graph)
+    }
+}
+
+#[cfg(not(all(feature = "io", feature = "arrow")))]
+mod bulk {
+    use crate::{db::graph::graph::Graph, errors::GraphError, prelude::*};
+    use rayon::prelude::*;
+
+    pub(super) fn add_nodes(
+        graph: &Graph,
+        ids: Vec<u64>,
+        times: Vec<i64>,
+    ) -> Result<(), GraphError> {
+        ids.into_par_iter().zip(times).try_for_each(|(id, time)| {
+            graph.add_node(time, id, NO_PROPS, None)?;
+            Ok(())
+        })
+    }
+
+    pub(super) fn add_edges(
+        graph: &Graph,
+        src: Vec<u64>,
+        dst: Vec<u64>,
+        times: Vec<i64>,
+    ) -> Result<(), GraphError> {
+        src.into_par_iter()
+            .zip(dst)
+            .zip(times)
+            .try_for_each(|((src, dst), time)| {
+                graph.add_edge(time, src, dst, NO_PROPS, None)?;
+                Ok(())
+            })
+    }
+}
+
+#[cfg(test)]
+mod rmat_tests {
+    use super::*;
+    use crate::db::graph::graph::assert_graph_equal;
+
+    #[test]
+    fn node_and_edge_counts() {
+        let graph = Graph::new();
+        rmat(&graph, 10, 16, 0.57, 0.19, 0.19, Some([1; 32])).unwrap();
+        assert_eq!(graph.count_nodes(), 1 << 10);
+        assert_eq!(graph.count_temporal_edges(), 16 << 10);
+        assert_eq!(graph.earliest_time(), Some(1));
+        assert_eq!(graph.latest_time(), Some((1 << 10) + (16 << 10)));
+    }
+
+    #[test]
+    fn skewed_degrees() {
+        let graph = Graph::new();
+        rmat(&graph, 12, 8, 0.57, 0.19, 0.19, Some([2; 32])).unwrap();
+        let degrees = graph.nodes().out_degree();
+        // with a = 0.57 the node with the smallest id is a hub
+        assert!(degrees.max().unwrap() as f64 > 10.0 * degrees.mean());
+    }
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let g1 = Graph::new();
+        let g2 = Graph::new();
+        rmat(&g1, 9, 8, 0.45, 0.15, 0.15, Some([3; 32])).unwrap();
+        rmat(&g2, 9, 8, 0.45, 0.15, 0.15, Some([3; 32])).unwrap();
+        assert_graph_equal(&g1, &g2);
+    }
+
+    #[test]
+    fn invalid_probabilities() {
+        let graph = Graph::new();
+        assert!(matches!(
+            rmat(&graph, 4, 2, 0.5, 0.3, 0.3, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert!(matches!(
+            rmat(&graph, 4, 2, 0.5, -0.1, 0.3, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+}
@@ -0,0 +1,277 @@
+//! Generates a graph with planted communities using the stochastic block model
+//!
+//! This function is a graph generation model based upon:
+//! Holland, Paul W., Kathryn Blackmond Laskey, and Samuel Leinhardt. "Stochastic blockmodels: First steps." Social networks 5.2 (1983): 109-137.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::stochastic_block_model::stochastic_block_model;
+//!
+//! let graph = Graph::new();
+//! let p_matrix = vec![vec![0.5, 0.01], vec![0.01, 0.5]];
+//! stochastic_block_model(&graph, &[50, 50], &p_matrix, None).unwrap();
+//! ```
+
+use super::{next_id, PropertySpec};
+use crate::{
+    db::{
+        api::{mutation::AdditionOps, view::*},
+        graph::graph::Graph,
+    },
+    errors::GraphError,
+    prelude::Prop,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Generates a graph using the stochastic block model.
+///
+/// For every entry of `block_sizes` a block of that many new nodes is added to the graph and each
+/// node is tagged with a `block` metadata property holding the index of its block (as `u64`).
+/// Afterwards, every ordered pair of distinct new nodes `(a, b)`, with `a` in block `i` and `b` in
+/// block `j`, is connected independently with probability `p_matrix[i][j]`.
+///
+/// Every node and edge addition receives its own timestamp, starting after the latest time
+/// already in the graph.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `block_sizes` - The number of nodes in each block
+/// * `p_matrix` - The edge probabilities between blocks, `p_matrix[i][j]` is the probability of
+///    an edge from a node in block `i` to a node in block `j`. Rows can be given as `Vec<f64>`,
+///    arrays or `&[f64]` slices
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `p_matrix` is not a square matrix with one
+/// row per block or if any probability is not in `[0, 1]`. Otherwise returns the first
+/// [GraphError] raised while updating the graph.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::stochastic_block_model::stochastic_block_model;
+///
+/// let graph = Graph::new();
+/// let p_matrix = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+/// stochastic_block_model(&graph, &[3, 4], &p_matrix, None).unwrap();
+/// assert_eq!(graph.count_edges(), 3 * 2 + 4 * 3);
+/// ```
+pub fn stochastic_block_model<R: AsRef<[f64]>>(
+    graph: &Graph,
+    block_sizes: &[usize],
+    p_matrix: &[R],
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    stochastic_block_model_with_props(graph, block_sizes, p_matrix, PropertySpec::new(), seed)
+}
+
+/// Like [stochastic_block_model] but every new node and edge gets the random properties
+/// described by `props`, drawn from the same seeded rng as the rest of the graph.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] in the same cases as
+/// [stochastic_block_model] and if `props` is invalid. Otherwise returns the first
+/// [GraphError] raised while adding a node or an edge, e.g., if a generated property clashes
+/// with an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     stochastic_block_model::stochastic_block_model_with_props, PropDistribution, PropertySpec,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// let p_matrix: &[&[f64]] = &[&[0.5, 0.01], &[0.01, 0.5]];
+/// stochastic_block_model_with_props(&graph, &[50, 50], p_matrix, props, None).unwrap();
+/// ```
+pub fn stochastic_block_model_with_props<R: AsRef<[f64]>>(
+    graph: &Graph,
+    block_sizes: &[usize],
+    p_matrix: &[R],
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
+    let n_blocks = block_sizes.len();
+    if p_matrix.len() != n_blocks || p_matrix.iter().any(|row| row.as_ref().len() != n_blocks) {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "p_matrix needs to be a {n_blocks}x{n_blocks} matrix to match the number of blocks"
+        )));
+    }
+    if let Some(p) = p_matrix
+        .iter()
+        .flat_map(|row| row.as_ref())
+        .find(|p| !(0.0..=1.0).contains(*p))
+    {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "edge probabilities must be in [0, 1], got {p}"
+        )));
+    }
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut latest_time = graph.latest_time().unwrap_or(0);
+    let mut nodes = Vec::with_capacity(block_sizes.iter().sum());
+    let mut node_id = next_id(graph, None);
+    for (block, &size) in block_sizes.iter().enumerate() {
+        for _ in 0..size {
+            latest_time += 1;
+            graph
+                .add_node(latest_time, &node_id, props.node_props(&mut rng), None)?
+                .add_metadata([("block", Prop::U64(block as u64))])?;
+            nodes.push((node_id.clone(), block));
+            node_id = next_id(graph, Some(node_id));
+        }
+    }
+
+    for (src, (src_id, src_block)) in nodes.iter().enumerate() {
+        for (dst, (dst_id, dst_block)) in nodes.iter().enumerate() {
+            if src != dst && rng.gen_bool(p_matrix[*src_block].as_ref()[*dst_block]) {
+                latest_time += 1;
+                let edge_props = props.edge_props(&mut rng);
+                graph.add_edge(latest_time, src_id, dst_id, edge_props, None)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Shorthand for [stochastic_block_model]
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::stochastic_block_model::sbm;
+///
+/// let graph = Graph::new();
+/// let p_matrix: &[&[f64]] = &[&[0.5, 0.01], &[0.01, 0.5]];
+/// sbm(&graph, &[50, 50], p_matrix, None).unwrap();
+/// ```
+pub fn sbm<R: AsRef<[f64]>>(
+    graph: &Graph,
+    block_sizes: &[usize],
+    p_matrix: &[R],
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    stochastic_block_model(graph, block_sizes, p_matrix, seed)
+}
+
+#[cfg(test)]
+mod stochastic_block_model_tests {
+    use super::*;
+    use crate::prelude::*;
+    use itertools::Itertools;
+    use raphtory_api::core::entities::GID;
+    use std::collections::HashSet;
+
+    fn block(graph: &Graph, id: GID) -> u64 {
+        graph
+            .node(id)
+            .unwrap()
+            .metadata()
+            .get("block")
+            .and_then(|p| p.into_u64())
+            .unwrap()
+    }
+
+    #[test]
+    fn nodes_are_tagged_with_blocks() {
+        let graph = Graph::new();
+        let p_matrix = vec![vec![0.0; 3]; 3];
+        stochastic_block_model(&graph, &[2, 3, 4], &p_matrix, None).unwrap();
+        assert_eq!(graph.count_nodes(), 9);
+        assert_eq!(graph.count_edges(), 0);
+        let blocks: Vec<u64> = graph
+            .nodes()
+            .id()
+            .iter_values()
+            .sorted()
+            .map(|id| block(&graph, id))
+            .collect();
+        assert_eq!(blocks, vec![0, 0, 1, 1, 1, 2, 2, 2, 2]);
+    }
+
+    #[test]
+    fn intra_block_density_dominates() {
+        let graph = Graph::new();
+        let sizes = [40, 40, 40];
+        let p_matrix = vec![
+            vec![0.3, 0.02, 0.02],
+            vec![0.02, 0.3, 0.02],
+            vec![0.02, 0.02, 0.3],
+        ];
+        stochastic_block_model(&graph, &sizes, &p_matrix, Some([2; 32])).unwrap();
+
+        let (intra, inter) = graph
+            .edges()
+            .id()
+            .fold((0, 0), |(intra, inter), (src, dst)| {
+                if block(&graph, src) == block(&graph, dst) {
+                    (intra + 1, inter)
+                } else {
+                    (intra, inter + 1)
+                }
+            });
+        let intra_pairs: usize = sizes.iter().map(|s| s * (s - 1)).sum();
+        let n: usize = sizes.iter().sum();
+        let inter_pairs = n * (n - 1) - intra_pairs;
+        let intra_density = intra as f64 / intra_pairs as f64;
+        let inter_density = inter as f64 / inter_pairs as f64;
+        assert!(
+            intra_density > 5.0 * inter_density,
+            "intra-block density {intra_density} is not much higher than inter-block density {inter_density}"
+        );
+    }
+
+    #[test]
+    fn slice_rows() {
+        let graph = Graph::new();
+        let p_matrix: &[&[f64]] = &[&[1.0, 0.0], &[0.0, 1.0]];
+        sbm(&graph, &[3, 4], p_matrix, Some([3; 32])).unwrap();
+        assert_eq!(graph.count_edges(), 3 * 2 + 4 * 3);
+        assert!(graph
+            .edges()
+            .id()
+            .all(|(src, dst)| block(&graph, src) == block(&graph, dst)));
+    }
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let p_matrix = vec![vec![0.2, 0.05], vec![0.05, 0.2]];
+        let edges = |seed| {
+            let graph = Graph::new();
+            stochastic_block_model(&graph, &[30, 20], &p_matrix, Some(seed)).unwrap();
+            graph.edges().id().collect::<HashSet<_>>()
+        };
+        assert_eq!(edges([5; 32]), edges([5; 32]));
+    }
+
+    #[test]
+    fn invalid_p_matrix() {
+        let graph = Graph::new();
+        let not_square = vec![vec![0.1, 0.1], vec![0.1]];
+        assert!(matches!(
+            stochastic_block_model(&graph, &[5, 5], &not_square, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        let wrong_size = vec![vec![0.1; 3]; 3];
+        assert!(matches!(
+            stochastic_block_model(&graph, &[5, 5], &wrong_size, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        let bad_probability = vec![vec![0.1, 1.1], vec![0.1, 0.1]];
+        assert!(matches!(
+            stochastic_block_model(&graph, &[5, 5], &bad_probability, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+}
@@ -0,0 +1,262 @@
+//! Generates a small-world graph using the Watts–Strogatz model
+//!
+//! This function is a graph generation model based upon:
+//! Watts, Duncan J., and Steven H. Strogatz. "Collective dynamics of ‘small-world’ networks." nature 393.6684 (1998): 440-442.
+//!
+//! # Examples
+//!
+//! ```
+//! use raphtory::prelude::*;
+//! use raphtory::graphgen::watts_strogatz::watts_strogatz;
+//!
+//! let graph = Graph::new();
+//! watts_strogatz(&graph, 100, 4, 0.1, None).unwrap();
+//! ```
+
+use super::{add_nodes_with, PropertySpec, TimestampStrategy, Timestamps};
+use crate::{
+    db::{
+        api::mutation::{AdditionOps, DeletionOps},
+        graph::graph::Graph,
+    },
+    errors::GraphError,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// Generates a graph using the Watts–Strogatz small-world model.
+///
+/// `n_nodes` new nodes are arranged on a ring and every node is connected to its `k / 2` nearest
+/// neighbours on either side. Afterwards, every lattice edge `(u, v)` is rewired with
+/// probability `beta` to `(u, w)`, where `w` is chosen uniformly among the nodes that are
+/// neither `u` nor already connected to `u`.
+///
+/// Every operation receives its own timestamp, starting after the latest time already in the
+/// graph. A rewiring is recorded as a deletion of the old edge followed by the addition of the new
+/// edge, so the whole rewiring history is kept in the graph. The final small-world topology is the
+/// persistent view of the graph at the latest time, i.e., `graph.persistent_graph().snapshot_latest()`.
+///
+/// # Arguments
+/// * `graph` - The graph you wish to add nodes and edges to
+/// * `n_nodes` - The number of nodes to add to the graph
+/// * `k` - The degree of each node in the initial ring lattice, needs to be even
+/// * `beta` - The probability of rewiring each edge
+/// * `seed` - (Optional) An array of u8 bytes to be used as the input seed, Default None
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] if `k >= n_nodes`, `k` is odd or `beta` is
+/// not in `[0, 1]`, in which case the graph is not modified. Otherwise returns the first
+/// [GraphError] raised while updating the graph.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::watts_strogatz::watts_strogatz;
+///
+/// let graph = Graph::new();
+/// watts_strogatz(&graph, 100, 4, 0.1, None).unwrap();
+/// assert_eq!(graph.persistent_graph().snapshot_latest().count_edges(), 200);
+/// ```
+pub fn watts_strogatz(
+    graph: &Graph,
+    n_nodes: usize,
+    k: usize,
+    beta: f64,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    watts_strogatz_with_props(graph, n_nodes, k, beta, PropertySpec::new(), seed)
+}
+
+/// Like [watts_strogatz] but every new node and edge gets the random properties described by
+/// `props`, drawn from the same seeded rng as the rest of the graph.
+///
+/// # Errors
+/// Returns [GraphError::InvalidGeneratorParameters] in the same cases as [watts_strogatz] and if
+/// `props` is invalid. Otherwise returns the first [GraphError] raised while adding a node or
+/// an edge, e.g., if a generated property clashes with an existing property of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+/// use raphtory::graphgen::{
+///     watts_strogatz::watts_strogatz_with_props, PropDistribution, PropertySpec,
+/// };
+///
+/// let graph = Graph::new();
+/// let props = PropertySpec::new().with_edge_prop("weight", PropDistribution::Uniform(0.0, 1.0));
+/// watts_strogatz_with_props(&graph, 100, 4, 0.1, props, None).unwrap();
+/// ```
+pub fn watts_strogatz_with_props(
+    graph: &Graph,
+    n_nodes: usize,
+    k: usize,
+    beta: f64,
+    props: PropertySpec,
+    seed: Option<[u8; 32]>,
+) -> Result<(), GraphError> {
+    props.validate()?;
+    if k >= n_nodes {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "watts_strogatz requires k < n_nodes, got k = {k} and n_nodes = {n_nodes}"
+        )));
+    }
+    if k % 2 != 0 {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "watts_strogatz requires k to be even, got {k}"
+        )));
+    }
+    if !(0.0..=1.0).contains(&beta) {
+        return Err(GraphError::InvalidGeneratorParameters(format!(
+            "watts_strogatz requires beta to be in [0, 1], got {beta}"
+        )));
+    }
+    let mut rng = match seed {
+        Some(seed_value) => StdRng::from_seed(seed_value),
+        None => StdRng::from_entropy(),
+    };
+    let mut timestamps = Timestamps::new(graph, TimestampStrategy::Sequential)?;
+    let ids = add_nodes_with(graph, n_nodes, &mut timestamps, || {
+        props.node_props(&mut rng)
+    })?;
+
+    let mut neighbours: Vec<HashSet<usize>> = vec![HashSet::new(); n_nodes];
+    let mut edges = Vec::with_capacity(n_nodes * (k / 2));
+    for offset in 1..=k / 2 {
+        for u in 0..n_nodes {
+            let v = (u + offset) % n_nodes;
+            let time = timestamps.edge_time(&mut rng);
+            graph.add_edge(time, &ids[u], &ids[v], props.edge_props(&mut rng), None)?;
+            neighbours[u].insert(v);
+            neighbours[v].insert(u);
+            edges.push((u, v));
+        }
+    }
+
+    for (u, v) in edges {
+        // only rewire if the draw asks for it and `u` is not already connected to every other node
+        if rng.gen_bool(beta) && neighbours[u].len() < n_nodes - 1 {
+            let w = loop {
+                let w = rng.gen_range(0..n_nodes);
+                if w != u && !neighbours[u].contains(&w) {
+                    break w;
+                }
+            };
+            let time = timestamps.edge_time(&mut rng);
+            graph.delete_edge(time, &ids[u], &ids[v], None)?;
+            let time = timestamps.edge_time(&mut rng);
+            graph.add_edge(time, &ids[u], &ids[w], props.edge_props(&mut rng), None)?;
+            neighbours[u].remove(&v);
+            neighbours[v].remove(&u);
+            neighbours[u].insert(w);
+            neighbours[w].insert(u);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod watts_strogatz_tests {
+    use super::*;
+    use crate::{
+        algorithms::metrics::clustering_coefficient::global_clustering_coefficient::global_clustering_coefficient,
+        db::graph::graph::assert_graph_equal, prelude::*,
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn lattice_without_rewiring() {
+        let graph = Graph::new();
+        watts_strogatz(&graph, 20, 4, 0.0, None).unwrap();
+        assert_eq!(graph.count_nodes(), 20);
+        assert_eq!(graph.count_edges(), 40);
+        assert!(graph.nodes().degree().iter_values().all(|d| d == 4));
+        for i in 1..=20u64 {
+            for offset in 1..=2 {
+                let j = (i - 1 + offset) % 20 + 1;
+                assert!(graph.has_edge(i, j), "missing lattice edge ({i}, {j})");
+            }
+        }
+    }
+
+    #[test]
+    fn edge_count_independent_of_beta() {
+        for beta in [0.0, 0.2, 0.5, 1.0] {
+            let graph = Graph::new();
+            watts_strogatz(&graph, 100, 6, beta, Some([8; 32])).unwrap();
+            let snapshot = graph.persistent_graph().snapshot_latest();
+            assert_eq!(snapshot.count_edges(), 100 * 6 / 2);
+            assert!(snapshot
+                .edges()
+                .iter()
+                .all(|e| e.src().id() != e.dst().id()));
+        }
+    }
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let g1 = Graph::new();
+        let g2 = Graph::new();
+        watts_strogatz(&g1, 100, 4, 0.3, Some([1; 32])).unwrap();
+        watts_strogatz(&g2, 100, 4, 0.3, Some([1; 32])).unwrap();
+        assert_graph_equal(&g1, &g2);
+    }
+
+    #[test]
+    fn rewiring_keeps_history() {
+        let graph = Graph::new();
+        watts_strogatz(&graph, 50, 4, 1.0, Some([5; 32])).unwrap();
+        let snapshot = graph.persistent_graph().snapshot_latest();
+        assert_eq!(snapshot.count_edges(), 100);
+        // every lattice edge was rewired, the original edges are still in the history
+        assert!(graph.count_edges() > 100);
+    }
+
+    #[test]
+    fn k_too_large() {
+        let graph = Graph::new();
+        assert!(matches!(
+            watts_strogatz(&graph, 4, 4, 0.5, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+
+    #[test]
+    fn k_odd() {
+        let graph = Graph::new();
+        assert!(matches!(
+            watts_strogatz(&graph, 10, 3, 0.5, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+
+    #[test]
+    fn beta_out_of_range() {
+        let graph = Graph::new();
+        assert!(matches!(
+            watts_strogatz(&graph, 10, 4, 1.5, None),
+            Err(GraphError::InvalidGeneratorParameters(_))
+        ));
+        assert_eq!(graph.count_nodes(), 0);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(10))]
+        #[test]
+        fn clustering_decreases_with_beta(seed in any::<[u8; 32]>()) {
+            let clustering: Vec<f64> = [0.0, 0.1, 0.8]
+                .into_iter()
+                .map(|beta| {
+                    let graph = Graph::new();
+                    watts_strogatz(&graph, 200, 8, beta, Some(seed)).unwrap();
+                    global_clustering_coefficient(&graph.persistent_graph().snapshot_latest())
+                })
+                .collect();
+            prop_assert!(clustering[0] > clustering[1]);
+            prop_assert!(clustering[1] > clustering[2]);
+        }
+    }
+}
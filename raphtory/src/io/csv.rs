@@ -0,0 +1,820 @@
+//! Functions for loading edge and node lists from CSV files with a configurable column mapping.
+//!
+//! Unlike [CsvLoader](crate::io::csv_loader::CsvLoader), which hands every record to a user
+//! defined closure, these loaders only need to know which columns hold the ids, timestamps and
+//! properties.
+//!
+//! # Example
+//! ```no_run
+//! use std::path::Path;
+//! use raphtory::io::csv::{load_edges_from_csv, CsvEdgeConfig};
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! let config = CsvEdgeConfig::new("src", "dst", "time")
+//!     .with_property("weight", "weight")
+//!     .with_header(true);
+//! load_edges_from_csv(&g, Path::new("edges.csv"), config).expect("Csv did not parse.");
+//! ```
+
+use crate::{errors::GraphError, prelude::*};
+use ::csv::{ReaderBuilder, StringRecord};
+use chrono::DateTime;
+use raphtory_api::core::entities::{properties::prop::PropType, GidType};
+use raphtory_storage::core_ops::CoreGraphOps;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    path::Path,
+    str::FromStr,
+};
+
+#[derive(Debug)]
+pub enum CsvError {
+    /// An IO error that occurred during file read.
+    IoError(io::Error),
+    /// A CSV parsing error that occurred while reading a record.
+    CsvError(::csv::Error),
+    /// A configured column does not exist in the file.
+    MissingColumn { column: String },
+    /// A value could not be parsed.
+    ParseError {
+        row: usize,
+        column: String,
+        value: String,
+        reason: String,
+    },
+    /// A GraphError that occurred while adding a row to the graph.
+    GraphError { row: usize, source: GraphError },
+}
+
+impl From<io::Error> for CsvError {
+    fn from(value: io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(value: ::csv::Error) -> Self {
+        Self::CsvError(value)
+    }
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::IoError(error) => write!(f, "CSV loader failed with error: {}", error),
+            CsvError::CsvError(error) => write!(f, "CSV loader failed with error: {}", error),
+            CsvError::MissingColumn { column } => {
+                write!(f, "CSV loader failed: column {} does not exist", column)
+            }
+            CsvError::ParseError {
+                row,
+                column,
+                value,
+                reason,
+            } => write!(
+                f,
+                "CSV loader failed to parse {:?} in row {}, column {}: {}",
+                value, row, column, reason
+            ),
+            CsvError::GraphError { row, source } => {
+                write!(f, "CSV loader failed to add row {}: {}", row, source)
+            }
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::IoError(error) => Some(error),
+            CsvError::CsvError(error) => Some(error),
+            CsvError::GraphError { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A column of a CSV file, either by position or by its name in the header row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    Index(usize),
+    Name(String),
+}
+
+impl From<usize> for Column {
+    fn from(value: usize) -> Self {
+        Column::Index(value)
+    }
+}
+
+impl From<&str> for Column {
+    fn from(value: &str) -> Self {
+        Column::Name(value.to_owned())
+    }
+}
+
+impl From<String> for Column {
+    fn from(value: String) -> Self {
+        Column::Name(value)
+    }
+}
+
+/// A property read from a column, the type is inferred from the first non-empty value if unset
+#[derive(Debug, Clone)]
+struct PropertyColumn {
+    name: String,
+    column: Column,
+    dtype: Option<PropType>,
+}
+
+/// Options shared by the edge and node configs
+#[derive(Debug, Clone)]
+struct CsvFormat {
+    header: bool,
+    delimiter: u8,
+    id_type: Option<GidType>,
+    properties: Vec<PropertyColumn>,
+}
+
+impl Default for CsvFormat {
+    fn default() -> Self {
+        Self {
+            header: true,
+            delimiter: b',',
+            id_type: None,
+            properties: vec![],
+        }
+    }
+}
+
+impl CsvFormat {
+    fn add_property(&mut self, name: String, column: Column, dtype: Option<PropType>) {
+        self.properties.push(PropertyColumn {
+            name,
+            column,
+            dtype,
+        });
+    }
+}
+
+/// The column mapping for [load_edges_from_csv]
+#[derive(Debug, Clone)]
+pub struct CsvEdgeConfig {
+    src: Column,
+    dst: Column,
+    time: Column,
+    layer: Option<Column>,
+    format: CsvFormat,
+}
+
+impl CsvEdgeConfig {
+    /// Creates a config reading the source id, destination id and timestamp of each edge from the
+    /// given columns. By default the file has a header row and is comma separated.
+    pub fn new(src: impl Into<Column>, dst: impl Into<Column>, time: impl Into<Column>) -> Self {
+        Self {
+            src: src.into(),
+            dst: dst.into(),
+            time: time.into(),
+            layer: None,
+            format: CsvFormat::default(),
+        }
+    }
+
+    /// Add the value of `column` as the edge property `name`
+    ///
+    /// The type of the property is inferred from the first non-empty value in the column as
+    /// `i64`, `f64`, `bool` or `str` (in that order) and all other values must have the same type.
+    pub fn with_property(mut self, name: impl Into<String>, column: impl Into<Column>) -> Self {
+        self.format.add_property(name.into(), column.into(), None);
+        self
+    }
+
+    /// Add the value of `column` as the edge property `name` of type `dtype`
+    ///
+    /// Supported types are the integer and float types, `bool` and `str`.
+    pub fn with_property_type(
+        mut self,
+        name: impl Into<String>,
+        column: impl Into<Column>,
+        dtype: PropType,
+    ) -> Self {
+        self.format
+            .add_property(name.into(), column.into(), Some(dtype));
+        self
+    }
+
+    /// The type of the node ids, inferred from the existing nodes of the graph or the first id in
+    /// the file if not set
+    pub fn with_id_type(mut self, id_type: GidType) -> Self {
+        self.format.id_type = Some(id_type);
+        self
+    }
+
+    /// Read the layer of each edge from `column`
+    pub fn with_layer(mut self, column: impl Into<Column>) -> Self {
+        self.layer = Some(column.into());
+        self
+    }
+
+    /// Specifies whether the first row is a header, columns can only be referenced by name if it is
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.format.header = header;
+        self
+    }
+
+    /// The delimiter character used in the file
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.format.delimiter = delimiter;
+        self
+    }
+}
+
+/// The column mapping for [load_nodes_from_csv]
+#[derive(Debug, Clone)]
+pub struct CsvNodeConfig {
+    id: Column,
+    time: Column,
+    node_type: Option<Column>,
+    format: CsvFormat,
+}
+
+impl CsvNodeConfig {
+    /// Creates a config reading the id and timestamp of each node from the given columns. By
+    /// default the file has a header row and is comma separated.
+    pub fn new(id: impl Into<Column>, time: impl Into<Column>) -> Self {
+        Self {
+            id: id.into(),
+            time: time.into(),
+            node_type: None,
+            format: CsvFormat::default(),
+        }
+    }
+
+    /// Add the value of `column` as the node property `name`
+    ///
+    /// The type of the property is inferred from the first non-empty value in the column as
+    /// `i64`, `f64`, `bool` or `str` (in that order) and all other values must have the same type.
+    pub fn with_property(mut self, name: impl Into<String>, column: impl Into<Column>) -> Self {
+        self.format.add_property(name.into(), column.into(), None);
+        self
+    }
+
+    /// Add the value of `column` as the node property `name` of type `dtype`
+    ///
+    /// Supported types are the integer and float types, `bool` and `str`.
+    pub fn with_property_type(
+        mut self,
+        name: impl Into<String>,
+        column: impl Into<Column>,
+        dtype: PropType,
+    ) -> Self {
+        self.format
+            .add_property(name.into(), column.into(), Some(dtype));
+        self
+    }
+
+    /// The type of the node ids, inferred from the existing nodes of the graph or the first id in
+    /// the file if not set
+    pub fn with_id_type(mut self, id_type: GidType) -> Self {
+        self.format.id_type = Some(id_type);
+        self
+    }
+
+    /// Read the node type of each node from `column`
+    pub fn with_node_type(mut self, column: impl Into<Column>) -> Self {
+        self.node_type = Some(column.into());
+        self
+    }
+
+    /// Specifies whether the first row is a header, columns can only be referenced by name if it is
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.format.header = header;
+        self
+    }
+
+    /// The delimiter character used in the file
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.format.delimiter = delimiter;
+        self
+    }
+}
+
+/// A column resolved against the header, `name` is used for error messages
+struct ResolvedColumn {
+    index: usize,
+    name: String,
+}
+
+impl ResolvedColumn {
+    fn value<'a>(&self, row: usize, record: &'a StringRecord) -> Result<&'a str, CsvError> {
+        record.get(self.index).ok_or_else(|| CsvError::ParseError {
+            row,
+            column: self.name.clone(),
+            value: String::new(),
+            reason: "the row is too short".to_owned(),
+        })
+    }
+
+    fn parse_error(&self, row: usize, value: &str, reason: impl Into<String>) -> CsvError {
+        CsvError::ParseError {
+            row,
+            column: self.name.clone(),
+            value: value.to_owned(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Ids are integers if the first id is an integer and strings otherwise, unless `id_type` is
+    /// already settled
+    fn id(
+        &self,
+        row: usize,
+        record: &StringRecord,
+        id_type: &mut Option<GidType>,
+    ) -> Result<GID, CsvError> {
+        let value = self.value(row, record)?;
+        if value.is_empty() {
+            return Err(self.parse_error(row, value, "ids cannot be empty"));
+        }
+        let id_type = id_type.get_or_insert_with(|| match value.parse::<u64>() {
+            Ok(_) => GidType::U64,
+            Err(_) => GidType::Str,
+        });
+        match id_type {
+            GidType::U64 => value.parse::<u64>().map(GID::U64).map_err(|err| {
+                self.parse_error(row, value, format!("the ids are integers ({})", err))
+            }),
+            GidType::Str => Ok(GID::Str(value.to_owned())),
+        }
+    }
+
+    /// Timestamps are either integers or RFC-3339 strings, which are converted to epoch millis
+    fn time(&self, row: usize, record: &StringRecord) -> Result<i64, CsvError> {
+        let value = self.value(row, record)?;
+        value.parse::<i64>().or_else(|_| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|datetime| datetime.timestamp_millis())
+                .map_err(|err| {
+                    self.parse_error(
+                        row,
+                        value,
+                        format!("not an integer or RFC-3339 timestamp ({})", err),
+                    )
+                })
+        })
+    }
+}
+
+fn resolve(column: &Column, headers: Option<&StringRecord>) -> Result<ResolvedColumn, CsvError> {
+    match (column, headers) {
+        (Column::Index(index), headers) => {
+            let name = headers
+                .and_then(|headers| headers.get(*index))
+                .map(|name| name.to_owned())
+                .unwrap_or_else(|| index.to_string());
+            Ok(ResolvedColumn {
+                index: *index,
+                name,
+            })
+        }
+        (Column::Name(name), Some(headers)) => headers
+            .iter()
+            .position(|header| header == name)
+            .map(|index| ResolvedColumn {
+                index,
+                name: name.clone(),
+            })
+            .ok_or_else(|| CsvError::MissingColumn {
+                column: name.clone(),
+            }),
+        (Column::Name(name), None) => Err(CsvError::MissingColumn {
+            column: format!(
+                "{} (columns can only be referenced by name with a header)",
+                name
+            ),
+        }),
+    }
+}
+
+/// The type of a property column without a configured type
+fn infer_type(value: &str) -> PropType {
+    if value.parse::<i64>().is_ok() {
+        PropType::I64
+    } else if value.parse::<f64>().is_ok() {
+        PropType::F64
+    } else if value.parse::<bool>().is_ok() {
+        PropType::Bool
+    } else {
+        PropType::Str
+    }
+}
+
+fn parse_value(value: &str, dtype: &PropType) -> Result<Prop, String> {
+    fn parse<T: FromStr>(value: &str, f: impl FnOnce(T) -> Prop) -> Result<Prop, String>
+    where
+        T::Err: Display,
+    {
+        value.parse::<T>().map(f).map_err(|err| err.to_string())
+    }
+    match dtype {
+        PropType::Str => Ok(Prop::str(value)),
+        PropType::U8 => parse(value, Prop::U8),
+        PropType::U16 => parse(value, Prop::U16),
+        PropType::I32 => parse(value, Prop::I32),
+        PropType::I64 => parse(value, Prop::I64),
+        PropType::U32 => parse(value, Prop::U32),
+        PropType::U64 => parse(value, Prop::U64),
+        PropType::F32 => parse(value, Prop::F32),
+        PropType::F64 => parse(value, Prop::F64),
+        PropType::Bool => parse(value, Prop::Bool),
+        dtype => Err(format!("values of type {} cannot be read from CSV", dtype)),
+    }
+}
+
+/// A property column resolved against the header, `dtype` is settled by the first non-empty
+/// value if it was not configured
+struct ResolvedProperty {
+    name: String,
+    column: ResolvedColumn,
+    dtype: Option<PropType>,
+}
+
+/// Every value is parsed with the type of its column, see [ResolvedProperty]. Empty values are
+/// skipped.
+fn parse_props(
+    row: usize,
+    record: &StringRecord,
+    properties: &mut [ResolvedProperty],
+) -> Result<Vec<(String, Prop)>, CsvError> {
+    let mut props = Vec::with_capacity(properties.len());
+    for property in properties {
+        let value = property.column.value(row, record)?;
+        if value.is_empty() {
+            continue;
+        }
+        let dtype = property.dtype.get_or_insert_with(|| infer_type(value));
+        let prop = parse_value(value, dtype).map_err(|reason| {
+            property.column.parse_error(
+                row,
+                value,
+                format!("the column has type {} ({})", dtype, reason),
+            )
+        })?;
+        props.push((property.name.clone(), prop));
+    }
+    Ok(props)
+}
+
+/// Streams the records of `path`, calling `f` with the 1-based row number of each record
+fn for_each_record<F>(
+    path: &Path,
+    format: &CsvFormat,
+    columns: impl FnOnce(Option<&StringRecord>) -> Result<F, CsvError>,
+) -> Result<(), CsvError>
+where
+    F: FnMut(usize, &StringRecord) -> Result<(), CsvError>,
+{
+    let mut reader = ReaderBuilder::new()
+        .has_headers(format.header)
+        .delimiter(format.delimiter)
+        .from_path(path)?;
+    let headers = if format.header {
+        Some(reader.headers()?.clone())
+    } else {
+        None
+    };
+    let mut f = columns(headers.as_ref())?;
+    let mut record = StringRecord::new();
+    let mut row = 0;
+    while reader.read_record(&mut record)? {
+        row += 1;
+        f(row, &record)?;
+    }
+    Ok(())
+}
+
+fn resolve_properties(
+    properties: &[PropertyColumn],
+    headers: Option<&StringRecord>,
+) -> Result<Vec<ResolvedProperty>, CsvError> {
+    properties
+        .iter()
+        .map(|property| {
+            Ok(ResolvedProperty {
+                name: property.name.clone(),
+                column: resolve(&property.column, headers)?,
+                dtype: property.dtype.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Load the edges of a CSV file into a graph.
+///
+/// The file is read one row at a time, every row is added as an edge update.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph object where the data should be loaded.
+/// * `path` - The path of the CSV file.
+/// * `config` - The columns holding the ids, timestamps and properties, see [CsvEdgeConfig].
+///
+/// Returns:
+///
+/// A Result containing an empty Ok value if the data is loaded successfully.
+///
+/// # Errors
+///
+/// A [CsvError] is returned if the file cannot be read, a configured column is missing or a
+/// value cannot be parsed. The type of every id and property column is settled once (see
+/// [CsvEdgeConfig::with_property]), a value of a different type is a parse error. Parse errors and
+/// graph errors carry the row number (not counting the header) and the column name. Rows before
+/// the failing row stay in the graph.
+///
+pub fn load_edges_from_csv(
+    graph: &Graph,
+    path: &Path,
+    config: CsvEdgeConfig,
+) -> Result<(), CsvError> {
+    for_each_record(path, &config.format, |headers| {
+        let src = resolve(&config.src, headers)?;
+        let dst = resolve(&config.dst, headers)?;
+        let time = resolve(&config.time, headers)?;
+        let layer = config
+            .layer
+            .as_ref()
+            .map(|layer| resolve(layer, headers))
+            .transpose()?;
+        let mut properties = resolve_properties(&config.format.properties, headers)?;
+        let mut id_type = config.format.id_type.or_else(|| graph.id_type());
+        Ok(move |row: usize, record: &StringRecord| {
+            let src = src.id(row, record, &mut id_type)?;
+            let dst = dst.id(row, record, &mut id_type)?;
+            let time = time.time(row, record)?;
+            let props = parse_props(row, record, &mut properties)?;
+            let layer = layer
+                .as_ref()
+                .map(|layer| layer.value(row, record))
+                .transpose()?;
+            graph
+                .add_edge(time, src, dst, props, layer)
+                .map_err(|source| CsvError::GraphError { row, source })?;
+            Ok(())
+        })
+    })
+}
+
+/// Load the nodes of a CSV file into a graph.
+///
+/// The file is read one row at a time, every row is added as a node update.
+///
+/// # Arguments
+///
+/// * `graph` - A reference to the graph object where the data should be loaded.
+/// * `path` - The path of the CSV file.
+/// * `config` - The columns holding the ids, timestamps and properties, see [CsvNodeConfig].
+///
+/// Returns:
+///
+/// A Result containing an empty Ok value if the data is loaded successfully.
+///
+/// # Errors
+///
+/// A [CsvError] is returned if the file cannot be read, a configured column is missing or a
+/// value cannot be parsed. The type of every id and property column is settled once (see
+/// [CsvNodeConfig::with_property]), a value of a different type is a parse error. Parse errors and
+/// graph errors carry the row number (not counting the header) and the column name. Rows before
+/// the failing row stay in the graph.
+///
+pub fn load_nodes_from_csv(
+    graph: &Graph,
+    path: &Path,
+    config: CsvNodeConfig,
+) -> Result<(), CsvError> {
+    for_each_record(path, &config.format, |headers| {
+        let id = resolve(&config.id, headers)?;
+        let time = resolve(&config.time, headers)?;
+        let node_type = config
+            .node_type
+            .as_ref()
+            .map(|node_type| resolve(node_type, headers))
+            .transpose()?;
+        let mut properties = resolve_properties(&config.format.properties, headers)?;
+        let mut id_type = config.format.id_type.or_else(|| graph.id_type());
+        Ok(move |row: usize, record: &StringRecord| {
+            let id = id.id(row, record, &mut id_type)?;
+            let time = time.time(row, record)?;
+            let props = parse_props(row, record, &mut properties)?;
+            let node_type = node_type
+                .as_ref()
+                .map(|node_type| node_type.value(row, record))
+                .transpose()?;
+            graph
+                .add_node(time, id, props, node_type)
+                .map_err(|source| CsvError::GraphError { row, source })?;
+            Ok(())
+        })
+    })
+}
+
+#[cfg(test)]
+mod csv_tests {
+    use super::*;
+    use std::{fs::File, io::Write, path::PathBuf};
+    use tempfile::{tempdir, TempDir};
+
+    fn write_file(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        File::create(&path)
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_edges_round_trip() {
+        let edges = vec![
+            (1, 1, 2, 0.5, "a", true),
+            (2, 2, 3, 1.5, "b", false),
+            (3, 3, 1, 2.0, "c", true),
+            (4, 1, 2, 3.25, "d", false),
+        ];
+        let mut content = "time,src,dst,weight,label,flag\n".to_owned();
+        for (t, src, dst, weight, label, flag) in &edges {
+            content.push_str(&format!("{t},{src},{dst},{weight},{label},{flag}\n"));
+        }
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, "edges.csv", &content);
+
+        let graph = Graph::new();
+        let config = CsvEdgeConfig::new("src", "dst", "time")
+            .with_property("weight", "weight")
+            .with_property("label", "label")
+            .with_property("flag", 5usize);
+        load_edges_from_csv(&graph, &path, config).unwrap();
+
+        assert_eq!(graph.count_nodes(), 3);
+        assert_eq!(graph.count_edges(), 3);
+        assert_eq!(graph.count_temporal_edges(), edges.len());
+        let mut loaded: Vec<_> = graph
+            .edges()
+            .explode()
+            .iter()
+            .map(|e| {
+                let props = e.properties();
+                (
+                    e.time().unwrap(),
+                    e.src().id().as_u64().unwrap(),
+                    e.dst().id().as_u64().unwrap(),
+                    props.get("weight").and_then(|p| p.as_f64()).unwrap(),
+                    props
+                        .get("label")
+                        .and_then(|p| p.into_str())
+                        .unwrap()
+                        .to_string(),
+                    props.get("flag").and_then(|p| p.into_bool()).unwrap(),
+                )
+            })
+            .collect();
+        loaded.sort_by_key(|e| e.0);
+        let expected: Vec<_> = edges
+            .into_iter()
+            .map(|(t, src, dst, weight, label, flag)| {
+                (t, src, dst, weight, label.to_string(), flag)
+            })
+            .collect();
+        assert_eq!(loaded, expected);
+    }
+
+    #[test]
+    fn test_load_edges_without_header() {
+        let dir = tempdir().unwrap();
+        let path = write_file(
+            &dir,
+            "edges.csv",
+            "a;b;2021-09-09T01:46:39Z;x\nb;c;2021-09-09T01:46:40.5Z;y\n",
+        );
+        let graph = Graph::new();
+        let config = CsvEdgeConfig::new(0usize, 1usize, 2usize)
+            .with_layer(3usize)
+            .with_header(false)
+            .with_delimiter(b';');
+        load_edges_from_csv(&graph, &path, config).unwrap();
+
+        assert_eq!(graph.earliest_time(), Some(1631151999000));
+        assert_eq!(graph.latest_time(), Some(1631152000500));
+        assert!(graph.layers("x").unwrap().has_edge("a", "b"));
+        assert!(graph.layers("y").unwrap().has_edge("b", "c"));
+    }
+
+    #[test]
+    fn test_load_nodes() {
+        let dir = tempdir().unwrap();
+        let path = write_file(
+            &dir,
+            "nodes.csv",
+            "id,time,type,age\nalice,1,person,30\nbob,2,person,\nshop,3,place,5\n",
+        );
+        let graph = Graph::new();
+        let config = CsvNodeConfig::new("id", "time")
+            .with_node_type("type")
+            .with_property("age", "age");
+        load_nodes_from_csv(&graph, &path, config).unwrap();
+
+        assert_eq!(graph.count_nodes(), 3);
+        let alice = graph.node("alice").unwrap();
+        assert_eq!(alice.node_type().as_deref(), Some("person"));
+        assert_eq!(alice.properties().get("age"), Some(Prop::I64(30)));
+        assert_eq!(graph.node("bob").unwrap().properties().get("age"), None);
+        assert_eq!(graph.node("shop").unwrap().earliest_time(), Some(3));
+    }
+
+    #[test]
+    fn test_parse_error_reports_row_and_column() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, "edges.csv", "src,dst,time\n1,2,3\n2,3,yesterday\n");
+        let graph = Graph::new();
+        let result = load_edges_from_csv(&graph, &path, CsvEdgeConfig::new("src", "dst", "time"));
+        match result {
+            Err(CsvError::ParseError { row, column, .. }) => {
+                assert_eq!(row, 2);
+                assert_eq!(column, "time");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert_eq!(graph.count_edges(), 1);
+    }
+
+    #[test]
+    fn test_column_types_are_settled_once() {
+        let dir = tempdir().unwrap();
+        let path = write_file(
+            &dir,
+            "edges.csv",
+            "src,dst,time,weight,score\n7,8,1,1,0.5\na7,8,2,1.5,1\n",
+        );
+
+        // the ids and the weights are read as the type of their first value
+        let graph = Graph::new();
+        let config = CsvEdgeConfig::new("src", "dst", "time").with_property("weight", "weight");
+        match load_edges_from_csv(&graph, &path, config) {
+            Err(CsvError::ParseError { row, column, .. }) => {
+                assert_eq!(row, 2);
+                assert_eq!(column, "src");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        let graph = Graph::new();
+        let config = CsvEdgeConfig::new("src", "dst", "time")
+            .with_id_type(GidType::Str)
+            .with_property("weight", "weight");
+        match load_edges_from_csv(&graph, &path, config) {
+            Err(CsvError::ParseError { row, column, .. }) => {
+                assert_eq!(row, 2);
+                assert_eq!(column, "weight");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        let graph = Graph::new();
+        let config = CsvEdgeConfig::new("src", "dst", "time")
+            .with_id_type(GidType::Str)
+            .with_property_type("weight", "weight", PropType::F64)
+            .with_property("score", "score");
+        load_edges_from_csv(&graph, &path, config).unwrap();
+        let weights: Vec<_> = graph
+            .edges()
+            .explode()
+            .iter()
+            .map(|e| {
+                let props = e.properties();
+                (props.get("weight").unwrap(), props.get("score").unwrap())
+            })
+            .collect();
+        assert_eq!(
+            weights,
+            [
+                (Prop::F64(1.0), Prop::F64(0.5)),
+                (Prop::F64(1.5), Prop::F64(1.0))
+            ]
+        );
+        assert_eq!(graph.node("7").unwrap().id(), GID::Str("7".to_owned()));
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, "edges.csv", "src,dst,time\n1,2,3\n");
+        let graph = Graph::new();
+        let config = CsvEdgeConfig::new("src", "dst", "time").with_property("weight", "weight");
+        assert!(matches!(
+            load_edges_from_csv(&graph, &path, config),
+            Err(CsvError::MissingColumn { column }) if column == "weight"
+        ));
+    }
+}
@@ -0,0 +1,589 @@
+//! Functions for reading and writing graphs in the Graph Modelling Language (GML).
+//!
+//! GML describes a static graph, so every node and edge read from a GML document is added at
+//! time 0, and writing a graph keeps the latest value of every property. Nodes with a `label`
+//! (as written by NetworkX) are identified by their label, otherwise by their `id`. All other
+//! keys of a node or edge become properties, nested lists are read as map properties.
+//!
+//! # Example
+//! ```
+//! use raphtory::io::gml::{read_graph, write_graph};
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! g.add_edge(0, "a", "b", [("weight", 0.5)], None).unwrap();
+//!
+//! let mut buffer = vec![];
+//! write_graph(&g, &mut buffer).unwrap();
+//! let restored = read_graph(buffer.as_slice()).unwrap();
+//! assert!(restored.has_edge("a", "b"));
+//! ```
+
+use crate::{errors::GraphError, prelude::*};
+use raphtory_api::core::storage::arc_str::ArcStr;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    io::{BufRead, Read, Write},
+    iter::Peekable,
+    str::Chars,
+};
+use tracing::warn;
+
+#[derive(Debug)]
+pub enum GmlError {
+    /// An IO error that occurred while reading or writing.
+    IoError(io::Error),
+    /// The document is not valid GML.
+    ParseError { line: usize, reason: String },
+    /// A GraphError that occurred while loading the data into the graph.
+    GraphError(GraphError),
+}
+
+impl From<io::Error> for GmlError {
+    fn from(value: io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
+
+impl From<GraphError> for GmlError {
+    fn from(value: GraphError) -> Self {
+        Self::GraphError(value)
+    }
+}
+
+impl Display for GmlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GmlError::ParseError { line, reason } => {
+                write!(f, "GML loader failed on line {}: {}", line, reason)
+            }
+            _ => match self.source() {
+                Some(error) => write!(f, "GML loader failed with error: {}", error),
+                None => write!(f, "GML loader failed with unknown error"),
+            },
+        }
+    }
+}
+
+impl Error for GmlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GmlError::IoError(error) => Some(error),
+            GmlError::GraphError(error) => Some(error),
+            GmlError::ParseError { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Int(i64),
+    Real(f64),
+    Str(String),
+    List(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn into_prop(self) -> Prop {
+        match self {
+            Value::Int(value) => Prop::I64(value),
+            Value::Real(value) => Prop::F64(value),
+            Value::Str(value) => Prop::str(value),
+            Value::List(entries) => {
+                // repeated keys are collected into a list
+                let mut map: HashMap<String, Vec<Prop>> = HashMap::new();
+                for (key, value) in entries {
+                    map.entry(key).or_default().push(value.into_prop());
+                }
+                Prop::map(map.into_iter().map(|(key, mut values)| {
+                    let prop = if values.len() == 1 {
+                        values.pop().unwrap()
+                    } else {
+                        Prop::List(values.into())
+                    };
+                    (key, prop)
+                }))
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Key(String),
+    Value(Value),
+    Open,
+    Close,
+}
+
+struct Tokenizer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars().peekable(),
+            line: 1,
+        }
+    }
+
+    fn error(&self, reason: impl Into<String>) -> GmlError {
+        GmlError::ParseError {
+            line: self.line,
+            reason: reason.into(),
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut value = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !predicate(c) {
+                break;
+            }
+            value.push(c);
+            self.chars.next();
+        }
+        value
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token>, GmlError> {
+        loop {
+            match self.chars.peek().copied() {
+                None => return Ok(None),
+                Some('\n') => {
+                    self.line += 1;
+                    self.chars.next();
+                }
+                Some(c) if c.is_whitespace() => {
+                    self.chars.next();
+                }
+                Some('#') => {
+                    self.take_while(|c| c != '\n');
+                }
+                Some(_) => break,
+            }
+        }
+        let token = match *self.chars.peek().unwrap() {
+            '[' => {
+                self.chars.next();
+                Token::Open
+            }
+            ']' => {
+                self.chars.next();
+                Token::Close
+            }
+            '"' => {
+                self.chars.next();
+                let value = self.take_while(|c| c != '"');
+                if self.chars.next().is_none() {
+                    return Err(self.error("unterminated string"));
+                }
+                self.line += value.matches('\n').count();
+                Token::Value(Value::Str(unescape(&value)))
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                Token::Key(self.take_while(|c| c.is_ascii_alphanumeric() || c == '_'))
+            }
+            c if c.is_ascii_digit() || matches!(c, '-' | '+' | '.') => {
+                let number =
+                    self.take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.'));
+                match number.parse::<i64>() {
+                    Ok(value) => Token::Value(Value::Int(value)),
+                    Err(_) => Token::Value(Value::Real(
+                        number
+                            .parse()
+                            .map_err(|_| self.error(format!("invalid number {}", number)))?,
+                    )),
+                }
+            }
+            c => return Err(self.error(format!("unexpected character {:?}", c))),
+        };
+        Ok(Some(token))
+    }
+
+    /// Parse the key-value pairs up to the closing bracket (or the end of the input at the top level)
+    fn parse_list(&mut self, nested: bool) -> Result<Vec<(String, Value)>, GmlError> {
+        let mut entries = vec![];
+        loop {
+            let key = match self.next_token()? {
+                Some(Token::Key(key)) => key,
+                Some(Token::Close) if nested => return Ok(entries),
+                None if !nested => return Ok(entries),
+                None => return Err(self.error("unexpected end of input, missing ]")),
+                Some(token) => return Err(self.error(format!("expected a key, got {:?}", token))),
+            };
+            let value = match self.next_token()? {
+                Some(Token::Value(value)) => value,
+                Some(Token::Open) => Value::List(self.parse_list(true)?),
+                Some(token) => {
+                    return Err(self.error(format!("expected a value for {}, got {:?}", key, token)))
+                }
+                None => return Err(self.error(format!("missing value for {}", key))),
+            };
+            entries.push((key, value));
+        }
+    }
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Read a GML document into a new graph.
+///
+/// The document needs to contain a top-level `graph` list with `node` and `edge` lists. Every
+/// node and edge is added at time 0 and edges always point from `source` to `target`, even if
+/// the graph is marked as undirected.
+///
+/// # Arguments
+///
+/// * `reader` - The source of the document.
+///
+/// Returns:
+///
+/// The graph described by the document.
+///
+/// # Errors
+///
+/// A [GmlError] is returned if the document cannot be read or parsed, if it has no `graph` or if
+/// an edge refers to a node that is not declared.
+///
+pub fn read_graph(mut reader: impl BufRead) -> Result<Graph, GmlError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let mut tokenizer = Tokenizer::new(&input);
+    let document = tokenizer.parse_list(false)?;
+    let parse_error = |reason: String| GmlError::ParseError { line: 0, reason };
+
+    let entries = document
+        .into_iter()
+        .find_map(|(key, value)| match (key.as_str(), value) {
+            ("graph", Value::List(entries)) => Some(entries),
+            _ => None,
+        })
+        .ok_or_else(|| parse_error("missing graph".to_owned()))?;
+
+    let mut nodes = vec![];
+    let mut edges = vec![];
+    for (key, value) in entries {
+        match (key.as_str(), value) {
+            ("node", Value::List(entries)) => nodes.push(entries),
+            ("edge", Value::List(entries)) => edges.push(entries),
+            _ => {}
+        }
+    }
+
+    let take = |entries: &mut Vec<(String, Value)>, key: &str| {
+        entries
+            .iter()
+            .position(|(k, _)| k == key)
+            .map(|index| entries.remove(index).1)
+    };
+    let with_labels = nodes
+        .iter()
+        .all(|node| node.iter().any(|(key, _)| key == "label"));
+
+    let graph = Graph::new();
+    let mut ids: HashMap<i64, GID> = HashMap::new();
+    for mut node in nodes {
+        let gml_id = match take(&mut node, "id") {
+            Some(Value::Int(id)) => Some(id),
+            Some(value) => return Err(parse_error(format!("invalid node id {:?}", value))),
+            None => None,
+        };
+        let id = if with_labels {
+            match take(&mut node, "label") {
+                Some(Value::Str(label)) => GID::Str(label),
+                Some(Value::Int(label)) => GID::Str(label.to_string()),
+                value => return Err(parse_error(format!("invalid node label {:?}", value))),
+            }
+        } else {
+            match gml_id {
+                Some(id) if id >= 0 => GID::U64(id as u64),
+                _ => return Err(parse_error("node without a valid id".to_owned())),
+            }
+        };
+        let props = node
+            .into_iter()
+            .map(|(key, value)| (key, value.into_prop()));
+        graph.add_node(0, id.clone(), props, None)?;
+        if let Some(gml_id) = gml_id {
+            ids.insert(gml_id, id);
+        }
+    }
+    for mut edge in edges {
+        let mut endpoint = |key: &str| match take(&mut edge, key) {
+            Some(Value::Int(id)) => ids
+                .get(&id)
+                .cloned()
+                .ok_or_else(|| parse_error(format!("edge {} {} is not a node", key, id))),
+            value => Err(parse_error(format!("invalid edge {} {:?}", key, value))),
+        };
+        let src = endpoint("source")?;
+        let dst = endpoint("target")?;
+        let props = edge
+            .into_iter()
+            .map(|(key, value)| (key, value.into_prop()));
+        graph.add_edge(0, src, dst, props, None)?;
+    }
+    Ok(graph)
+}
+
+fn is_key(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn write_value(writer: &mut impl Write, prop: &Prop, indent: usize) -> io::Result<()> {
+    match prop {
+        Prop::Bool(value) => write!(writer, "{}", *value as i64),
+        Prop::U8(_) | Prop::U16(_) | Prop::U32(_) | Prop::U64(_) | Prop::I32(_) | Prop::I64(_) => {
+            write!(writer, "{}", prop)
+        }
+        Prop::F32(_) | Prop::F64(_) => {
+            let value = prop.as_f64().unwrap();
+            if value.is_finite() {
+                write!(writer, "{:?}", value)
+            } else {
+                write!(writer, "\"{}\"", value)
+            }
+        }
+        Prop::Map(map) => {
+            writeln!(writer, "[")?;
+            for (key, value) in map.iter() {
+                write_entry(writer, key, value, indent + 2)?;
+            }
+            write!(writer, "{:indent$}]", "", indent = indent)
+        }
+        Prop::Str(value) => write!(writer, "\"{}\"", escape(value)),
+        prop => write!(writer, "\"{}\"", escape(&prop.to_string())),
+    }
+}
+
+fn write_entry(writer: &mut impl Write, key: &str, prop: &Prop, indent: usize) -> io::Result<()> {
+    write!(writer, "{:indent$}{} ", "", key, indent = indent)?;
+    write_value(writer, prop, indent)?;
+    writeln!(writer)
+}
+
+fn write_props(
+    writer: &mut impl Write,
+    props: Vec<(ArcStr, Prop)>,
+    reserved: &[&str],
+) -> io::Result<()> {
+    for (name, prop) in props {
+        if !is_key(&name) || reserved.contains(&name.as_ref()) {
+            warn!("Skipping property {:?} which is not a valid GML key", name);
+            continue;
+        }
+        write_entry(writer, &name, &prop, 4)?;
+    }
+    Ok(())
+}
+
+/// Write a graph as a GML document.
+///
+/// Every node and edge is written once with the latest values of its properties. Nodes of a
+/// graph with integer ids are written with their id, nodes of a graph with string ids with their
+/// index as `id` and their name as `label`. Properties whose names are not valid GML keys (or
+/// clash with `id`, `label`, `source` or `target`) are skipped.
+///
+/// # Arguments
+///
+/// * `graph` - The graph (or graph view) to write.
+/// * `writer` - Where the document is written to.
+///
+/// Returns:
+///
+/// A Result containing an empty Ok value if the graph is written successfully.
+///
+/// # Errors
+///
+/// A [GmlError::IoError] is returned if writing fails.
+///
+pub fn write_graph<'graph, G: GraphViewOps<'graph>>(
+    graph: &G,
+    mut writer: impl Write,
+) -> Result<(), GmlError> {
+    let mut ids: HashMap<GID, u64> = HashMap::new();
+    writeln!(writer, "graph [")?;
+    writeln!(writer, "  directed 1")?;
+    for (index, node) in graph.nodes().iter().enumerate() {
+        writeln!(writer, "  node [")?;
+        let gid = node.id();
+        let id = match &gid {
+            GID::U64(id) => *id,
+            GID::Str(_) => index as u64,
+        };
+        writeln!(writer, "    id {}", id)?;
+        if let GID::Str(label) = &gid {
+            writeln!(writer, "    label \"{}\"", escape(label))?;
+        }
+        write_props(&mut writer, node.properties().as_vec(), &["id", "label"])?;
+        writeln!(writer, "  ]")?;
+        ids.insert(gid, id);
+    }
+    for edge in graph.edges() {
+        writeln!(writer, "  edge [")?;
+        writeln!(writer, "    source {}", ids[&edge.src().id()])?;
+        writeln!(writer, "    target {}", ids[&edge.dst().id()])?;
+        write_props(
+            &mut writer,
+            edge.properties().as_vec(),
+            &["source", "target"],
+        )?;
+        writeln!(writer, "  ]")?;
+    }
+    writeln!(writer, "]")?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod gml_tests {
+    use super::*;
+    use crate::db::graph::graph::assert_graph_equal;
+
+    const FIXTURE: &str = r#"
+# written by NetworkX
+graph [
+  directed 1
+  name "karate &quot;club&quot;"
+  node [
+    id 0
+    label "Mr. Hi"
+    club "Mr. Hi"
+    size 1.5
+    graphics [
+      x -12.5
+      y 3
+    ]
+  ]
+  node [
+    id 1
+    label "Officer"
+    club "Officer"
+    size 2.5
+  ]
+  node [
+    id 2
+    label "John A"
+    club "Officer"
+  ]
+  edge [
+    source 0
+    target 1
+    weight 4.0
+  ]
+  edge [
+    source 1
+    target 2
+    weight 1E1
+  ]
+]
+"#;
+
+    #[test]
+    fn test_read_fixture() {
+        let graph = read_graph(FIXTURE.as_bytes()).unwrap();
+        assert_eq!(graph.count_nodes(), 3);
+        assert_eq!(graph.count_edges(), 2);
+        assert_eq!(graph.earliest_time(), Some(0));
+        assert_eq!(graph.latest_time(), Some(0));
+        assert!(graph.has_edge("Mr. Hi", "Officer"));
+        assert!(graph.has_edge("Officer", "John A"));
+
+        let node = graph.node("Mr. Hi").unwrap();
+        assert_eq!(node.properties().get("club"), Some(Prop::str("Mr. Hi")));
+        assert_eq!(node.properties().get("size"), Some(Prop::F64(1.5)));
+        assert_eq!(
+            node.properties().get("graphics"),
+            Some(Prop::map([("x", Prop::F64(-12.5)), ("y", Prop::I64(3))]))
+        );
+        assert_eq!(
+            graph.node("Officer").unwrap().properties().get("size"),
+            Some(Prop::F64(2.5))
+        );
+        assert_eq!(
+            graph
+                .edge("Officer", "John A")
+                .unwrap()
+                .properties()
+                .get("weight"),
+            Some(Prop::F64(10.0))
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let graph = read_graph(FIXTURE.as_bytes()).unwrap();
+        let mut buffer = vec![];
+        write_graph(&graph, &mut buffer).unwrap();
+        let restored = read_graph(buffer.as_slice()).unwrap();
+        assert_graph_equal(&graph, &restored);
+        assert_eq!(
+            restored.node("John A").unwrap().properties().get("club"),
+            Some(Prop::str("Officer"))
+        );
+    }
+
+    #[test]
+    fn test_round_trip_integer_ids() {
+        let graph = Graph::new();
+        // a node label would turn the ids into strings, so it is not written
+        graph
+            .add_node(0, 3, [("label", Prop::str("reserved"))], None)
+            .unwrap();
+        graph.add_edge(0, 3, 7, NO_PROPS, None).unwrap();
+        graph
+            .add_edge(0, 7, 1, [("quote", Prop::str("say \"hi\""))], None)
+            .unwrap();
+
+        let mut buffer = vec![];
+        write_graph(&graph, &mut buffer).unwrap();
+        let restored = read_graph(buffer.as_slice()).unwrap();
+        assert_eq!(restored.count_nodes(), 3);
+        assert!(restored.has_edge(3, 7));
+        assert_eq!(restored.node(3).unwrap().properties().get("label"), None);
+        assert_eq!(
+            restored.edge(7, 1).unwrap().properties().get("quote"),
+            Some(Prop::str("say \"hi\""))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            read_graph("graph [ node [ id 0 ]".as_bytes()),
+            Err(GmlError::ParseError { .. })
+        ));
+        assert!(matches!(
+            read_graph("graph [ node [ id 0 ] edge [ source 0 target 1 ] ]".as_bytes()),
+            Err(GmlError::ParseError { .. })
+        ));
+        assert!(matches!(
+            read_graph("creator \"nobody\"".as_bytes()),
+            Err(GmlError::ParseError { .. })
+        ));
+    }
+}
@@ -0,0 +1,573 @@
+//! Functions for reading and writing graphs in the GraphML format.
+//!
+//! GraphML describes a static graph, so a node is written with the time it was first seen and
+//! the latest values of its properties, while every edge update becomes its own `<edge>`
+//! element. The time of each element is stored in a `timestamp` attribute declared with a
+//! `<key>` like any other property. Metadata is not exported.
+//!
+//! # Example
+//! ```
+//! use raphtory::io::graphml::{read_graph, write_graph};
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, "a", "b", [("weight", 0.5)], None).unwrap();
+//!
+//! let mut buffer = vec![];
+//! write_graph(&g, &mut buffer).unwrap();
+//! let restored = read_graph(buffer.as_slice()).unwrap();
+//! assert!(restored.has_edge("a", "b"));
+//! ```
+
+use crate::{errors::GraphError, prelude::*};
+use quick_xml::{
+    escape::escape,
+    events::{BytesStart, Event},
+    Reader,
+};
+use raphtory_api::core::storage::arc_str::ArcStr;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    io::{BufReader, Read, Write},
+};
+
+/// The name of the attribute holding the Raphtory timestamp of a node or edge
+pub const TIMESTAMP: &str = "timestamp";
+
+#[derive(Debug)]
+pub enum GraphMLError {
+    /// An IO error that occurred while reading or writing.
+    IoError(io::Error),
+    /// An XML parsing error.
+    XmlError(quick_xml::Error),
+    /// The document is valid XML but not a valid GraphML document.
+    InvalidFormat(String),
+    /// A GraphError that occurred while loading the data into the graph.
+    GraphError(GraphError),
+}
+
+impl From<io::Error> for GraphMLError {
+    fn from(value: io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
+
+impl From<quick_xml::Error> for GraphMLError {
+    fn from(value: quick_xml::Error) -> Self {
+        Self::XmlError(value)
+    }
+}
+
+impl From<quick_xml::events::attributes::AttrError> for GraphMLError {
+    fn from(value: quick_xml::events::attributes::AttrError) -> Self {
+        Self::XmlError(value.into())
+    }
+}
+
+impl From<GraphError> for GraphMLError {
+    fn from(value: GraphError) -> Self {
+        Self::GraphError(value)
+    }
+}
+
+impl Display for GraphMLError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphMLError::InvalidFormat(reason) => {
+                write!(f, "GraphML loader failed, invalid document: {}", reason)
+            }
+            _ => match self.source() {
+                Some(error) => write!(f, "GraphML loader failed with error: {}", error),
+                None => write!(f, "GraphML loader failed with unknown error"),
+            },
+        }
+    }
+}
+
+impl Error for GraphMLError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GraphMLError::IoError(error) => Some(error),
+            GraphMLError::XmlError(error) => Some(error),
+            GraphMLError::GraphError(error) => Some(error),
+            GraphMLError::InvalidFormat(_) => None,
+        }
+    }
+}
+
+/// The GraphML `attr.type` used for a property value, unsupported types are written as strings
+fn graphml_type(prop: &Prop) -> &'static str {
+    match prop {
+        Prop::Bool(_) => "boolean",
+        Prop::I32(_) | Prop::U8(_) | Prop::U16(_) | Prop::U32(_) => "int",
+        Prop::I64(_) | Prop::U64(_) => "long",
+        Prop::F32(_) => "float",
+        Prop::F64(_) => "double",
+        _ => "string",
+    }
+}
+
+fn parse_value(value: &str, dtype: &str) -> Result<Prop, GraphMLError> {
+    let invalid = || GraphMLError::InvalidFormat(format!("{:?} is not a valid {}", value, dtype));
+    let trimmed = value.trim();
+    Ok(match dtype {
+        "boolean" => Prop::Bool(match trimmed {
+            "true" | "1" => true,
+            "false" | "0" => false,
+            _ => return Err(invalid()),
+        }),
+        "int" | "long" => Prop::I64(trimmed.parse().map_err(|_| invalid())?),
+        "float" | "double" => Prop::F64(trimmed.parse().map_err(|_| invalid())?),
+        _ => Prop::str(value),
+    })
+}
+
+/// The `<key>` declarations of one element kind (`node` or `edge`)
+#[derive(Default)]
+struct Keys {
+    /// property name -> (key id, graphml type)
+    keys: HashMap<ArcStr, (String, &'static str)>,
+    order: Vec<ArcStr>,
+}
+
+impl Keys {
+    fn observe(&mut self, prefix: &str, name: &ArcStr, prop: &Prop) {
+        let dtype = graphml_type(prop);
+        match self.keys.get_mut(name) {
+            // mixed types can only be written as strings
+            Some((_, existing)) if *existing != dtype => *existing = "string",
+            Some(_) => {}
+            None => {
+                let id = format!("{}{}", prefix, self.order.len());
+                self.keys.insert(name.clone(), (id, dtype));
+                self.order.push(name.clone());
+            }
+        }
+    }
+
+    fn id(&self, name: &str) -> &str {
+        &self.keys[name].0
+    }
+
+    fn write(&self, writer: &mut impl Write, domain: &str) -> io::Result<()> {
+        for name in &self.order {
+            let (id, dtype) = &self.keys[name];
+            writeln!(
+                writer,
+                "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+                id,
+                domain,
+                escape(name.as_ref()),
+                dtype
+            )?;
+        }
+        Ok(())
+    }
+}
+
+struct Element {
+    ids: Vec<String>,
+    time: i64,
+    props: Vec<(ArcStr, Prop)>,
+}
+
+fn write_data(
+    writer: &mut impl Write,
+    keys: &Keys,
+    time: i64,
+    props: &[(ArcStr, Prop)],
+) -> io::Result<()> {
+    writeln!(writer, "      <data key=\"{}\">{}</data>", TIMESTAMP, time)?;
+    for (name, prop) in props {
+        let value = match prop {
+            Prop::Str(value) => value.to_string(),
+            prop => prop.to_string(),
+        };
+        writeln!(
+            writer,
+            "      <data key=\"{}\">{}</data>",
+            keys.id(name),
+            escape(value.as_str())
+        )?;
+    }
+    Ok(())
+}
+
+/// Write a graph as a GraphML document.
+///
+/// # Arguments
+///
+/// * `graph` - The graph (or graph view) to write.
+/// * `writer` - Where the document is written to.
+///
+/// Returns:
+///
+/// A Result containing an empty Ok value if the graph is written successfully.
+///
+/// # Errors
+///
+/// A [GraphMLError::IoError] is returned if writing fails.
+///
+pub fn write_graph<'graph, G: GraphViewOps<'graph>>(
+    graph: &G,
+    mut writer: impl Write,
+) -> Result<(), GraphMLError> {
+    let mut node_keys = Keys::default();
+    let mut edge_keys = Keys::default();
+    let nodes: Vec<Element> = graph
+        .nodes()
+        .iter()
+        .map(|node| {
+            let props = node.properties().as_vec();
+            for (name, prop) in &props {
+                node_keys.observe("n", name, prop);
+            }
+            Element {
+                ids: vec![node.id().to_string()],
+                time: node.earliest_time().unwrap_or(0),
+                props,
+            }
+        })
+        .collect();
+    let edges: Vec<Element> = graph
+        .edges()
+        .explode()
+        .iter()
+        .map(|edge| {
+            let props = edge.properties().as_vec();
+            for (name, prop) in &props {
+                edge_keys.observe("e", name, prop);
+            }
+            Element {
+                ids: vec![edge.src().id().to_string(), edge.dst().id().to_string()],
+                time: edge.time().unwrap_or(0),
+                props,
+            }
+        })
+        .collect();
+
+    writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(
+        writer,
+        "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">"
+    )?;
+    writeln!(
+        writer,
+        "  <key id=\"{}\" for=\"all\" attr.name=\"{}\" attr.type=\"long\"/>",
+        TIMESTAMP, TIMESTAMP
+    )?;
+    node_keys.write(&mut writer, "node")?;
+    edge_keys.write(&mut writer, "edge")?;
+    writeln!(writer, "  <graph edgedefault=\"directed\">")?;
+    for node in nodes {
+        writeln!(writer, "    <node id=\"{}\">", escape(node.ids[0].as_str()))?;
+        write_data(&mut writer, &node_keys, node.time, &node.props)?;
+        writeln!(writer, "    </node>")?;
+    }
+    for edge in edges {
+        writeln!(
+            writer,
+            "    <edge source=\"{}\" target=\"{}\">",
+            escape(edge.ids[0].as_str()),
+            escape(edge.ids[1].as_str())
+        )?;
+        write_data(&mut writer, &edge_keys, edge.time, &edge.props)?;
+        writeln!(writer, "    </edge>")?;
+    }
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")?;
+    Ok(())
+}
+
+/// A node or edge read from the document, the values are parsed once all keys are known
+#[derive(Default)]
+struct RawElement {
+    ids: Vec<String>,
+    time: Option<String>,
+    data: Vec<(String, String)>,
+}
+
+fn attribute(element: &BytesStart, name: &[u8]) -> Result<Option<String>, GraphMLError> {
+    for attr in element.attributes() {
+        let attr = attr?;
+        if attr.key.local_name().as_ref() == name {
+            return Ok(Some(attr.unescape_value()?.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+fn required_attribute(element: &BytesStart, name: &str) -> Result<String, GraphMLError> {
+    attribute(element, name.as_bytes())?.ok_or_else(|| {
+        GraphMLError::InvalidFormat(format!(
+            "<{}> is missing the {} attribute",
+            String::from_utf8_lossy(element.local_name().as_ref()),
+            name
+        ))
+    })
+}
+
+/// Read a GraphML document into a new graph.
+///
+/// Every `<node>` and `<edge>` is added at the time in its `timestamp` data (or attribute),
+/// defaulting to 0, with all its other data as properties. `int` and `long` values are read as
+/// `i64`, `float` and `double` values as `f64`. Edges always point from `source` to `target`.
+/// Node ids are read as integers if all of them are integers and as strings otherwise.
+///
+/// # Arguments
+///
+/// * `reader` - The source of the document.
+///
+/// Returns:
+///
+/// The graph described by the document.
+///
+/// # Errors
+///
+/// A [GraphMLError] is returned if the document cannot be read or parsed, if it uses undeclared
+/// keys or if a value does not match the type of its key.
+///
+pub fn read_graph(reader: impl Read) -> Result<Graph, GraphMLError> {
+    let mut reader = Reader::from_reader(BufReader::new(reader));
+    reader.config_mut().trim_text(true);
+
+    // key id -> (property name, graphml type)
+    let mut keys: HashMap<String, (String, String)> = HashMap::new();
+    let mut nodes: Vec<RawElement> = vec![];
+    let mut edges: Vec<RawElement> = vec![];
+    let mut current: Option<(bool, RawElement)> = None;
+    let mut data: Option<(String, String)> = None;
+
+    let mut buf = vec![];
+    loop {
+        let event = reader.read_event_into(&mut buf)?;
+        match &event {
+            Event::Start(element) | Event::Empty(element) => {
+                let is_empty = matches!(event, Event::Empty(_));
+                match element.local_name().as_ref() {
+                    b"key" => {
+                        let id = required_attribute(element, "id")?;
+                        let name = attribute(element, b"attr.name")?.unwrap_or_else(|| id.clone());
+                        let dtype =
+                            attribute(element, b"attr.type")?.unwrap_or_else(|| "string".into());
+                        keys.insert(id, (name, dtype));
+                    }
+                    tag @ (b"node" | b"edge") => {
+                        let is_node = tag == b"node";
+                        let ids = if is_node {
+                            vec![required_attribute(element, "id")?]
+                        } else {
+                            vec![
+                                required_attribute(element, "source")?,
+                                required_attribute(element, "target")?,
+                            ]
+                        };
+                        let element = RawElement {
+                            ids,
+                            time: attribute(element, TIMESTAMP.as_bytes())?,
+                            data: vec![],
+                        };
+                        if is_empty {
+                            if is_node {
+                                nodes.push(element)
+                            } else {
+                                edges.push(element)
+                            }
+                        } else {
+                            current = Some((is_node, element));
+                        }
+                    }
+                    b"data" => {
+                        let key = required_attribute(element, "key")?;
+                        if is_empty {
+                            if let Some((_, element)) = current.as_mut() {
+                                element.data.push((key, String::new()));
+                            }
+                        } else {
+                            data = Some((key, String::new()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(text) => {
+                if let Some((_, value)) = data.as_mut() {
+                    value.push_str(&text.unescape()?);
+                }
+            }
+            Event::CData(text) => {
+                if let Some((_, value)) = data.as_mut() {
+                    value.push_str(&String::from_utf8_lossy(text.as_ref()));
+                }
+            }
+            Event::End(element) => match element.local_name().as_ref() {
+                b"data" => {
+                    if let (Some(entry), Some((_, element))) = (data.take(), current.as_mut()) {
+                        element.data.push(entry);
+                    }
+                }
+                b"node" | b"edge" => match current.take() {
+                    Some((true, element)) => nodes.push(element),
+                    Some((false, element)) => edges.push(element),
+                    None => {}
+                },
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let numeric_ids = nodes
+        .iter()
+        .chain(&edges)
+        .flat_map(|element| &element.ids)
+        .all(|id| id.parse::<u64>().is_ok());
+    let to_gid = |id: &str| match id.parse::<u64>() {
+        Ok(id) if numeric_ids => GID::U64(id),
+        _ => GID::Str(id.to_owned()),
+    };
+    let parse = |element: RawElement| -> Result<(i64, Vec<(String, Prop)>), GraphMLError> {
+        let mut time = element.time;
+        let mut props = vec![];
+        for (key, value) in element.data {
+            let (name, dtype) = keys
+                .get(&key)
+                .ok_or_else(|| GraphMLError::InvalidFormat(format!("undeclared key {}", key)))?;
+            if name == TIMESTAMP {
+                time = Some(value);
+            } else {
+                props.push((name.clone(), parse_value(&value, dtype)?));
+            }
+        }
+        let time = match time {
+            Some(time) => time.trim().parse().map_err(|_| {
+                GraphMLError::InvalidFormat(format!("{:?} is not a valid timestamp", time))
+            })?,
+            None => 0,
+        };
+        Ok((time, props))
+    };
+
+    let graph = Graph::new();
+    for node in nodes {
+        let id = to_gid(&node.ids[0]);
+        let (time, props) = parse(node)?;
+        graph.add_node(time, id, props, None)?;
+    }
+    for edge in edges {
+        let src = to_gid(&edge.ids[0]);
+        let dst = to_gid(&edge.ids[1]);
+        let (time, props) = parse(edge)?;
+        graph.add_edge(time, src, dst, props, None)?;
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod graphml_tests {
+    use super::*;
+    use crate::db::graph::graph::assert_graph_equal;
+
+    fn round_trip(graph: &Graph) -> Graph {
+        let mut buffer = vec![];
+        write_graph(graph, &mut buffer).unwrap();
+        read_graph(buffer.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_heterogeneous_properties() {
+        let graph = Graph::new();
+        graph
+            .add_node(
+                1,
+                "alice",
+                [
+                    ("name", Prop::str("Alice <A&B>")),
+                    ("age", Prop::I64(31)),
+                    ("score", Prop::F64(0.25)),
+                    ("active", Prop::Bool(true)),
+                ],
+                None,
+            )
+            .unwrap();
+        graph
+            .add_node(2, "bob", [("age", Prop::I64(45))], None)
+            .unwrap();
+        graph
+            .add_edge(3, "alice", "bob", [("weight", Prop::F64(1.5))], None)
+            .unwrap();
+        graph
+            .add_edge(
+                4,
+                "bob",
+                "alice",
+                [("weight", Prop::F64(2.0)), ("kind", Prop::str("reply"))],
+                None,
+            )
+            .unwrap();
+        graph
+            .add_edge(5, "alice", "bob", [("weight", Prop::F64(0.5))], None)
+            .unwrap();
+
+        let restored = round_trip(&graph);
+        assert_graph_equal(&graph, &restored);
+        assert_eq!(
+            restored.node("alice").unwrap().properties().get("name"),
+            Some(Prop::str("Alice <A&B>"))
+        );
+        assert!(restored.has_edge("bob", "alice"));
+        assert_eq!(restored.edge("alice", "bob").unwrap().history(), vec![3, 5]);
+    }
+
+    #[test]
+    fn test_round_trip_integer_ids() {
+        let graph = Graph::new();
+        graph.add_edge(1, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_edge(2, 2, 3, NO_PROPS, None).unwrap();
+        graph.add_node(0, 4, NO_PROPS, None).unwrap();
+        assert_graph_equal(&graph, &round_trip(&graph));
+    }
+
+    #[test]
+    fn test_read_without_timestamps() {
+        let document = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="color" attr.type="string"/>
+  <key id="d1" for="edge" attr.name="weight" attr.type="double"/>
+  <graph id="G" edgedefault="directed">
+    <node id="n0"><data key="d0">green</data></node>
+    <node id="n1"/>
+    <edge source="n0" target="n1"><data key="d1">1.0</data></edge>
+  </graph>
+</graphml>"#;
+        let graph = read_graph(document.as_bytes()).unwrap();
+        assert_eq!(graph.count_nodes(), 2);
+        assert_eq!(graph.count_edges(), 1);
+        assert!(graph.has_edge("n0", "n1"));
+        assert!(!graph.has_edge("n1", "n0"));
+        assert_eq!(graph.earliest_time(), Some(0));
+        assert_eq!(
+            graph.node("n0").unwrap().properties().get("color"),
+            Some(Prop::str("green"))
+        );
+        assert_eq!(
+            graph.edge("n0", "n1").unwrap().properties().get("weight"),
+            Some(Prop::F64(1.0))
+        );
+    }
+
+    #[test]
+    fn test_undeclared_key() {
+        let document =
+            r#"<graphml><graph><node id="a"><data key="d9">x</data></node></graph></graphml>"#;
+        assert!(matches!(
+            read_graph(document.as_bytes()),
+            Err(GraphMLError::InvalidFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,431 @@
+//! Functions for streaming graphs as JSON Lines.
+//!
+//! Every line of the stream is a single JSON object describing one update, ordered by time:
+//!
+//! ```text
+//! {"type":"node","id":"a","time":1,"node_type":"person","props":{"age":31}}
+//! {"type":"edge","src":"a","dst":"b","time":2,"layer":"follows","props":{"weight":0.5}}
+//! ```
+//!
+//! `node_type`, `layer` and `props` are optional. Replaying the stream with [read_graph] rebuilds
+//! the graph update by update, so the format can be produced and consumed incrementally by
+//! streaming pipelines. Properties are converted to JSON, so integers are read back as `i64`,
+//! floats as `f64` and times as strings. Metadata and deletions are not part of the stream.
+//!
+//! # Example
+//! ```
+//! use raphtory::io::jsonl::{read_graph, write_graph};
+//! use raphtory::prelude::*;
+//!
+//! let g = Graph::new();
+//! g.add_edge(1, "a", "b", [("weight", 0.5)], None).unwrap();
+//!
+//! let mut buffer = vec![];
+//! write_graph(&g, &mut buffer).unwrap();
+//! let restored = read_graph(buffer.as_slice()).unwrap();
+//! assert!(restored.has_edge("a", "b"));
+//! ```
+
+use crate::{errors::GraphError, prelude::*};
+use raphtory_api::core::storage::{
+    arc_str::ArcStr,
+    timeindex::{AsTime, TimeIndexEntry},
+};
+use raphtory_storage::core_ops::CoreGraphOps;
+use serde_json::{Map, Value};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+    io::{BufRead, Write},
+};
+use tracing::warn;
+
+#[derive(Debug)]
+pub enum JsonlError {
+    /// An IO error that occurred while reading or writing the stream.
+    IoError(io::Error),
+    /// A line of the stream is not valid JSON.
+    JsonError {
+        line: usize,
+        source: serde_json::Error,
+    },
+    /// A line of the stream is valid JSON but not a valid event.
+    InvalidEvent { line: usize, reason: String },
+    /// A GraphError that occurred while replaying an event.
+    GraphError(GraphError),
+}
+
+impl From<io::Error> for JsonlError {
+    fn from(value: io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
+
+impl From<GraphError> for JsonlError {
+    fn from(value: GraphError) -> Self {
+        Self::GraphError(value)
+    }
+}
+
+impl Display for JsonlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonlError::JsonError { line, source } => {
+                write!(f, "JSON Lines loader failed on line {}: {}", line, source)
+            }
+            JsonlError::InvalidEvent { line, reason } => {
+                write!(f, "JSON Lines loader failed on line {}: {}", line, reason)
+            }
+            _ => match self.source() {
+                Some(error) => write!(f, "JSON Lines loader failed with error: {}", error),
+                None => write!(f, "JSON Lines loader failed with unknown error"),
+            },
+        }
+    }
+}
+
+impl Error for JsonlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            JsonlError::IoError(error) => Some(error),
+            JsonlError::JsonError { source, .. } => Some(source),
+            JsonlError::GraphError(error) => Some(error),
+            JsonlError::InvalidEvent { .. } => None,
+        }
+    }
+}
+
+fn gid_to_json(id: GID) -> Value {
+    match id {
+        GID::U64(id) => Value::from(id),
+        GID::Str(id) => Value::from(id),
+    }
+}
+
+fn props_to_json(props: impl IntoIterator<Item = (ArcStr, Prop)>) -> Value {
+    Value::Object(
+        props
+            .into_iter()
+            .map(|(name, prop)| (name.to_string(), Value::from(prop)))
+            .collect(),
+    )
+}
+
+/// Write all node and edge updates of a graph as JSON Lines, ordered by time.
+///
+/// Updates with the same timestamp are written in the order they were added to the graph.
+///
+/// # Arguments
+///
+/// * `graph` - The graph (or graph view) to write.
+/// * `writer` - Where the stream is written to.
+///
+/// Returns:
+///
+/// A Result containing an empty Ok value if the graph is written successfully.
+///
+/// # Errors
+///
+/// A [JsonlError::IoError] is returned if writing fails.
+///
+pub fn write_graph<'graph, G: GraphViewOps<'graph>>(
+    graph: &G,
+    mut writer: impl Write,
+) -> Result<(), JsonlError> {
+    let prop_mapper = graph.node_meta().temporal_prop_mapper();
+    let mut events: Vec<(TimeIndexEntry, Value)> = vec![];
+    for node in graph.nodes() {
+        let mut node_type = node.node_type();
+        for (t, props) in node.rows() {
+            let mut event = Map::new();
+            event.insert("type".into(), "node".into());
+            event.insert("id".into(), gid_to_json(node.id()));
+            event.insert("time".into(), t.t().into());
+            if let Some(node_type) = node_type.take() {
+                event.insert("node_type".into(), node_type.to_string().into());
+            }
+            let props = props
+                .into_iter()
+                .map(|(id, prop)| (prop_mapper.get_name(id), prop));
+            event.insert("props".into(), props_to_json(props));
+            events.push((t, Value::Object(event)));
+        }
+    }
+    for edge in graph.edges().explode() {
+        let t = edge.time_and_index()?;
+        let mut event = Map::new();
+        event.insert("type".into(), "edge".into());
+        event.insert("src".into(), gid_to_json(edge.src().id()));
+        event.insert("dst".into(), gid_to_json(edge.dst().id()));
+        event.insert("time".into(), t.t().into());
+        let layer = edge.layer_name()?;
+        if layer != "_default" {
+            event.insert("layer".into(), layer.to_string().into());
+        }
+        event.insert("props".into(), props_to_json(edge.properties().as_vec()));
+        events.push((t, Value::Object(event)));
+    }
+    events.sort_by_key(|(t, _)| *t);
+
+    for (_, event) in events {
+        serde_json::to_writer(&mut writer, &event).map_err(io::Error::from)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+struct Event {
+    line: usize,
+    fields: Map<String, Value>,
+}
+
+impl Event {
+    fn invalid(&self, reason: String) -> JsonlError {
+        JsonlError::InvalidEvent {
+            line: self.line,
+            reason,
+        }
+    }
+
+    fn id(&self, field: &str) -> Result<GID, JsonlError> {
+        match self.fields.get(field) {
+            Some(Value::String(id)) => Ok(GID::Str(id.clone())),
+            Some(Value::Number(id)) => id
+                .as_u64()
+                .map(GID::U64)
+                .ok_or_else(|| self.invalid(format!("{} is not a valid node id", id))),
+            Some(value) => Err(self.invalid(format!("{} is not a valid node id", value))),
+            None => Err(self.invalid(format!("missing field \"{}\"", field))),
+        }
+    }
+
+    fn time(&self) -> Result<i64, JsonlError> {
+        self.fields
+            .get("time")
+            .and_then(|time| time.as_i64())
+            .ok_or_else(|| self.invalid("missing or invalid field \"time\"".to_owned()))
+    }
+
+    fn optional_str(&self, field: &str) -> Result<Option<&str>, JsonlError> {
+        match self.fields.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(value)) => Ok(Some(value)),
+            Some(value) => Err(self.invalid(format!("{} is not a valid {}", value, field))),
+        }
+    }
+
+    fn props(&self) -> Result<Vec<(String, Prop)>, JsonlError> {
+        match self.fields.get("props") {
+            None | Some(Value::Null) => Ok(vec![]),
+            Some(Value::Object(props)) => props
+                .iter()
+                .map(|(name, value)| {
+                    let prop = Prop::try_from(value.clone())
+                        .map_err(|reason| self.invalid(format!("property {}: {}", name, reason)))?;
+                    Ok((name.clone(), prop))
+                })
+                .collect(),
+            Some(value) => Err(self.invalid(format!("{} is not a valid props map", value))),
+        }
+    }
+}
+
+/// Replay a JSON Lines stream of node and edge updates into a new graph.
+///
+/// Every `"node"` event adds a node update and every `"edge"` event adds an edge update, in the
+/// order they appear in the stream. Empty lines are ignored and events with an unknown `"type"`
+/// are skipped with a warning.
+///
+/// # Arguments
+///
+/// * `reader` - The source of the stream.
+///
+/// Returns:
+///
+/// The graph described by the stream.
+///
+/// # Errors
+///
+/// A [JsonlError] is returned if the stream cannot be read, if a line is not a JSON object with
+/// the fields required by its type or if an update cannot be added to the graph.
+///
+pub fn read_graph(reader: impl BufRead) -> Result<Graph, JsonlError> {
+    let graph = Graph::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value: Value = serde_json::from_str(&line).map_err(|source| JsonlError::JsonError {
+            line: line_number,
+            source,
+        })?;
+        let Value::Object(fields) = value else {
+            return Err(JsonlError::InvalidEvent {
+                line: line_number,
+                reason: "expected a JSON object".to_owned(),
+            });
+        };
+        let event = Event {
+            line: line_number,
+            fields,
+        };
+        match event.fields.get("type").and_then(|t| t.as_str()) {
+            Some("node") => {
+                graph.add_node(
+                    event.time()?,
+                    event.id("id")?,
+                    event.props()?,
+                    event.optional_str("node_type")?,
+                )?;
+            }
+            Some("edge") => {
+                graph.add_edge(
+                    event.time()?,
+                    event.id("src")?,
+                    event.id("dst")?,
+                    event.props()?,
+                    event.optional_str("layer")?,
+                )?;
+            }
+            event_type => {
+                warn!(
+                    "Skipping line {} with unknown event type {:?}",
+                    line_number, event_type
+                );
+            }
+        }
+    }
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod jsonl_tests {
+    use super::*;
+    use crate::db::graph::graph::assert_graph_equal;
+
+    fn round_trip(graph: &Graph) -> Graph {
+        let mut buffer = vec![];
+        write_graph(graph, &mut buffer).unwrap();
+        read_graph(buffer.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let graph = Graph::new();
+        graph
+            .add_node(
+                0,
+                "alice",
+                [("age", Prop::I64(31)), ("name", Prop::str("Alice"))],
+                Some("person"),
+            )
+            .unwrap();
+        graph
+            .add_node(3, "alice", [("age", Prop::I64(32))], None)
+            .unwrap();
+        graph
+            .add_edge(1, "alice", "bob", [("weight", Prop::F64(0.5))], None)
+            .unwrap();
+        graph
+            .add_edge(
+                2,
+                "bob",
+                "alice",
+                [("active", Prop::Bool(true))],
+                Some("follows"),
+            )
+            .unwrap();
+        graph
+            .add_edge(2, "alice", "carol", NO_PROPS, Some("follows"))
+            .unwrap();
+
+        let restored = round_trip(&graph);
+        assert_graph_equal(&graph, &restored);
+        assert_eq!(
+            restored.node("alice").unwrap().node_type().as_deref(),
+            Some("person")
+        );
+        assert_eq!(
+            restored
+                .node("alice")
+                .unwrap()
+                .properties()
+                .temporal()
+                .get("age")
+                .unwrap()
+                .values()
+                .collect::<Vec<_>>(),
+            vec![Prop::I64(31), Prop::I64(32)]
+        );
+    }
+
+    #[test]
+    fn test_events_are_ordered_by_time() {
+        let graph = Graph::new();
+        graph.add_edge(5, 1, 2, NO_PROPS, None).unwrap();
+        graph.add_node(1, 3, NO_PROPS, None).unwrap();
+        graph.add_edge(3, 2, 3, NO_PROPS, None).unwrap();
+
+        let mut buffer = vec![];
+        write_graph(&graph, &mut buffer).unwrap();
+        let times: Vec<i64> = String::from_utf8(buffer.clone())
+            .unwrap()
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<Value>(line).unwrap()["time"]
+                    .as_i64()
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(times, vec![1, 3, 5]);
+        assert_graph_equal(&graph, &read_graph(buffer.as_slice()).unwrap());
+    }
+
+    #[test]
+    fn test_interleaved_events_match_sequential_events() {
+        let sequential = r#"{"type":"node","id":1,"time":1,"props":{"name":"a"}}
+{"type":"node","id":2,"time":2,"props":{"name":"b"}}
+{"type":"node","id":3,"time":3}
+{"type":"edge","src":1,"dst":2,"time":4,"props":{"weight":1.5}}
+{"type":"edge","src":2,"dst":3,"time":5}"#;
+        let interleaved = r#"{"type":"node","id":1,"time":1,"props":{"name":"a"}}
+{"type":"edge","src":1,"dst":2,"time":4,"props":{"weight":1.5}}
+{"type":"node","id":3,"time":3}
+
+{"type":"edge","src":2,"dst":3,"time":5}
+{"type":"node","id":2,"time":2,"props":{"name":"b"}}"#;
+        let sequential = read_graph(sequential.as_bytes()).unwrap();
+        let interleaved = read_graph(interleaved.as_bytes()).unwrap();
+        assert_eq!(sequential.count_nodes(), 3);
+        assert_eq!(sequential.count_edges(), 2);
+        assert_graph_equal(&sequential, &interleaved);
+    }
+
+    #[test]
+    fn test_unknown_event_type_is_skipped() {
+        let stream = r#"{"type":"node","id":"a","time":1}
+{"type":"deletion","src":"a","dst":"b","time":2}
+{"type":"edge","src":"a","dst":"b","time":3}"#;
+        let graph = read_graph(stream.as_bytes()).unwrap();
+        assert_eq!(graph.count_nodes(), 2);
+        assert_eq!(graph.count_temporal_edges(), 1);
+    }
+
+    #[test]
+    fn test_invalid_event() {
+        let stream = r#"{"type":"node","id":"a","time":1}
+{"type":"edge","src":"a","time":2}"#;
+        assert!(matches!(
+            read_graph(stream.as_bytes()),
+            Err(JsonlError::InvalidEvent { line: 2, .. })
+        ));
+        assert!(matches!(
+            read_graph("not json".as_bytes()),
+            Err(JsonlError::JsonError { line: 1, .. })
+        ));
+    }
+}
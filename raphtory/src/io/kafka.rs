@@ -0,0 +1,455 @@
+//! Functions for building graphs from Apache Kafka topics.
+//!
+//! Every message on the topic describes one node or edge update. The payload is a JSON object and
+//! a [KafkaSchema] maps its fields (or the message key) to the fields of the update, e.g., with
+//! `KafkaSchema::edges("transfers", KafkaField::Key, KafkaField::value("dst"))` the message
+//!
+//! ```text
+//! key: alice, payload: {"dst":"bob","time":2,"amount":10}
+//! ```
+//!
+//! adds an edge from `alice` to `bob` at time 2. If the schema has no time field, the timestamp of
+//! the message in milliseconds is used instead. Numeric ids in the payload are read as integer
+//! node ids and all other ids as strings.
+//!
+//! # Example
+//! ```no_run
+//! use futures_util::StreamExt;
+//! use raphtory::io::kafka::{KafkaField, KafkaGraphSource, KafkaSchema};
+//! use raphtory::prelude::*;
+//! use rdkafka::ClientConfig;
+//! use std::sync::Arc;
+//!
+//! # async fn run() {
+//! let source = KafkaGraphSource::from_config(
+//!     ClientConfig::new()
+//!         .set("bootstrap.servers", "localhost:9092")
+//!         .set("group.id", "raphtory"),
+//! )
+//! .unwrap();
+//! let graph = Arc::new(Graph::new());
+//! let schema = KafkaSchema::edges("transfers", KafkaField::Key, KafkaField::value("dst"))
+//!     .with_time("time")
+//!     .with_properties(["amount"]);
+//! let mut events = source.stream_to_graph(graph.clone(), schema);
+//! while let Some(event) = events.next().await {
+//!     println!("{:?}", event);
+//! }
+//! # }
+//! ```
+
+use crate::{db::graph::graph::GraphEvent, errors::GraphError, prelude::*};
+use futures_util::{future, stream, Stream, StreamExt};
+use rdkafka::{
+    consumer::{Consumer, StreamConsumer},
+    error::KafkaError,
+    ClientConfig, Message,
+};
+use serde_json::{Map, Value};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+
+#[derive(Debug)]
+pub enum KafkaLoaderError {
+    /// An error returned by the Kafka client.
+    KafkaError(KafkaError),
+    /// The payload of a message is not valid JSON.
+    JsonError {
+        offset: i64,
+        source: serde_json::Error,
+    },
+    /// The message is valid JSON but does not contain the fields required by the schema.
+    InvalidMessage { offset: i64, reason: String },
+    /// A GraphError that occurred while adding the update to the graph.
+    GraphError(GraphError),
+}
+
+impl From<KafkaError> for KafkaLoaderError {
+    fn from(value: KafkaError) -> Self {
+        Self::KafkaError(value)
+    }
+}
+
+impl From<GraphError> for KafkaLoaderError {
+    fn from(value: GraphError) -> Self {
+        Self::GraphError(value)
+    }
+}
+
+impl Display for KafkaLoaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KafkaLoaderError::JsonError { offset, source } => {
+                write!(f, "Kafka loader failed on offset {}: {}", offset, source)
+            }
+            KafkaLoaderError::InvalidMessage { offset, reason } => {
+                write!(f, "Kafka loader failed on offset {}: {}", offset, reason)
+            }
+            _ => match self.source() {
+                Some(error) => write!(f, "Kafka loader failed with error: {}", error),
+                None => write!(f, "Kafka loader failed with unknown error"),
+            },
+        }
+    }
+}
+
+impl Error for KafkaLoaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KafkaLoaderError::KafkaError(error) => Some(error),
+            KafkaLoaderError::JsonError { source, .. } => Some(source),
+            KafkaLoaderError::GraphError(error) => Some(error),
+            KafkaLoaderError::InvalidMessage { .. } => None,
+        }
+    }
+}
+
+/// Where the value of a field of an update is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KafkaField {
+    /// The key of the message, read as a string
+    Key,
+    /// A field of the JSON payload of the message
+    Value(String),
+}
+
+impl KafkaField {
+    pub fn value(name: impl Into<String>) -> Self {
+        Self::Value(name.into())
+    }
+}
+
+/// Maps the messages of a topic to node or edge updates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KafkaSchema {
+    /// The topic to consume
+    pub topic: String,
+    /// The node id for node updates or the source node id for edge updates
+    pub src: KafkaField,
+    /// The destination node id, `None` for node updates
+    pub dst: Option<KafkaField>,
+    /// The payload field containing the time of the update, `None` to use the message timestamp
+    pub time: Option<String>,
+    /// The layer of edge updates
+    pub layer: Option<String>,
+    /// The node type of node updates
+    pub node_type: Option<String>,
+    /// The payload fields added as properties of the update, missing fields are ignored
+    pub properties: Vec<String>,
+}
+
+impl KafkaSchema {
+    /// A schema that adds a node update with id `id` for every message
+    pub fn nodes(topic: impl Into<String>, id: KafkaField) -> Self {
+        Self {
+            topic: topic.into(),
+            src: id,
+            dst: None,
+            time: None,
+            layer: None,
+            node_type: None,
+            properties: vec![],
+        }
+    }
+
+    /// A schema that adds an edge update from `src` to `dst` for every message
+    pub fn edges(topic: impl Into<String>, src: KafkaField, dst: KafkaField) -> Self {
+        Self {
+            dst: Some(dst),
+            ..Self::nodes(topic, src)
+        }
+    }
+
+    pub fn with_time(self, field: impl Into<String>) -> Self {
+        Self {
+            time: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn with_layer(self, layer: impl Into<String>) -> Self {
+        Self {
+            layer: Some(layer.into()),
+            ..self
+        }
+    }
+
+    pub fn with_node_type(self, node_type: impl Into<String>) -> Self {
+        Self {
+            node_type: Some(node_type.into()),
+            ..self
+        }
+    }
+
+    pub fn with_properties<I: IntoIterator<Item = S>, S: Into<String>>(self, fields: I) -> Self {
+        Self {
+            properties: fields.into_iter().map(|field| field.into()).collect(),
+            ..self
+        }
+    }
+
+    /// Add the update described by `message` to `graph`
+    fn apply(
+        &self,
+        graph: &Graph,
+        message: &impl Message,
+    ) -> Result<(i64, GraphEvent), KafkaLoaderError> {
+        let offset = message.offset();
+        let invalid = |reason: String| KafkaLoaderError::InvalidMessage { offset, reason };
+        let fields = match message.payload() {
+            None => Map::new(),
+            Some(payload) => match serde_json::from_slice(payload) {
+                Ok(Value::Object(fields)) => fields,
+                Ok(_) => return Err(invalid("expected a JSON object".to_owned())),
+                Err(source) => return Err(KafkaLoaderError::JsonError { offset, source }),
+            },
+        };
+
+        let id = |field: &KafkaField| match field {
+            KafkaField::Key => message
+                .key()
+                .and_then(|key| std::str::from_utf8(key).ok())
+                .map(|key| GID::Str(key.to_owned()))
+                .ok_or_else(|| invalid("missing or invalid message key".to_owned())),
+            KafkaField::Value(name) => match fields.get(name) {
+                Some(Value::String(id)) => Ok(GID::Str(id.clone())),
+                Some(Value::Number(id)) => id
+                    .as_u64()
+                    .map(GID::U64)
+                    .ok_or_else(|| invalid(format!("{} is not a valid node id", id))),
+                Some(value) => Err(invalid(format!("{} is not a valid node id", value))),
+                None => Err(invalid(format!("missing field \"{}\"", name))),
+            },
+        };
+        let time = match &self.time {
+            Some(name) => fields
+                .get(name)
+                .and_then(|time| time.as_i64())
+                .ok_or_else(|| invalid(format!("missing or invalid field \"{}\"", name)))?,
+            None => message
+                .timestamp()
+                .to_millis()
+                .ok_or_else(|| invalid("message has no timestamp".to_owned()))?,
+        };
+        let props = self
+            .properties
+            .iter()
+            .filter_map(|name| match fields.get(name) {
+                None | Some(Value::Null) => None,
+                Some(value) => Some(
+                    Prop::try_from(value.clone())
+                        .map(|prop| (name.clone(), prop))
+                        .map_err(|reason| invalid(format!("property {}: {}", name, reason))),
+                ),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let src = id(&self.src)?;
+        match &self.dst {
+            None => {
+                graph.add_node(time, &src, props.clone(), self.node_type.as_deref())?;
+                Ok((time, GraphEvent::AddNode { id: src, props }))
+            }
+            Some(dst) => {
+                let dst = id(dst)?;
+                graph.add_edge(time, &src, &dst, props.clone(), self.layer.as_deref())?;
+                Ok((
+                    time,
+                    GraphEvent::AddEdge {
+                        src,
+                        dst,
+                        props,
+                        layer: self.layer.clone(),
+                    },
+                ))
+            }
+        }
+    }
+}
+
+/// Adds the messages of a Kafka topic to a graph as they arrive
+pub struct KafkaGraphSource {
+    consumer: StreamConsumer,
+}
+
+impl KafkaGraphSource {
+    pub fn new(consumer: StreamConsumer) -> Self {
+        Self { consumer }
+    }
+
+    /// Create a source with a new consumer, `config` needs to set at least `bootstrap.servers`
+    /// and `group.id`
+    pub fn from_config(config: &ClientConfig) -> Result<Self, KafkaLoaderError> {
+        Ok(Self::new(config.create()?))
+    }
+
+    /// Subscribe to the topic of `schema` and add every message to `graph`.
+    ///
+    /// The returned stream yields the time and the update added for every message, in the order
+    /// the messages are consumed, and does not end on its own. The updates can be replayed into
+    /// another graph with [Graph::replay]. Messages that do not match the schema yield an
+    /// error and are skipped, so the stream can continue to be polled.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The graph the updates are added to.
+    /// * `schema` - The topic and the mapping of its messages to updates.
+    ///
+    /// Returns:
+    ///
+    /// The stream of updates, the stream only yields an error if subscribing to the topic fails.
+    ///
+    pub fn stream_to_graph(
+        &self,
+        graph: Arc<Graph>,
+        schema: KafkaSchema,
+    ) -> impl Stream<Item = Result<(i64, GraphEvent), KafkaLoaderError>> + '_ {
+        let messages = match self.consumer.subscribe(&[schema.topic.as_str()]) {
+            Ok(()) => future::Either::Left(self.consumer.stream()),
+            Err(error) => future::Either::Right(stream::once(future::ready(Err(error)))),
+        };
+        messages.map(move |message| {
+            let message = message?;
+            schema.apply(&graph, &message)
+        })
+    }
+}
+
+#[cfg(test)]
+mod kafka_tests {
+    use super::*;
+    use rdkafka::{
+        mocking::MockCluster,
+        producer::{FutureProducer, FutureRecord},
+    };
+    use std::time::Duration;
+
+    async fn produce(
+        cluster: &MockCluster<'_, impl rdkafka::ClientContext>,
+        messages: &[(&str, &str)],
+    ) {
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", cluster.bootstrap_servers())
+            .create()
+            .unwrap();
+        for (key, payload) in messages {
+            producer
+                .send(
+                    FutureRecord::to("transfers").key(*key).payload(*payload),
+                    Duration::from_secs(5),
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    fn source(cluster: &MockCluster<'_, impl rdkafka::ClientContext>) -> KafkaGraphSource {
+        KafkaGraphSource::from_config(
+            ClientConfig::new()
+                .set("bootstrap.servers", cluster.bootstrap_servers())
+                .set("group.id", "raphtory")
+                .set("auto.offset.reset", "earliest"),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_messages_become_edges() {
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic("transfers", 1, 1).unwrap();
+        produce(
+            &cluster,
+            &[
+                ("alice", r#"{"dst":"bob","time":1,"amount":10}"#),
+                ("bob", r#"{"dst":"carol","time":2,"amount":5.5}"#),
+                ("carol", "not json"),
+                ("alice", r#"{"dst":"bob","time":3}"#),
+            ],
+        )
+        .await;
+
+        let graph = Arc::new(Graph::new());
+        let schema = KafkaSchema::edges("transfers", KafkaField::Key, KafkaField::value("dst"))
+            .with_time("time")
+            .with_layer("transfers")
+            .with_properties(["amount"]);
+        let source = source(&cluster);
+        let events: Vec<_> = source
+            .stream_to_graph(graph.clone(), schema)
+            .take(4)
+            .collect()
+            .await;
+
+        assert!(matches!(
+            events[2],
+            Err(KafkaLoaderError::JsonError { offset: 2, .. })
+        ));
+        assert_eq!(
+            events[3].as_ref().unwrap(),
+            &(
+                3,
+                GraphEvent::AddEdge {
+                    src: GID::Str("alice".to_owned()),
+                    dst: GID::Str("bob".to_owned()),
+                    props: vec![],
+                    layer: Some("transfers".to_owned()),
+                }
+            )
+        );
+        assert_eq!(graph.count_nodes(), 3);
+        assert_eq!(graph.count_edges(), 2);
+        let edge = graph.edge("alice", "bob").unwrap();
+        assert_eq!(edge.history(), vec![1, 3]);
+        assert_eq!(edge.layer_names(), ["transfers"]);
+        assert_eq!(edge.properties().get("amount"), Some(Prop::I64(10)));
+        assert_eq!(
+            graph
+                .edge("bob", "carol")
+                .unwrap()
+                .properties()
+                .get("amount"),
+            Some(Prop::F64(5.5))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_messages_become_nodes() {
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic("transfers", 1, 1).unwrap();
+        produce(
+            &cluster,
+            &[("1", r#"{"id":1,"name":"a"}"#), ("2", r#"{"name":"b"}"#)],
+        )
+        .await;
+
+        let graph = Arc::new(Graph::new());
+        let schema = KafkaSchema::nodes("transfers", KafkaField::value("id"))
+            .with_node_type("account")
+            .with_properties(["name"]);
+        let source = source(&cluster);
+        let events: Vec<_> = source
+            .stream_to_graph(graph.clone(), schema)
+            .take(2)
+            .collect()
+            .await;
+
+        // without a time field the message timestamp is used
+        assert!(matches!(
+            events[0],
+            Ok((time, GraphEvent::AddNode {
+                id: GID::U64(1),
+                ref props,
+            })) if time > 0 && props == &[("name".to_owned(), Prop::str("a"))]
+        ));
+        assert!(matches!(
+            events[1],
+            Err(KafkaLoaderError::InvalidMessage { offset: 1, .. })
+        ));
+        let node = graph.node(1).unwrap();
+        assert_eq!(node.node_type().as_deref(), Some("account"));
+        assert_eq!(node.properties().get("name"), Some(Prop::str("a")));
+    }
+}
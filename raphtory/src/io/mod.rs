@@ -1,7 +1,13 @@
 #[cfg(feature = "arrow")]
 pub(crate) mod arrow;
+pub mod csv;
 pub mod csv_loader;
+pub mod gml;
+pub mod graphml;
 pub mod json_loader;
+pub mod jsonl;
+#[cfg(feature = "kafka")]
+pub mod kafka;
 pub mod neo4j_loader;
 #[cfg(feature = "arrow")]
 pub mod parquet_loaders;
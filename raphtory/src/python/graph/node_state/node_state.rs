@@ -22,6 +22,7 @@ use crate::{
     },
 };
 use chrono::{DateTime, Utc};
+use numpy::{IntoPyArray, Ix1, PyArray};
 use pyo3::{
     exceptions::{PyKeyError, PyTypeError},
     prelude::*,
@@ -292,6 +293,23 @@ macro_rules! impl_node_state_ord_ops {
     };
 }
 
+fn node_ids_to_numpy<'py>(
+    py: Python<'py>,
+    ids: impl Iterator<Item = GID>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let ids: Vec<_> = ids.collect();
+    match ids.iter().map(|id| id.as_u64()).collect::<Option<Vec<_>>>() {
+        Some(ids) => Ok(ids.into_pyarray(py).into_any()),
+        None => {
+            let ids = ids
+                .into_iter()
+                .map(|id| id.into_py_any(py))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyArray::from_vec(py, ids).into_any())
+        }
+    }
+}
+
 macro_rules! impl_node_state_num_ops {
     ($name:ident, $value:ty, $py_value:literal) => {
         #[pymethods]
@@ -311,6 +329,28 @@ macro_rules! impl_node_state_num_ops {
             fn mean(&self) -> f64 {
                 self.inner.mean()
             }
+
+            /// Convert the values to a numpy array
+            ///
+            /// The values are in the same order as the ids returned by `node_ids()`, i.e., the
+            /// value at position `i` belongs to the `i`-th node of the node state.
+            ///
+            /// Returns:
+            ///     np.ndarray: one-dimensional array of the values
+            fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray<$value, Ix1>> {
+                self.iter().collect::<Vec<_>>().into_pyarray(py)
+            }
+
+            /// The ids of the nodes as a numpy array
+            ///
+            /// Integer ids are returned as an array of unsigned integers and string ids as an
+            /// array of objects.
+            ///
+            /// Returns:
+            ///     np.ndarray: one-dimensional array of the node ids
+            fn node_ids<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+                node_ids_to_numpy(py, self.inner.nodes().id().iter_values())
+            }
         }
     };
 }
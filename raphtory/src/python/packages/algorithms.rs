@@ -839,18 +839,20 @@ pub fn temporal_SEIR(
 ///     resolution (float): the resolution parameter for modularity. Defaults to 1.0.
 ///     weight_prop (str | None): the edge property to use for weights (has to be float)
 ///     tol (None | float): the floating point tolerance for deciding if improvements are significant (default: 1e-8)
+///     seed (bytes, optional): Array of 32 bytes of u8 which is set as the rng seed
 ///
 /// Returns:
 ///     NodeStateUsize: Mapping of nodes to their community assignment
 #[pyfunction]
-#[pyo3[signature=(graph, resolution=1.0, weight_prop=None, tol=None)]]
+#[pyo3[signature=(graph, resolution=1.0, weight_prop=None, tol=None, seed=None)]]
 pub fn louvain(
     graph: &PyGraphView,
     resolution: f64,
     weight_prop: Option<&str>,
     tol: Option<f64>,
+    seed: Option<[u8; 32]>,
 ) -> NodeState<'static, usize, DynamicGraph> {
-    louvain_rs::<ModularityUnDir, _>(&graph.graph, resolution, weight_prop, tol)
+    louvain_rs::<ModularityUnDir, _>(&graph.graph, resolution, weight_prop, tol, seed)
 }
 
 /// Fruchterman Reingold layout algorithm
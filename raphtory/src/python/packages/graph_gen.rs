@@ -5,7 +5,9 @@ use crate::{
     graphgen::{
         preferential_attachment::ba_preferential_attachment as pa,
         random_attachment::random_attachment as ra,
+        TimestampStrategy,
     },
+    errors::GraphError,
     python::graph::graph::PyGraph,
 };
 use pyo3::prelude::*;
@@ -31,8 +33,14 @@ pub fn random_attachment(
     nodes_to_add: usize,
     edges_per_step: usize,
     seed: Option<[u8; 32]>,
-) {
-    ra(&g.graph, nodes_to_add, edges_per_step, seed);
+) -> Result<(), GraphError> {
+    ra(
+        &g.graph,
+        nodes_to_add,
+        edges_per_step,
+        TimestampStrategy::Sequential,
+        seed,
+    )
 }
 
 /// Generates a graph using the preferential attachment model.
@@ -64,6 +72,12 @@ pub fn ba_preferential_attachment(
     nodes_to_add: usize,
     edges_per_step: usize,
     seed: Option<[u8; 32]>,
-) {
-    pa(&g.graph, nodes_to_add, edges_per_step, seed);
+) -> Result<(), GraphError> {
+    pa(
+        &g.graph,
+        nodes_to_add,
+        edges_per_step,
+        TimestampStrategy::Sequential,
+        seed,
+    )
 }
@@ -44,7 +44,7 @@ macro_rules! impl_layerops {
                 &self,
                 name: &str,
             ) -> Result<<$base_type as LayerOps<'static>>::LayeredViewType, $crate::errors::GraphError> {
-                self.$field.exclude_layers(name)
+                self.$field.exclude_layer(name)
             }
 
             #[doc = concat!(" Return a view of ", $name, " containing all layers except the excluded `name`")]
@@ -319,7 +319,10 @@ impl<G: InternalCache + InternalStableDecode + StableEncode + AdditionOps> Cache
 
 #[cfg(test)]
 mod test {
-    use crate::serialise::{incremental::GraphWriter, GraphFolder};
+    use crate::{
+        prelude::*,
+        serialise::{incremental::GraphWriter, GraphFolder},
+    };
     use raphtory_api::core::{
         entities::{GidRef, VID},
         storage::dict_mapper::MaybeNew,
@@ -347,4 +350,36 @@ mod test {
         assert!(res.is_err());
         assert_eq!(cache.proto_delta.lock().nodes.len(), 1);
     }
+
+    #[test]
+    fn test_cached_graph_survives_reopen() {
+        let tmp_dir = TempDir::new().unwrap();
+        {
+            let graph = Graph::new();
+            graph.cache(tmp_dir.path()).unwrap();
+            graph
+                .add_edge(1, "a", "b", [("weight", 1.0)], None)
+                .unwrap();
+            graph
+                .add_edge(2, "b", "c", NO_PROPS, Some("follows"))
+                .unwrap();
+            graph.write_updates().unwrap();
+        }
+
+        let graph = Graph::load_cached(tmp_dir.path()).unwrap();
+        assert!(graph.has_edge("a", "b"));
+        assert!(graph.layers("follows").unwrap().has_edge("b", "c"));
+        assert_eq!(
+            graph.edge("a", "b").unwrap().properties().get("weight"),
+            Some(Prop::F64(1.0))
+        );
+
+        // updates after reopening are appended to the same folder
+        graph.add_edge(3, "c", "a", NO_PROPS, None).unwrap();
+        graph.write_updates().unwrap();
+        drop(graph);
+        let graph = Graph::load_cached(tmp_dir.path()).unwrap();
+        assert_eq!(graph.count_edges(), 3);
+        assert_eq!(graph.latest_time(), Some(3));
+    }
 }
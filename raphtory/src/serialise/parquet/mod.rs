@@ -45,10 +45,36 @@ mod nodes;
 
 mod graph;
 
+/// Write a graph to a directory of Parquet files.
+///
+/// Node updates, edge updates, edge deletions, metadata and graph properties are written to
+/// separate sub-directories with one column per property, so the files can also be read by
+/// other Parquet tools. The graph can be restored with [ParquetDecoder::decode_parquet].
+///
+/// # Examples
+///
+/// ```
+/// use raphtory::prelude::*;
+///
+/// let graph = Graph::new();
+/// graph.add_edge(1, 1, 2, [("weight", 0.5)], None).unwrap();
+/// graph.add_node(2, 3, [("name", "c")], None).unwrap();
+///
+/// let dir = tempfile::tempdir().unwrap();
+/// graph.encode_parquet(&dir).unwrap();
+/// let restored = Graph::decode_parquet(&dir).unwrap();
+/// assert_eq!(restored.count_nodes(), 3);
+/// assert_eq!(
+///     restored.edge(1, 2).unwrap().properties().get("weight"),
+///     Some(Prop::F64(0.5))
+/// );
+/// ```
 pub trait ParquetEncoder {
     fn encode_parquet(&self, path: impl AsRef<Path>) -> Result<(), GraphError>;
 }
 
+/// Read a graph written by [ParquetEncoder::encode_parquet], the property types are taken from
+/// the Parquet schema.
 pub trait ParquetDecoder {
     fn decode_parquet(path: impl AsRef<Path>) -> Result<Self, GraphError>
     where
@@ -91,6 +91,22 @@ pub trait InternalStableDecode: Sized {
     }
 }
 
+/// Persist a graph to a folder and keep the folder up to date with later updates, so the graph
+/// survives process restarts.
+///
+/// # Example
+/// ```no_run
+/// use raphtory::prelude::*;
+///
+/// let graph = Graph::new();
+/// graph.cache("/tmp/my_graph").unwrap();
+/// graph.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+/// graph.write_updates().unwrap();
+/// drop(graph);
+///
+/// let graph = Graph::load_cached("/tmp/my_graph").unwrap();
+/// assert!(graph.has_edge("a", "b"));
+/// ```
 pub trait CacheOps: Sized {
     /// Write graph to file and append future updates to the same file.
     ///